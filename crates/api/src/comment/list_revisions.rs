@@ -0,0 +1,58 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  comment::{CommentRevisionView, ListCommentRevisions, ListCommentRevisionsResponse},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::{comment::Comment, comment_revision::CommentRevision, post::Post},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::diff::line_diff,
+};
+use std::iter::once;
+
+/// Lists the previous versions of a comment, each with a diff against the version which replaced
+/// it. Revisions are only visible to the creator of the comment, mods of its community and admins.
+#[tracing::instrument(skip(context))]
+pub async fn list_comment_revisions(
+  data: Query<ListCommentRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCommentRevisionsResponse>> {
+  let comment = Comment::read(&mut context.pool(), data.comment_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindComment)?;
+  if comment.creator_id != local_user_view.person.id {
+    let post = Post::read(&mut context.pool(), comment.post_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPost)?;
+    is_mod_or_admin(
+      &mut context.pool(),
+      &local_user_view.person,
+      post.community_id,
+    )
+    .await?;
+  }
+
+  let revisions = CommentRevision::list_for_comment(&mut context.pool(), comment.id).await?;
+  let versions = revisions
+    .iter()
+    .map(|r| r.content.as_str())
+    .chain(once(comment.content.as_str()))
+    .collect::<Vec<_>>();
+  let revisions = revisions
+    .iter()
+    .cloned()
+    .zip(versions.iter().zip(versions.iter().skip(1)))
+    .map(|(revision, (old, new))| CommentRevisionView {
+      revision,
+      diff: line_diff(old, new),
+    })
+    .collect();
+
+  Ok(Json(ListCommentRevisionsResponse { revisions }))
+}
@@ -1,4 +1,5 @@
 pub mod distinguish;
 pub mod like;
 pub mod list_comment_likes;
+pub mod list_revisions;
 pub mod save;
@@ -8,6 +8,7 @@ use lemmy_api_common::{
   utils::{
     check_comment_deleted_or_removed,
     check_community_user_action,
+    check_rule_in_community,
     send_new_report_email_to_admins,
   },
 };
@@ -52,12 +53,15 @@ pub async fn create_comment_report(
 
   // Don't allow creating reports for removed / deleted comments
   check_comment_deleted_or_removed(&comment_view.comment)?;
+  check_rule_in_community(data.rule_id, comment_view.community.id, &mut context.pool()).await?;
 
   let report_form = CommentReportForm {
     creator_id: person_id,
     comment_id,
     original_comment_text: comment_view.comment.content,
     reason,
+    category_id: data.category_id,
+    rule_id: data.rule_id,
   };
 
   let report = CommentReport::report(&mut context.pool(), &report_form)
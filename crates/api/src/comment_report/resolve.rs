@@ -1,10 +1,15 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
+  build_response::send_local_notification,
   comment::{CommentReportResponse, ResolveCommentReport},
   context::LemmyContext,
   utils::check_community_mod_action,
 };
-use lemmy_db_schema::{source::comment_report::CommentReport, traits::Reportable};
+use lemmy_db_schema::{
+  source::{comment_report::CommentReport, notification::NotificationInsertForm},
+  traits::Reportable,
+  NotificationType,
+};
 use lemmy_db_views::structs::{CommentReportView, LocalUserView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -34,6 +39,15 @@ pub async fn resolve_comment_report(
     CommentReport::resolve(&mut context.pool(), report_id, person_id)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(report.creator.id)
+      .kind(NotificationType::ReportResolved)
+      .comment_id(Some(report.comment.id))
+      .post_id(Some(report.post.id))
+      .community_id(Some(report.community.id))
+      .build();
+    send_local_notification(notification_form, person_id, &context).await;
   } else {
     CommentReport::unresolve(&mut context.pool(), report_id, person_id)
       .await
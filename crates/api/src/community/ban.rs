@@ -1,6 +1,7 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
+  build_response::send_local_notification,
   community::{BanFromCommunity, BanFromCommunityResponse},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
@@ -16,8 +17,10 @@ use lemmy_db_schema::{
     },
     local_user::LocalUser,
     moderator::{ModBanFromCommunity, ModBanFromCommunityForm},
+    notification::NotificationInsertForm,
   },
   traits::{Bannable, Crud, Followable},
+  NotificationType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::PersonView;
@@ -101,6 +104,16 @@ pub async fn ban_from_community(
 
   ModBanFromCommunity::create(&mut context.pool(), &form).await?;
 
+  if data.ban {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(data.person_id)
+      .kind(NotificationType::ModBanFromCommunity)
+      .community_id(Some(data.community_id))
+      .reason(data.reason.clone())
+      .build();
+    send_local_notification(notification_form, local_user_view.person.id, &context).await;
+  }
+
   let person_view = PersonView::read(&mut context.pool(), data.person_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindPerson)?;
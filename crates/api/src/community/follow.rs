@@ -4,7 +4,7 @@ use lemmy_api_common::{
   community::{CommunityResponse, FollowCommunity},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_user_action,
+  utils::check_community_follow_action,
 };
 use lemmy_db_schema::{
   source::{
@@ -12,9 +12,10 @@ use lemmy_db_schema::{
     community::{Community, CommunityFollower, CommunityFollowerForm},
   },
   traits::{Crud, Followable},
+  CommunityVisibility,
 };
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
 #[tracing::instrument(skip(context))]
@@ -34,9 +35,25 @@ pub async fn follow_community(
 
   if data.follow {
     if community.local {
-      check_community_user_action(&local_user_view.person, community.id, &mut context.pool())
+      check_community_follow_action(&local_user_view.person, community.id, &mut context.pool())
         .await?;
 
+      // Follows of private communities need to be approved by a mod, unless the user is already
+      // an approved follower or a mod
+      if community.visibility == CommunityVisibility::Private {
+        let person_id = local_user_view.person.id;
+        let is_follower =
+          CommunityFollower::is_approved_follower(&mut context.pool(), community.id, person_id)
+            .await?;
+        let is_mod = CommunityModeratorView::is_community_moderator(
+          &mut context.pool(),
+          community.id,
+          person_id,
+        )
+        .await?;
+        community_follower_form.pending = !(is_follower || is_mod);
+      }
+
       CommunityFollower::follow(&mut context.pool(), &community_follower_form)
         .await
         .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
@@ -3,4 +3,5 @@ pub mod ban;
 pub mod block;
 pub mod follow;
 pub mod hide;
+pub mod pending_follows;
 pub mod transfer;
@@ -0,0 +1,73 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::ApproveCommunityPendingFollower,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    community::{Community, CommunityFollower, CommunityFollowerForm},
+    person::Person,
+  },
+  traits::{Crud, Followable},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Approve or deny a pending follow of a private community. For remote followers the decision is
+/// federated, so that their instance can update the follow state.
+#[tracing::instrument(skip(context))]
+pub async fn approve_community_pending_follow(
+  data: Json<ApproveCommunityPendingFollower>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_community_mod_action(
+    &local_user_view.person,
+    data.community_id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let community = Community::read(&mut context.pool(), data.community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+  let follower = Person::read(&mut context.pool(), data.follower_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+
+  if data.approve {
+    CommunityFollower::approve(&mut context.pool(), community.id, follower.id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntApproveFollow)?;
+  } else {
+    // Only pending follows can be denied, use a community ban to remove approved followers
+    if CommunityFollower::is_approved_follower(&mut context.pool(), community.id, follower.id)
+      .await?
+    {
+      Err(LemmyErrorType::CouldntApproveFollow)?
+    }
+    let form = CommunityFollowerForm {
+      community_id: community.id,
+      person_id: follower.id,
+      pending: true,
+    };
+    CommunityFollower::unfollow(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntApproveFollow)?;
+  }
+
+  if community.local && !follower.local {
+    ActivityChannel::submit_activity(
+      SendActivityData::ApproveCommunityFollower(community, follower, data.approve),
+      &context,
+    )
+    .await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
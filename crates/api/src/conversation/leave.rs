@@ -0,0 +1,47 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::LeaveConversation,
+  send_activity::{ActivityChannel, SendActivityData},
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::conversation::{Conversation, ConversationParticipant, ConversationParticipantUpdateForm},
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn leave_conversation(
+  data: Json<LeaveConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation_id = data.conversation_id;
+  let participant = ConversationParticipant::read(&mut context.pool(), conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+  if participant.left_at.is_some() {
+    Err(LemmyErrorType::CouldntFindConversation)?
+  }
+  let conversation = Conversation::read(&mut context.pool(), conversation_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+
+  let form = ConversationParticipantUpdateForm {
+    left_at: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  ConversationParticipant::update(&mut context.pool(), conversation_id, person_id, &form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::LeaveConversation(local_user_view.person, conversation),
+    &context,
+  )
+  .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
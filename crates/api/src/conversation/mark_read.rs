@@ -0,0 +1,42 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ConversationResponse, MarkConversationAsRead},
+};
+use lemmy_db_schema::{
+  source::{
+    conversation::{ConversationParticipant, ConversationParticipantUpdateForm},
+    notification::Notification,
+  },
+  utils::naive_now,
+};
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn mark_conversation_as_read(
+  data: Json<MarkConversationAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation_id = data.conversation_id;
+  ConversationParticipant::read(&mut context.pool(), conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+
+  let form = ConversationParticipantUpdateForm {
+    last_read: Some(naive_now()),
+    ..Default::default()
+  };
+  ConversationParticipant::update(&mut context.pool(), conversation_id, person_id, &form).await?;
+
+  Notification::mark_read_for_conversation(&mut context.pool(), person_id, conversation_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
+  let conversation_view = ConversationView::read(&mut context.pool(), conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+  Ok(Json(ConversationResponse { conversation_view }))
+}
@@ -0,0 +1,36 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ConversationResponse, MuteConversation},
+};
+use lemmy_db_schema::source::conversation::{
+  ConversationParticipant,
+  ConversationParticipantUpdateForm,
+};
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Muted conversations are still listed, but new messages don't create notifications.
+#[tracing::instrument(skip(context))]
+pub async fn mute_conversation(
+  data: Json<MuteConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation_id = data.conversation_id;
+  ConversationParticipant::read(&mut context.pool(), conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+
+  let form = ConversationParticipantUpdateForm {
+    muted: Some(data.muted),
+    ..Default::default()
+  };
+  ConversationParticipant::update(&mut context.pool(), conversation_id, person_id, &form).await?;
+
+  let conversation_view = ConversationView::read(&mut context.pool(), conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+  Ok(Json(ConversationResponse { conversation_view }))
+}
@@ -1,8 +1,7 @@
 use activitypub_federation::config::Data;
 use actix_web::{http::header::Header, HttpRequest};
 use actix_web_httpauth::headers::authorization::{Authorization, Bearer};
-use base64::{engine::general_purpose::STANDARD_NO_PAD as base64, Engine};
-use captcha::Captcha;
+use bcrypt::verify;
 use lemmy_api_common::{
   claims::Claims,
   community::BanFromCommunity,
@@ -11,6 +10,8 @@ use lemmy_api_common::{
   utils::{check_expire_time, check_user_valid, local_site_to_slur_regex, AUTH_COOKIE_NAME},
 };
 use lemmy_db_schema::{
+  newtypes::{LocalUserId, WebauthnCredentialId},
+  sensitive::SensitiveString,
   source::{
     community::{
       CommunityFollower,
@@ -19,70 +20,42 @@ use lemmy_db_schema::{
       CommunityPersonBanForm,
     },
     local_site::LocalSite,
+    login_token::LoginToken,
     moderator::{ModBanFromCommunity, ModBanFromCommunityForm},
     person::Person,
+    totp_recovery_code::TotpRecoveryCode,
+    webauthn_challenge::WebauthnChallenge,
+    webauthn_credential::WebauthnCredential,
   },
   traits::{Bannable, Crud, Followable},
+  utils::DbPool,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorExt2, LemmyErrorType, LemmyResult},
   utils::slurs::check_slurs,
 };
-use std::io::Cursor;
 use totp_rs::{Secret, TOTP};
+use url::Url;
+use webauthn_rs::{
+  prelude::{Passkey, PasskeyAuthentication, PublicKeyCredential, Uuid},
+  Webauthn,
+  WebauthnBuilder,
+};
 
 pub mod comment;
 pub mod comment_report;
 pub mod community;
+pub mod conversation;
 pub mod local_user;
 pub mod post;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod report;
 pub mod site;
 pub mod sitemap;
 
-/// Converts the captcha to a base64 encoded wav audio file
-pub(crate) fn captcha_as_wav_base64(captcha: &Captcha) -> LemmyResult<String> {
-  let letters = captcha.as_wav();
-
-  // Decode each wav file, concatenate the samples
-  let mut concat_samples: Vec<i16> = Vec::new();
-  let mut any_header: Option<hound::WavSpec> = None;
-  for letter in letters {
-    let mut cursor = Cursor::new(letter.unwrap_or_default());
-    let reader = hound::WavReader::new(&mut cursor)?;
-    any_header = Some(reader.spec());
-    let samples16 = reader
-      .into_samples::<i16>()
-      .collect::<Result<Vec<_>, _>>()
-      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
-    concat_samples.extend(samples16);
-  }
-
-  // Encode the concatenated result as a wav file
-  let mut output_buffer = Cursor::new(vec![]);
-  if let Some(header) = any_header {
-    let mut writer = hound::WavWriter::new(&mut output_buffer, header)
-      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
-    let mut writer16 = writer.get_i16_writer(concat_samples.len() as u32);
-    for sample in concat_samples {
-      writer16.write_sample(sample);
-    }
-    writer16
-      .flush()
-      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
-    writer
-      .finalize()
-      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
-
-    Ok(base64.encode(output_buffer.into_inner()))
-  } else {
-    Err(LemmyErrorType::CouldntCreateAudioCaptcha)?
-  }
-}
-
 /// Check size of report
 pub(crate) fn check_report_reason(reason: &str, local_site: &LocalSite) -> LemmyResult<()> {
   let slur_regex = &local_site_to_slur_regex(local_site);
@@ -112,35 +85,133 @@ pub fn read_auth_token(req: &HttpRequest) -> LemmyResult<Option<String>> {
   }
 }
 
-pub(crate) fn check_totp_2fa_valid(
+/// Checks the second factor for a login, if the user enabled totp or added a WebAuthn credential.
+pub(crate) async fn check_second_factor(
   local_user_view: &LocalUserView,
   totp_token: &Option<String>,
-  site_name: &str,
+  webauthn_credential: &Option<PublicKeyCredential>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let passkeys = read_passkeys(&mut context.pool(), local_user_view.local_user.id).await?;
+  if let (Some(webauthn_credential), false) = (webauthn_credential, passkeys.is_empty()) {
+    check_webauthn_valid(local_user_view, webauthn_credential, passkeys, context).await
+  } else if local_user_view.local_user.totp_2fa_enabled {
+    check_totp_2fa_valid(local_user_view, totp_token, context).await
+  } else if !passkeys.is_empty() {
+    Err(LemmyErrorType::MissingWebauthnCredential)?
+  } else {
+    Ok(())
+  }
+}
+
+/// Confirms a change to the account's second factors with the current password, or with a totp
+/// token or recovery code if totp is enabled. This way a stolen session alone can't be used to add
+/// or remove credentials.
+pub(crate) async fn check_password_or_totp(
+  local_user_view: &LocalUserView,
+  password: &Option<SensitiveString>,
+  totp_token: &Option<String>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if let Some(password) = password {
+    let valid = verify(password, &local_user_view.local_user.password_encrypted).unwrap_or(false);
+    if !valid {
+      Err(LemmyErrorType::IncorrectLogin)?
+    }
+    Ok(())
+  } else if local_user_view.local_user.totp_2fa_enabled && totp_token.is_some() {
+    check_totp_2fa_valid(local_user_view, totp_token, context).await
+  } else {
+    Err(LemmyErrorType::IncorrectLogin)?
+  }
+}
+
+/// Accepts either the current totp token, or one of the user's recovery codes.
+pub(crate) async fn check_totp_2fa_valid(
+  local_user_view: &LocalUserView,
+  totp_token: &Option<String>,
+  context: &LemmyContext,
 ) -> LemmyResult<()> {
   // Throw an error if their token is missing
   let token = totp_token
     .as_deref()
     .ok_or(LemmyErrorType::MissingTotpToken)?;
-  let secret = local_user_view
-    .local_user
-    .totp_2fa_secret
-    .as_deref()
-    .ok_or(LemmyErrorType::MissingTotpSecret)?;
+  let secret = local_user_view.local_user.totp_2fa_secret.as_deref();
 
-  let totp = build_totp_2fa(site_name, &local_user_view.person.name, secret)?;
+  if let Some(secret) = secret {
+    let totp = build_totp_2fa(
+      &context.settings().hostname,
+      &local_user_view.person.name,
+      secret,
+    )?;
+    if totp.check_current(token)? {
+      return Ok(());
+    }
+  }
 
-  let check_passed = totp.check_current(token)?;
-  if !check_passed {
-    return Err(LemmyErrorType::IncorrectTotpToken.into());
+  let recovery_code = normalize_recovery_code(token);
+  if TotpRecoveryCode::consume(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    &recovery_code,
+  )
+  .await?
+  {
+    return Ok(());
   }
 
-  Ok(())
+  if secret.is_none() {
+    return Err(LemmyErrorType::MissingTotpSecret.into());
+  }
+  Err(LemmyErrorType::IncorrectTotpToken.into())
 }
 
 pub(crate) fn generate_totp_2fa_secret() -> String {
   Secret::generate_secret().to_string()
 }
 
+/// Generates new recovery codes for the user, replacing any previous ones. The plaintext codes
+/// are returned so that they can be shown to the user once.
+pub(crate) async fn generate_recovery_codes(
+  local_user_id: LocalUserId,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<String>> {
+  let codes = (0..RECOVERY_CODE_COUNT)
+    .map(|_| generate_recovery_code())
+    .collect::<Vec<_>>();
+  let normalized_codes = codes
+    .iter()
+    .map(|c| normalize_recovery_code(c))
+    .collect::<Vec<_>>();
+  TotpRecoveryCode::replace(&mut context.pool(), local_user_id, &normalized_codes).await?;
+  Ok(codes)
+}
+
+/// 16 random hex characters, in groups of four for readability.
+fn generate_recovery_code() -> String {
+  let chars = Secret::generate_secret()
+    .to_string()
+    .chars()
+    .take(16)
+    .collect::<Vec<_>>();
+  chars
+    .chunks(4)
+    .map(|c| c.iter().collect::<String>())
+    .collect::<Vec<_>>()
+    .join("-")
+}
+
+/// Users may type recovery codes without dashes, or in uppercase.
+fn normalize_recovery_code(code: &str) -> String {
+  code
+    .chars()
+    .filter(char::is_ascii_alphanumeric)
+    .collect::<String>()
+    .to_lowercase()
+}
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
 fn build_totp_2fa(hostname: &str, username: &str, secret: &str) -> LemmyResult<TOTP> {
   let sec = Secret::Raw(secret.as_bytes().to_vec());
   let sec_bytes = sec
@@ -159,6 +230,66 @@ fn build_totp_2fa(hostname: &str, username: &str, secret: &str) -> LemmyResult<T
   .with_lemmy_type(LemmyErrorType::CouldntGenerateTotp)
 }
 
+pub(crate) fn build_webauthn(context: &LemmyContext) -> LemmyResult<Webauthn> {
+  let settings = context.settings();
+  let rp_id = settings.get_hostname_without_port()?;
+  let rp_origin = Url::parse(&settings.get_protocol_and_hostname())?;
+  WebauthnBuilder::new(&rp_id, &rp_origin)
+    .and_then(|builder| {
+      builder
+        .rp_name(&settings.hostname)
+        // The frontend may run on a different port than the backend
+        .allow_any_port(true)
+        .build()
+    })
+    .with_lemmy_type(LemmyErrorType::CouldntStartWebauthn)
+}
+
+/// The WebAuthn user handle. It only needs to be unique on this instance.
+pub(crate) fn webauthn_user_id(local_user_id: LocalUserId) -> Uuid {
+  Uuid::from_u128(local_user_id.0.unsigned_abs().into())
+}
+
+pub(crate) async fn read_passkeys(
+  pool: &mut DbPool<'_>,
+  local_user_id: LocalUserId,
+) -> LemmyResult<Vec<(WebauthnCredentialId, Passkey)>> {
+  WebauthnCredential::list_passkeys(pool, local_user_id)
+    .await?
+    .into_iter()
+    .map(|(id, passkey)| -> LemmyResult<_> { Ok((id, serde_json::from_value(passkey)?)) })
+    .collect()
+}
+
+/// Finishes the WebAuthn authentication which was started with `start_webauthn_login`, and updates
+/// the signature counter of the used credential.
+async fn check_webauthn_valid(
+  local_user_view: &LocalUserView,
+  webauthn_credential: &PublicKeyCredential,
+  passkeys: Vec<(WebauthnCredentialId, Passkey)>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let local_user_id = local_user_view.local_user.id;
+  let state = WebauthnChallenge::take(&mut context.pool(), local_user_id)
+    .await?
+    .ok_or(LemmyErrorType::IncorrectWebauthnCredential)?;
+  let state: PasskeyAuthentication =
+    serde_json::from_value(state).with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+  let result = build_webauthn(context)?
+    .finish_passkey_authentication(webauthn_credential, &state)
+    .with_lemmy_type(LemmyErrorType::IncorrectWebauthnCredential)?;
+
+  // The credential may have been revoked after the authentication was started
+  let (id, mut passkey) = passkeys
+    .into_iter()
+    .find(|(_, p)| p.cred_id() == result.cred_id())
+    .ok_or(LemmyErrorType::IncorrectWebauthnCredential)?;
+  passkey.update_credential(&result);
+  WebauthnCredential::mark_used(&mut context.pool(), id, serde_json::to_value(passkey)?).await?;
+
+  Ok(())
+}
+
 /// Site bans are only federated for local users.
 /// This is a problem, because site-banning non-local users will still leave content
 /// they've posted to our local communities, on other servers.
@@ -251,19 +382,20 @@ pub(crate) async fn ban_nonlocal_user_from_local_communities(
 }
 
 #[tracing::instrument(skip_all)]
+/// Also returns the login token, so that the scopes of API tokens can be checked.
 pub async fn local_user_view_from_jwt(
   jwt: &str,
   context: &LemmyContext,
-) -> LemmyResult<LocalUserView> {
-  let local_user_id = Claims::validate(jwt, context)
+) -> LemmyResult<(LocalUserView, LoginToken)> {
+  let login_token = Claims::validate(jwt, context)
     .await
     .with_lemmy_type(LemmyErrorType::NotLoggedIn)?;
-  let local_user_view = LocalUserView::read(&mut context.pool(), local_user_id)
+  let local_user_view = LocalUserView::read(&mut context.pool(), login_token.user_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindLocalUser)?;
   check_user_valid(&local_user_view.person)?;
 
-  Ok(local_user_view)
+  Ok((local_user_view, login_token))
 }
 
 #[cfg(test)]
@@ -279,4 +411,17 @@ mod tests {
     let totp = build_totp_2fa("lemmy.ml", "my_name", &generated_secret);
     assert!(totp.is_ok());
   }
+
+  #[test]
+  fn test_recovery_code() {
+    let code = generate_recovery_code();
+    assert_eq!(19, code.len());
+    assert_eq!(3, code.matches('-').count());
+    assert_ne!(code, generate_recovery_code());
+
+    let normalized = normalize_recovery_code(&code);
+    assert_eq!(16, normalized.len());
+    assert_eq!(normalized, normalize_recovery_code(&code.to_uppercase()));
+    assert_eq!("abcd1234", normalize_recovery_code(" ABCD-1234 "));
+  }
 }
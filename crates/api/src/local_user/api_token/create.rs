@@ -0,0 +1,38 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  claims::Claims,
+  context::LemmyContext,
+  person::{CreateApiToken, CreateApiTokenResponse},
+};
+use lemmy_db_schema::ApiTokenScope;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_api_token_name,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_api_token(
+  data: Json<CreateApiToken>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CreateApiTokenResponse>> {
+  is_valid_api_token_name(&data.name)?;
+  if data.scopes.is_empty() {
+    Err(LemmyErrorType::ApiTokenNeedsScope)?
+  }
+  if data.scopes.contains(&ApiTokenScope::Admin) && !local_user_view.local_user.admin {
+    Err(LemmyErrorType::ApiTokenScopeNotAllowed)?
+  }
+
+  let token = Claims::generate_api_token(
+    local_user_view.local_user.id,
+    data.name.trim().to_string(),
+    data.scopes.clone(),
+    data.expires,
+    &context,
+  )
+  .await?;
+
+  Ok(Json(CreateApiTokenResponse { token }))
+}
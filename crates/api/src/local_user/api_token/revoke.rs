@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::RevokeApiToken, SuccessResponse};
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn revoke_api_token(
+  data: Json<RevokeApiToken>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let revoked = LoginToken::invalidate_api_token(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    &data.name,
+  )
+  .await?;
+  if revoked == 0 {
+    Err(LemmyErrorType::CouldntFindApiToken)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,88 @@
+use crate::{check_second_factor, local_user::login::check_password_login};
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{BanAppealResponse, CreateBanAppeal},
+};
+use lemmy_db_schema::{
+  source::ban_appeal::{BanAppeal, BanAppealInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::{BanAppealView, LocalUserView};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_ban_appeal(
+  data: Json<CreateBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  // Site banned users can't log in, so they have to send their login details instead
+  let local_user_view = match local_user_view {
+    Some(local_user_view) => local_user_view,
+    None => {
+      let (Some(username_or_email), Some(password)) = (&data.username_or_email, &data.password)
+      else {
+        Err(LemmyErrorType::IncorrectLogin)?
+      };
+      let local_user_view = check_password_login(username_or_email, password, &context).await?;
+      check_second_factor(
+        &local_user_view,
+        &data.totp_2fa_token,
+        &data.webauthn_credential,
+        &context,
+      )
+      .await?;
+      local_user_view
+    }
+  };
+  let person = &local_user_view.person;
+
+  let reason = data.reason.trim().to_string();
+  if reason.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  is_valid_body_field(&reason, false)?;
+
+  let ban_published = if let Some(community_id) = data.community_id {
+    BanAppeal::ban_published(&mut context.pool(), person.id, Some(community_id))
+      .await?
+      .ok_or(LemmyErrorType::NotBanned)?
+  } else {
+    if !person.banned {
+      Err(LemmyErrorType::NotBanned)?
+    }
+    // Site bans without a modlog entry can only be appealed once overall
+    BanAppeal::ban_published(&mut context.pool(), person.id, None)
+      .await?
+      .unwrap_or_default()
+  };
+
+  // Only one appeal per ban
+  if BanAppeal::exists_since(
+    &mut context.pool(),
+    person.id,
+    data.community_id,
+    ban_published,
+  )
+  .await?
+  {
+    Err(LemmyErrorType::BanAlreadyAppealed)?
+  }
+
+  let form = BanAppealInsertForm {
+    person_id: person.id,
+    community_id: data.community_id,
+    reason,
+  };
+  let ban_appeal = BanAppeal::create(&mut context.pool(), &form).await?;
+
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), ban_appeal.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindBanAppeal)?;
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
@@ -0,0 +1,35 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListBanAppeals, ListBanAppealsResponse},
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::{ban_appeal_view::BanAppealQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the ban appeals of a community if an id is supplied, or all ban appeals the user can
+/// resolve
+#[tracing::instrument(skip(context))]
+pub async fn list_ban_appeals(
+  data: Query<ListBanAppeals>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListBanAppealsResponse>> {
+  let community_id = data.community_id;
+  let unresolved_only = data.unresolved_only.unwrap_or_default();
+
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let page = data.page;
+  let limit = data.limit;
+  let ban_appeals = BanAppealQuery {
+    community_id,
+    unresolved_only,
+    page,
+    limit,
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  Ok(Json(ListBanAppealsResponse { ban_appeals }))
+}
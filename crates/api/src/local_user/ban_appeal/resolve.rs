@@ -0,0 +1,156 @@
+use crate::{community::ban::ban_from_community, local_user::ban_person::ban_from_site};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_common::{
+  community::BanFromCommunity,
+  context::LemmyContext,
+  person::{BanAppealResponse, BanPerson, ResolveBanAppeal},
+  utils::{check_community_mod_action, is_admin, send_ban_appeal_resolved_email},
+};
+use lemmy_db_schema::{
+  source::{
+    ban_appeal::{BanAppeal, BanAppealUpdateForm},
+    community::Community,
+    moderator::{ModResolveBanAppeal, ModResolveBanAppealForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{BanAppealView, LocalUserView};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+use tracing::warn;
+
+/// Accepts or denies a ban appeal. Accepting it lifts the ban.
+#[tracing::instrument(skip(context))]
+pub async fn resolve_ban_appeal(
+  data: Json<ResolveBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  let ban_appeal = BanAppeal::read(&mut context.pool(), data.ban_appeal_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindBanAppeal)?;
+  if ban_appeal.resolver_id.is_some() {
+    Err(LemmyErrorType::BanAppealAlreadyResolved)?
+  }
+
+  let community = if let Some(community_id) = ban_appeal.community_id {
+    check_community_mod_action(
+      &local_user_view.person,
+      community_id,
+      true,
+      &mut context.pool(),
+    )
+    .await?;
+    let community = Community::read(&mut context.pool(), community_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+    Some(community)
+  } else {
+    is_admin(&local_user_view)?;
+    None
+  };
+
+  let reason = data.reason.as_ref().map(|r| r.trim().to_string());
+  if let Some(reason) = &reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  // Resolve the appeal first, so that it can't be resolved twice at the same time
+  let form = BanAppealUpdateForm {
+    resolver_id: Some(Some(local_user_view.person.id)),
+    accepted: Some(data.accept),
+    deny_reason: Some(reason.clone().filter(|_| !data.accept)),
+    updated: Some(Some(Utc::now())),
+  };
+  BanAppeal::resolve(&mut context.pool(), ban_appeal.id, &form)
+    .await?
+    .ok_or(LemmyErrorType::BanAppealAlreadyResolved)?;
+
+  if data.accept {
+    if let Err(e) = lift_ban(&ban_appeal, &reason, &context, &local_user_view).await {
+      // Leave the appeal open, so that it can be accepted again
+      let form = BanAppealUpdateForm {
+        resolver_id: Some(None),
+        accepted: Some(false),
+        deny_reason: Some(None),
+        updated: Some(ban_appeal.updated),
+      };
+      BanAppeal::update(&mut context.pool(), ban_appeal.id, &form).await?;
+      return Err(e);
+    }
+  }
+
+  let form = ModResolveBanAppealForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: ban_appeal.person_id,
+    community_id: ban_appeal.community_id,
+    accepted: data.accept,
+    reason: reason.clone(),
+  };
+  ModResolveBanAppeal::create(&mut context.pool(), &form).await?;
+
+  let appellant = LocalUserView::read_person(&mut context.pool(), ban_appeal.person_id).await?;
+  if let Some(appellant) = appellant {
+    // The appeal is already resolved, so a failed email doesn't fail the request
+    send_ban_appeal_resolved_email(
+      &appellant,
+      community.as_ref().map(|c| c.name.as_str()),
+      data.accept,
+      reason.as_deref(),
+      context.settings(),
+    )
+    .await
+    .map_err(|e| warn!("Failed to send ban appeal email: {e}"))
+    .ok();
+  }
+
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), ban_appeal.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindBanAppeal)?;
+
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
+
+/// Lifts the ban the same way a manual unban does, so it is federated and logged too.
+async fn lift_ban(
+  ban_appeal: &BanAppeal,
+  reason: &Option<String>,
+  context: &Data<LemmyContext>,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  if let Some(community_id) = ban_appeal.community_id {
+    let form = BanFromCommunity {
+      community_id,
+      person_id: ban_appeal.person_id,
+      ban: false,
+      remove_data: None,
+      reason: reason.clone(),
+      expires: None,
+    };
+    ban_from_community(
+      Json(form),
+      context.reset_request_count(),
+      local_user_view.clone(),
+    )
+    .await?;
+  } else {
+    let form = BanPerson {
+      person_id: ban_appeal.person_id,
+      ban: false,
+      remove_data: None,
+      reason: reason.clone(),
+      expires: None,
+    };
+    ban_from_site(
+      Json(form),
+      context.reset_request_count(),
+      local_user_view.clone(),
+    )
+    .await?;
+  }
+  Ok(())
+}
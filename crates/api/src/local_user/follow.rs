@@ -0,0 +1,71 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FollowPerson, FollowPersonResponse},
+  send_activity::{ActivityChannel, SendActivityData},
+};
+use lemmy_db_schema::{
+  source::person::{Person, PersonFollower, PersonFollowerForm},
+  traits::{Crud, Followable},
+  SubscribedType,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::PersonView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn follow_person(
+  data: Json<FollowPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FollowPersonResponse>> {
+  let target_id = data.person_id;
+  let person_id = local_user_view.person.id;
+
+  if target_id == person_id {
+    Err(LemmyErrorType::CantFollowYourself)?
+  }
+
+  let target = Person::read(&mut context.pool(), target_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+
+  // Follows of remote persons stay pending until the accept activity arrives
+  let form = PersonFollowerForm {
+    person_id: target_id,
+    follower_id: person_id,
+    pending: !target.local,
+  };
+
+  if data.follow {
+    PersonFollower::follow(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::PersonFollowerAlreadyExists)?;
+  } else {
+    PersonFollower::unfollow(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUnfollowPerson)?;
+  }
+
+  if !target.local {
+    ActivityChannel::submit_activity(
+      SendActivityData::FollowPerson(target, local_user_view.person.clone(), data.follow),
+      &context,
+    )
+    .await?;
+  }
+
+  let person_view = PersonView::read(&mut context.pool(), target_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+  let subscribed = match (data.follow, form.pending) {
+    (false, _) => SubscribedType::NotSubscribed,
+    (true, true) => SubscribedType::Pending,
+    (true, false) => SubscribedType::Subscribed,
+  };
+  Ok(Json(FollowPersonResponse {
+    person_view,
+    subscribed,
+  }))
+}
@@ -1,4 +1,3 @@
-use crate::captcha_as_wav_base64;
 use actix_web::{
   http::{
     header::{CacheControl, CacheDirective},
@@ -8,15 +7,12 @@ use actix_web::{
   HttpResponse,
   HttpResponseBuilder,
 };
-use captcha::{gen, Difficulty};
 use lemmy_api_common::{
+  challenge::generate_challenge,
   context::LemmyContext,
-  person::{CaptchaResponse, GetCaptchaResponse},
-};
-use lemmy_db_schema::source::{
-  captcha_answer::{CaptchaAnswer, CaptchaAnswerForm},
-  local_site::LocalSite,
+  person::GetCaptchaResponse,
 };
+use lemmy_db_schema::source::local_site::LocalSite;
 use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
@@ -25,32 +21,15 @@ pub async fn get_captcha(context: Data<LemmyContext>) -> LemmyResult<HttpRespons
   let mut res = HttpResponseBuilder::new(StatusCode::OK);
   res.insert_header(CacheControl(vec![CacheDirective::NoStore]));
 
-  if !local_site.captcha_enabled {
-    return Ok(res.json(Json(GetCaptchaResponse { ok: None })));
+  // Challenges are also needed for posts from new accounts
+  if !local_site.captcha_enabled && local_site.new_account_post_challenge_hours == 0 {
+    return Ok(res.json(Json(GetCaptchaResponse {
+      ok: None,
+      proof_of_work: None,
+    })));
   }
 
-  let captcha = gen(match local_site.captcha_difficulty.as_str() {
-    "easy" => Difficulty::Easy,
-    "hard" => Difficulty::Hard,
-    _ => Difficulty::Medium,
-  });
-
-  let answer = captcha.chars_as_string();
-
-  let png = captcha.as_base64().expect("failed to generate captcha");
-
-  let wav = captcha_as_wav_base64(&captcha)?;
-
-  let captcha_form: CaptchaAnswerForm = CaptchaAnswerForm { answer };
-  // Stores the captcha item in the db
-  let captcha = CaptchaAnswer::insert(&mut context.pool(), &captcha_form).await?;
-
-  let json = Json(GetCaptchaResponse {
-    ok: Some(CaptchaResponse {
-      png,
-      wav,
-      uuid: captcha.uuid.to_string(),
-    }),
-  });
+  // Stores the answer in the db
+  let json = Json(generate_challenge(&local_site, &mut context.pool()).await?);
   Ok(res.json(json))
 }
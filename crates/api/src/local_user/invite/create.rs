@@ -0,0 +1,52 @@
+use actix_web::web::{Data, Json};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreateInvite, InviteResponse},
+  utils::check_expire_time,
+};
+use lemmy_db_schema::source::{
+  invite::{Invite, InviteInsertForm},
+  local_site::LocalSite,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+const MAX_INVITE_USES: i32 = 1000;
+
+#[tracing::instrument(skip(context))]
+pub async fn create_invite(
+  data: Json<CreateInvite>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<InviteResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let local_user_id = local_user_view.local_user.id;
+
+  if let Some(max_uses) = data.max_uses {
+    if !(1..=MAX_INVITE_USES).contains(&max_uses) {
+      Err(LemmyErrorType::InvalidInviteMaxUses)?
+    }
+  }
+
+  // Admins can invite as many users as they want
+  if !local_user_view.local_user.admin {
+    let min_account_age = TimeDelta::days(local_site.invite_min_account_age_days.into());
+    if Utc::now() - local_user_view.person.published < min_account_age {
+      Err(LemmyErrorType::AccountTooNewToInvite)?
+    }
+    let usable = Invite::count_usable(&mut context.pool(), local_user_id).await?;
+    if usable >= local_site.invites_per_user.into() {
+      Err(LemmyErrorType::TooManyInvites)?
+    }
+  }
+
+  let form = InviteInsertForm::builder()
+    .creator_id(local_user_id)
+    .max_uses(data.max_uses)
+    .expires(check_expire_time(data.expires)?)
+    .build();
+  let invite = Invite::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(InviteResponse { invite }))
+}
@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::DeleteInvite, SuccessResponse};
+use lemmy_db_schema::source::invite::Invite;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_invite(
+  data: Json<DeleteInvite>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let deleted = Invite::delete(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.invite_id,
+  )
+  .await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::CouldntFindInvite)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
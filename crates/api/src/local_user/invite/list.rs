@@ -0,0 +1,15 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::ListInvitesResponse};
+use lemmy_db_schema::source::invite::Invite;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_invites(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListInvitesResponse>> {
+  let invites = Invite::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListInvitesResponse { invites }))
+}
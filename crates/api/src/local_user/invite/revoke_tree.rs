@@ -0,0 +1,100 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::RevokeInviteTree,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{is_admin, remove_user_data},
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    invite::Invite,
+    local_user::LocalUser,
+    login_token::LoginToken,
+    moderator::{ModBan, ModBanForm},
+    person::{Person, PersonUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn revoke_invite_tree(
+  data: Json<RevokeInviteTree>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  let root = LocalUserView::read_person(&mut context.pool(), data.person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+  let tree = Invite::invite_tree(&mut context.pool(), root.local_user.id).await?;
+
+  // Make sure you're a higher admin than everyone in the tree
+  let person_ids = tree.iter().map(|(_, i)| i.person.id).collect();
+  LocalUser::is_higher_admin_check(&mut context.pool(), local_user_view.person.id, person_ids)
+    .await?;
+
+  let remove_data = data.remove_data.unwrap_or(false);
+  let mut local_user_ids = vec![root.local_user.id];
+  for (local_user_id, invited) in tree {
+    local_user_ids.push(local_user_id);
+    if invited.person.banned {
+      continue;
+    }
+
+    let person = Person::update(
+      &mut context.pool(),
+      invited.person.id,
+      &PersonUpdateForm {
+        banned: Some(true),
+        ban_expires: Some(None),
+        ..Default::default()
+      },
+    )
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateUser)?;
+    LoginToken::invalidate_all(&mut context.pool(), local_user_id).await?;
+
+    if remove_data {
+      remove_user_data(person.id, &context).await?;
+    }
+
+    let form = ModBanForm {
+      mod_person_id: local_user_view.person.id,
+      other_person_id: person.id,
+      reason: data.reason.clone(),
+      banned: Some(true),
+      expires: None,
+    };
+    ModBan::create(&mut context.pool(), &form).await?;
+
+    ActivityChannel::submit_activity(
+      SendActivityData::BanFromSite {
+        moderator: local_user_view.person.clone(),
+        banned_user: person,
+        reason: data.reason.clone(),
+        remove_data: data.remove_data,
+        ban: true,
+        expires: None,
+      },
+      &context,
+    )
+    .await?;
+  }
+
+  // Nobody in the tree can invite anyone anymore
+  Invite::delete_for_creators(&mut context.pool(), local_user_ids).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
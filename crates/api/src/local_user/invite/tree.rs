@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{GetInviteTree, GetInviteTreeResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::invite::Invite;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_invite_tree(
+  data: Query<GetInviteTree>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetInviteTreeResponse>> {
+  is_admin(&local_user_view)?;
+
+  let root = LocalUserView::read_person(&mut context.pool(), data.person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+  let invited = Invite::invite_tree(&mut context.pool(), root.local_user.id)
+    .await?
+    .into_iter()
+    .map(|(_, invited)| invited)
+    .collect();
+
+  Ok(Json(GetInviteTreeResponse { invited }))
+}
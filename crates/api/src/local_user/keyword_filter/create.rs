@@ -0,0 +1,40 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreateKeywordFilter, KeywordFilterResponse},
+};
+use lemmy_db_schema::source::keyword_filter::{KeywordFilter, KeywordFilterInsertForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_keyword_filter,
+  MAX_KEYWORD_FILTERS,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_keyword_filter(
+  data: Json<CreateKeywordFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<KeywordFilterResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  let keyword = data.keyword.trim().to_string();
+  is_valid_keyword_filter(&keyword)?;
+
+  if KeywordFilter::count(&mut context.pool(), local_user_id).await? >= MAX_KEYWORD_FILTERS {
+    Err(LemmyErrorType::TooManyKeywordFilters)?
+  }
+
+  let form = KeywordFilterInsertForm::builder()
+    .local_user_id(local_user_id)
+    .keyword(keyword)
+    .is_regex(data.is_regex)
+    .community_id(data.community_id)
+    .build();
+  // Invalid regexes are rejected by the database
+  let keyword_filter = KeywordFilter::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateKeywordFilter)?;
+
+  Ok(Json(KeywordFilterResponse { keyword_filter }))
+}
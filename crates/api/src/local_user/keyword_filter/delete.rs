@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::DeleteKeywordFilter, SuccessResponse};
+use lemmy_db_schema::source::keyword_filter::KeywordFilter;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_keyword_filter(
+  data: Json<DeleteKeywordFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let deleted = KeywordFilter::delete(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.keyword_filter_id,
+  )
+  .await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::CouldntFindKeywordFilter)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
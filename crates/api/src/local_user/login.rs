@@ -1,4 +1,4 @@
-use crate::{check_totp_2fa_valid, local_user::check_email_verified};
+use crate::check_second_factor;
 use actix_web::{
   web::{Data, Json},
   HttpRequest,
@@ -8,12 +8,7 @@ use lemmy_api_common::{
   claims::Claims,
   context::LemmyContext,
   person::{Login, LoginResponse},
-  utils::check_user_valid,
-};
-use lemmy_db_schema::{
-  source::{local_site::LocalSite, registration_application::RegistrationApplication},
-  utils::DbPool,
-  RegistrationMode,
+  utils::{check_email_verified, check_registration_application, check_user_valid},
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -28,36 +23,22 @@ pub async fn login(
     .await?
     .ok_or(LemmyErrorType::LocalSiteNotSetup)?;
 
-  // Fetch that username / email
-  let username_or_email = data.username_or_email.clone();
   let local_user_view =
-    LocalUserView::find_by_email_or_name(&mut context.pool(), &username_or_email)
-      .await?
-      .ok_or(LemmyErrorType::IncorrectLogin)?;
-
-  // Verify the password
-  let valid: bool = verify(
-    &data.password,
-    &local_user_view.local_user.password_encrypted,
-  )
-  .unwrap_or(false);
-  if !valid {
-    Err(LemmyErrorType::IncorrectLogin)?
-  }
+    check_password_login(&data.username_or_email, &data.password, &context).await?;
   check_user_valid(&local_user_view.person)?;
   check_email_verified(&local_user_view, &site_view)?;
 
   check_registration_application(&local_user_view, &site_view.local_site, &mut context.pool())
     .await?;
 
-  // Check the totp if enabled
-  if local_user_view.local_user.totp_2fa_enabled {
-    check_totp_2fa_valid(
-      &local_user_view,
-      &data.totp_2fa_token,
-      &context.settings().hostname,
-    )?;
-  }
+  // Check the totp or WebAuthn credential if enabled
+  check_second_factor(
+    &local_user_view,
+    &data.totp_2fa_token,
+    &data.webauthn_credential,
+    &context,
+  )
+  .await?;
 
   let jwt = Claims::generate(local_user_view.local_user.id, req, &context).await?;
 
@@ -68,27 +49,22 @@ pub async fn login(
   }))
 }
 
-async fn check_registration_application(
-  local_user_view: &LocalUserView,
-  local_site: &LocalSite,
-  pool: &mut DbPool<'_>,
-) -> LemmyResult<()> {
-  if (local_site.registration_mode == RegistrationMode::RequireApplication
-    || local_site.registration_mode == RegistrationMode::Closed)
-    && !local_user_view.local_user.accepted_application
-    && !local_user_view.local_user.admin
-  {
-    // Fetch the registration application. If no admin id is present its still pending. Otherwise it
-    // was processed (either accepted or denied).
-    let local_user_id = local_user_view.local_user.id;
-    let registration = RegistrationApplication::find_by_local_user_id(pool, local_user_id)
+/// Fetches the user by username or email, and verifies their password.
+pub(crate) async fn check_password_login(
+  username_or_email: &str,
+  password: &str,
+  context: &LemmyContext,
+) -> LemmyResult<LocalUserView> {
+  let local_user_view =
+    LocalUserView::find_by_email_or_name(&mut context.pool(), username_or_email)
       .await?
-      .ok_or(LemmyErrorType::CouldntFindRegistrationApplication)?;
-    if registration.admin_id.is_some() {
-      Err(LemmyErrorType::RegistrationDenied(registration.deny_reason))?
-    } else {
-      Err(LemmyErrorType::RegistrationApplicationIsPending)?
-    }
+      .ok_or(LemmyErrorType::IncorrectLogin)?;
+
+  // Verify the password
+  let valid: bool =
+    verify(password, &local_user_view.local_user.password_encrypted).unwrap_or(false);
+  if !valid {
+    Err(LemmyErrorType::IncorrectLogin)?
   }
-  Ok(())
+  Ok(local_user_view)
 }
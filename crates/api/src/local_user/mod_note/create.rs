@@ -0,0 +1,42 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreateModNote, ModNoteResponse},
+  utils::is_mod_or_admin_opt,
+};
+use lemmy_db_schema::source::mod_note::{ModNote, ModNoteInsertForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_mod_note(
+  data: Json<CreateModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ModNoteResponse>> {
+  is_mod_or_admin_opt(
+    &mut context.pool(),
+    Some(&local_user_view),
+    data.community_id,
+  )
+  .await?;
+
+  let content = data.content.trim().to_string();
+  if content.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  is_valid_body_field(&content, false)?;
+
+  let form = ModNoteInsertForm::builder()
+    .person_id(data.person_id)
+    .creator_id(local_user_view.person.id)
+    .community_id(data.community_id)
+    .content(content)
+    .build();
+  let mod_note = ModNote::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(ModNoteResponse { mod_note }))
+}
@@ -0,0 +1,32 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::DeleteModNote,
+  utils::is_mod_or_admin_opt,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::mod_note::ModNote;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Notes can be deleted by anyone who is allowed to see them.
+#[tracing::instrument(skip(context))]
+pub async fn delete_mod_note(
+  data: Json<DeleteModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let mod_note = ModNote::read(&mut context.pool(), data.mod_note_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindModNote)?;
+  is_mod_or_admin_opt(
+    &mut context.pool(),
+    Some(&local_user_view),
+    mod_note.community_id,
+  )
+  .await?;
+
+  ModNote::delete(&mut context.pool(), mod_note.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,32 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListModNotes, ListModNotesResponse},
+  utils::is_mod_or_admin_opt,
+};
+use lemmy_db_schema::source::{mod_note::ModNote, rule::Rule};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_mod_notes(
+  data: Query<ListModNotes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListModNotesResponse>> {
+  is_mod_or_admin_opt(
+    &mut context.pool(),
+    Some(&local_user_view),
+    data.community_id,
+  )
+  .await?;
+
+  let mod_notes = ModNote::list(&mut context.pool(), data.person_id, data.community_id).await?;
+  let rule_violations =
+    Rule::violation_counts(&mut context.pool(), data.person_id, data.community_id).await?;
+
+  Ok(Json(ListModNotesResponse {
+    mod_notes,
+    rule_violations,
+  }))
+}
@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListNotifications, ListNotificationsResponse},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::notification_view::NotificationQuery;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_notifications(
+  data: Query<ListNotifications>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListNotificationsResponse>> {
+  let notifications = NotificationQuery {
+    recipient_id: local_user_view.person.id,
+    notification_id: None,
+    kind: data.kind,
+    unread_only: data.unread_only.unwrap_or_default(),
+    show_bot_accounts: local_user_view.local_user.show_bot_accounts,
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListNotificationsResponse { notifications }))
+}
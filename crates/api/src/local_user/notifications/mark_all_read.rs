@@ -2,6 +2,7 @@ use actix_web::web::{Data, Json};
 use lemmy_api_common::{context::LemmyContext, person::GetRepliesResponse};
 use lemmy_db_schema::source::{
   comment_reply::CommentReply,
+  notification::Notification,
   person_mention::PersonMention,
   private_message::PrivateMessage,
 };
@@ -30,5 +31,10 @@ pub async fn mark_all_notifications_read(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
 
+  // Mark all other notifications as read
+  Notification::mark_as_read(&mut context.pool(), person_id, None, true)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
   Ok(Json(GetRepliesResponse { replies: vec![] }))
 }
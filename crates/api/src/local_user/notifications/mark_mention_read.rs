@@ -4,8 +4,12 @@ use lemmy_api_common::{
   person::{MarkPersonMentionAsRead, PersonMentionResponse},
 };
 use lemmy_db_schema::{
-  source::person_mention::{PersonMention, PersonMentionUpdateForm},
+  source::{
+    notification::Notification,
+    person_mention::{PersonMention, PersonMentionUpdateForm},
+  },
   traits::Crud,
+  NotificationType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::PersonMentionView;
@@ -36,6 +40,16 @@ pub async fn mark_person_mention_as_read(
   .await
   .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
+  Notification::update_read_for_comment(
+    &mut context.pool(),
+    read_person_mention.recipient_id,
+    read_person_mention.comment_id,
+    NotificationType::Mention,
+    data.read,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
   let person_mention_id = read_person_mention.id;
   let person_id = local_user_view.person.id;
   let person_mention_view =
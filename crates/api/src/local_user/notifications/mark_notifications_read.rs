@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::MarkNotificationsAsRead, SuccessResponse};
+use lemmy_db_schema::source::notification::Notification;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn mark_notifications_as_read(
+  data: Json<MarkNotificationsAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Only notifications of the current user are updated, so there is no need for a separate
+  // permission check
+  Notification::mark_as_read(
+    &mut context.pool(),
+    local_user_view.person.id,
+    data.notification_ids.clone(),
+    data.read,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
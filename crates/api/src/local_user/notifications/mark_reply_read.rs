@@ -4,8 +4,12 @@ use lemmy_api_common::{
   person::{CommentReplyResponse, MarkCommentReplyAsRead},
 };
 use lemmy_db_schema::{
-  source::comment_reply::{CommentReply, CommentReplyUpdateForm},
+  source::{
+    comment_reply::{CommentReply, CommentReplyUpdateForm},
+    notification::Notification,
+  },
   traits::Crud,
+  NotificationType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::CommentReplyView;
@@ -37,6 +41,16 @@ pub async fn mark_reply_as_read(
   .await
   .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
+  Notification::update_read_for_comment(
+    &mut context.pool(),
+    read_comment_reply.recipient_id,
+    read_comment_reply.comment_id,
+    NotificationType::Reply,
+    data.read,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
   let comment_reply_id = read_comment_reply.id;
   let person_id = local_user_view.person.id;
   let comment_reply_view =
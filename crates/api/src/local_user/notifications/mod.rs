@@ -1,6 +1,9 @@
 pub mod list_mentions;
+pub mod list_notifications;
 pub mod list_replies;
 pub mod mark_all_read;
 pub mod mark_mention_read;
+pub mod mark_notifications_read;
 pub mod mark_reply_read;
+pub mod push_stream;
 pub mod unread_count;
@@ -0,0 +1,187 @@
+use actix_web::{
+  http::header::{CacheControl, CacheDirective, ContentEncoding},
+  web::{Bytes, Data, Query},
+  HttpMessage,
+  HttpRequest,
+  HttpResponse,
+};
+use futures::stream;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::GetPushStream,
+  push::{PushChannel, PushEvent},
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{community::CommunityFollower, login_token::LoginToken},
+  SubscribedType,
+};
+use lemmy_db_views::structs::{LocalUserView, PostView};
+use lemmy_db_views_actor::notification_view::NotificationQuery;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use serde::Serialize;
+use std::{collections::HashSet, time::Duration};
+use tokio::{
+  sync::broadcast::{error::RecvError, Receiver},
+  time::{interval, interval_at, Instant, Interval},
+};
+use tracing::warn;
+
+/// Send a comment at this interval, so that proxies don't close idle connections.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Check at this interval if the login is still valid, and reload the followed communities.
+const REVALIDATE_INTERVAL: Duration = Duration::from_secs(60);
+
+struct PushStreamState {
+  events: Receiver<PushEvent>,
+  keepalive: Interval,
+  revalidate: Interval,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+  login_token: LoginToken,
+  include_posts: bool,
+  /// New posts are only read from the database if they are in one of these communities.
+  followed_communities: HashSet<CommunityId>,
+}
+
+impl PushStreamState {
+  /// Waits for the next event which is meant for this user, or for the next keepalive. Returns
+  /// `None` once the push channel is closed, or the login is no longer valid.
+  async fn next(&mut self) -> Option<Bytes> {
+    loop {
+      let event = tokio::select! {
+        _ = self.keepalive.tick() => return Some(Bytes::from_static(b": keepalive\n\n")),
+        _ = self.revalidate.tick() => {
+          match self.revalidate().await {
+            Ok(true) => continue,
+            Ok(false) => return None,
+            Err(e) => {
+              warn!("Failed to revalidate push stream: {e}");
+              return None;
+            }
+          }
+        }
+        event = self.events.recv() => event,
+      };
+      match event {
+        Ok(event) => match self.build_event(event).await {
+          Ok(Some(bytes)) => return Some(bytes),
+          Ok(None) => {}
+          Err(e) => warn!("Failed to build push event: {e}"),
+        },
+        // Events which the client missed can be fetched with the regular endpoints
+        Err(RecvError::Lagged(_)) => {}
+        Err(RecvError::Closed) => return None,
+      }
+    }
+  }
+
+  /// Returns false if the login was revoked or expired. Otherwise reloads the followed
+  /// communities, as the user may have followed or left communities since the last check.
+  async fn revalidate(&mut self) -> LemmyResult<bool> {
+    let token = &self.login_token;
+    let valid = LoginToken::validate(&mut self.context.pool(), token.user_id, &token.token)
+      .await?
+      .is_some();
+    if valid && self.include_posts {
+      let person_id = self.local_user_view.person.id;
+      self.followed_communities =
+        CommunityFollower::list_followed_community_ids(&mut self.context.pool(), person_id)
+          .await?
+          .into_iter()
+          .collect();
+    }
+    Ok(valid)
+  }
+
+  async fn build_event(&self, event: PushEvent) -> LemmyResult<Option<Bytes>> {
+    let person_id = self.local_user_view.person.id;
+    let local_user = &self.local_user_view.local_user;
+    match event {
+      PushEvent::Notification {
+        notification_id,
+        recipient_id,
+      } if recipient_id == person_id => {
+        // Apply the same filters as the inbox, eg to hide notifications from bots
+        let notification = NotificationQuery {
+          recipient_id,
+          notification_id: Some(notification_id),
+          show_bot_accounts: local_user.show_bot_accounts,
+          ..Default::default()
+        }
+        .list(&mut self.context.pool())
+        .await?
+        .pop();
+        notification
+          .map(|n| format_event("notification", &n))
+          .transpose()
+      }
+      PushEvent::Post {
+        post_id,
+        community_id,
+      } if self.include_posts && self.followed_communities.contains(&community_id) => {
+        let post = PostView::read(&mut self.context.pool(), post_id, Some(local_user), false)
+          .await?
+          .filter(|p| p.subscribed == SubscribedType::Subscribed);
+        post.map(|p| format_event("post", &p)).transpose()
+      }
+      _ => Ok(None),
+    }
+  }
+}
+
+fn format_event<T: Serialize>(name: &str, data: &T) -> LemmyResult<Bytes> {
+  let data = serde_json::to_string(data)?;
+  Ok(Bytes::from(format!("event: {name}\ndata: {data}\n\n")))
+}
+
+/// Streams new notifications, and optionally new posts in subscribed communities, as server-sent
+/// events. Events are passed between Lemmy processes using Postgres `LISTEN`/`NOTIFY`, so this
+/// works with horizontally scaled setups.
+#[tracing::instrument(skip(context))]
+pub async fn push_stream(
+  data: Query<GetPushStream>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<HttpResponse> {
+  let login_token = req
+    .extensions()
+    .get::<LoginToken>()
+    .cloned()
+    .ok_or(LemmyErrorType::NotLoggedIn)?;
+  let include_posts = data.include_posts.unwrap_or_default();
+  let followed_communities = if include_posts {
+    let person_id = local_user_view.person.id;
+    CommunityFollower::list_followed_community_ids(&mut context.pool(), person_id)
+      .await?
+      .into_iter()
+      .collect()
+  } else {
+    HashSet::new()
+  };
+  let state = PushStreamState {
+    events: PushChannel::subscribe(),
+    keepalive: interval(KEEPALIVE_INTERVAL),
+    revalidate: interval_at(Instant::now() + REVALIDATE_INTERVAL, REVALIDATE_INTERVAL),
+    context,
+    local_user_view,
+    login_token,
+    include_posts,
+    followed_communities,
+  };
+  let events = stream::unfold(state, |mut state| async move {
+    let bytes = state.next().await?;
+    Some((Ok::<_, actix_web::Error>(bytes), state))
+  });
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("text/event-stream")
+      .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+      // Prevent the compression middleware from buffering events
+      .insert_header(ContentEncoding::Identity)
+      .streaming(events),
+  )
+}
@@ -1,7 +1,8 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{context::LemmyContext, person::GetUnreadCountResponse};
-use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
-use lemmy_db_views_actor::structs::{CommentReplyView, PersonMentionView};
+use lemmy_db_schema::NotificationType;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::NotificationView;
 use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
@@ -9,21 +10,29 @@ pub async fn unread_count(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<GetUnreadCountResponse>> {
-  let person_id = local_user_view.person.id;
+  let counts =
+    NotificationView::get_unread_counts(&mut context.pool(), &local_user_view.local_user).await?;
 
-  let replies =
-    CommentReplyView::get_unread_replies(&mut context.pool(), &local_user_view.local_user).await?;
+  let mut res = GetUnreadCountResponse {
+    replies: 0,
+    mentions: 0,
+    private_messages: 0,
+    mod_actions: 0,
+    resolved_reports: 0,
+  };
+  for (kind, count) in counts {
+    match kind {
+      NotificationType::Reply => res.replies += count,
+      NotificationType::Mention => res.mentions += count,
+      NotificationType::PrivateMessage => res.private_messages += count,
+      NotificationType::ModRemovePost
+      | NotificationType::ModRemoveComment
+      | NotificationType::ModLockPost
+      | NotificationType::ModBanFromCommunity
+      | NotificationType::ModWarning => res.mod_actions += count,
+      NotificationType::ReportResolved => res.resolved_reports += count,
+    }
+  }
 
-  let mentions =
-    PersonMentionView::get_unread_mentions(&mut context.pool(), &local_user_view.local_user)
-      .await?;
-
-  let private_messages =
-    PrivateMessageView::get_unread_messages(&mut context.pool(), person_id).await?;
-
-  Ok(Json(GetUnreadCountResponse {
-    replies,
-    mentions,
-    private_messages,
-  }))
+  Ok(Json(res))
 }
@@ -0,0 +1,211 @@
+use activitypub_federation::config::Data;
+use actix_web::{
+  test::TestRequest,
+  web::{self, Json},
+  App, HttpRequest, HttpResponse, HttpServer,
+};
+use lemmy_api_common::{
+  context::LemmyContext, oauth_provider::AuthenticateWithOauth, person::LoginResponse,
+};
+use lemmy_api_crud::user::create::authenticate_with_oauth;
+use lemmy_db_schema::{
+  newtypes::OAuthProviderId,
+  source::{
+    instance::Instance,
+    local_site::{LocalSite, LocalSiteInsertForm},
+    local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitInsertForm},
+    oauth_provider::{OAuthProvider, OAuthProviderInsertForm},
+    site::{Site, SiteInsertForm},
+  },
+  traits::Crud,
+  RegistrationMode,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+use serial_test::serial;
+use std::time::Duration;
+use tokio::time::sleep;
+use url::Url;
+
+const MOCK_SERVER: &str = "http://127.0.0.1:8086";
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+
+/// A minimal OpenID Connect provider which accepts a single authorization code.
+fn mock_oidc_server() -> LemmyResult<actix_web::dev::ServerHandle> {
+  let run = HttpServer::new(|| {
+    App::new()
+      .route(
+        "/token",
+        web::post().to(|body: String| async move {
+          if body.contains("code=valid_code") && body.contains("code_verifier=") {
+            HttpResponse::Ok()
+              .content_type("application/json")
+              .body(r#"{"access_token":"mock_token","token_type":"Bearer"}"#)
+          } else {
+            HttpResponse::BadRequest().finish()
+          }
+        }),
+      )
+      .route(
+        "/userinfo",
+        web::get().to(|req: HttpRequest| async move {
+          let auth = req.headers().get("Authorization");
+          if auth.and_then(|a| a.to_str().ok()) == Some("Bearer mock_token") {
+            HttpResponse::Ok().content_type("application/json").body(
+              r#"{"sub":"mock_user_1","email":"oauth_user@example.com","email_verified":true}"#,
+            )
+          } else {
+            HttpResponse::Unauthorized().finish()
+          }
+        }),
+      )
+  })
+  .bind(("127.0.0.1", 8086))?
+  .run();
+  let handle = run.handle();
+  tokio::spawn(run);
+  Ok(handle)
+}
+
+async fn authenticate(
+  context: &Data<LemmyContext>,
+  oauth_provider_id: OAuthProviderId,
+  code: &str,
+  pkce_code_verifier: Option<&str>,
+  username: Option<&str>,
+) -> LemmyResult<Json<LoginResponse>> {
+  let redirect_uri = Url::parse(&format!(
+    "{}/oauth/callback",
+    context.settings().get_protocol_and_hostname()
+  ))?;
+  let form = AuthenticateWithOauth {
+    code: code.to_string(),
+    oauth_provider_id,
+    redirect_uri,
+    pkce_code_verifier: pkce_code_verifier.map(|v| v.to_string().into()),
+    username: username.map(ToString::to_string),
+    show_nsfw: None,
+    answer: None,
+  };
+  authenticate_with_oauth(
+    Json(form),
+    TestRequest::default().to_http_request(),
+    context.reset_request_count(),
+  )
+  .await
+}
+
+#[allow(clippy::unwrap_used)]
+#[tokio::test]
+#[serial]
+async fn test_authenticate_with_oauth() -> LemmyResult<()> {
+  let context = LemmyContext::init_test_context().await;
+  let pool = &mut context.pool();
+  let server = mock_oidc_server()?;
+
+  let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+  let site_form = SiteInsertForm::builder()
+    .name("test site".to_string())
+    .instance_id(instance.id)
+    .build();
+  let site = Site::create(pool, &site_form).await?;
+  let local_site_form = LocalSiteInsertForm::builder()
+    .site_id(site.id)
+    .registration_mode(Some(RegistrationMode::Open))
+    .oauth_registration(Some(true))
+    .site_setup(Some(true))
+    .build();
+  let local_site = LocalSite::create(pool, &local_site_form).await?;
+  let rate_limit_form = LocalSiteRateLimitInsertForm::builder()
+    .local_site_id(local_site.id)
+    .build();
+  LocalSiteRateLimit::create(pool, &rate_limit_form).await?;
+
+  let provider_form = OAuthProviderInsertForm::builder()
+    .display_name("Mock".to_string())
+    .issuer(Url::parse(MOCK_SERVER)?.into())
+    .authorization_endpoint(Url::parse(&format!("{MOCK_SERVER}/authorize"))?.into())
+    .token_endpoint(Url::parse(&format!("{MOCK_SERVER}/token"))?.into())
+    .userinfo_endpoint(Url::parse(&format!("{MOCK_SERVER}/userinfo"))?.into())
+    .id_claim("sub".to_string())
+    .client_id("mock_client".to_string())
+    .client_secret("mock_secret".to_string())
+    .scopes("openid email".to_string())
+    .auto_verify_email(Some(true))
+    .build();
+  let provider = OAuthProvider::create(pool, &provider_form).await?;
+
+  // PKCE is required by default
+  let res = authenticate(
+    &context,
+    provider.id,
+    "valid_code",
+    None,
+    Some("oauth_user"),
+  )
+  .await;
+  assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::OauthAuthorizationInvalid));
+
+  // The provider rejects the code
+  let res = authenticate(
+    &context,
+    provider.id,
+    "invalid_code",
+    Some(CODE_VERIFIER),
+    Some("oauth_user"),
+  )
+  .await;
+  assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::OauthLoginFailed));
+
+  // Signing up needs a username
+  let res = authenticate(
+    &context,
+    provider.id,
+    "valid_code",
+    Some(CODE_VERIFIER),
+    None,
+  )
+  .await;
+  assert!(res.is_err_and(|e| e.error_type == LemmyErrorType::RegistrationUsernameRequired));
+
+  // Sign up, which logs in directly with open registration
+  let res = authenticate(
+    &context,
+    provider.id,
+    "valid_code",
+    Some(CODE_VERIFIER),
+    Some("oauth_user"),
+  )
+  .await?;
+  assert!(res.jwt.is_some());
+  let local_user_view = LocalUserView::find_by_oauth_id(pool, provider.id, "mock_user_1")
+    .await?
+    .unwrap();
+  assert_eq!("oauth_user", local_user_view.person.name);
+  assert_eq!(
+    Some("oauth_user@example.com"),
+    local_user_view.local_user.email.as_deref()
+  );
+  assert!(local_user_view.local_user.email_verified);
+
+  // Logging in again uses the linked account, no username needed. Login tokens only differ in the
+  // issue time, so wait to get a new one.
+  sleep(Duration::from_secs(1)).await;
+  let res = authenticate(
+    &context,
+    provider.id,
+    "valid_code",
+    Some(CODE_VERIFIER),
+    None,
+  )
+  .await?;
+  assert!(res.jwt.is_some());
+
+  server.stop(true).await;
+  OAuthProvider::delete(pool, provider.id).await?;
+  LocalSite::delete(pool).await?;
+  // Instance deletion cascades cleanup of all created persons
+  Instance::delete(pool, instance.id).await?;
+
+  Ok(())
+}
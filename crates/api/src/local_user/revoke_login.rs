@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::RevokeLogin, SuccessResponse};
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn revoke_login(
+  data: Json<RevokeLogin>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let revoked = LoginToken::invalidate_by_id(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.login_token_id,
+  )
+  .await?;
+  if revoked == 0 {
+    Err(LemmyErrorType::CouldntFindLogin)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,21 @@
+use crate::read_auth_token;
+use activitypub_federation::config::Data;
+use actix_web::{web::Json, HttpRequest};
+use lemmy_api_common::{context::LemmyContext, SuccessResponse};
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Log out everywhere except for the current session. API tokens need to be revoked separately.
+#[tracing::instrument(skip(context))]
+pub async fn revoke_other_logins(
+  req: HttpRequest,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let jwt = read_auth_token(&req)?.ok_or(LemmyErrorType::NotLoggedIn)?;
+  LoginToken::invalidate_other_sessions(&mut context.pool(), local_user_view.local_user.id, &jwt)
+    .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
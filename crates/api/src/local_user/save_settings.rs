@@ -20,15 +20,25 @@ use lemmy_db_schema::{
     local_user_vote_display_mode::{LocalUserVoteDisplayMode, LocalUserVoteDisplayModeUpdateForm},
     person::{Person, PersonUpdateForm},
   },
+  newtypes::DbUrl,
   traits::Crud,
   utils::{diesel_string_update, diesel_url_update},
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
-  error::{LemmyErrorType, LemmyResult},
-  utils::validation::{is_valid_bio_field, is_valid_display_name, is_valid_matrix_id},
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::{
+    is_valid_bio_field,
+    is_valid_display_name,
+    is_valid_matrix_id,
+    is_valid_url,
+  },
 };
 use std::ops::Deref;
+use url::Url;
+
+/// Maximum number of previous accounts which can be listed as aliases.
+const MAX_ACCOUNT_ALIASES: usize = 10;
 
 #[tracing::instrument(skip(context))]
 pub async fn save_user_settings(
@@ -113,6 +123,24 @@ pub async fn save_user_settings(
     is_valid_matrix_id(matrix_user_id)?;
   }
 
+  let also_known_as = data
+    .also_known_as
+    .as_ref()
+    .map(|aliases| {
+      if aliases.len() > MAX_ACCOUNT_ALIASES {
+        Err(LemmyErrorType::TooManyAccountAliases)?
+      }
+      aliases
+        .iter()
+        .map(|alias| {
+          let url = Url::parse(alias).with_lemmy_type(LemmyErrorType::InvalidUrl)?;
+          is_valid_url(&url)?;
+          Ok(url.into())
+        })
+        .collect::<LemmyResult<Vec<DbUrl>>>()
+    })
+    .transpose()?;
+
   let local_user_id = local_user_view.local_user.id;
   let person_id = local_user_view.person.id;
   let default_listing_type = data.default_listing_type;
@@ -125,6 +153,7 @@ pub async fn save_user_settings(
     bot_account: data.bot_account,
     avatar,
     banner,
+    also_known_as,
     ..Default::default()
   };
 
@@ -143,6 +172,7 @@ pub async fn save_user_settings(
     show_avatars: data.show_avatars,
     show_read_posts: data.show_read_posts,
     send_notifications_to_email: data.send_notifications_to_email,
+    send_login_alerts_to_email: data.send_login_alerts_to_email,
     show_nsfw: data.show_nsfw,
     blur_nsfw: data.blur_nsfw,
     auto_expand: data.auto_expand,
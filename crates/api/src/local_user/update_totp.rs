@@ -1,10 +1,13 @@
-use crate::check_totp_2fa_valid;
+use crate::{check_totp_2fa_valid, generate_recovery_codes};
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   context::LemmyContext,
   person::{UpdateTotp, UpdateTotpResponse},
 };
-use lemmy_db_schema::source::local_user::{LocalUser, LocalUserUpdateForm};
+use lemmy_db_schema::source::{
+  local_user::{LocalUser, LocalUserUpdateForm},
+  totp_recovery_code::TotpRecoveryCode,
+};
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::LemmyResult;
 
@@ -15,18 +18,16 @@ use lemmy_utils::error::LemmyResult;
 /// function.
 ///
 /// Disabling is only possible if 2FA was previously enabled. Again it is necessary to pass a valid
-/// token.
+/// token, or one of the recovery codes.
+///
+/// Enabling returns a new set of recovery codes, and disabling deletes them.
 #[tracing::instrument(skip(context))]
 pub async fn update_totp(
   data: Json<UpdateTotp>,
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UpdateTotpResponse>> {
-  check_totp_2fa_valid(
-    &local_user_view,
-    &Some(data.totp_token.clone()),
-    &context.settings().hostname,
-  )?;
+  check_totp_2fa_valid(&local_user_view, &Some(data.totp_token.clone()), &context).await?;
 
   // toggle the 2fa setting
   let local_user_form = LocalUserUpdateForm {
@@ -43,7 +44,17 @@ pub async fn update_totp(
   )
   .await?;
 
+  let local_user_id = local_user_view.local_user.id;
+  let recovery_codes = if data.enabled {
+    let codes = generate_recovery_codes(local_user_id, &context).await?;
+    Some(codes.into_iter().map(Into::into).collect())
+  } else {
+    TotpRecoveryCode::delete_all(&mut context.pool(), local_user_id).await?;
+    None
+  };
+
   Ok(Json(UpdateTotpResponse {
     enabled: data.enabled,
+    recovery_codes,
   }))
 }
@@ -0,0 +1,207 @@
+use crate::{community::ban::ban_from_community, local_user::ban_person::ban_from_site};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::{DateTime, Days, Utc};
+use lemmy_api_common::{
+  build_response::send_local_notification,
+  community::BanFromCommunity,
+  context::LemmyContext,
+  person::{BanPerson, WarnPerson, WarnPersonResponse},
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    local_user::LocalUser,
+    moderator::{ModWarning, ModWarningForm},
+    notification::NotificationInsertForm,
+    person::Person,
+  },
+  traits::Crud,
+  NotificationType,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityPersonBanView, PersonView};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+use tracing::warn;
+
+#[tracing::instrument(skip(context))]
+pub async fn warn_person(
+  data: Json<WarnPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WarnPersonResponse>> {
+  let my_person_id = local_user_view.person.id;
+
+  // Community mods can warn in their community, only admins site-wide
+  if let Some(community_id) = data.community_id {
+    check_community_mod_action(
+      &local_user_view.person,
+      community_id,
+      false,
+      &mut context.pool(),
+    )
+    .await?;
+    LocalUser::is_higher_mod_or_admin_check(
+      &mut context.pool(),
+      community_id,
+      my_person_id,
+      vec![data.person_id],
+    )
+    .await?;
+  } else {
+    is_admin(&local_user_view)?;
+    LocalUser::is_higher_admin_check(&mut context.pool(), my_person_id, vec![data.person_id])
+      .await?;
+  }
+
+  let reason = data.reason.trim().to_string();
+  if reason.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  is_valid_body_field(&reason, false)?;
+
+  Person::read(&mut context.pool(), data.person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let expiry_days = u64::try_from(local_site.strike_expiry_days).unwrap_or_default();
+  let form = ModWarningForm {
+    mod_person_id: my_person_id,
+    other_person_id: data.person_id,
+    community_id: data.community_id,
+    reason: reason.clone(),
+    expires: Utc::now() + Days::new(expiry_days),
+  };
+  let mod_warning = ModWarning::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateModWarning)?;
+
+  let notification_form = NotificationInsertForm::builder()
+    .recipient_id(data.person_id)
+    .kind(NotificationType::ModWarning)
+    .community_id(data.community_id)
+    .reason(Some(reason))
+    .build();
+  send_local_notification(notification_form, my_person_id, &context).await;
+
+  Ok(Json(WarnPersonResponse { mod_warning }))
+}
+
+/// Bans everyone who reached the strike limit of the site with their active warnings, from the
+/// community or the whole site depending on where they were warned. The ban is done in the name
+/// of the moderator who gave the latest warning, see [banning_moderator]. Failed bans are tried
+/// again on the next run.
+pub async fn escalate_strikes(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let strike_limit = usize::try_from(local_site.strike_limit).unwrap_or_default();
+  if strike_limit == 0 {
+    return Ok(());
+  }
+  let ban_days = u64::try_from(local_site.strike_ban_days).unwrap_or_default();
+  let expires = Utc::now() + Days::new(ban_days);
+  let reason = Some(format!("Reached {strike_limit} strikes"));
+
+  let warnings = ModWarning::list_strike_limit_reached(&mut context.pool(), strike_limit).await?;
+  for warning in warnings {
+    if let Err(e) = escalate_warning(&warning, expires, &reason, context).await {
+      warn!(
+        "Failed to ban person {} for strikes: {e}",
+        warning.other_person_id.0
+      );
+    }
+  }
+  Ok(())
+}
+
+async fn escalate_warning(
+  warning: &ModWarning,
+  expires: DateTime<Utc>,
+  reason: &Option<String>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let target = Person::read(&mut context.pool(), warning.other_person_id).await?;
+  if let Some(target) = target {
+    let moderator = banning_moderator(warning, context)
+      .await?
+      .ok_or(LemmyErrorType::NotAModOrAdmin)?;
+    let expires = Some(expires.timestamp());
+    if let Some(community_id) = warning.community_id {
+      // Don't replace a longer ban
+      if !CommunityPersonBanView::get(&mut context.pool(), target.id, community_id).await? {
+        let form = BanFromCommunity {
+          community_id,
+          person_id: target.id,
+          ban: true,
+          remove_data: None,
+          reason: reason.clone(),
+          expires,
+        };
+        ban_from_community(Json(form), context.reset_request_count(), moderator).await?;
+      }
+    } else if !target.banned {
+      let form = BanPerson {
+        person_id: target.id,
+        ban: true,
+        remove_data: None,
+        reason: reason.clone(),
+        expires,
+      };
+      ban_from_site(Json(form), context.reset_request_count(), moderator).await?;
+    }
+  }
+  ModWarning::mark_escalated(
+    &mut context.pool(),
+    warning.other_person_id,
+    warning.community_id,
+  )
+  .await?;
+  Ok(())
+}
+
+/// The moderator who gave the warning, if they can still ban. Otherwise the ban is done in the
+/// name of the oldest local moderator of the community, or of the oldest admin.
+async fn banning_moderator(
+  warning: &ModWarning,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Option<LocalUserView>> {
+  let can_ban = |moderator: &LocalUserView| !moderator.person.banned && !moderator.person.deleted;
+
+  let moderator = LocalUserView::read_person(&mut context.pool(), warning.mod_person_id).await?;
+  if let Some(moderator) = moderator.filter(can_ban) {
+    let is_mod = match warning.community_id {
+      Some(community_id) => {
+        CommunityModeratorView::is_community_moderator(
+          &mut context.pool(),
+          community_id,
+          moderator.person.id,
+        )
+        .await?
+      }
+      None => false,
+    };
+    if is_mod || moderator.local_user.admin {
+      return Ok(Some(moderator));
+    }
+  }
+
+  let mut candidates = vec![];
+  if let Some(community_id) = warning.community_id {
+    let mods = CommunityModeratorView::for_community(&mut context.pool(), community_id).await?;
+    candidates.extend(mods.into_iter().map(|m| m.moderator).filter(|p| p.local));
+  }
+  let admins = PersonView::admins(&mut context.pool()).await?;
+  candidates.extend(admins.into_iter().map(|a| a.person));
+
+  for candidate in candidates {
+    let moderator = LocalUserView::read_person(&mut context.pool(), candidate.id).await?;
+    if let Some(moderator) = moderator.filter(can_ban) {
+      return Ok(Some(moderator));
+    }
+  }
+  Ok(None)
+}
@@ -0,0 +1,33 @@
+use crate::check_password_or_totp;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::DeleteWebauthnCredential, SuccessResponse};
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_webauthn_credential(
+  data: Json<DeleteWebauthnCredential>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_password_or_totp(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &context,
+  )
+  .await?;
+
+  let deleted = WebauthnCredential::delete(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.webauthn_credential_id,
+  )
+  .await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::CouldntFindWebauthnCredential)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,62 @@
+use super::MAX_WEBAUTHN_CREDENTIALS;
+use crate::{build_webauthn, check_password_or_totp, read_passkeys};
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FinishWebauthnRegistration, WebauthnCredentialResponse},
+};
+use lemmy_db_schema::source::{
+  webauthn_challenge::WebauthnChallenge,
+  webauthn_credential::{WebauthnCredential, WebauthnCredentialInsertForm},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_webauthn_credential_name,
+};
+use webauthn_rs::prelude::PasskeyRegistration;
+
+#[tracing::instrument(skip(context))]
+pub async fn finish_webauthn_registration(
+  data: Json<FinishWebauthnRegistration>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WebauthnCredentialResponse>> {
+  check_password_or_totp(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &context,
+  )
+  .await?;
+
+  let local_user_id = local_user_view.local_user.id;
+  let name = data.name.trim();
+  is_valid_webauthn_credential_name(name)?;
+  let passkeys = read_passkeys(&mut context.pool(), local_user_id).await?;
+  if passkeys.len() >= MAX_WEBAUTHN_CREDENTIALS {
+    Err(LemmyErrorType::TooManyWebauthnCredentials)?
+  }
+
+  let state = WebauthnChallenge::take(&mut context.pool(), local_user_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntRegisterWebauthnCredential)?;
+  let state: PasskeyRegistration = serde_json::from_value(state)
+    .with_lemmy_type(LemmyErrorType::CouldntRegisterWebauthnCredential)?;
+  let passkey = build_webauthn(&context)?
+    .finish_passkey_registration(&data.credential, &state)
+    .with_lemmy_type(LemmyErrorType::CouldntRegisterWebauthnCredential)?;
+
+  let form = WebauthnCredentialInsertForm {
+    local_user_id,
+    name: name.to_string(),
+    passkey: serde_json::to_value(passkey)?,
+  };
+  let webauthn_credential = WebauthnCredential::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntRegisterWebauthnCredential)?;
+
+  Ok(Json(WebauthnCredentialResponse {
+    webauthn_credential,
+  }))
+}
@@ -0,0 +1,18 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::ListWebauthnCredentialsResponse};
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_webauthn_credentials(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListWebauthnCredentialsResponse>> {
+  let webauthn_credentials =
+    WebauthnCredential::list(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListWebauthnCredentialsResponse {
+    webauthn_credentials,
+  }))
+}
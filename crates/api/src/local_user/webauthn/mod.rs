@@ -0,0 +1,8 @@
+pub mod delete;
+pub mod finish_registration;
+pub mod list;
+pub mod start_login;
+pub mod start_registration;
+
+/// Limit how many credentials each user can register.
+const MAX_WEBAUTHN_CREDENTIALS: usize = 20;
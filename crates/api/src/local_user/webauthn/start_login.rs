@@ -0,0 +1,42 @@
+use crate::{build_webauthn, local_user::login::check_password_login, read_passkeys};
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{StartWebauthnLogin, StartWebauthnLoginResponse},
+};
+use lemmy_db_schema::source::webauthn_challenge::WebauthnChallenge;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use webauthn_rs::prelude::Passkey;
+
+/// Start a login with a WebAuthn credential as second factor. The credential which the browser
+/// returns for the challenge is then passed to [login](crate::local_user::login::login).
+#[tracing::instrument(skip(context))]
+pub async fn start_webauthn_login(
+  data: Json<StartWebauthnLogin>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<StartWebauthnLoginResponse>> {
+  let local_user_view =
+    check_password_login(&data.username_or_email, &data.password, &context).await?;
+  let local_user_id = local_user_view.local_user.id;
+
+  let passkeys = read_passkeys(&mut context.pool(), local_user_id)
+    .await?
+    .into_iter()
+    .map(|(_, passkey)| passkey)
+    .collect::<Vec<Passkey>>();
+  if passkeys.is_empty() {
+    Err(LemmyErrorType::CouldntFindWebauthnCredential)?
+  }
+
+  let (challenge, state) = build_webauthn(&context)?
+    .start_passkey_authentication(&passkeys)
+    .with_lemmy_type(LemmyErrorType::CouldntStartWebauthn)?;
+  WebauthnChallenge::upsert(
+    &mut context.pool(),
+    local_user_id,
+    serde_json::to_value(state)?,
+  )
+  .await?;
+
+  Ok(Json(StartWebauthnLoginResponse { challenge }))
+}
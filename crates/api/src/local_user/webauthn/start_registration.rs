@@ -0,0 +1,58 @@
+use super::MAX_WEBAUTHN_CREDENTIALS;
+use crate::{build_webauthn, check_password_or_totp, read_passkeys, webauthn_user_id};
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{StartWebauthnRegistration, StartWebauthnRegistrationResponse},
+};
+use lemmy_db_schema::source::webauthn_challenge::WebauthnChallenge;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Start adding a WebAuthn credential. The returned challenge has to be passed to the browser,
+/// and the created credential to [finish_webauthn_registration].
+///
+/// [finish_webauthn_registration]: super::finish_registration::finish_webauthn_registration
+#[tracing::instrument(skip(context))]
+pub async fn start_webauthn_registration(
+  data: Json<StartWebauthnRegistration>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<StartWebauthnRegistrationResponse>> {
+  check_password_or_totp(
+    &local_user_view,
+    &data.password,
+    &data.totp_2fa_token,
+    &context,
+  )
+  .await?;
+
+  let local_user_id = local_user_view.local_user.id;
+  let passkeys = read_passkeys(&mut context.pool(), local_user_id).await?;
+  if passkeys.len() >= MAX_WEBAUTHN_CREDENTIALS {
+    Err(LemmyErrorType::TooManyWebauthnCredentials)?
+  }
+
+  // Prevent registering the same authenticator twice
+  let exclude_credentials = passkeys
+    .iter()
+    .map(|(_, passkey)| passkey.cred_id().clone())
+    .collect();
+  let person = &local_user_view.person;
+  let (challenge, state) = build_webauthn(&context)?
+    .start_passkey_registration(
+      webauthn_user_id(local_user_id),
+      &person.name,
+      person.display_name.as_deref().unwrap_or(&person.name),
+      Some(exclude_credentials),
+    )
+    .with_lemmy_type(LemmyErrorType::CouldntStartWebauthn)?;
+  WebauthnChallenge::upsert(
+    &mut context.pool(),
+    local_user_id,
+    serde_json::to_value(state)?,
+  )
+  .await?;
+
+  Ok(Json(StartWebauthnRegistrationResponse { challenge }))
+}
@@ -5,7 +5,7 @@ use lemmy_api_common::{
   context::LemmyContext,
   post::{FeaturePost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, is_admin},
+  utils::{check_community_mod_action, check_post_published, is_admin},
 };
 use lemmy_db_schema::{
   source::{
@@ -28,6 +28,7 @@ pub async fn feature_post(
   let orig_post = Post::read(&mut context.pool(), post_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindPost)?;
+  check_post_published(&orig_post)?;
 
   check_community_mod_action(
     &local_user_view.person,
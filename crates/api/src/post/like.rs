@@ -9,6 +9,7 @@ use lemmy_api_common::{
     check_bot_account,
     check_community_user_action,
     check_downvotes_enabled,
+    check_post_published,
     mark_post_as_read,
   },
 };
@@ -41,6 +42,7 @@ pub async fn like_post(
   let post = Post::read(&mut context.pool(), post_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindPost)?;
+  check_post_published(&post)?;
 
   check_community_user_action(
     &local_user_view.person,
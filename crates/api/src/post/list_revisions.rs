@@ -0,0 +1,65 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{ListPostRevisions, ListPostRevisionsResponse, PostRevisionView},
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  newtypes::DbUrl,
+  source::{post::Post, post_revision::PostRevision},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::diff::line_diff,
+};
+use std::iter::once;
+
+/// Lists the previous versions of a post, each with a diff against the version which replaced it.
+/// Revisions are only visible to the creator of the post, mods of its community and admins.
+#[tracing::instrument(skip(context))]
+pub async fn list_post_revisions(
+  data: Query<ListPostRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPostRevisionsResponse>> {
+  let post = Post::read(&mut context.pool(), data.post_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPost)?;
+  if post.creator_id != local_user_view.person.id {
+    is_mod_or_admin(
+      &mut context.pool(),
+      &local_user_view.person,
+      post.community_id,
+    )
+    .await?;
+  }
+
+  let revisions = PostRevision::list_for_post(&mut context.pool(), post.id).await?;
+  let versions = revisions
+    .iter()
+    .map(|r| post_text(&r.name, r.url.as_ref(), r.body.as_deref()))
+    .chain(once(post_text(
+      &post.name,
+      post.url.as_ref(),
+      post.body.as_deref(),
+    )))
+    .collect::<Vec<_>>();
+  let revisions = revisions
+    .into_iter()
+    .zip(versions.iter().zip(versions.iter().skip(1)))
+    .map(|(revision, (old, new))| PostRevisionView {
+      revision,
+      diff: line_diff(old, new),
+    })
+    .collect();
+
+  Ok(Json(ListPostRevisionsResponse { revisions }))
+}
+
+/// The editable parts of a post as text, so that different versions can be compared.
+fn post_text(name: &str, url: Option<&DbUrl>, body: Option<&str>) -> String {
+  let url = url.map(ToString::to_string).unwrap_or_default();
+  format!("{name}\n{url}\n{}", body.unwrap_or_default())
+}
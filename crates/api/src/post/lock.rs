@@ -1,18 +1,20 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
-  build_response::build_post_response,
+  build_response::{build_post_response, send_local_notification},
   context::LemmyContext,
   post::{LockPost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_post_published},
 };
 use lemmy_db_schema::{
   source::{
     moderator::{ModLockPost, ModLockPostForm},
+    notification::NotificationInsertForm,
     post::{Post, PostUpdateForm},
   },
   traits::Crud,
+  NotificationType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{error::LemmyResult, LemmyErrorType};
@@ -27,6 +29,7 @@ pub async fn lock_post(
   let orig_post = Post::read(&mut context.pool(), post_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindPost)?;
+  check_post_published(&orig_post)?;
 
   check_community_mod_action(
     &local_user_view.person,
@@ -57,6 +60,16 @@ pub async fn lock_post(
   };
   ModLockPost::create(&mut context.pool(), &form).await?;
 
+  if locked {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(post.creator_id)
+      .kind(NotificationType::ModLockPost)
+      .post_id(Some(post.id))
+      .community_id(Some(post.community_id))
+      .build();
+    send_local_notification(notification_form, local_user_view.person.id, &context).await;
+  }
+
   ActivityChannel::submit_activity(
     SendActivityData::LockPost(post, local_user_view.person.clone(), data.locked),
     &context,
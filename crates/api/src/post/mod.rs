@@ -3,6 +3,9 @@ pub mod get_link_metadata;
 pub mod hide;
 pub mod like;
 pub mod list_post_likes;
+pub mod list_revisions;
 pub mod lock;
 pub mod mark_read;
 pub mod save;
+pub mod update_tags;
+pub mod vote_poll;
@@ -0,0 +1,64 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  post::{PostResponse, UpdatePostTags},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_user_action, check_post_deleted_or_removed, check_post_tags},
+};
+use lemmy_db_schema::{
+  source::{post::Post, tag::PostTag},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityModeratorView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_post_tags(
+  data: Json<UpdatePostTags>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let post = Post::read(&mut context.pool(), data.post_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPost)?;
+  check_post_deleted_or_removed(&post)?;
+  check_community_user_action(
+    &local_user_view.person,
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let is_mod = CommunityModeratorView::is_community_moderator(
+    &mut context.pool(),
+    post.community_id,
+    local_user_view.person.id,
+  )
+  .await?;
+  if !is_mod && post.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoPostEditAllowed)?
+  }
+
+  let tags = check_post_tags(
+    post.community_id,
+    Some(post.id),
+    &data.tags,
+    is_mod,
+    &mut context.pool(),
+  )
+  .await?;
+  PostTag::set(&mut context.pool(), post.id, &tags)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
+
+  // The update can only be federated with the post creator as actor, so tags which moderators
+  // set on remote posts stay local.
+  if post.local && post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(SendActivityData::UpdatePost(post.clone()), &context).await?;
+  }
+
+  build_post_response(&context, post.community_id, local_user_view, post.id).await
+}
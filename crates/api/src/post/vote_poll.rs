@@ -0,0 +1,80 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  post::{PostResponse, VoteInPoll},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{
+    check_bot_account,
+    check_community_user_action,
+    check_poll_choices,
+    check_post_deleted_or_removed,
+    check_post_published,
+    mark_post_as_read,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    poll::{Poll, PollVote},
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::ops::Deref;
+
+#[tracing::instrument(skip(context))]
+pub async fn vote_in_poll(
+  data: Json<VoteInPoll>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  check_bot_account(&local_user_view.person)?;
+
+  let post_id = data.post_id;
+  let post = Post::read(&mut context.pool(), post_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPost)?;
+  check_post_deleted_or_removed(&post)?;
+  check_post_published(&post)?;
+
+  check_community_user_action(
+    &local_user_view.person,
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let poll = Poll::read(&mut context.pool(), post_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPoll)?;
+  check_poll_choices(&poll, &data.choices)?;
+
+  let person_id = local_user_view.person.id;
+  let previous_choices = PollVote::list_choices(&mut context.pool(), post_id, person_id).await?;
+  PollVote::vote(
+    &mut context.pool(),
+    post_id,
+    person_id,
+    data.choices.clone(),
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntVoteInPoll)?;
+
+  mark_post_as_read(person_id, post_id, &mut context.pool()).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::VoteInPoll {
+      post: post.clone(),
+      actor: local_user_view.person.clone(),
+      choices: data.choices.clone(),
+      previous_choices,
+    },
+    &context,
+  )
+  .await?;
+
+  build_post_response(context.deref(), post.community_id, local_user_view, post_id).await
+}
@@ -8,6 +8,7 @@ use lemmy_api_common::{
   utils::{
     check_community_user_action,
     check_post_deleted_or_removed,
+    check_rule_in_community,
     send_new_report_email_to_admins,
   },
 };
@@ -47,6 +48,7 @@ pub async fn create_post_report(
   .await?;
 
   check_post_deleted_or_removed(&post_view.post)?;
+  check_rule_in_community(data.rule_id, post_view.community.id, &mut context.pool()).await?;
 
   let report_form = PostReportForm {
     creator_id: person_id,
@@ -55,6 +57,8 @@ pub async fn create_post_report(
     original_post_url: post_view.post.url,
     original_post_body: post_view.post.body,
     reason,
+    category_id: data.category_id,
+    rule_id: data.rule_id,
   };
 
   let report = PostReport::report(&mut context.pool(), &report_form)
@@ -29,6 +29,7 @@ pub async fn list_post_reports(
     unresolved_only,
     page,
     limit,
+    report_ids: None,
   }
   .list(&mut context.pool(), &local_user_view)
   .await?;
@@ -1,10 +1,15 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
+  build_response::send_local_notification,
   context::LemmyContext,
   post::{PostReportResponse, ResolvePostReport},
   utils::check_community_mod_action,
 };
-use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
+use lemmy_db_schema::{
+  source::{notification::NotificationInsertForm, post_report::PostReport},
+  traits::Reportable,
+  NotificationType,
+};
 use lemmy_db_views::structs::{LocalUserView, PostReportView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -34,6 +39,14 @@ pub async fn resolve_post_report(
     PostReport::resolve(&mut context.pool(), report_id, person_id)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(report.creator.id)
+      .kind(NotificationType::ReportResolved)
+      .post_id(Some(report.post.id))
+      .community_id(Some(report.community.id))
+      .build();
+    send_local_notification(notification_form, person_id, &context).await;
   } else {
     PostReport::unresolve(&mut context.pool(), report_id, person_id)
       .await
@@ -4,7 +4,10 @@ use lemmy_api_common::{
   private_message::{MarkPrivateMessageAsRead, PrivateMessageResponse},
 };
 use lemmy_db_schema::{
-  source::private_message::{PrivateMessage, PrivateMessageUpdateForm},
+  source::{
+    notification::Notification,
+    private_message::{PrivateMessage, PrivateMessageUpdateForm},
+  },
   traits::Crud,
 };
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
@@ -39,6 +42,15 @@ pub async fn mark_pm_as_read(
   .await
   .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
 
+  Notification::update_read_for_private_message(
+    &mut context.pool(),
+    orig_private_message.recipient_id,
+    private_message_id,
+    read,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateNotification)?;
+
   let view = PrivateMessageView::read(&mut context.pool(), private_message_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindPrivateMessage)?;
@@ -7,6 +7,7 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   source::{
+    conversation::ConversationParticipant,
     local_site::LocalSite,
     private_message::PrivateMessage,
     private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
@@ -33,8 +34,18 @@ pub async fn create_pm_report(
     .await?
     .ok_or(LemmyErrorType::CouldntFindPrivateMessage)?;
 
-  // Make sure that only the recipient of the private message can create a report
-  if person_id != private_message.recipient_id {
+  // Make sure that only the recipient of the private message can create a report. In a group
+  // conversation, all other participants are recipients.
+  let is_recipient = match private_message.conversation_id {
+    Some(conversation_id) => {
+      ConversationParticipant::read(&mut context.pool(), conversation_id, person_id)
+        .await?
+        .is_some()
+        && person_id != private_message.creator_id
+    }
+    None => person_id == private_message.recipient_id,
+  };
+  if !is_recipient {
     Err(LemmyErrorType::CouldntCreateReport)?
   }
 
@@ -43,6 +54,7 @@ pub async fn create_pm_report(
     private_message_id,
     original_pm_text: private_message.content,
     reason,
+    category_id: data.category_id,
   };
 
   let report = PrivateMessageReport::report(&mut context.pool(), &report_form)
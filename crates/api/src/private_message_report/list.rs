@@ -25,6 +25,7 @@ pub async fn list_pm_reports(
     unresolved_only,
     page,
     limit,
+    report_ids: None,
   }
   .list(&mut context.pool())
   .await?;
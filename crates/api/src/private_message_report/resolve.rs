@@ -1,10 +1,15 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
+  build_response::send_local_notification,
   context::LemmyContext,
   private_message::{PrivateMessageReportResponse, ResolvePrivateMessageReport},
   utils::is_admin,
 };
-use lemmy_db_schema::{source::private_message_report::PrivateMessageReport, traits::Reportable};
+use lemmy_db_schema::{
+  source::{notification::NotificationInsertForm, private_message_report::PrivateMessageReport},
+  traits::Reportable,
+  NotificationType,
+};
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageReportView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -32,6 +37,15 @@ pub async fn resolve_pm_report(
     .await?
     .ok_or(LemmyErrorType::CouldntFindPrivateMessageReport)?;
 
+  if data.resolved {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(private_message_report_view.creator.id)
+      .kind(NotificationType::ReportResolved)
+      .private_message_id(Some(private_message_report_view.private_message.id))
+      .build();
+    send_local_notification(notification_form, person_id, &context).await;
+  }
+
   Ok(Json(PrivateMessageReportResponse {
     private_message_report_view,
   }))
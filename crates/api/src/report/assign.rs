@@ -0,0 +1,49 @@
+use super::check_report_mod_action;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{AssignReport, ReportResponse},
+};
+use lemmy_db_schema::source::report_combined::{ReportCombined, ReportTriageForm};
+use lemmy_db_views::structs::{LocalUserView, ReportCombinedView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn assign_report(
+  data: Json<AssignReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportResponse>> {
+  let person_id = local_user_view.person.id;
+  let report = ReportCombined::read(&mut context.pool(), data.report_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindReport)?;
+  check_report_mod_action(&local_user_view, &report, &mut context.pool()).await?;
+
+  // The assignee has to be allowed to handle the report too
+  if let Some(assignee_id) = data.assignee_id {
+    let assignee = LocalUserView::read_person(&mut context.pool(), assignee_id)
+      .await?
+      .ok_or(LemmyErrorType::InvalidReportAssignee)?;
+    if check_report_mod_action(&assignee, &report, &mut context.pool())
+      .await
+      .is_err()
+    {
+      Err(LemmyErrorType::InvalidReportAssignee)?
+    }
+  }
+
+  let form = ReportTriageForm {
+    assignee_id: Some(data.assignee_id),
+    ..Default::default()
+  };
+  report.triage(&mut context.pool(), person_id, &form).await?;
+
+  let report_combined_view = ReportCombinedView::read(&mut context.pool(), report.id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindReport)?;
+
+  Ok(Json(ReportResponse {
+    report_combined_view,
+  }))
+}
@@ -0,0 +1,34 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{ListReports, ListReportsResponse},
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::{report_combined_view::ReportCombinedQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists post, comment and private message reports in a single queue, for all communities the
+/// user moderates or for the given community
+#[tracing::instrument(skip(context))]
+pub async fn list_reports(
+  data: Query<ListReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReportsResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let reports = ReportCombinedQuery {
+    community_id: data.community_id,
+    category_id: data.category_id,
+    assignee_id: data.assignee_id,
+    unassigned_only: data.unassigned_only.unwrap_or_default(),
+    status: data.status,
+    unresolved_only: data.unresolved_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  Ok(Json(ListReportsResponse { reports }))
+}
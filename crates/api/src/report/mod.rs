@@ -0,0 +1,22 @@
+use lemmy_api_common::utils::{check_community_mod_action, is_admin};
+use lemmy_db_schema::{source::report_combined::ReportCombined, utils::DbPool};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub mod assign;
+pub mod list;
+pub mod triage;
+
+/// Post and comment reports are handled by the moderators of the community, private message
+/// reports only by admins.
+async fn check_report_mod_action(
+  local_user_view: &LocalUserView,
+  report: &ReportCombined,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(community_id) = report.community_id {
+    check_community_mod_action(&local_user_view.person, community_id, true, pool).await
+  } else {
+    is_admin(local_user_view)
+  }
+}
@@ -0,0 +1,46 @@
+use super::check_report_mod_action;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{ReportResponse, TriageReport},
+};
+use lemmy_db_schema::{
+  source::report_combined::{ReportCombined, ReportTriageForm},
+  ReportStatus,
+};
+use lemmy_db_views::structs::{LocalUserView, ReportCombinedView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Changes the category or status of a report
+#[tracing::instrument(skip(context))]
+pub async fn triage_report(
+  data: Json<TriageReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportResponse>> {
+  let person_id = local_user_view.person.id;
+  let report = ReportCombined::read(&mut context.pool(), data.report_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindReport)?;
+  check_report_mod_action(&local_user_view, &report, &mut context.pool()).await?;
+
+  // Resolving has to go through the resolve endpoints, which also notify the reporter
+  if data.status == Some(ReportStatus::Resolved) {
+    Err(LemmyErrorType::InvalidReportStatus)?
+  }
+
+  let form = ReportTriageForm {
+    category_id: data.category_id.map(Some),
+    status: data.status,
+    ..Default::default()
+  };
+  report.triage(&mut context.pool(), person_id, &form).await?;
+
+  let report_combined_view = ReportCombinedView::read(&mut context.pool(), report.id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindReport)?;
+
+  Ok(Json(ReportResponse {
+    report_combined_view,
+  }))
+}
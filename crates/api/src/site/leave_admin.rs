@@ -7,6 +7,7 @@ use lemmy_db_schema::{
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     local_user::{LocalUser, LocalUserUpdateForm},
     moderator::{ModAdd, ModAddForm},
+    oauth_provider::OAuthProvider,
     tagline::Tagline,
   },
   traits::Crud,
@@ -66,6 +67,7 @@ pub async fn leave_admin(
   let custom_emojis =
     CustomEmojiView::get_all(&mut context.pool(), site_view.local_site.id).await?;
   let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
+  let oauth_providers = OAuthProvider::get_all_public(&mut context.pool()).await?;
 
   Ok(Json(GetSiteResponse {
     site_view,
@@ -77,5 +79,7 @@ pub async fn leave_admin(
     taglines,
     custom_emojis,
     blocked_urls,
+    oauth_providers,
+    admin_oauth_providers: None,
   }))
 }
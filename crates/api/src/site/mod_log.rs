@@ -4,7 +4,10 @@ use lemmy_api_common::{
   site::{GetModlog, GetModlogResponse},
   utils::{check_community_mod_of_any_or_admin_action, check_private_instance},
 };
-use lemmy_db_schema::{source::local_site::LocalSite, ModlogActionType};
+use lemmy_db_schema::{
+  source::{local_site::LocalSite, rule::Rule},
+  ModlogActionType,
+};
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_moderator::structs::{
   AdminPurgeCommentView,
@@ -21,7 +24,9 @@ use lemmy_db_views_moderator::structs::{
   ModRemoveCommentView,
   ModRemoveCommunityView,
   ModRemovePostView,
+  ModResolveBanAppealView,
   ModTransferCommunityView,
+  ModWarningView,
   ModlogListParams,
 };
 use lemmy_utils::error::LemmyResult;
@@ -105,6 +110,16 @@ pub async fn get_mod_log(
     _ => Default::default(),
   };
 
+  let warnings = match type_ {
+    All | ModWarning => ModWarningView::list(&mut context.pool(), params).await?,
+    _ => Default::default(),
+  };
+
+  let resolved_ban_appeals = match type_ {
+    All | ModResolveBanAppeal => ModResolveBanAppealView::list(&mut context.pool(), params).await?,
+    _ => Default::default(),
+  };
+
   let hidden_communities = match type_ {
     All | ModHideCommunity if other_person_id.is_none() => {
       ModHideCommunityView::list(&mut context.pool(), params).await?
@@ -166,6 +181,11 @@ pub async fn get_mod_log(
     Default::default()
   };
 
+  let rule_violations = match other_person_id {
+    Some(person_id) => Rule::violation_counts(&mut context.pool(), person_id, community_id).await?,
+    None => Default::default(),
+  };
+
   // Return the jwt
   Ok(Json(GetModlogResponse {
     removed_posts,
@@ -183,5 +203,8 @@ pub async fn get_mod_log(
     admin_purged_posts,
     admin_purged_comments,
     hidden_communities,
+    warnings,
+    resolved_ban_appeals,
+    rule_violations,
   }))
 }
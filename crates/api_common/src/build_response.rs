@@ -12,23 +12,26 @@ use crate::{
 };
 use actix_web::web::Json;
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, LocalUserId, PostId},
+  newtypes::{CommentId, CommunityId, LocalUserId, PersonId, PostId},
   source::{
     actor_language::CommunityLanguage,
     comment::Comment,
     comment_reply::{CommentReply, CommentReplyInsertForm},
+    notification::{Notification, NotificationInsertForm},
     person::Person,
     person_mention::{PersonMention, PersonMentionInsertForm},
   },
   traits::Crud,
+  NotificationType,
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView, PostView};
 use lemmy_db_views_actor::structs::CommunityView;
 use lemmy_utils::{
-  error::LemmyResult,
+  error::{LemmyErrorExt, LemmyResult},
   utils::{markdown::markdown_to_html, mention::MentionData},
   LemmyErrorType,
 };
+use tracing::warn;
 
 pub async fn build_comment_response(
   context: &LemmyContext,
@@ -143,6 +146,17 @@ pub async fn send_local_notifs(
       PersonMention::create(&mut context.pool(), &user_mention_form)
         .await
         .ok();
+      let notification_form = NotificationInsertForm::builder()
+        .recipient_id(mention_user_view.person.id)
+        .kind(NotificationType::Mention)
+        .creator_id(Some(comment.creator_id))
+        .comment_id(Some(comment_id))
+        .post_id(Some(post.id))
+        .community_id(Some(community.id))
+        .build();
+      Notification::create(&mut context.pool(), &notification_form)
+        .await
+        .ok();
 
       // Send an email to those local users that have notifications on
       if do_send_email {
@@ -198,6 +212,17 @@ pub async fn send_local_notifs(
           CommentReply::create(&mut context.pool(), &comment_reply_form)
             .await
             .ok();
+          let notification_form = NotificationInsertForm::builder()
+            .recipient_id(parent_user_view.person.id)
+            .kind(NotificationType::Reply)
+            .creator_id(Some(comment.creator_id))
+            .comment_id(Some(comment.id))
+            .post_id(Some(post.id))
+            .community_id(Some(community.id))
+            .build();
+          Notification::create(&mut context.pool(), &notification_form)
+            .await
+            .ok();
 
           if do_send_email {
             let lang = get_interface_language(&parent_user_view);
@@ -244,6 +269,17 @@ pub async fn send_local_notifs(
           CommentReply::create(&mut context.pool(), &comment_reply_form)
             .await
             .ok();
+          let notification_form = NotificationInsertForm::builder()
+            .recipient_id(parent_user_view.person.id)
+            .kind(NotificationType::Reply)
+            .creator_id(Some(comment.creator_id))
+            .comment_id(Some(comment.id))
+            .post_id(Some(post.id))
+            .community_id(Some(community.id))
+            .build();
+          Notification::create(&mut context.pool(), &notification_form)
+            .await
+            .ok();
 
           if do_send_email {
             let lang = get_interface_language(&parent_user_view);
@@ -263,3 +299,36 @@ pub async fn send_local_notifs(
 
   Ok(recipient_ids)
 }
+
+/// Creates a notification about a mod action or a resolved report. Nothing is created if the
+/// recipient isn't a local user, or if they caused the notification themselves.
+///
+/// The moderator isn't stored as creator of the notification, so that it doesn't reveal more than
+/// the modlog. Errors are only logged, as the action itself has already been applied.
+pub async fn send_local_notification(
+  form: NotificationInsertForm,
+  mod_person_id: PersonId,
+  context: &LemmyContext,
+) {
+  if form.recipient_id == mod_person_id {
+    return;
+  }
+  if let Err(e) = create_local_notification(&form, context).await {
+    warn!("Failed to create notification: {e}");
+  }
+}
+
+async fn create_local_notification(
+  form: &NotificationInsertForm,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let recipient = Person::read(&mut context.pool(), form.recipient_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?;
+  if recipient.local {
+    Notification::create(&mut context.pool(), form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreateNotification)?;
+  }
+  Ok(())
+}
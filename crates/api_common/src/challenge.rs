@@ -0,0 +1,232 @@
+use crate::person::{CaptchaResponse, GetCaptchaResponse, ProofOfWorkResponse};
+use base64::{engine::general_purpose::STANDARD_NO_PAD as base64, Engine};
+use captcha::{gen, Captcha, Difficulty};
+use lemmy_db_schema::{
+  source::{
+    captcha_answer::{CaptchaAnswer, CaptchaAnswerForm},
+    local_site::LocalSite,
+  },
+  utils::DbPool,
+  ChallengeType,
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use uuid::Uuid;
+
+/// Longer proof of work nonces are rejected, so that checking an answer stays cheap.
+const MAX_NONCE_LENGTH: usize = 64;
+
+/// What the client gets to solve a challenge.
+pub enum ChallengeData {
+  Captcha { png: String, wav: String },
+  ProofOfWork { challenge: String, difficulty: i32 },
+}
+
+/// A challenge which has to be solved to sign up or to post from a new account, to keep out bots.
+/// The expected answer is stored in `captcha_answer` until the client answers.
+pub trait Challenge {
+  /// Creates a new challenge. Returns the answer to store, and the data for the client.
+  fn generate(&self) -> LemmyResult<(String, ChallengeData)>;
+
+  /// Checks the answer of the client against the stored one.
+  fn verify(&self, expected: &str, answer: &str) -> bool;
+}
+
+/// An image captcha, which also comes as audio.
+pub struct ImageCaptcha {
+  /// Can be easy, medium or hard.
+  pub difficulty: String,
+}
+
+impl Challenge for ImageCaptcha {
+  fn generate(&self) -> LemmyResult<(String, ChallengeData)> {
+    let captcha = gen(match self.difficulty.as_str() {
+      "easy" => Difficulty::Easy,
+      "hard" => Difficulty::Hard,
+      _ => Difficulty::Medium,
+    });
+    let png = captcha.as_base64().expect("failed to generate captcha");
+    let wav = captcha_as_wav_base64(&captcha)?;
+    Ok((
+      captcha.chars_as_string(),
+      ChallengeData::Captcha { png, wav },
+    ))
+  }
+
+  fn verify(&self, expected: &str, answer: &str) -> bool {
+    expected.to_lowercase() == answer.to_lowercase()
+  }
+}
+
+/// The client has to find a nonce, so that the sha256 hash of the challenge followed by the nonce
+/// starts with `difficulty` zero bits. Each additional bit doubles the expected work.
+pub struct ProofOfWork {
+  pub difficulty: i32,
+}
+
+impl Challenge for ProofOfWork {
+  fn generate(&self) -> LemmyResult<(String, ChallengeData)> {
+    let challenge = Uuid::new_v4().simple().to_string();
+    Ok((
+      challenge.clone(),
+      ChallengeData::ProofOfWork {
+        challenge,
+        difficulty: self.difficulty,
+      },
+    ))
+  }
+
+  fn verify(&self, expected: &str, answer: &str) -> bool {
+    if answer.len() > MAX_NONCE_LENGTH {
+      return false;
+    }
+    let hash = Sha256::digest(format!("{expected}{answer}"));
+    leading_zero_bits(&hash) as i32 >= self.difficulty
+  }
+}
+
+fn leading_zero_bits(hash: &[u8]) -> u32 {
+  let mut bits = 0;
+  for byte in hash {
+    bits += byte.leading_zeros();
+    if *byte != 0 {
+      break;
+    }
+  }
+  bits
+}
+
+/// The challenge which the admins selected for the site.
+pub fn site_challenge(local_site: &LocalSite) -> Box<dyn Challenge> {
+  match local_site.challenge_type {
+    ChallengeType::Captcha => Box::new(ImageCaptcha {
+      difficulty: local_site.captcha_difficulty.clone(),
+    }),
+    ChallengeType::ProofOfWork => Box::new(ProofOfWork {
+      difficulty: local_site.proof_of_work_difficulty,
+    }),
+  }
+}
+
+/// Creates a new challenge for the site, and stores the expected answer.
+pub async fn generate_challenge(
+  local_site: &LocalSite,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<GetCaptchaResponse> {
+  let (answer, data) = site_challenge(local_site).generate()?;
+  let captcha = CaptchaAnswer::insert(pool, &CaptchaAnswerForm { answer }).await?;
+  let uuid = captcha.uuid.to_string();
+
+  Ok(match data {
+    ChallengeData::Captcha { png, wav } => GetCaptchaResponse {
+      ok: Some(CaptchaResponse { png, wav, uuid }),
+      proof_of_work: None,
+    },
+    ChallengeData::ProofOfWork {
+      challenge,
+      difficulty,
+    } => GetCaptchaResponse {
+      ok: None,
+      proof_of_work: Some(ProofOfWorkResponse {
+        challenge,
+        difficulty,
+        uuid,
+      }),
+    },
+  })
+}
+
+/// Checks the answer to a challenge. Each challenge can only be answered once.
+pub async fn check_challenge(
+  local_site: &LocalSite,
+  uuid: Option<&str>,
+  answer: Option<&str>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let uuid = Uuid::parse_str(uuid.ok_or(LemmyErrorType::CaptchaIncorrect)?)?;
+  let expected = CaptchaAnswer::take(pool, uuid)
+    .await?
+    .ok_or(LemmyErrorType::CaptchaIncorrect)?;
+  if !site_challenge(local_site).verify(&expected.answer, answer.unwrap_or_default()) {
+    Err(LemmyErrorType::CaptchaIncorrect)?
+  }
+  Ok(())
+}
+
+/// Converts the captcha to a base64 encoded wav audio file
+fn captcha_as_wav_base64(captcha: &Captcha) -> LemmyResult<String> {
+  let letters = captcha.as_wav();
+
+  // Decode each wav file, concatenate the samples
+  let mut concat_samples: Vec<i16> = Vec::new();
+  let mut any_header: Option<hound::WavSpec> = None;
+  for letter in letters {
+    let mut cursor = Cursor::new(letter.unwrap_or_default());
+    let reader = hound::WavReader::new(&mut cursor)?;
+    any_header = Some(reader.spec());
+    let samples16 = reader
+      .into_samples::<i16>()
+      .collect::<Result<Vec<_>, _>>()
+      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
+    concat_samples.extend(samples16);
+  }
+
+  // Encode the concatenated result as a wav file
+  let mut output_buffer = Cursor::new(vec![]);
+  if let Some(header) = any_header {
+    let mut writer = hound::WavWriter::new(&mut output_buffer, header)
+      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
+    let mut writer16 = writer.get_i16_writer(concat_samples.len() as u32);
+    for sample in concat_samples {
+      writer16.write_sample(sample);
+    }
+    writer16
+      .flush()
+      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
+    writer
+      .finalize()
+      .with_lemmy_type(LemmyErrorType::CouldntCreateAudioCaptcha)?;
+
+    Ok(base64.encode(output_buffer.into_inner()))
+  } else {
+    Err(LemmyErrorType::CouldntCreateAudioCaptcha)?
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::challenge::{leading_zero_bits, Challenge, ChallengeData, ProofOfWork};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_leading_zero_bits() {
+    assert_eq!(0, leading_zero_bits(&[0b1000_0000, 0]));
+    assert_eq!(3, leading_zero_bits(&[0b0001_0000, 0]));
+    assert_eq!(12, leading_zero_bits(&[0, 0b0000_1111]));
+    assert_eq!(16, leading_zero_bits(&[0, 0]));
+  }
+
+  #[test]
+  fn test_proof_of_work() -> LemmyResult<()> {
+    let proof_of_work = ProofOfWork { difficulty: 8 };
+    let (expected, data) = proof_of_work.generate()?;
+    let ChallengeData::ProofOfWork { challenge, .. } = data else {
+      panic!("wrong challenge type");
+    };
+    assert_eq!(expected, challenge);
+
+    // Solve it the same way as a client would
+    let nonce = (0..)
+      .map(|n: u64| n.to_string())
+      .find(|nonce| proof_of_work.verify(&challenge, nonce))
+      .unwrap_or_default();
+    assert!(proof_of_work.verify(&challenge, &nonce));
+    assert!(!ProofOfWork { difficulty: 256 }.verify(&challenge, &nonce));
+    assert!(!proof_of_work.verify(&challenge, &"0".repeat(100)));
+
+    Ok(())
+  }
+}
@@ -1,14 +1,21 @@
-use crate::context::LemmyContext;
+use crate::{context::LemmyContext, utils::send_new_login_email};
 use actix_web::{http::header::USER_AGENT, HttpRequest};
-use chrono::Utc;
+use chrono::{DateTime, TimeDelta, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use lemmy_db_schema::{
   newtypes::LocalUserId,
   sensitive::SensitiveString,
   source::login_token::{LoginToken, LoginTokenCreateForm},
+  ApiTokenScope,
 };
+use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+/// How often the last use time of a login is updated.
+const LAST_USED_UPDATE_INTERVAL: TimeDelta = TimeDelta::minutes(10);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Claims {
@@ -17,10 +24,14 @@ pub struct Claims {
   pub iss: String,
   /// Time when this token was issued as UNIX-timestamp in seconds
   pub iat: i64,
+  /// Random id which keeps API tokens unique, even if several are created at the same time.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
 }
 
 impl Claims {
-  pub async fn validate(jwt: &str, context: &LemmyContext) -> LemmyResult<LocalUserId> {
+  /// Returns the login token, which contains the scopes for API tokens.
+  pub async fn validate(jwt: &str, context: &LemmyContext) -> LemmyResult<LoginToken> {
     let mut validation = Validation::default();
     validation.validate_exp = false;
     validation.required_spec_claims.remove("exp");
@@ -29,12 +40,16 @@ impl Claims {
     let claims =
       decode::<Claims>(jwt, &key, &validation).with_lemmy_type(LemmyErrorType::NotLoggedIn)?;
     let user_id = LocalUserId(claims.claims.sub.parse()?);
-    let is_valid = LoginToken::validate(&mut context.pool(), user_id, jwt).await?;
-    if !is_valid {
-      Err(LemmyErrorType::NotLoggedIn)?
-    } else {
-      Ok(user_id)
+    let login_token = LoginToken::validate(&mut context.pool(), user_id, jwt)
+      .await?
+      .ok_or(LemmyErrorType::NotLoggedIn)?;
+
+    // Only write the last use time occasionally, to avoid a database write for every request
+    let last_used = login_token.last_used.unwrap_or(login_token.published);
+    if Utc::now() - last_used > LAST_USED_UPDATE_INTERVAL {
+      LoginToken::update_last_used(&mut context.pool(), jwt).await?;
     }
+    Ok(login_token)
   }
 
   pub async fn generate(
@@ -42,16 +57,7 @@ impl Claims {
     req: HttpRequest,
     context: &LemmyContext,
   ) -> LemmyResult<SensitiveString> {
-    let hostname = context.settings().hostname.clone();
-    let my_claims = Claims {
-      sub: user_id.0.to_string(),
-      iss: hostname,
-      iat: Utc::now().timestamp(),
-    };
-
-    let secret = &context.secret().jwt_secret;
-    let key = EncodingKey::from_secret(secret.as_ref());
-    let token: SensitiveString = encode(&Header::default(), &my_claims, &key)?.into();
+    let token = Claims::encode_jwt(user_id, None, context)?;
     let ip = req
       .connection_info()
       .realip_remote_addr()
@@ -61,15 +67,85 @@ impl Claims {
       .get(USER_AGENT)
       .and_then(|ua| ua.to_str().ok())
       .map(ToString::to_string);
+    let is_known_device = LoginToken::is_known_device(
+      &mut context.pool(),
+      user_id,
+      ip.as_deref(),
+      user_agent.as_deref(),
+    )
+    .await?;
     let form = LoginTokenCreateForm {
       token: token.clone(),
       user_id,
-      ip,
-      user_agent,
+      ip: ip.clone(),
+      user_agent: user_agent.clone(),
+      name: None,
+      scopes: None,
+      expires: None,
     };
     LoginToken::create(&mut context.pool(), form).await?;
+
+    if !is_known_device {
+      let local_user_view = LocalUserView::read(&mut context.pool(), user_id).await?;
+      if let Some(local_user_view) =
+        local_user_view.filter(|l| l.local_user.send_login_alerts_to_email)
+      {
+        send_new_login_email(
+          &local_user_view,
+          ip.as_deref(),
+          user_agent.as_deref(),
+          context.settings(),
+        )
+        .await
+        .map_err(|e| warn!("Failed to send login alert: {e}"))
+        .ok();
+      }
+    }
     Ok(token)
   }
+
+  /// Create a named API token which can only be used for the given scopes.
+  pub async fn generate_api_token(
+    user_id: LocalUserId,
+    name: String,
+    scopes: Vec<ApiTokenScope>,
+    expires: Option<DateTime<Utc>>,
+    context: &LemmyContext,
+  ) -> LemmyResult<SensitiveString> {
+    let jti = Uuid::new_v4().to_string();
+    let token = Claims::encode_jwt(user_id, Some(jti), context)?;
+    let form = LoginTokenCreateForm {
+      token: token.clone(),
+      user_id,
+      ip: None,
+      user_agent: None,
+      name: Some(name),
+      scopes: Some(scopes),
+      expires,
+    };
+    LoginToken::create(&mut context.pool(), form)
+      .await
+      .with_lemmy_type(LemmyErrorType::ApiTokenAlreadyExists)?;
+    Ok(token)
+  }
+
+  fn encode_jwt(
+    user_id: LocalUserId,
+    jti: Option<String>,
+    context: &LemmyContext,
+  ) -> LemmyResult<SensitiveString> {
+    let hostname = context.settings().hostname.clone();
+    let my_claims = Claims {
+      sub: user_id.0.to_string(),
+      iss: hostname,
+      iat: Utc::now().timestamp(),
+      jti,
+    };
+
+    let secret = &context.secret().jwt_secret;
+    let key = EncodingKey::from_secret(secret.as_ref());
+    Ok(encode(&Header::default(), &my_claims, &key)?.into())
+  }
 }
 
 #[cfg(test)]
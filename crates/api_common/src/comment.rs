@@ -1,5 +1,15 @@
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommentReportId, CommunityId, LanguageId, LocalUserId, PostId},
+  newtypes::{
+    CommentId,
+    CommentReportId,
+    CommunityId,
+    LanguageId,
+    LocalUserId,
+    PostId,
+    ReportCategoryId,
+    RuleId,
+  },
+  source::comment_revision::CommentRevision,
   CommentSortType,
   ListingType,
 };
@@ -70,6 +80,8 @@ pub struct RemoveComment {
   pub comment_id: CommentId,
   pub removed: bool,
   pub reason: Option<String>,
+  /// The rule of the community or site which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -129,6 +141,7 @@ pub struct GetCommentsResponse {
   pub comments: Vec<CommentView>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -136,6 +149,10 @@ pub struct GetCommentsResponse {
 pub struct CreateCommentReport {
   pub comment_id: CommentId,
   pub reason: String,
+  /// One of the report categories which the admins defined.
+  pub category_id: Option<ReportCategoryId>,
+  /// The rule of the community or site which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -196,3 +213,30 @@ pub struct ListCommentLikes {
 pub struct ListCommentLikesResponse {
   pub comment_likes: Vec<VoteView>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the previous versions of an edited comment.
+pub struct ListCommentRevisions {
+  pub comment_id: CommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A previous version of a comment, and what was changed by the following edit.
+pub struct CommentRevisionView {
+  pub revision: CommentRevision,
+  /// Line based diff against the next version. Removed lines start with `- `, added lines with
+  /// `+ `.
+  pub diff: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The comment revisions response, from oldest to newest.
+pub struct ListCommentRevisionsResponse {
+  pub revisions: Vec<CommentRevisionView>,
+}
@@ -1,22 +1,33 @@
 #[cfg(feature = "full")]
 pub mod build_response;
 #[cfg(feature = "full")]
+pub mod challenge;
+#[cfg(feature = "full")]
 pub mod claims;
 pub mod comment;
 pub mod community;
 #[cfg(feature = "full")]
 pub mod context;
+pub mod conversation;
 pub mod custom_emoji;
+pub mod multi_community;
+pub mod oauth_provider;
 pub mod person;
 pub mod post;
 pub mod private_message;
 #[cfg(feature = "full")]
+pub mod push;
+pub mod report;
+#[cfg(feature = "full")]
 pub mod request;
+pub mod rule;
 #[cfg(feature = "full")]
 pub mod send_activity;
 pub mod site;
+pub mod tag;
 #[cfg(feature = "full")]
 pub mod utils;
+pub mod webhook;
 
 pub extern crate lemmy_db_schema;
 pub extern crate lemmy_db_views;
@@ -0,0 +1,78 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, MultiCommunityId, PersonId},
+  source::{community::Community, multi_community::MultiCommunity},
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a feed which combines the posts of several communities.
+pub struct CreateMultiCommunity {
+  pub name: String,
+  pub description: Option<String>,
+  /// Public feeds can be viewed by anyone who has the link.
+  pub public: Option<bool>,
+  pub community_ids: Vec<CommunityId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a multi-community feed. If set, `community_ids` replaces the communities of the feed.
+pub struct EditMultiCommunity {
+  pub multi_community_id: MultiCommunityId,
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub public: Option<bool>,
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a multi-community feed.
+pub struct DeleteMultiCommunity {
+  pub multi_community_id: MultiCommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a multi-community feed. Private feeds are only visible to their creator.
+pub struct GetMultiCommunity {
+  pub id: MultiCommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A multi-community feed response.
+pub struct MultiCommunityResponse {
+  pub multi_community: MultiCommunity,
+  pub communities: Vec<Community>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List your own and public multi-community feeds, optionally only those of a single creator.
+pub struct ListMultiCommunities {
+  pub creator_id: Option<PersonId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of multi-community feeds.
+pub struct ListMultiCommunitiesResponse {
+  pub multi_communities: Vec<MultiCommunity>,
+}
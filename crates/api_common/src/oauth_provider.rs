@@ -0,0 +1,99 @@
+use lemmy_db_schema::{
+  newtypes::OAuthProviderId,
+  sensitive::SensitiveString,
+  source::oauth_provider::OAuthProvider,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use url::Url;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add an OpenID Connect provider which users can log in with. Only admins can do this.
+pub struct CreateOAuthProvider {
+  pub display_name: String,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub issuer: Url,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub authorization_endpoint: Url,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub token_endpoint: Url,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub userinfo_endpoint: Url,
+  /// The claim which uniquely identifies a user, usually `sub`.
+  pub id_claim: String,
+  pub client_id: String,
+  pub client_secret: SensitiveString,
+  /// Space separated list of scopes to request, for example `openid email`.
+  pub scopes: String,
+  pub auto_verify_email: Option<bool>,
+  pub account_linking_enabled: Option<bool>,
+  pub use_pkce: Option<bool>,
+  pub enabled: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit an OAuth provider.
+pub struct EditOAuthProvider {
+  pub id: OAuthProviderId,
+  pub display_name: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub authorization_endpoint: Option<Url>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub token_endpoint: Option<Url>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub userinfo_endpoint: Option<Url>,
+  pub id_claim: Option<String>,
+  pub client_secret: Option<SensitiveString>,
+  pub scopes: Option<String>,
+  pub auto_verify_email: Option<bool>,
+  pub account_linking_enabled: Option<bool>,
+  pub use_pkce: Option<bool>,
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete an OAuth provider. This also removes the links of local users to the provider.
+pub struct DeleteOAuthProvider {
+  pub id: OAuthProviderId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a created or edited OAuth provider.
+pub struct OAuthProviderResponse {
+  pub oauth_provider: OAuthProvider,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Log in or sign up with an OAuth provider, after the user was redirected back from its
+/// authorization endpoint.
+pub struct AuthenticateWithOauth {
+  /// The authorization code which the provider added to the redirect.
+  pub code: String,
+  pub oauth_provider_id: OAuthProviderId,
+  /// Must be the same redirect uri which was used for the authorization request.
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub redirect_uri: Url,
+  /// The PKCE code verifier whose challenge was sent with the authorization request.
+  pub pkce_code_verifier: Option<SensitiveString>,
+  /// Only used when signing up.
+  pub username: Option<String>,
+  /// Only used when signing up.
+  pub show_nsfw: Option<bool>,
+  /// An answer is mandatory for signing up if require application is enabled on the server
+  pub answer: Option<String>,
+}
@@ -1,16 +1,43 @@
+use chrono::{DateTime, Utc};
 use lemmy_db_schema::{
-  newtypes::{CommentReplyId, CommunityId, LanguageId, PersonId, PersonMentionId},
+  newtypes::{
+    BanAppealId,
+    CommentReplyId,
+    CommunityId,
+    InviteId,
+    KeywordFilterId,
+    LanguageId,
+    LoginTokenId,
+    ModNoteId,
+    NotificationId,
+    PersonId,
+    PersonMentionId,
+    WebauthnCredentialId,
+  },
   sensitive::SensitiveString,
-  source::site::Site,
+  source::{
+    data_export::DataExport,
+    invite::{Invite, InvitedPerson},
+    keyword_filter::KeywordFilter,
+    mod_note::ModNote,
+    moderator::ModWarning,
+    rule::RuleViolationCount,
+    site::Site,
+    webauthn_credential::WebauthnCredential,
+  },
+  ApiTokenScope,
   CommentSortType,
   ListingType,
+  NotificationType,
   PostListingMode,
   SortType,
+  SubscribedType,
 };
-use lemmy_db_views::structs::{CommentView, LocalImageView, PostView};
+use lemmy_db_views::structs::{BanAppealView, CommentView, LocalImageView, PostView};
 use lemmy_db_views_actor::structs::{
   CommentReplyView,
   CommunityModeratorView,
+  NotificationView,
   PersonMentionView,
   PersonView,
 };
@@ -18,17 +45,27 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use ts_rs::TS;
+use webauthn_rs_proto::{
+  CreationChallengeResponse,
+  PublicKeyCredential,
+  RegisterPublicKeyCredential,
+  RequestChallengeResponse,
+};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// Logging into lemmy.
 pub struct Login {
   pub username_or_email: SensitiveString,
   pub password: SensitiveString,
-  /// May be required, if totp is enabled for their account.
+  /// May be required, if totp is enabled for their account. A recovery code can be used instead.
   pub totp_2fa_token: Option<String>,
+  /// Can be used instead of the totp token, if the user has a WebAuthn credential. The challenge
+  /// has to be requested first with [StartWebauthnLogin].
+  #[cfg_attr(feature = "full", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
 }
 
 #[skip_serializing_none]
@@ -51,6 +88,8 @@ pub struct Register {
   pub honeypot: Option<String>,
   /// An answer is mandatory if require application is enabled on the server
   pub answer: Option<String>,
+  /// An invite code is mandatory if the server is invite only
+  pub invite_code: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -59,8 +98,10 @@ pub struct Register {
 #[cfg_attr(feature = "full", ts(export))]
 /// A wrapper for the captcha response.
 pub struct GetCaptchaResponse {
-  /// Will be None if captchas are disabled.
+  /// Will be None if captchas are disabled, or if the site uses another challenge type.
   pub ok: Option<CaptchaResponse>,
+  /// Set if the site uses proof of work challenges.
+  pub proof_of_work: Option<ProofOfWorkResponse>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -76,6 +117,18 @@ pub struct CaptchaResponse {
   pub uuid: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A proof of work challenge. Find a nonce so that the sha256 hash of the challenge followed by the
+/// nonce starts with `difficulty` zero bits, and send it as captcha answer.
+pub struct ProofOfWorkResponse {
+  pub challenge: String,
+  pub difficulty: i32,
+  /// The UUID for the challenge, send it as captcha uuid.
+  pub uuid: String,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -104,10 +157,15 @@ pub struct SaveUserSettings {
   pub bio: Option<String>,
   /// Your matrix user id. Ex: @my_user:matrix.org
   pub matrix_user_id: Option<String>,
+  /// Your previous accounts on other instances. Listing an account here allows it to move to this
+  /// one.
+  pub also_known_as: Option<Vec<String>>,
   /// Whether to show or hide avatars.
   pub show_avatars: Option<bool>,
   /// Sends notifications to your email.
   pub send_notifications_to_email: Option<bool>,
+  /// Sends an email when someone logs in from a new IP address or device.
+  pub send_login_alerts_to_email: Option<bool>,
   /// Whether this account is a bot account. Users can hide these accounts easily if they wish.
   pub bot_account: Option<bool>,
   /// Whether to show bot accounts.
@@ -262,6 +320,53 @@ pub struct BlockPersonResponse {
   pub blocked: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Follow a person, to see their posts in the FollowedPeople listing.
+pub struct FollowPerson {
+  pub person_id: PersonId,
+  pub follow: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a person follow.
+pub struct FollowPersonResponse {
+  pub person_view: PersonView,
+  /// Follows of remote persons are pending until their instance accepts them.
+  pub subscribed: SubscribedType,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Hide posts and comments which contain a keyword or match a regex.
+pub struct CreateKeywordFilter {
+  pub keyword: String,
+  pub is_regex: Option<bool>,
+  /// Only apply the filter in this community.
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a created keyword filter.
+pub struct KeywordFilterResponse {
+  pub keyword_filter: KeywordFilter,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete one of your keyword filters.
+pub struct DeleteKeywordFilter {
+  pub keyword_filter_id: KeywordFilterId,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -393,6 +498,55 @@ pub struct GetUnreadCountResponse {
   pub replies: i64,
   pub mentions: i64,
   pub private_messages: i64,
+  /// Moderator actions against your posts, comments or account.
+  pub mod_actions: i64,
+  /// Reports you filed which have been resolved.
+  pub resolved_reports: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the notifications in your inbox.
+pub struct ListNotifications {
+  /// Only return notifications of this kind.
+  pub kind: Option<NotificationType>,
+  pub unread_only: Option<bool>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The notifications in your inbox.
+pub struct ListNotificationsResponse {
+  pub notifications: Vec<NotificationView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mark notifications as read or unread.
+pub struct MarkNotificationsAsRead {
+  /// The notifications to update. If not given, all of your notifications are updated.
+  pub notification_ids: Option<Vec<NotificationId>>,
+  pub read: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Open a server-sent events stream of new content.
+///
+/// New notifications are sent as `notification` events containing a `NotificationView`. New posts
+/// are sent as `post` events containing a `PostView`.
+pub struct GetPushStream {
+  /// Also send new posts in communities you're subscribed to.
+  pub include_posts: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
@@ -418,11 +572,218 @@ pub struct UpdateTotp {
   pub enabled: bool,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 pub struct UpdateTotpResponse {
   pub enabled: bool,
+  /// One-time codes which can be used instead of a totp token, if the authenticator is lost. Only
+  /// returned when enabling totp, and they can't be retrieved again later.
+  pub recovery_codes: Option<Vec<SensitiveString>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Start adding a WebAuthn credential. Requires the current password, or a totp token if totp is
+/// enabled.
+pub struct StartWebauthnRegistration {
+  pub password: Option<SensitiveString>,
+  pub totp_2fa_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The options to pass to `navigator.credentials.create()` for registering a new WebAuthn
+/// credential.
+pub struct StartWebauthnRegistrationResponse {
+  #[cfg_attr(feature = "full", ts(type = "any"))]
+  pub challenge: CreationChallengeResponse,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add a WebAuthn credential as second factor, after it was created by the browser.
+pub struct FinishWebauthnRegistration {
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(type = "any"))]
+  pub credential: RegisterPublicKeyCredential,
+  /// The current password, or a totp token if totp is enabled.
+  pub password: Option<SensitiveString>,
+  pub totp_2fa_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a registered WebAuthn credential.
+pub struct WebauthnCredentialResponse {
+  pub webauthn_credential: WebauthnCredential,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Your WebAuthn credentials.
+pub struct ListWebauthnCredentialsResponse {
+  pub webauthn_credentials: Vec<WebauthnCredential>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Revoke one of your WebAuthn credentials. Requires the current password, or a totp token if totp
+/// is enabled.
+pub struct DeleteWebauthnCredential {
+  pub webauthn_credential_id: WebauthnCredentialId,
+  pub password: Option<SensitiveString>,
+  pub totp_2fa_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Request a WebAuthn challenge for logging in. The password is checked here already, so that the
+/// challenge doesn't reveal which credentials a user has.
+pub struct StartWebauthnLogin {
+  pub username_or_email: SensitiveString,
+  pub password: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The options to pass to `navigator.credentials.get()`. The result is then sent with [Login].
+pub struct StartWebauthnLoginResponse {
+  #[cfg_attr(feature = "full", ts(type = "any"))]
+  pub challenge: RequestChallengeResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Revoke one of your logins, for example from a lost device.
+pub struct RevokeLogin {
+  pub login_token_id: LoginTokenId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a named API token for a bot or integration, which can only be used for the given
+/// scopes. API tokens are listed together with your logins.
+pub struct CreateApiToken {
+  pub name: String,
+  pub scopes: Vec<ApiTokenScope>,
+  pub expires: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The new API token. It is only shown once.
+pub struct CreateApiTokenResponse {
+  pub token: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Revoke one of your API tokens.
+pub struct RevokeApiToken {
+  pub name: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create an invite code, which others can use to sign up when the site is invite-only.
+pub struct CreateInvite {
+  /// How many users can sign up with the code. Defaults to one.
+  pub max_uses: Option<i32>,
+  /// A time that the invite expires at, in unix epoch seconds.
+  pub expires: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a created invite.
+pub struct InviteResponse {
+  pub invite: Invite,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// All invites you created, including used up and expired ones.
+pub struct ListInvitesResponse {
+  pub invites: Vec<Invite>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete one of your invites, so that it can't be used anymore.
+pub struct DeleteInvite {
+  pub invite_id: InviteId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the users who signed up with invites from a person, directly or indirectly. Admins only.
+pub struct GetInviteTree {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The invite tree of a person.
+pub struct GetInviteTreeResponse {
+  pub invited: Vec<InvitedPerson>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Ban everyone in the invite tree of a person, and delete all of their invites. The person
+/// themselves isn't banned, use [BanPerson] for that. Admins only.
+pub struct RevokeInviteTree {
+  pub person_id: PersonId,
+  pub reason: Option<String>,
+  pub remove_data: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The status of your latest data export. Once it is finished, the archive and outbox can be
+/// downloaded.
+pub struct DataExportResponse {
+  pub data_export: Option<DataExport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Move your account to a new account, which may be on another instance. The new account must
+/// list this one in its `also_known_as` aliases. Your community follows are moved over, and the
+/// account is shown as redirecting to the new one.
+pub struct MoveAccount {
+  /// The ActivityPub id of the new account.
+  pub target: String,
 }
 
 #[skip_serializing_none]
@@ -441,3 +802,136 @@ pub struct ListMedia {
 pub struct ListMediaResponse {
   pub images: Vec<LocalImageView>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Write a private note about a person.
+pub struct CreateModNote {
+  pub person_id: PersonId,
+  /// Visible to the moderators of this community. Without it, the note is site-wide and only
+  /// visible to admins.
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a created moderator note.
+pub struct ModNoteResponse {
+  pub mod_note: ModNote,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a moderator note.
+pub struct DeleteModNote {
+  pub mod_note_id: ModNoteId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the moderator notes about a person. Without a community, all notes are listed, which is
+/// only allowed for admins.
+pub struct ListModNotes {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The moderator notes about a person, newest first.
+pub struct ListModNotesResponse {
+  pub mod_notes: Vec<ModNote>,
+  /// How often the content of the person was removed for breaking each rule.
+  pub rule_violations: Vec<RuleViolationCount>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Formally warn a person. The warning counts as a strike until it expires, and too many strikes
+/// lead to an automatic ban, depending on the site settings.
+pub struct WarnPerson {
+  pub person_id: PersonId,
+  /// The community in which the person misbehaved. Without it, the warning is site-wide, which
+  /// is only allowed for admins.
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a warning.
+pub struct WarnPersonResponse {
+  pub mod_warning: ModWarning,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Appeal against a community ban, or the site ban if no community is given. Each ban can only
+/// be appealed once.
+///
+/// Site banned users can't log in, so they have to send their login details instead.
+pub struct CreateBanAppeal {
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub username_or_email: Option<SensitiveString>,
+  pub password: Option<SensitiveString>,
+  /// May be required, if totp is enabled for their account. A recovery code can be used instead.
+  pub totp_2fa_token: Option<String>,
+  /// Can be used instead of the totp token, if the user has a WebAuthn credential.
+  #[cfg_attr(feature = "full", ts(type = "any"))]
+  pub webauthn_credential: Option<PublicKeyCredential>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A ban appeal response.
+pub struct BanAppealResponse {
+  pub ban_appeal_view: BanAppealView,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the ban appeals which you can resolve. Admins get all of them, moderators only those for
+/// their communities.
+pub struct ListBanAppeals {
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: Option<bool>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The list of ban appeals.
+pub struct ListBanAppealsResponse {
+  pub ban_appeals: Vec<BanAppealView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Accept a ban appeal, which lifts the ban, or deny it.
+pub struct ResolveBanAppeal {
+  pub ban_appeal_id: BanAppealId,
+  pub accept: bool,
+  /// Sent to the appellant, and shown in the modlog.
+  pub reason: Option<String>,
+}
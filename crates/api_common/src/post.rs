@@ -1,5 +1,18 @@
+use chrono::{DateTime, Utc};
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, DbUrl, LanguageId, PostId, PostReportId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    DbUrl,
+    LanguageId,
+    MultiCommunityId,
+    PostId,
+    PostReportId,
+    ReportCategoryId,
+    RuleId,
+    TagId,
+  },
+  source::post_revision::PostRevision,
   ListingType,
   PostFeatureType,
   SortType,
@@ -30,6 +43,20 @@ pub struct CreatePost {
   pub language_id: Option<LanguageId>,
   /// Instead of fetching a thumbnail, use a custom one.
   pub custom_thumbnail: Option<String>,
+  /// Attach a poll with these options to the post.
+  pub poll_options: Option<Vec<String>>,
+  /// Allow voting for more than one poll option.
+  pub poll_multiple_choice: Option<bool>,
+  /// The poll stops accepting votes at this time.
+  pub poll_end_time: Option<DateTime<Utc>>,
+  /// Keep the post hidden and unfederated until this time, then publish it.
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
+  /// Tags of the community to attach to the post.
+  pub tags: Option<Vec<TagId>>,
+  /// The UUID of a solved challenge. Only needed for new accounts, if the site requires it.
+  pub captcha_uuid: Option<String>,
+  /// The answer to the challenge.
+  pub captcha_answer: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -83,6 +110,12 @@ pub struct GetPosts {
   pub show_read: Option<bool>,
   /// If true, then show the nsfw posts (even if your user setting is to hide them)
   pub show_nsfw: Option<bool>,
+  /// If true, only list your own posts which are scheduled to be published later.
+  pub scheduled_only: Option<bool>,
+  /// Only list posts from the communities of this feed.
+  pub multi_community_id: Option<MultiCommunityId>,
+  /// Only list posts which have this tag.
+  pub tag_id: Option<TagId>,
   pub page_cursor: Option<PaginationCursor>,
 }
 
@@ -107,6 +140,17 @@ pub struct CreatePostLike {
   pub score: i16,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Vote in a poll.
+pub struct VoteInPoll {
+  pub post_id: PostId,
+  /// The indexes of the chosen options. Only one is allowed unless the poll is multiple choice.
+  /// An empty list removes your vote.
+  pub choices: Vec<i32>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -124,12 +168,14 @@ pub struct EditPost {
   pub language_id: Option<LanguageId>,
   /// Instead of fetching a thumbnail, use a custom one.
   pub custom_thumbnail: Option<String>,
+  /// Change the publish time of a post which is still scheduled.
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// Delete a post.
+/// Delete a post. Deleting a scheduled post cancels it.
 pub struct DeletePost {
   pub post_id: PostId,
   pub deleted: bool,
@@ -144,6 +190,8 @@ pub struct RemovePost {
   pub post_id: PostId,
   pub removed: bool,
   pub reason: Option<String>,
+  /// The rule of the community or site which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[skip_serializing_none]
@@ -185,6 +233,16 @@ pub struct FeaturePost {
   pub feature_type: PostFeatureType,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Replace the tags of a post. Can be done by the post creator or by moderators. Mod-only tags can
+/// only be added or removed by moderators.
+pub struct UpdatePostTags {
+  pub post_id: PostId,
+  pub tags: Vec<TagId>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -194,6 +252,7 @@ pub struct SavePost {
   pub save: bool,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -201,6 +260,10 @@ pub struct SavePost {
 pub struct CreatePostReport {
   pub post_id: PostId,
   pub reason: String,
+  /// One of the report categories which the admins defined.
+  pub category_id: Option<ReportCategoryId>,
+  /// The rule of the community or site which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -300,3 +363,30 @@ pub struct ListPostLikes {
 pub struct ListPostLikesResponse {
   pub post_likes: Vec<VoteView>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the previous versions of an edited post.
+pub struct ListPostRevisions {
+  pub post_id: PostId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A previous version of a post, and what was changed by the following edit.
+pub struct PostRevisionView {
+  pub revision: PostRevision,
+  /// Line based diff of the title, url and body against the next version. Removed lines start
+  /// with `- `, added lines with `+ `.
+  pub diff: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The post revisions response, from oldest to newest.
+pub struct ListPostRevisionsResponse {
+  pub revisions: Vec<PostRevisionView>,
+}
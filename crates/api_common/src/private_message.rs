@@ -1,4 +1,9 @@
-use lemmy_db_schema::newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId};
+use lemmy_db_schema::newtypes::{
+  PersonId,
+  PrivateMessageId,
+  PrivateMessageReportId,
+  ReportCategoryId,
+};
 use lemmy_db_views::structs::{PrivateMessageReportView, PrivateMessageView};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -69,6 +74,7 @@ pub struct PrivateMessageResponse {
   pub private_message_view: PrivateMessageView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -76,6 +82,8 @@ pub struct PrivateMessageResponse {
 pub struct CreatePrivateMessageReport {
   pub private_message_id: PrivateMessageId,
   pub reason: String,
+  /// One of the report categories which the admins defined.
+  pub category_id: Option<ReportCategoryId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -0,0 +1,91 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, NotificationId, PersonId, PostId},
+  utils::PgListener,
+};
+use lemmy_utils::error::LemmyResult;
+use serde::Deserialize;
+use std::{sync::LazyLock, time::Duration};
+use tokio::{sync::broadcast, time::sleep};
+use tracing::warn;
+
+/// Postgres channels which the push triggers in `replaceable_schema/triggers.sql` send to.
+const NOTIFICATION_CHANNEL: &str = "lemmy_notification";
+const POST_CHANNEL: &str = "lemmy_post";
+
+/// How many events a slow subscriber can fall behind before it starts missing events.
+const PUSH_CHANNEL_CAPACITY: usize = 1000;
+
+static PUSH_EVENTS: LazyLock<broadcast::Sender<PushEvent>> =
+  LazyLock::new(|| broadcast::channel(PUSH_CHANNEL_CAPACITY).0);
+
+/// Content which was created on any Lemmy process that uses the same database.
+#[derive(Debug, Clone)]
+pub enum PushEvent {
+  Notification {
+    notification_id: NotificationId,
+    recipient_id: PersonId,
+  },
+  Post {
+    post_id: PostId,
+    community_id: CommunityId,
+  },
+}
+
+#[derive(Deserialize)]
+struct NotificationPayload {
+  notification_id: NotificationId,
+  recipient_id: PersonId,
+}
+
+#[derive(Deserialize)]
+struct PostPayload {
+  post_id: PostId,
+  community_id: CommunityId,
+}
+
+pub struct PushChannel {}
+
+impl PushChannel {
+  /// Returns a receiver for all events which are created after this call.
+  pub fn subscribe() -> broadcast::Receiver<PushEvent> {
+    PUSH_EVENTS.subscribe()
+  }
+
+  /// Listens for new notifications and posts in the database, and passes them on to all
+  /// subscribers of this process. Reconnects if the database connection fails, so this never
+  /// returns.
+  pub async fn listen() {
+    loop {
+      if let Err(e) = Self::listen_inner().await {
+        warn!("Push listener failed: {e}");
+      }
+      sleep(Duration::from_secs(5)).await;
+    }
+  }
+
+  async fn listen_inner() -> LemmyResult<()> {
+    let mut listener = PgListener::connect(&[NOTIFICATION_CHANNEL, POST_CHANNEL]).await?;
+    while let Some(message) = listener.recv().await {
+      let event = match message.channel() {
+        NOTIFICATION_CHANNEL => {
+          let p: NotificationPayload = serde_json::from_str(message.payload())?;
+          PushEvent::Notification {
+            notification_id: p.notification_id,
+            recipient_id: p.recipient_id,
+          }
+        }
+        POST_CHANNEL => {
+          let p: PostPayload = serde_json::from_str(message.payload())?;
+          PushEvent::Post {
+            post_id: p.post_id,
+            community_id: p.community_id,
+          }
+        }
+        _ => continue,
+      };
+      // Sending only fails if nobody is subscribed
+      PUSH_EVENTS.send(event).ok();
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,100 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, PersonId, ReportCategoryId, ReportCombinedId},
+  source::report_category::ReportCategory,
+  ReportStatus,
+};
+use lemmy_db_views::structs::ReportCombinedView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a report category, like spam or harassment. Only admins can do this.
+pub struct CreateReportCategory {
+  pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a report category. It is removed from all reports.
+pub struct DeleteReportCategory {
+  pub report_category_id: ReportCategoryId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A report category response.
+pub struct ReportCategoryResponse {
+  pub report_category: ReportCategory,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The report categories of the site.
+pub struct ListReportCategoriesResponse {
+  pub report_categories: Vec<ReportCategory>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the post, comment and private message reports which you can handle in a single queue.
+/// Admins get all of them, moderators only those of their communities.
+pub struct ListReports {
+  pub community_id: Option<CommunityId>,
+  pub category_id: Option<ReportCategoryId>,
+  /// Only reports which are assigned to this person.
+  pub assignee_id: Option<PersonId>,
+  /// Only reports which nobody is assigned to.
+  pub unassigned_only: Option<bool>,
+  pub status: Option<ReportStatus>,
+  pub unresolved_only: Option<bool>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The combined report queue.
+pub struct ListReportsResponse {
+  pub reports: Vec<ReportCombinedView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Assign a report to a moderator of its community or an admin, so that others don't handle it
+/// too. Without an assignee, the report is unassigned.
+pub struct AssignReport {
+  pub report_id: ReportCombinedId,
+  pub assignee_id: Option<PersonId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Change the category or status of a report. Reports are resolved with the resolve endpoint of
+/// their type, but a resolved report can be reopened here.
+pub struct TriageReport {
+  pub report_id: ReportCombinedId,
+  pub category_id: Option<ReportCategoryId>,
+  pub status: Option<ReportStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A report in the combined report queue.
+pub struct ReportResponse {
+  pub report_combined_view: ReportCombinedView,
+}
@@ -15,6 +15,7 @@ use lemmy_db_schema::{
     images::{ImageDetailsForm, LocalImage, LocalImageForm},
     local_site::LocalSite,
     local_user::LocalUser,
+    oauth_provider::OAuthProvider,
     post::{Post, PostUpdateForm},
   },
 };
@@ -26,7 +27,7 @@ use lemmy_utils::{
 };
 use mime::{Mime, TEXT_HTML};
 use reqwest::{
-  header::{CONTENT_TYPE, LOCATION, RANGE},
+  header::{ACCEPT, CONTENT_TYPE, LOCATION, RANGE},
   redirect::Policy,
   Client,
   ClientBuilder,
@@ -53,6 +54,47 @@ pub fn client_builder(settings: &Settings) -> ClientBuilder {
     .redirect(Policy::none())
 }
 
+/// Resolves the domain of the url and throws an error if it points to any internal IP, so that
+/// users can't make the server send requests into its own network. Uses logic from nightly
+/// IpAddr::is_global.
+pub async fn check_url_is_public(url: &Url) -> LemmyResult<()> {
+  if !cfg!(debug_assertions) {
+    // TODO: Replace with IpAddr::is_global() once stabilized
+    //       https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_global
+    let domain = url.domain().ok_or(LemmyErrorType::UrlWithoutDomain)?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    let invalid_ip = lookup_host((domain.to_owned(), port))
+      .await?
+      .any(|addr| is_internal_ip(addr.ip()));
+    if invalid_ip {
+      return Err(LemmyErrorType::InvalidUrl.into());
+    }
+  }
+  Ok(())
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(addr) => {
+      addr.is_private()
+        || addr.is_link_local()
+        || addr.is_loopback()
+        || addr.is_multicast()
+        || addr.is_unspecified()
+    }
+    IpAddr::V6(addr) => match addr.to_ipv4_mapped() {
+      Some(addr) => is_internal_ip(IpAddr::V4(addr)),
+      None => {
+        addr.is_loopback()
+          || addr.is_multicast()
+          || addr.is_unspecified()
+          || ((addr.segments()[0] & 0xfe00) == 0xfc00) // is_unique_local
+          || ((addr.segments()[0] & 0xffc0) == 0xfe80) // is_unicast_link_local
+      }
+    },
+  }
+}
+
 /// Fetches metadata for the given link and optionally generates thumbnail.
 #[tracing::instrument(skip_all)]
 pub async fn fetch_link_metadata(
@@ -64,29 +106,7 @@ pub async fn fetch_link_metadata(
     return Err(LemmyErrorType::InvalidUrl.into());
   }
 
-  // Resolve the domain and throw an error if it points to any internal IP,
-  // using logic from nightly IpAddr::is_global.
-  if !cfg!(debug_assertions) {
-    // TODO: Replace with IpAddr::is_global() once stabilized
-    //       https://doc.rust-lang.org/std/net/enum.IpAddr.html#method.is_global
-    let domain = url.domain().ok_or(LemmyErrorType::UrlWithoutDomain)?;
-    let invalid_ip = lookup_host((domain.to_owned(), 80))
-      .await?
-      .any(|addr| match addr.ip() {
-        IpAddr::V4(addr) => {
-          addr.is_private() || addr.is_link_local() || addr.is_loopback() || addr.is_multicast()
-        }
-        IpAddr::V6(addr) => {
-          addr.is_loopback()
-                        || addr.is_multicast()
-                        || ((addr.segments()[0] & 0xfe00) == 0xfc00) // is_unique_local
-                        || ((addr.segments()[0] & 0xffc0) == 0xfe80) // is_unicast_link_local
-        }
-      });
-    if invalid_ip {
-      return Err(LemmyErrorType::InvalidUrl.into());
-    }
-  }
+  check_url_is_public(url).await?;
 
   info!("Fetching site metadata for url: {}", url);
   // We only fetch the first MB of data in order to not waste bandwidth especially for large
@@ -569,6 +589,95 @@ pub async fn replace_image(
   Ok(())
 }
 
+#[derive(Deserialize)]
+struct OAuthTokenResponse {
+  access_token: String,
+}
+
+/// The claims of an OAuth user which are used for logging in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthUserInfo {
+  /// The value of the provider's `id_claim`.
+  pub oauth_user_id: String,
+  pub email: Option<String>,
+  /// Whether the provider says that it verified the email. Some providers send this as a string.
+  pub email_verified: bool,
+}
+
+/// Exchanges an authorization code for an access token at the provider's token endpoint, and uses
+/// it to fetch the user's claims from the userinfo endpoint.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_oauth_user_info(
+  oauth_provider: &OAuthProvider,
+  code: &str,
+  pkce_code_verifier: Option<&str>,
+  redirect_uri: &Url,
+  context: &LemmyContext,
+) -> LemmyResult<OAuthUserInfo> {
+  let mut form = vec![
+    ("grant_type", "authorization_code"),
+    ("code", code),
+    ("redirect_uri", redirect_uri.as_str()),
+    ("client_id", oauth_provider.client_id.as_str()),
+    ("client_secret", &*oauth_provider.client_secret),
+  ];
+  if let Some(pkce_code_verifier) = pkce_code_verifier {
+    form.push(("code_verifier", pkce_code_verifier));
+  }
+  let token = context
+    .client()
+    .post(oauth_provider.token_endpoint.as_str())
+    .timeout(REQWEST_TIMEOUT)
+    .header(ACCEPT, "application/json")
+    .form(&form)
+    .send()
+    .await?
+    .error_for_status()?
+    .json::<OAuthTokenResponse>()
+    .await?;
+
+  let claims = context
+    .client()
+    .get(oauth_provider.userinfo_endpoint.as_str())
+    .timeout(REQWEST_TIMEOUT)
+    .header(ACCEPT, "application/json")
+    .bearer_auth(token.access_token)
+    .send()
+    .await?
+    .error_for_status()?
+    .json::<serde_json::Map<String, serde_json::Value>>()
+    .await?;
+
+  parse_oauth_user_info(&claims, &oauth_provider.id_claim)
+}
+
+fn parse_oauth_user_info(
+  claims: &serde_json::Map<String, serde_json::Value>,
+  id_claim: &str,
+) -> LemmyResult<OAuthUserInfo> {
+  // Some providers use numeric ids
+  let oauth_user_id = match claims.get(id_claim) {
+    Some(serde_json::Value::String(id)) if !id.is_empty() => id.clone(),
+    Some(serde_json::Value::Number(id)) => id.to_string(),
+    _ => Err(LemmyErrorType::OauthLoginFailed)?,
+  };
+  let email = claims
+    .get("email")
+    .and_then(serde_json::Value::as_str)
+    .map(str::to_lowercase);
+  let email_verified = email.is_some()
+    && match claims.get("email_verified") {
+      Some(serde_json::Value::Bool(verified)) => *verified,
+      Some(serde_json::Value::String(verified)) => verified == "true",
+      _ => false,
+    };
+  Ok(OAuthUserInfo {
+    oauth_user_id,
+    email,
+    email_verified,
+  })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::indexing_slicing)]
@@ -576,10 +685,17 @@ mod tests {
 
   use crate::{
     context::LemmyContext,
-    request::{extract_opengraph_data, fetch_link_metadata},
+    request::{
+      extract_opengraph_data,
+      fetch_link_metadata,
+      is_internal_ip,
+      parse_oauth_user_info,
+      OAuthUserInfo,
+    },
   };
   use pretty_assertions::assert_eq;
   use serial_test::serial;
+  use std::net::IpAddr;
   use url::Url;
 
   // These helped with testing
@@ -655,4 +771,58 @@ mod tests {
       Some(Url::parse("https://example.com/image.jpg").unwrap().into())
     );
   }
+
+  #[test]
+  fn test_parse_oauth_user_info() {
+    let claims = serde_json::json!({"sub": "abc123", "email": "User@Example.com"});
+    let info = parse_oauth_user_info(claims.as_object().unwrap(), "sub").unwrap();
+    assert_eq!(
+      OAuthUserInfo {
+        oauth_user_id: "abc123".to_string(),
+        email: Some("user@example.com".to_string()),
+        email_verified: false,
+      },
+      info
+    );
+
+    // only a verified email can be trusted
+    let claims = serde_json::json!({"sub": "abc123", "email": "a@b.com", "email_verified": true});
+    let info = parse_oauth_user_info(claims.as_object().unwrap(), "sub").unwrap();
+    assert!(info.email_verified);
+    let claims = serde_json::json!({"sub": "abc123", "email": "a@b.com", "email_verified": "true"});
+    let info = parse_oauth_user_info(claims.as_object().unwrap(), "sub").unwrap();
+    assert!(info.email_verified);
+
+    // numeric ids are accepted, the email is optional
+    let claims = serde_json::json!({"id": 42});
+    let info = parse_oauth_user_info(claims.as_object().unwrap(), "id").unwrap();
+    assert_eq!("42", info.oauth_user_id);
+    assert_eq!(None, info.email);
+    assert!(!info.email_verified);
+
+    // missing or empty id claim
+    let claims = serde_json::json!({"sub": "", "email": "user@example.com"});
+    assert!(parse_oauth_user_info(claims.as_object().unwrap(), "sub").is_err());
+    assert!(parse_oauth_user_info(claims.as_object().unwrap(), "id").is_err());
+  }
+
+  #[test]
+  fn test_is_internal_ip() {
+    for ip in [
+      "127.0.0.1",
+      "10.1.2.3",
+      "192.168.0.1",
+      "169.254.169.254",
+      "0.0.0.0",
+      "::1",
+      "fd00::1",
+      "fe80::1",
+      "::ffff:127.0.0.1",
+    ] {
+      assert!(is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+    }
+    for ip in ["1.1.1.1", "2606:4700:4700::1111"] {
+      assert!(!is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+    }
+  }
 }
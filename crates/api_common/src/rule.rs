@@ -0,0 +1,67 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, RuleId},
+  source::rule::Rule,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a rule for a community you moderate. Without a community, a site-wide rule is created,
+/// which is only allowed for admins.
+pub struct CreateRule {
+  pub community_id: Option<CommunityId>,
+  pub title: String,
+  pub description: Option<String>,
+  /// Where the rule is listed. By default it is added after the existing rules.
+  pub position: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a rule. An empty description removes it.
+pub struct EditRule {
+  pub rule_id: RuleId,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub position: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a rule. Reports and removals which cite it are kept.
+pub struct DeleteRule {
+  pub rule_id: RuleId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A rule response.
+pub struct RuleResponse {
+  pub rule: Rule,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the rules of a community, or the site-wide rules if no community is given.
+pub struct ListRules {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The rules of a community or the site, in order.
+pub struct ListRulesResponse {
+  pub rules: Vec<Rule>,
+}
@@ -1,18 +1,24 @@
 use crate::{community::BanFromCommunity, context::LemmyContext, post::DeletePost};
 use activitypub_federation::config::Data;
+use chrono::{DateTime, Utc};
 use futures::future::BoxFuture;
 use lemmy_db_schema::{
   newtypes::{CommunityId, DbUrl, PersonId},
   source::{
     comment::Comment,
     community::Community,
+    conversation::Conversation,
     person::Person,
     post::Post,
     private_message::PrivateMessage,
+    webhook::Webhook,
+    webhook_delivery::WebhookDelivery,
   },
+  traits::Crud,
 };
 use lemmy_db_views::structs::PrivateMessageView;
-use lemmy_utils::error::LemmyResult;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use serde::Serialize;
 use std::sync::{LazyLock, OnceLock};
 use tokio::{
   sync::{
@@ -58,7 +64,15 @@ pub enum SendActivityData {
     community: Community,
     score: i16,
   },
+  VoteInPoll {
+    post: Post,
+    actor: Person,
+    choices: Vec<i32>,
+    previous_choices: Vec<i32>,
+  },
   FollowCommunity(Community, Person, bool),
+  ApproveCommunityFollower(Community, Person, bool),
+  FollowPerson(Person, Person, bool),
   UpdateCommunity(Person, Community),
   DeleteCommunity(Person, Community, bool),
   RemoveCommunity {
@@ -91,6 +105,10 @@ pub enum SendActivityData {
   UpdatePrivateMessage(PrivateMessageView),
   DeletePrivateMessage(Person, PrivateMessage, bool),
   DeleteUser(Person, bool),
+  /// The first person migrated to the second one
+  MoveUser(Person, Person),
+  /// The person left the group conversation
+  LeaveConversation(Person, Conversation),
   CreateReport {
     object_id: Url,
     actor: Person,
@@ -99,6 +117,232 @@ pub enum SendActivityData {
   },
 }
 
+/// An event which is sent to webhooks. It is serialized as `{"event": ..., "data": {...}}`.
+#[derive(Serialize)]
+#[serde(tag = "event", content = "data")]
+enum WebhookEvent<'a> {
+  PostCreated {
+    post: &'a Post,
+  },
+  CommentCreated {
+    comment: &'a Comment,
+  },
+  ReportCreated {
+    object_id: &'a Url,
+    reporter: &'a Person,
+    reason: &'a str,
+  },
+  ModRemovePost {
+    post: &'a Post,
+    moderator: &'a Person,
+    reason: &'a Option<String>,
+    removed: bool,
+  },
+  ModLockPost {
+    post: &'a Post,
+    moderator: &'a Person,
+    locked: bool,
+  },
+  ModFeaturePost {
+    post: &'a Post,
+    moderator: &'a Person,
+    featured: bool,
+  },
+  ModRemoveComment {
+    comment: &'a Comment,
+    moderator: &'a Person,
+    reason: &'a Option<String>,
+    removed: bool,
+  },
+  ModRemoveCommunity {
+    community: &'a Community,
+    moderator: &'a Person,
+    reason: &'a Option<String>,
+    removed: bool,
+  },
+  ModAddCommunity {
+    moderator: &'a Person,
+    target: PersonId,
+    added: bool,
+  },
+  ModBanFromCommunity {
+    moderator: &'a Person,
+    target: &'a Person,
+    reason: &'a Option<String>,
+    banned: bool,
+    expires: Option<i64>,
+  },
+  ModBan {
+    moderator: &'a Person,
+    target: &'a Person,
+    reason: &'a Option<String>,
+    banned: bool,
+    expires: Option<i64>,
+  },
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+  #[serde(flatten)]
+  event: WebhookEvent<'a>,
+  /// None for site-wide events
+  community_id: Option<CommunityId>,
+  published: DateTime<Utc>,
+}
+
+impl SendActivityData {
+  /// Queues a payload for each webhook which is interested in this activity. The payloads are
+  /// delivered by lemmy_federate, so that delivery is retried like outgoing activities.
+  pub async fn queue_webhooks(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    use SendActivityData::*;
+    let (community_id, event) = match self {
+      CreatePost(post) => (Some(post.community_id), WebhookEvent::PostCreated { post }),
+      CreateComment(comment) => {
+        let post = Post::read(&mut context.pool(), comment.post_id)
+          .await?
+          .ok_or(LemmyErrorType::CouldntFindPost)?;
+        (
+          Some(post.community_id),
+          WebhookEvent::CommentCreated { comment },
+        )
+      }
+      CreateReport {
+        object_id,
+        actor,
+        community,
+        reason,
+      } => (
+        Some(community.id),
+        WebhookEvent::ReportCreated {
+          object_id,
+          reporter: actor,
+          reason,
+        },
+      ),
+      RemovePost {
+        post,
+        moderator,
+        reason,
+        removed,
+      } => (
+        Some(post.community_id),
+        WebhookEvent::ModRemovePost {
+          post,
+          moderator,
+          reason,
+          removed: *removed,
+        },
+      ),
+      LockPost(post, moderator, locked) => (
+        Some(post.community_id),
+        WebhookEvent::ModLockPost {
+          post,
+          moderator,
+          locked: *locked,
+        },
+      ),
+      FeaturePost(post, moderator, featured) => (
+        Some(post.community_id),
+        WebhookEvent::ModFeaturePost {
+          post,
+          moderator,
+          featured: *featured,
+        },
+      ),
+      RemoveComment {
+        comment,
+        moderator,
+        community,
+        reason,
+      } => (
+        Some(community.id),
+        WebhookEvent::ModRemoveComment {
+          comment,
+          moderator,
+          reason,
+          removed: comment.removed,
+        },
+      ),
+      RemoveCommunity {
+        moderator,
+        community,
+        reason,
+        removed,
+      } => (
+        Some(community.id),
+        WebhookEvent::ModRemoveCommunity {
+          community,
+          moderator,
+          reason,
+          removed: *removed,
+        },
+      ),
+      AddModToCommunity {
+        moderator,
+        community_id,
+        target,
+        added,
+      } => (
+        Some(*community_id),
+        WebhookEvent::ModAddCommunity {
+          moderator,
+          target: *target,
+          added: *added,
+        },
+      ),
+      BanFromCommunity {
+        moderator,
+        community_id,
+        target,
+        data,
+      } => (
+        Some(*community_id),
+        WebhookEvent::ModBanFromCommunity {
+          moderator,
+          target,
+          reason: &data.reason,
+          banned: data.ban,
+          expires: data.expires,
+        },
+      ),
+      BanFromSite {
+        moderator,
+        banned_user,
+        reason,
+        ban,
+        expires,
+        ..
+      } => (
+        None,
+        WebhookEvent::ModBan {
+          moderator,
+          target: banned_user,
+          reason,
+          banned: *ban,
+          expires: *expires,
+        },
+      ),
+      _ => return Ok(()),
+    };
+
+    let webhook_ids = Webhook::list_for_event(&mut context.pool(), community_id)
+      .await?
+      .into_iter()
+      .map(|w| w.id)
+      .collect::<Vec<_>>();
+    if webhook_ids.is_empty() {
+      return Ok(());
+    }
+    let payload = serde_json::to_value(WebhookPayload {
+      event,
+      community_id,
+      published: Utc::now(),
+    })?;
+    WebhookDelivery::queue(&mut context.pool(), webhook_ids, payload).await?;
+    Ok(())
+  }
+}
+
 // TODO: instead of static, move this into LemmyContext. make sure that stopping the process with
 //       ctrl+c still works.
 static ACTIVITY_CHANNEL: LazyLock<ActivityChannel> = LazyLock::new(|| {
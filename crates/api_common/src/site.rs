@@ -13,10 +13,14 @@ use lemmy_db_schema::{
   source::{
     federation_queue_state::FederationQueueState,
     instance::Instance,
+    keyword_filter::KeywordFilter,
     language::Language,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
+    oauth_provider::{OAuthProvider, PublicOAuthProvider},
+    rule::RuleViolationCount,
     tagline::Tagline,
   },
+  ChallengeType,
   ListingType,
   ModlogActionType,
   PostListingMode,
@@ -56,7 +60,9 @@ use lemmy_db_views_moderator::structs::{
   ModRemoveCommentView,
   ModRemoveCommunityView,
   ModRemovePostView,
+  ModResolveBanAppealView,
   ModTransferCommunityView,
+  ModWarningView,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -153,6 +159,10 @@ pub struct GetModlogResponse {
   pub admin_purged_posts: Vec<AdminPurgePostView>,
   pub admin_purged_comments: Vec<AdminPurgeCommentView>,
   pub hidden_communities: Vec<ModHideCommunityView>,
+  pub warnings: Vec<ModWarningView>,
+  pub resolved_ban_appeals: Vec<ModResolveBanAppealView>,
+  /// When filtering by a person, how often their content was removed for breaking each rule.
+  pub rule_violations: Vec<RuleViolationCount>,
 }
 
 #[skip_serializing_none]
@@ -203,6 +213,15 @@ pub struct CreateSite {
   pub registration_mode: Option<RegistrationMode>,
   pub content_warning: Option<String>,
   pub default_post_listing_mode: Option<PostListingMode>,
+  pub oauth_registration: Option<bool>,
+  pub invites_per_user: Option<i32>,
+  pub invite_min_account_age_days: Option<i32>,
+  pub challenge_type: Option<ChallengeType>,
+  pub proof_of_work_difficulty: Option<i32>,
+  pub new_account_post_challenge_hours: Option<i32>,
+  pub strike_limit: Option<i32>,
+  pub strike_expiry_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
 }
 
 #[skip_serializing_none]
@@ -290,6 +309,25 @@ pub struct EditSite {
   pub content_warning: Option<String>,
   /// Default value for [LocalUser.post_listing_mode]
   pub default_post_listing_mode: Option<PostListingMode>,
+  /// Whether new users can sign up through an OAuth provider.
+  pub oauth_registration: Option<bool>,
+  /// How many unused invites each user can have in invite-only mode.
+  pub invites_per_user: Option<i32>,
+  /// How old an account needs to be (in days) before it can create invites.
+  pub invite_min_account_age_days: Option<i32>,
+  /// Which kind of challenge new users have to solve, if captchas are enabled.
+  pub challenge_type: Option<ChallengeType>,
+  /// How many leading zero bits proof of work hashes need. Can be 1 to 32.
+  pub proof_of_work_difficulty: Option<i32>,
+  /// Accounts younger than this many hours have to solve a challenge to create a post. Zero
+  /// disables it.
+  pub new_account_post_challenge_hours: Option<i32>,
+  /// How many active warnings lead to an automatic ban. Zero disables it.
+  pub strike_limit: Option<i32>,
+  /// After how many days a warning stops counting as strike.
+  pub strike_expiry_days: Option<i32>,
+  /// How many days automatic bans for too many strikes last.
+  pub strike_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -318,6 +356,10 @@ pub struct GetSiteResponse {
   /// A list of custom emojis your site supports.
   pub custom_emojis: Vec<CustomEmojiView>,
   pub blocked_urls: Vec<LocalSiteUrlBlocklist>,
+  /// The OAuth providers which users can log in with.
+  pub oauth_providers: Vec<PublicOAuthProvider>,
+  /// All OAuth providers, including disabled ones. Only returned for admins.
+  pub admin_oauth_providers: Option<Vec<OAuthProvider>>,
 }
 
 #[skip_serializing_none]
@@ -341,6 +383,7 @@ pub struct MyUserInfo {
   pub community_blocks: Vec<CommunityBlockView>,
   pub instance_blocks: Vec<InstanceBlockView>,
   pub person_blocks: Vec<PersonBlockView>,
+  pub keyword_filters: Vec<KeywordFilter>,
   pub discussion_languages: Vec<LanguageId>,
 }
 
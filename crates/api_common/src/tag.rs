@@ -0,0 +1,66 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, TagId},
+  source::tag::Tag,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a post tag for a community you moderate.
+pub struct CreateTag {
+  pub community_id: CommunityId,
+  pub name: String,
+  /// A hex color like `#ff0000`.
+  pub color: Option<String>,
+  /// Only moderators can attach the tag to posts.
+  pub mod_only: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a post tag. An empty color removes it.
+pub struct EditTag {
+  pub tag_id: TagId,
+  pub name: Option<String>,
+  pub color: Option<String>,
+  pub mod_only: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a post tag. It is also removed from all posts.
+pub struct DeleteTag {
+  pub tag_id: TagId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A post tag response.
+pub struct TagResponse {
+  pub tag: Tag,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the post tags of a community.
+pub struct ListCommunityTags {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The post tags of a community.
+pub struct ListCommunityTagsResponse {
+  pub tags: Vec<Tag>,
+}
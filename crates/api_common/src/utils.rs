@@ -7,10 +7,11 @@ use chrono::{DateTime, Days, Local, TimeZone, Utc};
 use enum_map::{enum_map, EnumMap};
 use lemmy_db_schema::{
   aggregates::structs::{PersonPostAggregates, PersonPostAggregatesForm},
-  newtypes::{CommunityId, DbUrl, InstanceId, PersonId, PostId},
+  newtypes::{CommunityId, DbUrl, InstanceId, PersonId, PostId, RuleId, TagId},
   source::{
     comment::{Comment, CommentUpdateForm},
-    community::{Community, CommunityModerator, CommunityUpdateForm},
+    comment_revision::CommentRevision,
+    community::{Community, CommunityFollower, CommunityModerator, CommunityUpdateForm},
     community_block::CommunityBlock,
     email_verification::{EmailVerification, EmailVerificationForm},
     images::{ImageDetails, RemoteImage},
@@ -21,17 +22,24 @@ use lemmy_db_schema::{
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     password_reset_request::PasswordResetRequest,
     person::{Person, PersonUpdateForm},
+    poll::Poll,
     person_block::PersonBlock,
     post::{Post, PostRead},
+    post_revision::PostRevision,
     private_message::PrivateMessage,
+    registration_application::RegistrationApplication,
+    rule::Rule,
     site::Site,
+    tag::Tag,
   },
   traits::Crud,
   utils::DbPool,
+  CommunityVisibility,
+  RegistrationMode,
 };
 use lemmy_db_views::{
   comment_view::CommentQuery,
-  structs::{LocalImageView, LocalUserView},
+  structs::{LocalImageView, LocalUserView, SiteView},
 };
 use lemmy_db_views_actor::structs::{
   CommunityModeratorView,
@@ -45,7 +53,12 @@ use lemmy_utils::{
   settings::structs::{PictrsImageMode, Settings},
   spawn_try_task,
   utils::{
-    markdown::{markdown_check_for_blocked_urls, markdown_rewrite_image_links},
+    markdown::{
+      escape_markdown,
+      markdown_check_for_blocked_urls,
+      markdown_rewrite_image_links,
+      markdown_to_html,
+    },
     slurs::{build_slur_regex, remove_slurs},
     validation::clean_urls_in_text,
   },
@@ -56,9 +69,10 @@ use moka::future::Cache;
 use regex::{escape, Regex, RegexSet};
 use rosetta_i18n::{Language, LanguageId};
 use std::{collections::HashSet, sync::LazyLock};
-use tracing::warn;
+use tracing::{warn, Instrument};
 use url::{ParseError, Url};
 use urlencoding::encode;
+use webmention::{Webmention, WebmentionError};
 
 pub static AUTH_COOKIE_NAME: &str = "jwt";
 
@@ -199,6 +213,22 @@ pub async fn check_community_user_action(
   person: &Person,
   community_id: CommunityId,
   pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_user_valid(person)?;
+  let community = check_community_deleted_removed(community_id, pool).await?;
+  check_community_ban(person, community_id, pool).await?;
+  check_private_community_access(person, &community, pool).await?;
+  Ok(())
+}
+
+/// Checks that the user is allowed to follow the community.
+///
+/// Same as [check_community_user_action], except that it doesn't require an approved follow for
+/// private communities.
+pub async fn check_community_follow_action(
+  person: &Person,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
 ) -> LemmyResult<()> {
   check_user_valid(person)?;
   check_community_deleted_removed(community_id, pool).await?;
@@ -209,14 +239,33 @@ pub async fn check_community_user_action(
 async fn check_community_deleted_removed(
   community_id: CommunityId,
   pool: &mut DbPool<'_>,
-) -> LemmyResult<()> {
+) -> LemmyResult<Community> {
   let community = Community::read(pool, community_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindCommunity)?;
   if community.deleted || community.removed {
     Err(LemmyErrorType::Deleted)?
   }
-  Ok(())
+  Ok(community)
+}
+
+/// In private communities only mods and approved followers can interact.
+async fn check_private_community_access(
+  person: &Person,
+  community: &Community,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if community.visibility != CommunityVisibility::Private {
+    return Ok(());
+  }
+  let is_follower = CommunityFollower::is_approved_follower(pool, community.id, person.id).await?;
+  let is_mod =
+    CommunityModeratorView::is_community_moderator(pool, community.id, person.id).await?;
+  if is_follower || is_mod {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::NotApprovedFollower)?
+  }
 }
 
 async fn check_community_ban(
@@ -261,6 +310,117 @@ pub fn check_post_deleted_or_removed(post: &Post) -> LemmyResult<()> {
   }
 }
 
+/// Scheduled posts are only visible to their creator, so nobody can interact with them until they
+/// are published.
+pub fn check_post_published(post: &Post) -> LemmyResult<()> {
+  if post
+    .scheduled_publish_time
+    .is_some_and(|time| time > Utc::now())
+  {
+    Err(LemmyErrorType::PostIsScheduled)?
+  } else {
+    Ok(())
+  }
+}
+
+/// Checks that the poll still accepts votes, and that the choices are allowed in this poll.
+pub fn check_poll_choices(poll: &Poll, choices: &[i32]) -> LemmyResult<()> {
+  if poll.end_time.is_some_and(|end_time| end_time < Utc::now()) {
+    Err(LemmyErrorType::PollEnded)?
+  }
+  let mut unique_choices = choices.to_vec();
+  unique_choices.sort_unstable();
+  unique_choices.dedup();
+  let choices_exist = choices
+    .iter()
+    .all(|c| usize::try_from(*c).is_ok_and(|c| c < poll.options.len()));
+  if !choices_exist
+    || unique_choices.len() != choices.len()
+    || (!poll.multiple_choice && choices.len() > 1)
+  {
+    Err(LemmyErrorType::InvalidPollChoice)?
+  }
+  Ok(())
+}
+
+/// Checks that a rule which is cited by a report or removal is a rule of the community, or a
+/// site-wide rule.
+pub async fn check_rule_in_community(
+  rule_id: Option<RuleId>,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(rule_id) = rule_id {
+    let rule = Rule::read(pool, rule_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindRule)?;
+    if rule.community_id.is_some_and(|c| c != community_id) {
+      Err(LemmyErrorType::RuleNotInCommunity)?
+    }
+  }
+  Ok(())
+}
+
+/// Checks that the tags belong to the community, and returns the tags which should be stored for
+/// the post. Mod-only tags can only be added or removed by moderators, so for other users the
+/// mod-only tags which are already attached to the post are kept.
+pub async fn check_post_tags(
+  community_id: CommunityId,
+  post_id: Option<PostId>,
+  tag_ids: &[TagId],
+  is_mod: bool,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Vec<TagId>> {
+  let community_tags = Tag::list_for_community(pool, community_id).await?;
+  let current_tags = match post_id {
+    Some(post_id) => Tag::list_for_post(pool, post_id).await?,
+    None => vec![],
+  };
+
+  let mut tags: Vec<TagId> = vec![];
+  for tag_id in tag_ids {
+    let tag = community_tags
+      .iter()
+      .find(|t| &t.id == tag_id)
+      .ok_or(LemmyErrorType::CouldntFindTag)?;
+    if tag.mod_only && !is_mod && !current_tags.contains(tag) {
+      Err(LemmyErrorType::OnlyModsCanUseTag)?
+    }
+    if !tags.contains(tag_id) {
+      tags.push(*tag_id);
+    }
+  }
+  if !is_mod {
+    for tag in current_tags.iter().filter(|t| t.mod_only) {
+      if !tags.contains(&tag.id) {
+        tags.push(tag.id);
+      }
+    }
+  }
+  Ok(tags)
+}
+
+/// Notifies the linked website about a new post in a public community, if it supports webmentions.
+pub fn send_webmention(post: Post, community: &Community) {
+  if let Some(url) = post.url.clone() {
+    if community.visibility == CommunityVisibility::Public {
+      spawn_try_task(async move {
+        let mut webmention = Webmention::new::<Url>(post.ap_id.clone().into(), url.clone().into())?;
+        webmention.set_checked(true);
+        match webmention
+          .send()
+          .instrument(tracing::info_span!("Sending webmention"))
+          .await
+        {
+          Err(WebmentionError::NoEndpointDiscovered(_)) => Ok(()),
+          Ok(_) => Ok(()),
+          Err(e) => Err(e).with_lemmy_type(LemmyErrorType::CouldntSendWebmention),
+        }
+      });
+    }
+  };
+}
+
 pub fn check_comment_deleted_or_removed(comment: &Comment) -> LemmyResult<()> {
   if comment.deleted || comment.removed {
     Err(LemmyErrorType::Deleted)?
@@ -492,6 +652,126 @@ pub async fn send_verification_email(
   Ok(())
 }
 
+/// Warn the user about a login from an unknown IP address or device.
+pub async fn send_new_login_email(
+  user: &LocalUserView,
+  ip: Option<&str>,
+  user_agent: Option<&str>,
+  settings: &Settings,
+) -> LemmyResult<()> {
+  let lang = get_interface_language(user);
+  let subject = lang.new_login_subject(&settings.hostname);
+  let settings_link = format!("{}/settings", settings.get_protocol_and_hostname());
+  let body = lang.new_login_body(
+    escape_markdown(ip.unwrap_or("unknown")),
+    settings_link,
+    escape_markdown(user_agent.unwrap_or("unknown")),
+    &user.person.name,
+  );
+  send_markdown_email(user, &subject, &body, settings).await
+}
+
+/// Sends an email with a markdown body to the user, if they have an email address.
+async fn send_markdown_email(
+  user: &LocalUserView,
+  subject: &str,
+  body: &str,
+  settings: &Settings,
+) -> LemmyResult<()> {
+  let Some(email) = &user.local_user.email else {
+    return Ok(());
+  };
+  let body = markdown_to_html(body);
+  send_email(subject, email, &user.person.name, &body, settings).await
+}
+
+/// Tell the user that their requested data export can be downloaded.
+pub async fn send_data_export_email(user: &LocalUserView, settings: &Settings) -> LemmyResult<()> {
+  let lang = get_interface_language(user);
+  let subject = lang.data_export_subject(&settings.hostname);
+  let settings_link = format!("{}/settings", settings.get_protocol_and_hostname());
+  let body = lang.data_export_body(settings_link, &user.person.name);
+  send_markdown_email(user, &subject, &body, settings).await
+}
+
+/// Tell the user whether their ban appeal was accepted. `community_name` is only given for
+/// community bans.
+pub async fn send_ban_appeal_resolved_email(
+  user: &LocalUserView,
+  community_name: Option<&str>,
+  accepted: bool,
+  reason: Option<&str>,
+  settings: &Settings,
+) -> LemmyResult<()> {
+  let lang = get_interface_language(user);
+  let hostname = &settings.hostname;
+  let name = &user.person.name;
+  let (subject, mut body) = match (accepted, community_name) {
+    (true, Some(community_name)) => (
+      lang.ban_appeal_accepted_subject(hostname),
+      lang.ban_appeal_community_accepted_body(community_name, name),
+    ),
+    (true, None) => (
+      lang.ban_appeal_accepted_subject(hostname),
+      lang.ban_appeal_site_accepted_body(hostname, name),
+    ),
+    (false, Some(community_name)) => (
+      lang.ban_appeal_denied_subject(hostname),
+      lang.ban_appeal_community_denied_body(community_name, name),
+    ),
+    (false, None) => (
+      lang.ban_appeal_denied_subject(hostname),
+      lang.ban_appeal_site_denied_body(hostname, name),
+    ),
+  };
+  if let Some(reason) = reason {
+    body.push_str("\n\n");
+    body.push_str(&lang.ban_appeal_reason(reason));
+  }
+  send_markdown_email(user, &subject, &body, settings).await
+}
+
+/// Check if the user's email is verified if email verification is turned on
+/// However, skip checking verification if the user is an admin
+pub fn check_email_verified(
+  local_user_view: &LocalUserView,
+  site_view: &SiteView,
+) -> LemmyResult<()> {
+  if !local_user_view.local_user.admin
+    && site_view.local_site.require_email_verification
+    && !local_user_view.local_user.email_verified
+  {
+    Err(LemmyErrorType::EmailNotVerified)?
+  }
+  Ok(())
+}
+
+pub async fn check_registration_application(
+  local_user_view: &LocalUserView,
+  local_site: &LocalSite,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if (local_site.registration_mode == RegistrationMode::RequireApplication
+    || local_site.registration_mode == RegistrationMode::Closed
+    || local_site.registration_mode == RegistrationMode::InviteOnly)
+    && !local_user_view.local_user.accepted_application
+    && !local_user_view.local_user.admin
+  {
+    // Fetch the registration application. If no admin id is present its still pending. Otherwise it
+    // was processed (either accepted or denied).
+    let local_user_id = local_user_view.local_user.id;
+    let registration = RegistrationApplication::find_by_local_user_id(pool, local_user_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindRegistrationApplication)?;
+    if registration.admin_id.is_some() {
+      Err(LemmyErrorType::RegistrationDenied(registration.deny_reason))?
+    } else {
+      Err(LemmyErrorType::RegistrationApplicationIsPending)?
+    }
+  }
+  Ok(())
+}
+
 pub fn get_interface_language(user: &LocalUserView) -> Lang {
   lang_str_to_lang(&user.local_user.interface_language)
 }
@@ -773,6 +1053,10 @@ pub async fn purge_user_account(person_id: PersonId, context: &LemmyContext) ->
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
 
+  // Previous versions of edited posts and comments
+  PostRevision::delete_for_creator(pool, person_id).await?;
+  CommentRevision::delete_for_creator(pool, person_id).await?;
+
   // Leave communities they mod
   CommunityModerator::leave_all_communities(pool, person_id).await?;
 
@@ -1126,4 +1410,30 @@ mod tests {
     assert!(check_comment_depth(&comment).is_err());
     Ok(())
   }
+
+  #[test]
+  fn test_poll_choices() {
+    let mut poll = Poll {
+      post_id: PostId(0),
+      multiple_choice: false,
+      options: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+      vote_counts: vec![0, 0, 0],
+      voters_count: 0,
+      end_time: None,
+      published: Utc::now(),
+      updated: None,
+    };
+    assert!(check_poll_choices(&poll, &[]).is_ok());
+    assert!(check_poll_choices(&poll, &[2]).is_ok());
+    assert!(check_poll_choices(&poll, &[3]).is_err());
+    assert!(check_poll_choices(&poll, &[-1]).is_err());
+    assert!(check_poll_choices(&poll, &[0, 1]).is_err());
+
+    poll.multiple_choice = true;
+    assert!(check_poll_choices(&poll, &[0, 1]).is_ok());
+    assert!(check_poll_choices(&poll, &[1, 1]).is_err());
+
+    poll.end_time = Some(Utc::now() - Days::new(1));
+    assert!(check_poll_choices(&poll, &[0]).is_err());
+  }
 }
@@ -0,0 +1,81 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, WebhookId},
+  sensitive::SensitiveString,
+  source::webhook::Webhook,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use url::Url;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Register a webhook for a community you moderate. Admins can leave out the community to create a
+/// site-wide webhook.
+pub struct CreateWebhook {
+  pub community_id: Option<CommunityId>,
+  /// Must use https.
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Url,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a newly created webhook.
+pub struct CreateWebhookResponse {
+  pub webhook: Webhook,
+  /// The key for the `X-Lemmy-Signature` header, which is a hex-encoded HMAC-SHA256 of the
+  /// request body. It is only returned here, so store it.
+  pub secret: SensitiveString,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a webhook.
+pub struct EditWebhook {
+  pub webhook_id: WebhookId,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<Url>,
+  /// Disabling a webhook drops the payloads which weren't delivered yet. Enabling it resets the
+  /// fail count.
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A webhook response.
+pub struct WebhookResponse {
+  pub webhook: Webhook,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a webhook.
+pub struct DeleteWebhook {
+  pub webhook_id: WebhookId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the webhooks of a community you moderate, or the site-wide webhooks if you're an admin.
+pub struct ListWebhooks {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of webhooks.
+pub struct ListWebhooksResponse {
+  pub webhooks: Vec<Webhook>,
+}
@@ -9,6 +9,7 @@ use lemmy_api_common::{
     check_comment_depth,
     check_community_user_action,
     check_post_deleted_or_removed,
+    check_post_published,
     get_url_blocklist,
     is_mod_or_admin,
     local_site_to_slur_regex,
@@ -64,6 +65,7 @@ pub async fn create_comment(
 
   check_community_user_action(&local_user_view.person, community_id, &mut context.pool()).await?;
   check_post_deleted_or_removed(&post)?;
+  check_post_published(&post)?;
 
   // Check if post is locked, no new comments
   let is_mod_or_admin = is_mod_or_admin(&mut context.pool(), &local_user_view.person, community_id)
@@ -1,11 +1,11 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
-  build_response::{build_comment_response, send_local_notifs},
+  build_response::{build_comment_response, send_local_notification, send_local_notifs},
   comment::{CommentResponse, RemoveComment},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_rule_in_community},
 };
 use lemmy_db_schema::{
   source::{
@@ -13,8 +13,10 @@ use lemmy_db_schema::{
     comment_report::CommentReport,
     local_user::LocalUser,
     moderator::{ModRemoveComment, ModRemoveCommentForm},
+    notification::NotificationInsertForm,
   },
   traits::{Crud, Reportable},
+  NotificationType,
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
@@ -56,6 +58,8 @@ pub async fn remove_comment(
     return Err(LemmyErrorType::CouldntUpdateComment.into());
   }
 
+  check_rule_in_community(data.rule_id, orig_comment.community.id, &mut context.pool()).await?;
+
   // Do the remove
   let removed = data.removed;
   let updated_comment = Comment::update(
@@ -78,9 +82,22 @@ pub async fn remove_comment(
     comment_id: data.comment_id,
     removed: Some(removed),
     reason: data.reason.clone(),
+    rule_id: data.rule_id,
   };
   ModRemoveComment::create(&mut context.pool(), &form).await?;
 
+  if removed {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(orig_comment.creator.id)
+      .kind(NotificationType::ModRemoveComment)
+      .comment_id(Some(comment_id))
+      .post_id(Some(orig_comment.post.id))
+      .community_id(Some(orig_comment.community.id))
+      .reason(data.reason.clone())
+      .build();
+    send_local_notification(notification_form, local_user_view.person.id, &context).await;
+  }
+
   let recipient_ids = send_local_notifs(
     vec![],
     comment_id,
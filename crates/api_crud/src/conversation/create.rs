@@ -0,0 +1,61 @@
+use super::send_conversation_message;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ConversationResponse, CreateConversation},
+  utils::check_person_block,
+};
+use lemmy_db_schema::{
+  source::{
+    conversation::{Conversation, ConversationInsertForm},
+    person::Person,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Limit the size of conversations, as each message is delivered to every participant. The
+/// creator is not counted.
+const MAX_CONVERSATION_PARTICIPANTS: usize = 50;
+
+#[tracing::instrument(skip(context))]
+pub async fn create_conversation(
+  data: Json<CreateConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ConversationResponse>> {
+  let person_id = local_user_view.person.id;
+  let mut participant_ids = data.participant_ids.clone();
+  participant_ids.sort_by_key(|id| id.0);
+  participant_ids.dedup();
+  participant_ids.retain(|id| *id != person_id);
+  if participant_ids.is_empty() {
+    Err(LemmyErrorType::CouldntCreateConversation)?
+  }
+  if participant_ids.len() > MAX_CONVERSATION_PARTICIPANTS {
+    Err(LemmyErrorType::TooManyConversationParticipants)?
+  }
+
+  for participant_id in &participant_ids {
+    Person::read(&mut context.pool(), *participant_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPerson)?;
+    check_person_block(person_id, *participant_id, &mut context.pool()).await?;
+  }
+
+  let form = ConversationInsertForm::builder()
+    .creator_id(person_id)
+    .build();
+  let conversation = Conversation::create(&mut context.pool(), &form, &participant_ids)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateConversation)?;
+
+  send_conversation_message(conversation.id, &data.content, &local_user_view, &context).await?;
+
+  let conversation_view = ConversationView::read(&mut context.pool(), conversation.id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindConversation)?;
+  Ok(Json(ConversationResponse { conversation_view }))
+}
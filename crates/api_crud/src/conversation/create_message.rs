@@ -0,0 +1,35 @@
+use super::send_conversation_message;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::CreateConversationMessage,
+  private_message::PrivateMessageResponse,
+};
+use lemmy_db_schema::source::conversation::ConversationParticipant;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_conversation_message(
+  data: Json<CreateConversationMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PrivateMessageResponse>> {
+  let conversation_id = data.conversation_id;
+  let participant = ConversationParticipant::read(
+    &mut context.pool(),
+    conversation_id,
+    local_user_view.person.id,
+  )
+  .await?;
+  if !participant.is_some_and(|p| p.left_at.is_none()) {
+    Err(LemmyErrorType::CouldntFindConversation)?
+  }
+
+  let private_message_view =
+    send_conversation_message(conversation_id, &data.content, &local_user_view, &context).await?;
+  Ok(Json(PrivateMessageResponse {
+    private_message_view,
+  }))
+}
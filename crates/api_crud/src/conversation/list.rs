@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ListConversations, ListConversationsResponse},
+};
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_conversations(
+  data: Query<ListConversations>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListConversationsResponse>> {
+  let conversations = ConversationView::list(
+    &mut context.pool(),
+    local_user_view.person.id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListConversationsResponse { conversations }))
+}
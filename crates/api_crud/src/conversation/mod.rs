@@ -0,0 +1,116 @@
+use activitypub_federation::config::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{
+    get_interface_language,
+    get_url_blocklist,
+    local_site_to_slur_regex,
+    process_markdown,
+    send_email_to_user,
+  },
+};
+use lemmy_db_schema::{
+  newtypes::ConversationId,
+  source::{
+    conversation::{ConversationParticipant, ConversationParticipantUpdateForm},
+    local_site::LocalSite,
+    notification::{Notification, NotificationInsertForm},
+    person_block::PersonBlock,
+    private_message::{PrivateMessage, PrivateMessageInsertForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+  NotificationType,
+};
+use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::{markdown::markdown_to_html, validation::is_valid_body_field},
+};
+
+pub mod create;
+pub mod create_message;
+pub mod list;
+pub mod read;
+
+/// Stores a new message in a group conversation, notifies the local participants and federates
+/// it to the remote ones.
+async fn send_conversation_message(
+  conversation_id: ConversationId,
+  content: &str,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<PrivateMessageView> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  let url_blocklist = get_url_blocklist(context).await?;
+  let content = process_markdown(content, &slur_regex, &url_blocklist, context).await?;
+  is_valid_body_field(&content, false)?;
+
+  // The actual recipients are the conversation participants
+  let person_id = local_user_view.person.id;
+  let private_message_form = PrivateMessageInsertForm::builder()
+    .content(content.clone())
+    .creator_id(person_id)
+    .recipient_id(person_id)
+    .conversation_id(Some(conversation_id))
+    .build();
+  let inserted_private_message = PrivateMessage::create(&mut context.pool(), &private_message_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage)?;
+
+  let view = PrivateMessageView::read(&mut context.pool(), inserted_private_message.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPrivateMessage)?;
+
+  let participants = ConversationParticipant::list_active(&mut context.pool(), conversation_id)
+    .await?
+    .into_iter()
+    .filter(|(person, participant)| person.local && person.id != person_id && !participant.muted);
+  let sender_name = &local_user_view.person.name;
+  let html_content = markdown_to_html(&content);
+  for (person, _) in participants {
+    if PersonBlock::read(&mut context.pool(), person.id, person_id).await? {
+      continue;
+    }
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(person.id)
+      .kind(NotificationType::PrivateMessage)
+      .creator_id(Some(person_id))
+      .private_message_id(Some(inserted_private_message.id))
+      .build();
+    Notification::create(&mut context.pool(), &notification_form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreateNotification)?;
+
+    let Some(local_recipient) = LocalUserView::read_person(&mut context.pool(), person.id).await?
+    else {
+      continue;
+    };
+    let lang = get_interface_language(&local_recipient);
+    let inbox_link = format!("{}/inbox", context.settings().get_protocol_and_hostname());
+    send_email_to_user(
+      &local_recipient,
+      &lang.notification_private_message_subject(sender_name),
+      &lang.notification_private_message_body(inbox_link, &html_content, sender_name),
+      context.settings(),
+    )
+    .await;
+  }
+
+  // Own messages count as read
+  let form = ConversationParticipantUpdateForm {
+    last_read: Some(naive_now()),
+    ..Default::default()
+  };
+  ConversationParticipant::update(&mut context.pool(), conversation_id, person_id, &form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::CreatePrivateMessage(view.clone()),
+    context,
+  )
+  .await?;
+
+  Ok(view)
+}
@@ -0,0 +1,38 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{GetConversation, GetConversationResponse},
+};
+use lemmy_db_views::{
+  private_message_view::PrivateMessageQuery,
+  structs::{ConversationView, LocalUserView},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Participants who left can still read the messages from before.
+#[tracing::instrument(skip(context))]
+pub async fn get_conversation(
+  data: Query<GetConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetConversationResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation_view =
+    ConversationView::read(&mut context.pool(), data.conversation_id, person_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindConversation)?;
+
+  let messages = PrivateMessageQuery {
+    page: data.page,
+    limit: data.limit,
+    conversation_id: Some(data.conversation_id),
+    ..Default::default()
+  }
+  .list(&mut context.pool(), person_id)
+  .await?;
+
+  Ok(Json(GetConversationResponse {
+    conversation_view,
+    messages,
+  }))
+}
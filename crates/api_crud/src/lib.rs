@@ -1,7 +1,14 @@
 pub mod comment;
 pub mod community;
+pub mod conversation;
 pub mod custom_emoji;
+pub mod multi_community;
+pub mod oauth_provider;
 pub mod post;
 pub mod private_message;
+pub mod report_category;
+pub mod rule;
 pub mod site;
+pub mod tag;
 pub mod user;
+pub mod webhook;
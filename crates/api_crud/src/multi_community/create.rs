@@ -0,0 +1,67 @@
+use super::check_multi_community_fields;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection};
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::{CreateMultiCommunity, MultiCommunityResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    multi_community::{MultiCommunity, MultiCommunityInsertForm},
+  },
+  traits::Crud,
+  utils::get_conn,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_multi_community(
+  data: Json<CreateMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<MultiCommunityResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.trim();
+  check_multi_community_fields(
+    Some(name),
+    data.description.as_deref(),
+    Some(&data.community_ids),
+    &local_site,
+  )?;
+
+  let form = MultiCommunityInsertForm::builder()
+    .creator_id(local_user_view.person.id)
+    .name(name.to_string())
+    .description(data.description.clone())
+    .public(data.public)
+    .build();
+  // Create the feed and its entries in a transaction, so that a failure doesn't leave an empty
+  // feed behind.
+  let community_ids = &data.community_ids;
+  let pool = &mut context.pool();
+  let conn = &mut get_conn(pool).await?;
+  let multi_community = conn
+    .transaction::<_, LemmyError, _>(|conn| {
+      async move {
+        let multi_community = MultiCommunity::create(&mut conn.into(), &form)
+          .await
+          .with_lemmy_type(LemmyErrorType::MultiCommunityAlreadyExists)?;
+        MultiCommunity::update_entries(&mut conn.into(), multi_community.id, community_ids)
+          .await
+          .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+        Ok(multi_community)
+      }
+      .scope_boxed()
+    })
+    .await?;
+  let communities =
+    MultiCommunity::list_communities(&mut context.pool(), multi_community.id).await?;
+
+  Ok(Json(MultiCommunityResponse {
+    multi_community,
+    communities,
+  }))
+}
@@ -0,0 +1,28 @@
+use super::check_multi_community_creator;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::DeleteMultiCommunity,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::multi_community::MultiCommunity, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_multi_community(
+  data: Json<DeleteMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let multi_community = MultiCommunity::read(&mut context.pool(), data.multi_community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindMultiCommunity)?;
+  check_multi_community_creator(&multi_community, &local_user_view)?;
+
+  // Entries are removed by the foreign key
+  MultiCommunity::delete(&mut context.pool(), multi_community.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,31 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::{ListMultiCommunities, ListMultiCommunitiesResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{local_site::LocalSite, multi_community::MultiCommunity};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_multi_communities(
+  data: Query<ListMultiCommunities>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListMultiCommunitiesResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let multi_communities = MultiCommunity::list(
+    &mut context.pool(),
+    local_user_view.map(|l| l.person.id),
+    data.creator_id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListMultiCommunitiesResponse { multi_communities }))
+}
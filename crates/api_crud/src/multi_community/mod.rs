@@ -0,0 +1,54 @@
+use lemmy_api_common::utils::local_site_to_slur_regex;
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{local_site::LocalSite, multi_community::MultiCommunity},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::{check_slurs, check_slurs_opt},
+    validation::{is_valid_body_field, is_valid_display_name},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod read;
+pub mod update;
+
+/// The maximum number of communities in a single feed.
+const MAX_MULTI_COMMUNITY_ENTRIES: usize = 50;
+
+fn check_multi_community_fields(
+  name: Option<&str>,
+  description: Option<&str>,
+  community_ids: Option<&[CommunityId]>,
+  local_site: &LocalSite,
+) -> LemmyResult<()> {
+  let slur_regex = local_site_to_slur_regex(local_site);
+  if let Some(name) = name {
+    check_slurs(name, &slur_regex)?;
+    is_valid_display_name(name, local_site.actor_name_max_length as usize)?;
+  }
+  if let Some(description) = description {
+    check_slurs_opt(&Some(description.to_string()), &slur_regex)?;
+    is_valid_body_field(description, false)?;
+  }
+  if community_ids.is_some_and(|c| c.len() > MAX_MULTI_COMMUNITY_ENTRIES) {
+    Err(LemmyErrorType::TooManyCommunitiesInFeed)?
+  }
+  Ok(())
+}
+
+/// Feeds can only be changed by their creator.
+fn check_multi_community_creator(
+  multi_community: &MultiCommunity,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  if multi_community.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoMultiCommunityEditAllowed)?
+  }
+  Ok(())
+}
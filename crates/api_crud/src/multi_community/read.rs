@@ -0,0 +1,41 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::{GetMultiCommunity, MultiCommunityResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::{
+  source::{local_site::LocalSite, multi_community::MultiCommunity},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_multi_community(
+  data: Query<GetMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<MultiCommunityResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let multi_community = MultiCommunity::read(&mut context.pool(), data.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindMultiCommunity)?;
+  let is_creator = local_user_view
+    .as_ref()
+    .is_some_and(|l| l.person.id == multi_community.creator_id);
+  if !multi_community.public && !is_creator {
+    Err(LemmyErrorType::CouldntFindMultiCommunity)?
+  }
+
+  let communities =
+    MultiCommunity::list_communities(&mut context.pool(), multi_community.id).await?;
+
+  Ok(Json(MultiCommunityResponse {
+    multi_community,
+    communities,
+  }))
+}
@@ -0,0 +1,61 @@
+use super::{check_multi_community_creator, check_multi_community_fields};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::{EditMultiCommunity, MultiCommunityResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    multi_community::{MultiCommunity, MultiCommunityUpdateForm},
+  },
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_multi_community(
+  data: Json<EditMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<MultiCommunityResponse>> {
+  let multi_community = MultiCommunity::read(&mut context.pool(), data.multi_community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindMultiCommunity)?;
+  check_multi_community_creator(&multi_community, &local_user_view)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.as_deref().map(str::trim);
+  check_multi_community_fields(
+    name,
+    data.description.as_deref(),
+    data.community_ids.as_deref(),
+    &local_site,
+  )?;
+
+  let form = MultiCommunityUpdateForm {
+    name: name.map(ToString::to_string),
+    description: diesel_string_update(data.description.as_deref()),
+    public: data.public,
+    updated: Some(Some(naive_now())),
+  };
+  let multi_community = MultiCommunity::update(&mut context.pool(), multi_community.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::MultiCommunityAlreadyExists)?;
+
+  if let Some(community_ids) = &data.community_ids {
+    MultiCommunity::update_entries(&mut context.pool(), multi_community.id, community_ids)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+  }
+  let communities =
+    MultiCommunity::list_communities(&mut context.pool(), multi_community.id).await?;
+
+  Ok(Json(MultiCommunityResponse {
+    multi_community,
+    communities,
+  }))
+}
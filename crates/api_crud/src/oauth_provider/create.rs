@@ -0,0 +1,54 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  oauth_provider::{CreateOAuthProvider, OAuthProviderResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::oauth_provider::{OAuthProvider, OAuthProviderInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_url,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_oauth_provider(
+  data: Json<CreateOAuthProvider>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<OAuthProviderResponse>> {
+  is_admin(&local_user_view)?;
+  for url in [
+    &data.issuer,
+    &data.authorization_endpoint,
+    &data.token_endpoint,
+    &data.userinfo_endpoint,
+  ] {
+    is_valid_url(url)?;
+  }
+
+  let form = OAuthProviderInsertForm::builder()
+    .display_name(data.display_name.clone())
+    .issuer(data.issuer.clone().into())
+    .authorization_endpoint(data.authorization_endpoint.clone().into())
+    .token_endpoint(data.token_endpoint.clone().into())
+    .userinfo_endpoint(data.userinfo_endpoint.clone().into())
+    .id_claim(data.id_claim.clone())
+    .client_id(data.client_id.clone())
+    .client_secret(data.client_secret.clone().into_inner())
+    .scopes(data.scopes.clone())
+    .auto_verify_email(data.auto_verify_email)
+    .account_linking_enabled(data.account_linking_enabled)
+    .use_pkce(data.use_pkce)
+    .enabled(data.enabled)
+    .build();
+  let oauth_provider = OAuthProvider::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateOauthProvider)?;
+
+  Ok(Json(OAuthProviderResponse { oauth_provider }))
+}
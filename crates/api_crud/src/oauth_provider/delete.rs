@@ -0,0 +1,26 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  oauth_provider::DeleteOAuthProvider,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::oauth_provider::OAuthProvider, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_oauth_provider(
+  data: Json<DeleteOAuthProvider>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  // Linked accounts are removed by the foreign key. Users who signed up through the provider need
+  // to reset their password to log in again.
+  OAuthProvider::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,57 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  oauth_provider::{EditOAuthProvider, OAuthProviderResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  sensitive::SensitiveString,
+  source::oauth_provider::{OAuthProvider, OAuthProviderUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_url,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_oauth_provider(
+  data: Json<EditOAuthProvider>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<OAuthProviderResponse>> {
+  is_admin(&local_user_view)?;
+  for url in [
+    &data.authorization_endpoint,
+    &data.token_endpoint,
+    &data.userinfo_endpoint,
+  ]
+  .into_iter()
+  .flatten()
+  {
+    is_valid_url(url)?;
+  }
+
+  let form = OAuthProviderUpdateForm {
+    display_name: data.display_name.clone(),
+    authorization_endpoint: data.authorization_endpoint.clone().map(Into::into),
+    token_endpoint: data.token_endpoint.clone().map(Into::into),
+    userinfo_endpoint: data.userinfo_endpoint.clone().map(Into::into),
+    id_claim: data.id_claim.clone(),
+    client_secret: data.client_secret.clone().map(SensitiveString::into_inner),
+    scopes: data.scopes.clone(),
+    auto_verify_email: data.auto_verify_email,
+    account_linking_enabled: data.account_linking_enabled,
+    use_pkce: data.use_pkce,
+    enabled: data.enabled,
+    updated: Some(Some(naive_now())),
+  };
+  let oauth_provider = OAuthProvider::update(&mut context.pool(), data.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateOauthProvider)?;
+
+  Ok(Json(OAuthProviderResponse { oauth_provider }))
+}
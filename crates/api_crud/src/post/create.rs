@@ -1,18 +1,23 @@
+use super::{check_scheduled_publish_time, MAX_SCHEDULED_POSTS};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
+use chrono::{TimeDelta, Utc};
 use lemmy_api_common::{
   build_response::build_post_response,
+  challenge::check_challenge,
   context::LemmyContext,
   post::{CreatePost, PostResponse},
   request::generate_post_link_metadata,
   send_activity::SendActivityData,
   utils::{
     check_community_user_action,
+    check_post_tags,
     get_url_blocklist,
     honeypot_check,
     local_site_to_slur_regex,
     mark_post_as_read,
     process_markdown_opt,
+    send_webmention,
   },
 };
 use lemmy_db_schema::{
@@ -21,31 +26,28 @@ use lemmy_db_schema::{
     actor_language::CommunityLanguage,
     community::Community,
     local_site::LocalSite,
+    poll::Poll,
     post::{Post, PostInsertForm, PostLike, PostLikeForm},
   },
   traits::{Crud, Likeable},
   utils::diesel_url_create,
-  CommunityVisibility,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::CommunityModeratorView;
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
-  spawn_try_task,
   utils::{
     slurs::check_slurs,
     validation::{
       is_url_blocked,
       is_valid_alt_text_field,
       is_valid_body_field,
+      is_valid_poll_options,
       is_valid_post_title,
       is_valid_url,
     },
   },
 };
-use tracing::Instrument;
-use url::Url;
-use webmention::{Webmention, WebmentionError};
 
 #[tracing::instrument(skip(context))]
 pub async fn create_post(
@@ -57,6 +59,22 @@ pub async fn create_post(
 
   honeypot_check(&data.honeypot)?;
 
+  // New accounts may have to solve a challenge, to slow down spam bots
+  let challenge_hours = local_site.new_account_post_challenge_hours;
+  let account_age = Utc::now() - local_user_view.person.published;
+  if challenge_hours > 0
+    && account_age < TimeDelta::hours(challenge_hours.into())
+    && !local_user_view.local_user.admin
+  {
+    check_challenge(
+      &local_site,
+      data.captcha_uuid.as_deref(),
+      data.captcha_answer.as_deref(),
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
   let slur_regex = local_site_to_slur_regex(&local_site);
   check_slurs(&data.name, &slur_regex)?;
   let url_blocklist = get_url_blocklist(&context).await?;
@@ -84,6 +102,23 @@ pub async fn create_post(
     is_valid_body_field(body, true)?;
   }
 
+  let poll_options = data
+    .poll_options
+    .as_ref()
+    .map(|o| o.iter().map(|o| o.trim().to_string()).collect::<Vec<_>>());
+  if let Some(poll_options) = &poll_options {
+    is_valid_poll_options(poll_options)?;
+    for option in poll_options {
+      check_slurs(option, &slur_regex)?;
+    }
+    if data
+      .poll_end_time
+      .is_some_and(|end_time| end_time < Utc::now())
+    {
+      Err(LemmyErrorType::PollEnded)?
+    }
+  }
+
   check_community_user_action(
     &local_user_view.person,
     data.community_id,
@@ -91,6 +126,15 @@ pub async fn create_post(
   )
   .await?;
 
+  check_scheduled_publish_time(data.scheduled_publish_time)?;
+  if data.scheduled_publish_time.is_some() {
+    let scheduled_posts =
+      Post::user_scheduled_post_count(&mut context.pool(), local_user_view.person.id).await?;
+    if scheduled_posts >= MAX_SCHEDULED_POSTS {
+      Err(LemmyErrorType::TooManyScheduledPosts)?
+    }
+  }
+
   let community_id = data.community_id;
   let community = Community::read(&mut context.pool(), community_id)
     .await?
@@ -116,6 +160,19 @@ pub async fn create_post(
     }
   }
 
+  let tags = match &data.tags {
+    Some(tags) => {
+      let is_mod = CommunityModeratorView::is_community_moderator(
+        &mut context.pool(),
+        community_id,
+        local_user_view.person.id,
+      )
+      .await?;
+      check_post_tags(community_id, None, tags, is_mod, &mut context.pool()).await?
+    }
+    None => vec![],
+  };
+
   // Only need to check if language is allowed in case user set it explicitly. When using default
   // language, it already only returns allowed languages.
   CommunityLanguage::is_allowed_community_language(
@@ -147,16 +204,39 @@ pub async fn create_post(
     .creator_id(local_user_view.person.id)
     .nsfw(nsfw)
     .language_id(language_id)
+    .scheduled_publish_time(data.scheduled_publish_time)
     .build();
 
-  let inserted_post = Post::create(&mut context.pool(), &post_form)
-    .await
-    .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
+  // The poll needs to exist before the post is federated
+  let inserted_post = match poll_options {
+    Some(poll_options) => {
+      Poll::create_with_post(
+        &mut context.pool(),
+        &post_form,
+        &tags,
+        poll_options,
+        data.poll_multiple_choice,
+        data.poll_end_time,
+      )
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?
+      .0
+    }
+    None => Post::create_with_tags(&mut context.pool(), &post_form, &tags)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?,
+  };
 
   generate_post_link_metadata(
     inserted_post.clone(),
     custom_thumbnail.map(Into::into),
-    |post| Some(SendActivityData::CreatePost(post)),
+    // Scheduled posts are federated once they are published
+    |post| {
+      post
+        .scheduled_publish_time
+        .is_none()
+        .then_some(SendActivityData::CreatePost(post))
+    },
     Some(local_site),
     context.reset_request_count(),
   )
@@ -177,24 +257,9 @@ pub async fn create_post(
 
   mark_post_as_read(person_id, post_id, &mut context.pool()).await?;
 
-  if let Some(url) = inserted_post.url.clone() {
-    if community.visibility == CommunityVisibility::Public {
-      spawn_try_task(async move {
-        let mut webmention =
-          Webmention::new::<Url>(inserted_post.ap_id.clone().into(), url.clone().into())?;
-        webmention.set_checked(true);
-        match webmention
-          .send()
-          .instrument(tracing::info_span!("Sending webmention"))
-          .await
-        {
-          Err(WebmentionError::NoEndpointDiscovered(_)) => Ok(()),
-          Ok(_) => Ok(()),
-          Err(e) => Err(e).with_lemmy_type(LemmyErrorType::CouldntSendWebmention),
-        }
-      });
-    }
-  };
+  if inserted_post.scheduled_publish_time.is_none() {
+    send_webmention(inserted_post, &community);
+  }
 
   build_post_response(&context, community_id, local_user_view, post_id).await
 }
@@ -53,11 +53,14 @@ pub async fn delete_post(
   )
   .await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::DeletePost(post, local_user_view.person.clone(), data.0),
-    &context,
-  )
-  .await?;
+  // Scheduled posts were never federated, so deleting them simply cancels them
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::DeletePost(post, local_user_view.person.clone(), data.0),
+      &context,
+    )
+    .await?;
+  }
 
   build_post_response(
     &context,
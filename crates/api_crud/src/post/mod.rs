@@ -1,5 +1,19 @@
+use chrono::{DateTime, Utc};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
 pub mod create;
 pub mod delete;
 pub mod read;
 pub mod remove;
 pub mod update;
+
+/// The maximum number of posts which a user can have scheduled at the same time.
+const MAX_SCHEDULED_POSTS: i64 = 35;
+
+/// Scheduled posts can only be published in the future.
+fn check_scheduled_publish_time(scheduled_publish_time: Option<DateTime<Utc>>) -> LemmyResult<()> {
+  if scheduled_publish_time.is_some_and(|time| time < Utc::now()) {
+    Err(LemmyErrorType::InvalidScheduledPublishTime)?
+  }
+  Ok(())
+}
@@ -1,20 +1,22 @@
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
-  build_response::build_post_response,
+  build_response::{build_post_response, send_local_notification},
   context::LemmyContext,
   post::{PostResponse, RemovePost},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_rule_in_community},
 };
 use lemmy_db_schema::{
   source::{
     local_user::LocalUser,
     moderator::{ModRemovePost, ModRemovePostForm},
+    notification::NotificationInsertForm,
     post::{Post, PostUpdateForm},
     post_report::PostReport,
   },
   traits::{Crud, Reportable},
+  NotificationType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{error::LemmyResult, LemmyErrorType};
@@ -46,6 +48,8 @@ pub async fn remove_post(
   )
   .await?;
 
+  check_rule_in_community(data.rule_id, orig_post.community_id, &mut context.pool()).await?;
+
   // Update the post
   let post_id = data.post_id;
   let removed = data.removed;
@@ -68,9 +72,21 @@ pub async fn remove_post(
     post_id: data.post_id,
     removed: Some(removed),
     reason: data.reason.clone(),
+    rule_id: data.rule_id,
   };
   ModRemovePost::create(&mut context.pool(), &form).await?;
 
+  if removed {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(post.creator_id)
+      .kind(NotificationType::ModRemovePost)
+      .post_id(Some(post.id))
+      .community_id(Some(post.community_id))
+      .reason(data.reason.clone())
+      .build();
+    send_local_notification(notification_form, local_user_view.person.id, &context).await;
+  }
+
   ActivityChannel::submit_activity(
     SendActivityData::RemovePost {
       post,
@@ -1,3 +1,4 @@
+use super::check_scheduled_publish_time;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
@@ -84,6 +85,8 @@ pub async fn update_post(
     is_valid_url(custom_thumbnail)?;
   }
 
+  check_scheduled_publish_time(data.scheduled_publish_time)?;
+
   let post_id = data.post_id;
   let orig_post = Post::read(&mut context.pool(), post_id)
     .await?
@@ -101,6 +104,11 @@ pub async fn update_post(
     Err(LemmyErrorType::NoPostEditAllowed)?
   }
 
+  // Posts which are already published can't be scheduled again
+  if data.scheduled_publish_time.is_some() && orig_post.scheduled_publish_time.is_none() {
+    Err(LemmyErrorType::InvalidScheduledPublishTime)?
+  }
+
   let language_id = data.language_id;
   CommunityLanguage::is_allowed_community_language(
     &mut context.pool(),
@@ -117,6 +125,7 @@ pub async fn update_post(
     nsfw: data.nsfw,
     language_id: data.language_id,
     updated: Some(Some(naive_now())),
+    scheduled_publish_time: data.scheduled_publish_time.map(Some),
     ..Default::default()
   };
 
@@ -128,7 +137,13 @@ pub async fn update_post(
   generate_post_link_metadata(
     updated_post.clone(),
     custom_thumbnail.flatten().map(Into::into),
-    |post| Some(SendActivityData::UpdatePost(post)),
+    // Scheduled posts are not federated yet
+    |post| {
+      post
+        .scheduled_publish_time
+        .is_none()
+        .then_some(SendActivityData::UpdatePost(post))
+    },
     Some(local_site),
     context.reset_request_count(),
   )
@@ -16,9 +16,11 @@ use lemmy_api_common::{
 use lemmy_db_schema::{
   source::{
     local_site::LocalSite,
+    notification::{Notification, NotificationInsertForm},
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
   traits::Crud,
+  NotificationType,
 };
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
 use lemmy_utils::{
@@ -62,6 +64,16 @@ pub async fn create_private_message(
 
   // Send email to the local recipient, if one exists
   if view.recipient.local {
+    let notification_form = NotificationInsertForm::builder()
+      .recipient_id(view.recipient.id)
+      .kind(NotificationType::PrivateMessage)
+      .creator_id(Some(local_user_view.person.id))
+      .private_message_id(Some(inserted_private_message.id))
+      .build();
+    Notification::create(&mut context.pool(), &notification_form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreateNotification)?;
+
     let recipient_id = data.recipient_id;
     let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id)
       .await?
@@ -23,6 +23,7 @@ pub async fn get_private_message(
     limit,
     unread_only,
     creator_id,
+    conversation_id: None,
   }
   .list(&mut context.pool(), person_id)
   .await?;
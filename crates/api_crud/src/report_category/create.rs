@@ -0,0 +1,37 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{CreateReportCategory, ReportCategoryResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::report_category::{ReportCategory, ReportCategoryForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_report_category_name,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_report_category(
+  data: Json<CreateReportCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportCategoryResponse>> {
+  is_admin(&local_user_view)?;
+
+  let name = data.name.trim();
+  is_valid_report_category_name(name)?;
+
+  let form = ReportCategoryForm {
+    name: name.to_string(),
+  };
+  let report_category = ReportCategory::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateReportCategory)?;
+
+  Ok(Json(ReportCategoryResponse { report_category }))
+}
@@ -0,0 +1,28 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::DeleteReportCategory,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::report_category::ReportCategory, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_report_category(
+  data: Json<DeleteReportCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  // The category is removed from reports by the foreign key
+  let deleted = ReportCategory::delete(&mut context.pool(), data.report_category_id).await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::CouldntFindReportCategory)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,17 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, report::ListReportCategoriesResponse};
+use lemmy_db_schema::source::report_category::ReportCategory;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the report categories, which users can pick when creating a report.
+#[tracing::instrument(skip(context))]
+pub async fn list_report_categories(
+  context: Data<LemmyContext>,
+  _local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReportCategoriesResponse>> {
+  let report_categories = ReportCategory::list(&mut context.pool()).await?;
+
+  Ok(Json(ListReportCategoriesResponse { report_categories }))
+}
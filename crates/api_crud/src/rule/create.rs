@@ -0,0 +1,47 @@
+use super::{check_rule_fields, check_rule_permission, send_community_update};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  rule::{CreateRule, RuleResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    rule::{Rule, RuleInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_rule(
+  data: Json<CreateRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RuleResponse>> {
+  check_rule_permission(&local_user_view, data.community_id, &mut context.pool()).await?;
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let title = data.title.trim();
+  let description = data.description.as_deref().filter(|d| !d.is_empty());
+  check_rule_fields(Some(title), description, &local_site)?;
+
+  let position = match data.position {
+    Some(position) => position,
+    None => Rule::next_position(&mut context.pool(), data.community_id).await?,
+  };
+  let form = RuleInsertForm::builder()
+    .community_id(data.community_id)
+    .position(position)
+    .title(title.to_string())
+    .description(description.map(ToString::to_string))
+    .build();
+  let rule = Rule::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateRule)?;
+
+  send_community_update(rule.community_id, &local_user_view, &context).await?;
+
+  Ok(Json(RuleResponse { rule }))
+}
@@ -0,0 +1,26 @@
+use super::{check_rule_permission, send_community_update};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, rule::DeleteRule, SuccessResponse};
+use lemmy_db_schema::{source::rule::Rule, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_rule(
+  data: Json<DeleteRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindRule)?;
+  check_rule_permission(&local_user_view, rule.community_id, &mut context.pool()).await?;
+
+  // Reports and removals which cite the rule are kept, without the rule
+  Rule::delete(&mut context.pool(), rule.id).await?;
+
+  send_community_update(rule.community_id, &local_user_view, &context).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,24 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  rule::{ListRules, ListRulesResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{local_site::LocalSite, rule::Rule};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_rules(
+  data: Query<ListRules>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListRulesResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let rules = Rule::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListRulesResponse { rules }))
+}
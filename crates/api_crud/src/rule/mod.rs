@@ -0,0 +1,81 @@
+use activitypub_federation::config::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, is_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{community::Community, local_site::LocalSite},
+  traits::Crud,
+  utils::DbPool,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_rule_title},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Community rules are managed by the moderators of the community, and site-wide rules by the
+/// admins. Rules of remote communities come in through federation, so they can't be changed here.
+async fn check_rule_permission(
+  local_user_view: &LocalUserView,
+  community_id: Option<CommunityId>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let Some(community_id) = community_id else {
+    return is_admin(local_user_view);
+  };
+  check_community_mod_action(&local_user_view.person, community_id, false, pool).await?;
+  let community = Community::read(pool, community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+  if !community.local {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+  Ok(())
+}
+
+fn check_rule_fields(
+  title: Option<&str>,
+  description: Option<&str>,
+  local_site: &LocalSite,
+) -> LemmyResult<()> {
+  let slur_regex = local_site_to_slur_regex(local_site);
+  if let Some(title) = title {
+    is_valid_rule_title(title)?;
+    check_slurs(title, &slur_regex)?;
+  }
+  if let Some(description) = description {
+    is_valid_body_field(description, false)?;
+    check_slurs(description, &slur_regex)?;
+  }
+  Ok(())
+}
+
+/// Community rules federate as part of the group, so the community is sent again after a change.
+async fn send_community_update(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if let Some(community_id) = community_id {
+    let community = Community::read(&mut context.pool(), community_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+    ActivityChannel::submit_activity(
+      SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+      context,
+    )
+    .await?;
+  }
+  Ok(())
+}
@@ -0,0 +1,47 @@
+use super::{check_rule_fields, check_rule_permission, send_community_update};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  rule::{EditRule, RuleResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    rule::{Rule, RuleUpdateForm},
+  },
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_rule(
+  data: Json<EditRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RuleResponse>> {
+  let rule = Rule::read(&mut context.pool(), data.rule_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindRule)?;
+  check_rule_permission(&local_user_view, rule.community_id, &mut context.pool()).await?;
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let title = data.title.as_deref().map(str::trim);
+  let description = data.description.as_deref().filter(|d| !d.is_empty());
+  check_rule_fields(title, description, &local_site)?;
+
+  let form = RuleUpdateForm {
+    position: data.position,
+    title: title.map(ToString::to_string),
+    description: diesel_string_update(data.description.as_deref()),
+    updated: Some(Some(naive_now())),
+  };
+  let rule = Rule::update(&mut context.pool(), rule.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateRule)?;
+
+  send_community_update(rule.community_id, &local_user_view, &context).await?;
+
+  Ok(Json(RuleResponse { rule }))
+}
@@ -1,5 +1,10 @@
 use super::not_zero;
-use crate::site::{application_question_check, site_default_post_listing_type_check};
+use crate::site::{
+  application_question_check,
+  proof_of_work_difficulty_check,
+  site_default_post_listing_type_check,
+  strike_policy_check,
+};
 use activitypub_federation::{config::Data, http_signatures::generate_actor_keypair};
 use actix_web::web::Json;
 use lemmy_api_common::{
@@ -110,6 +115,15 @@ pub async fn create_site(
     captcha_enabled: data.captcha_enabled,
     captcha_difficulty: data.captcha_difficulty.clone(),
     default_post_listing_mode: data.default_post_listing_mode,
+    oauth_registration: data.oauth_registration,
+    invites_per_user: data.invites_per_user,
+    invite_min_account_age_days: data.invite_min_account_age_days,
+    challenge_type: data.challenge_type,
+    proof_of_work_difficulty: data.proof_of_work_difficulty,
+    new_account_post_challenge_hours: data.new_account_post_challenge_hours,
+    strike_limit: data.strike_limit,
+    strike_expiry_days: data.strike_expiry_days,
+    strike_ban_days: data.strike_ban_days,
     ..Default::default()
   };
 
@@ -174,6 +188,12 @@ fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) ->
   }
 
   site_default_post_listing_type_check(&create_site.default_post_listing_type)?;
+  proof_of_work_difficulty_check(create_site.proof_of_work_difficulty)?;
+  strike_policy_check(
+    create_site.strike_limit,
+    create_site.strike_expiry_days,
+    create_site.strike_ban_days,
+  )?;
 
   // Ensure that the sidebar has fewer than the max num characters...
   if let Some(body) = &create_site.sidebar {
@@ -541,6 +561,15 @@ mod tests {
       registration_mode: site_registration_mode,
       content_warning: None,
       default_post_listing_mode: None,
+      oauth_registration: None,
+      invites_per_user: None,
+      invite_min_account_age_days: None,
+      challenge_type: None,
+      proof_of_work_difficulty: None,
+      new_account_post_challenge_hours: None,
+      strike_limit: None,
+      strike_expiry_days: None,
+      strike_ban_days: None,
     }
   }
 }
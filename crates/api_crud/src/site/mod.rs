@@ -40,6 +40,29 @@ pub fn application_question_check(
   }
 }
 
+/// Checks that proof of work challenges can be solved in a reasonable time.
+pub fn proof_of_work_difficulty_check(difficulty: Option<i32>) -> LemmyResult<()> {
+  if difficulty.is_some_and(|d| !(1..=32).contains(&d)) {
+    Err(LemmyErrorType::InvalidProofOfWorkDifficulty)?
+  }
+  Ok(())
+}
+
+/// Checks the automatic ban policy for strikes. A strike limit of zero disables it.
+pub fn strike_policy_check(
+  limit: Option<i32>,
+  expiry_days: Option<i32>,
+  ban_days: Option<i32>,
+) -> LemmyResult<()> {
+  if limit.is_some_and(|l| l < 0)
+    || expiry_days.is_some_and(|d| d < 1)
+    || ban_days.is_some_and(|d| d < 1)
+  {
+    Err(LemmyErrorType::InvalidStrikePolicy)?
+  }
+  Ok(())
+}
+
 fn not_zero(val: Option<i32>) -> Option<i32> {
   match val {
     Some(0) => None,
@@ -52,7 +75,13 @@ fn not_zero(val: Option<i32>) -> Option<i32> {
 #[allow(clippy::indexing_slicing)]
 mod tests {
 
-  use crate::site::{application_question_check, not_zero, site_default_post_listing_type_check};
+  use crate::site::{
+    application_question_check,
+    not_zero,
+    proof_of_work_difficulty_check,
+    site_default_post_listing_type_check,
+    strike_policy_check,
+  };
   use lemmy_db_schema::{ListingType, RegistrationMode};
 
   #[test]
@@ -101,6 +130,23 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_proof_of_work_difficulty_check() {
+    assert!(proof_of_work_difficulty_check(None).is_ok());
+    assert!(proof_of_work_difficulty_check(Some(20)).is_ok());
+    assert!(proof_of_work_difficulty_check(Some(0)).is_err());
+    assert!(proof_of_work_difficulty_check(Some(33)).is_err());
+  }
+
+  #[test]
+  fn test_strike_policy_check() {
+    assert!(strike_policy_check(None, None, None).is_ok());
+    assert!(strike_policy_check(Some(0), Some(30), Some(7)).is_ok());
+    assert!(strike_policy_check(Some(-1), None, None).is_err());
+    assert!(strike_policy_check(Some(3), Some(0), None).is_err());
+    assert!(strike_policy_check(Some(3), None, Some(0)).is_err());
+  }
+
   #[test]
   fn test_not_zero() {
     assert_eq!(None, not_zero(None));
@@ -5,8 +5,10 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::source::{
   actor_language::{LocalUserLanguage, SiteLanguage},
+  keyword_filter::KeywordFilter,
   language::Language,
   local_site_url_blocklist::LocalSiteUrlBlocklist,
+  oauth_provider::OAuthProvider,
   tagline::Tagline,
 };
 use lemmy_db_views::structs::{CustomEmojiView, LocalUserView, SiteView};
@@ -51,6 +53,7 @@ pub async fn get_site(
       let custom_emojis =
         CustomEmojiView::get_all(&mut context.pool(), site_view.local_site.id).await?;
       let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
+      let oauth_providers = OAuthProvider::get_all_public(&mut context.pool()).await?;
       Ok(GetSiteResponse {
         site_view,
         admins,
@@ -61,11 +64,18 @@ pub async fn get_site(
         taglines,
         custom_emojis,
         blocked_urls,
+        oauth_providers,
+        admin_oauth_providers: None,
       })
     })
     .await
     .map_err(|e| anyhow::anyhow!("Failed to construct site response: {e}"))?;
 
+  // Admins can see the full provider configuration
+  if local_user_view.as_ref().is_some_and(|l| l.local_user.admin) {
+    site_response.admin_oauth_providers = Some(OAuthProvider::get_all(&mut context.pool()).await?);
+  }
+
   // Build the local user with parallel queries and add it to site response
   site_response.my_user = if let Some(local_user_view) = local_user_view {
     let person_id = local_user_view.person.id;
@@ -78,6 +88,7 @@ pub async fn get_site(
       instance_blocks,
       person_blocks,
       moderates,
+      keyword_filters,
       discussion_languages,
     ) = lemmy_db_schema::try_join_with_pool!(pool => (
       |pool| CommunityFollowerView::for_person(pool, person_id),
@@ -85,6 +96,7 @@ pub async fn get_site(
       |pool| InstanceBlockView::for_person(pool, person_id),
       |pool| PersonBlockView::for_person(pool, person_id),
       |pool| CommunityModeratorView::for_person(pool, person_id, Some(&local_user_view.local_user)),
+      |pool| KeywordFilter::list(pool, local_user_id),
       |pool| LocalUserLanguage::read(pool, local_user_id)
     ))
     .with_lemmy_type(LemmyErrorType::SystemErrLogin)?;
@@ -96,6 +108,7 @@ pub async fn get_site(
       community_blocks,
       instance_blocks,
       person_blocks,
+      keyword_filters,
       discussion_languages,
     })
   } else {
@@ -1,5 +1,10 @@
 use super::not_zero;
-use crate::site::{application_question_check, site_default_post_listing_type_check};
+use crate::site::{
+  application_question_check,
+  proof_of_work_difficulty_check,
+  site_default_post_listing_type_check,
+  strike_policy_check,
+};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
@@ -122,6 +127,15 @@ pub async fn update_site(
     captcha_difficulty: data.captcha_difficulty.clone(),
     reports_email_admins: data.reports_email_admins,
     default_post_listing_mode: data.default_post_listing_mode,
+    oauth_registration: data.oauth_registration,
+    invites_per_user: data.invites_per_user,
+    invite_min_account_age_days: data.invite_min_account_age_days,
+    challenge_type: data.challenge_type,
+    proof_of_work_difficulty: data.proof_of_work_difficulty,
+    new_account_post_challenge_hours: data.new_account_post_challenge_hours,
+    strike_limit: data.strike_limit,
+    strike_expiry_days: data.strike_expiry_days,
+    strike_ban_days: data.strike_ban_days,
     ..Default::default()
   };
 
@@ -229,6 +243,12 @@ fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> Lemm
   }
 
   site_default_post_listing_type_check(&edit_site.default_post_listing_type)?;
+  proof_of_work_difficulty_check(edit_site.proof_of_work_difficulty)?;
+  strike_policy_check(
+    edit_site.strike_limit,
+    edit_site.strike_expiry_days,
+    edit_site.strike_ban_days,
+  )?;
 
   // Ensure that the sidebar has fewer than the max num characters...
   if let Some(body) = &edit_site.sidebar {
@@ -561,6 +581,15 @@ mod tests {
       reports_email_admins: None,
       content_warning: None,
       default_post_listing_mode: None,
+      oauth_registration: None,
+      invites_per_user: None,
+      invite_min_account_age_days: None,
+      challenge_type: None,
+      proof_of_work_difficulty: None,
+      new_account_post_challenge_hours: None,
+      strike_limit: None,
+      strike_expiry_days: None,
+      strike_ban_days: None,
     }
   }
 }
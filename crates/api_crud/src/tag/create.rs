@@ -0,0 +1,45 @@
+use super::{check_tag_fields, check_tag_permission};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  tag::{CreateTag, TagResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    tag::{Tag, TagInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_tag(
+  data: Json<CreateTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<TagResponse>> {
+  check_tag_permission(&local_user_view, data.community_id, &mut context.pool()).await?;
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.trim();
+  check_tag_fields(Some(name), data.color.as_deref(), &local_site)?;
+
+  let existing = Tag::list_for_community(&mut context.pool(), data.community_id).await?;
+  if existing.iter().any(|t| t.name.eq_ignore_ascii_case(name)) {
+    Err(LemmyErrorType::TagAlreadyExists)?
+  }
+
+  let form = TagInsertForm::builder()
+    .community_id(data.community_id)
+    .name(name.to_string())
+    .color(data.color.clone().filter(|c| !c.is_empty()))
+    .mod_only(data.mod_only)
+    .build();
+  let tag = Tag::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateTag)?;
+
+  Ok(Json(TagResponse { tag }))
+}
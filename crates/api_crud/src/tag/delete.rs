@@ -0,0 +1,24 @@
+use super::check_tag_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, tag::DeleteTag, SuccessResponse};
+use lemmy_db_schema::{source::tag::Tag, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_tag(
+  data: Json<DeleteTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let tag = Tag::read(&mut context.pool(), data.tag_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindTag)?;
+  check_tag_permission(&local_user_view, tag.community_id, &mut context.pool()).await?;
+
+  // The tag is removed from posts by the foreign key
+  Tag::delete(&mut context.pool(), tag.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
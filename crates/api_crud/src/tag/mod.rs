@@ -0,0 +1,52 @@
+use lemmy_api_common::utils::{check_community_mod_action, local_site_to_slur_regex};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{community::Community, local_site::LocalSite},
+  traits::Crud,
+  utils::DbPool,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_tag_color, is_valid_tag_name},
+  },
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// Tags are managed by the moderators of a community. Tags of remote communities come in through
+/// federation, so they can't be changed here.
+async fn check_tag_permission(
+  local_user_view: &LocalUserView,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_community_mod_action(&local_user_view.person, community_id, false, pool).await?;
+  let community = Community::read(pool, community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+  if !community.local {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+  Ok(())
+}
+
+fn check_tag_fields(
+  name: Option<&str>,
+  color: Option<&str>,
+  local_site: &LocalSite,
+) -> LemmyResult<()> {
+  if let Some(name) = name {
+    is_valid_tag_name(name)?;
+    check_slurs(name, &local_site_to_slur_regex(local_site))?;
+  }
+  if let Some(color) = color.filter(|c| !c.is_empty()) {
+    is_valid_tag_color(color)?;
+  }
+  Ok(())
+}
@@ -0,0 +1,54 @@
+use super::{check_tag_fields, check_tag_permission};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  tag::{EditTag, TagResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    tag::{Tag, TagUpdateForm},
+  },
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_tag(
+  data: Json<EditTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<TagResponse>> {
+  let tag = Tag::read(&mut context.pool(), data.tag_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindTag)?;
+  check_tag_permission(&local_user_view, tag.community_id, &mut context.pool()).await?;
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.as_deref().map(str::trim);
+  check_tag_fields(name, data.color.as_deref(), &local_site)?;
+
+  if let Some(name) = name {
+    let existing = Tag::list_for_community(&mut context.pool(), tag.community_id).await?;
+    if existing
+      .iter()
+      .any(|t| t.id != tag.id && t.name.eq_ignore_ascii_case(name))
+    {
+      Err(LemmyErrorType::TagAlreadyExists)?
+    }
+  }
+
+  let form = TagUpdateForm {
+    name: name.map(ToString::to_string),
+    color: diesel_string_update(data.color.as_deref()),
+    mod_only: data.mod_only,
+    updated: Some(Some(naive_now())),
+  };
+  let tag = Tag::update(&mut context.pool(), tag.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateTag)?;
+
+  Ok(Json(TagResponse { tag }))
+}
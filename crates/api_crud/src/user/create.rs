@@ -2,10 +2,16 @@ use activitypub_federation::{config::Data, http_signatures::generate_actor_keypa
 use actix_web::{web::Json, HttpRequest};
 use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection};
 use lemmy_api_common::{
+  challenge::check_challenge,
   claims::Claims,
   context::LemmyContext,
+  oauth_provider::AuthenticateWithOauth,
   person::{LoginResponse, Register},
+  request::fetch_oauth_user_info,
   utils::{
+    check_email_verified,
+    check_registration_application,
+    check_user_valid,
     generate_inbox_url,
     generate_local_apub_endpoint,
     generate_shared_inbox_url,
@@ -19,14 +25,18 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   aggregates::structs::PersonAggregates,
+  newtypes::{LanguageId, SiteId},
   source::{
     actor_language::SiteLanguage,
-    captcha_answer::{CaptchaAnswer, CheckCaptchaAnswer},
+    invite::Invite,
     language::Language,
     local_user::{LocalUser, LocalUserInsertForm},
     local_user_vote_display_mode::LocalUserVoteDisplayMode,
+    oauth_account::{OAuthAccount, OAuthAccountInsertForm},
+    oauth_provider::OAuthProvider,
     person::{Person, PersonInsertForm},
     registration_application::{RegistrationApplication, RegistrationApplicationInsertForm},
+    webauthn_credential::WebauthnCredential,
   },
   traits::Crud,
   utils::get_conn,
@@ -71,28 +81,25 @@ pub async fn register(
     Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?
   }
 
+  let require_invite =
+    local_site.site_setup && local_site.registration_mode == RegistrationMode::InviteOnly;
+  if require_invite && data.invite_code.is_none() {
+    Err(LemmyErrorType::InviteCodeRequired)?
+  }
+
   // Make sure passwords match
   if data.password != data.password_verify {
     Err(LemmyErrorType::PasswordsDoNotMatch)?
   }
 
   if local_site.site_setup && local_site.captcha_enabled {
-    if let Some(captcha_uuid) = &data.captcha_uuid {
-      let uuid = uuid::Uuid::parse_str(captcha_uuid)?;
-      let check = CaptchaAnswer::check_captcha(
-        pool,
-        CheckCaptchaAnswer {
-          uuid,
-          answer: data.captcha_answer.clone().unwrap_or_default(),
-        },
-      )
-      .await?;
-      if !check {
-        Err(LemmyErrorType::CaptchaIncorrect)?
-      }
-    } else {
-      Err(LemmyErrorType::CaptchaIncorrect)?
-    }
+    check_challenge(
+      &local_site,
+      data.captcha_uuid.as_deref(),
+      data.captcha_answer.as_deref(),
+      pool,
+    )
+    .await?;
   }
 
   let slur_regex = local_site_to_slur_regex(&local_site);
@@ -122,37 +129,8 @@ pub async fn register(
     .show_nsfw
     .unwrap_or(site_view.site.content_warning.is_some());
 
-  // Get the user's preferred language using the Accept-Language header
-  let language_tags: Vec<String> = req
-    .headers()
-    .get("Accept-Language")
-    .map(|hdr| accept_language::parse(hdr.to_str().unwrap_or_default()))
-    .iter()
-    .flatten()
-    // Remove the optional region code
-    .map(|lang_str| lang_str.split('-').next().unwrap_or_default().to_string())
-    .collect();
-
-  let all_languages = Language::read_all(&mut context.pool()).await?;
-  // use hashset to avoid duplicates
-  let mut language_ids = HashSet::new();
-
-  // Enable site languages. Ignored if all languages are enabled.
-  let discussion_languages = SiteLanguage::read(&mut context.pool(), local_site.site_id).await?;
-
-  // Enable languages from `Accept-Language` header only if no site languages are set. Otherwise it
-  // is possible that browser languages are only set to e.g. French, and the user won't see any
-  // English posts.
-  if !discussion_languages.is_empty() {
-    for l in &language_tags {
-      if let Some(found) = all_languages.iter().find(|all| &all.code == l) {
-        language_ids.insert(found.id);
-      }
-    }
-  }
-  language_ids.extend(discussion_languages);
-
-  let language_ids = language_ids.into_iter().collect();
+  let language_tags = get_language_tags(&req);
+  let language_ids = get_language_ids(&language_tags, local_site.site_id, &context).await?;
 
   // Register the new person
   let person_form = PersonInsertForm {
@@ -175,6 +153,17 @@ pub async fn register(
   let (person, local_user) = conn
     .transaction::<_, LemmyError, _>(|conn| {
       async move {
+        // Count the use of the invite code, so that it gets rolled back if the signup fails
+        let invited_by = if require_invite {
+          let code = tx_data.invite_code.as_deref().unwrap_or_default();
+          let invite = Invite::use_code(&mut conn.into(), code)
+            .await?
+            .ok_or(LemmyErrorType::InvalidInviteCode)?;
+          Some(invite.creator_id)
+        } else {
+          None
+        };
+
         // We have to create both a person, and local_user
         let person = Person::create(&mut conn.into(), &person_form)
           .await
@@ -191,6 +180,7 @@ pub async fn register(
           interface_language: language_tags.first().cloned(),
           // If its the initial site setup, they are an admin
           admin: Some(!tx_local_site.site_setup),
+          invited_by,
           ..LocalUserInsertForm::new(person.id, tx_data.password.to_string())
         };
 
@@ -262,3 +252,291 @@ pub async fn register(
 
   Ok(Json(login_response))
 }
+
+#[tracing::instrument(skip(context))]
+pub async fn authenticate_with_oauth(
+  data: Json<AuthenticateWithOauth>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<LoginResponse>> {
+  let pool = &mut context.pool();
+  let site_view = SiteView::read_local(pool)
+    .await?
+    .ok_or(LemmyErrorType::LocalSiteNotSetup)?;
+  let local_site = site_view.local_site.clone();
+
+  // The redirect uri must point back to this instance, otherwise the code could have been issued
+  // for a different client.
+  let hostname = context.settings().get_hostname_without_port()?;
+  if data.code.is_empty() || data.redirect_uri.host_str() != Some(hostname.as_str()) {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+  if let Some(pkce_code_verifier) = &data.pkce_code_verifier {
+    check_pkce_code_verifier(pkce_code_verifier)?;
+  }
+
+  let oauth_provider = OAuthProvider::read(pool, data.oauth_provider_id)
+    .await?
+    .filter(|p| p.enabled)
+    .ok_or(LemmyErrorType::OauthAuthorizationInvalid)?;
+  if oauth_provider.use_pkce && data.pkce_code_verifier.is_none() {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+
+  let user_info = fetch_oauth_user_info(
+    &oauth_provider,
+    &data.code,
+    data.pkce_code_verifier.as_deref(),
+    &data.redirect_uri,
+    &context,
+  )
+  .await
+  .map_err(|_| LemmyErrorType::OauthLoginFailed)?;
+
+  // Log in users which already used this provider before
+  if let Some(local_user_view) =
+    LocalUserView::find_by_oauth_id(pool, oauth_provider.id, &user_info.oauth_user_id).await?
+  {
+    check_oauth_login(&local_user_view, &site_view, &context).await?;
+    return oauth_login_response(&local_user_view, req, &context).await;
+  }
+
+  // Link the provider to an existing user with the same email, if allowed. Otherwise anyone who
+  // can set an unverified email at the provider could take over the account.
+  if let Some(email) = &user_info.email {
+    if let Some(local_user_view) = LocalUserView::find_by_email(pool, email).await? {
+      if !oauth_provider.account_linking_enabled || !user_info.email_verified {
+        Err(LemmyErrorType::EmailAlreadyExists)?
+      }
+      check_oauth_login(&local_user_view, &site_view, &context).await?;
+      let form = OAuthAccountInsertForm {
+        local_user_id: local_user_view.local_user.id,
+        oauth_provider_id: oauth_provider.id,
+        oauth_user_id: user_info.oauth_user_id.clone(),
+      };
+      OAuthAccount::create(pool, &form).await?;
+      return oauth_login_response(&local_user_view, req, &context).await;
+    }
+  }
+
+  // Otherwise sign up a new user
+  let require_registration_application =
+    local_site.registration_mode == RegistrationMode::RequireApplication;
+  if !local_site.oauth_registration
+    || local_site.registration_mode == RegistrationMode::Closed
+    || local_site.registration_mode == RegistrationMode::InviteOnly
+  {
+    Err(LemmyErrorType::OauthRegistrationClosed)?
+  }
+  let username = data
+    .username
+    .clone()
+    .ok_or(LemmyErrorType::RegistrationUsernameRequired)?;
+  if require_registration_application && data.answer.is_none() {
+    Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?
+  }
+  if local_site.require_email_verification && user_info.email.is_none() {
+    Err(LemmyErrorType::EmailRequired)?
+  }
+
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  check_slurs(&username, &slur_regex)?;
+  check_slurs_opt(&data.answer, &slur_regex)?;
+  is_valid_actor_name(&username, local_site.actor_name_max_length as usize)?;
+
+  let actor_keypair = generate_actor_keypair()?;
+  let actor_id = generate_local_apub_endpoint(
+    EndpointType::Person,
+    &username,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let person_form = PersonInsertForm {
+    actor_id: Some(actor_id.clone()),
+    inbox_url: Some(generate_inbox_url(&actor_id)?),
+    shared_inbox_url: Some(generate_shared_inbox_url(context.settings())?),
+    private_key: Some(actor_keypair.private_key),
+    ..PersonInsertForm::new(
+      username.clone(),
+      actor_keypair.public_key,
+      site_view.site.instance_id,
+    )
+  };
+
+  let show_nsfw = data
+    .show_nsfw
+    .unwrap_or(site_view.site.content_warning.is_some());
+  let email_verified = oauth_provider.auto_verify_email && user_info.email_verified;
+  let language_tags = get_language_tags(&req);
+  let language_ids = get_language_ids(&language_tags, local_site.site_id, &context).await?;
+
+  let conn = &mut get_conn(pool).await?;
+  let tx_data = data.clone();
+  let tx_local_site = local_site.clone();
+  let tx_user_info = user_info.clone();
+  let oauth_provider_id = oauth_provider.id;
+  let (person, local_user) = conn
+    .transaction::<_, LemmyError, _>(|conn| {
+      async move {
+        let person = Person::create(&mut conn.into(), &person_form)
+          .await
+          .with_lemmy_type(LemmyErrorType::UserAlreadyExists)?;
+
+        // The user logs in through the provider, so the password is random. It can be changed
+        // later with a password reset.
+        let password = uuid::Uuid::new_v4().to_string();
+        let local_user_form = LocalUserInsertForm {
+          email: tx_user_info.email.clone(),
+          email_verified: Some(email_verified),
+          show_nsfw: Some(show_nsfw),
+          accepted_application: Some(!require_registration_application),
+          default_listing_type: Some(tx_local_site.default_post_listing_type),
+          post_listing_mode: Some(tx_local_site.default_post_listing_mode),
+          interface_language: language_tags.first().cloned(),
+          ..LocalUserInsertForm::new(person.id, password)
+        };
+        let local_user =
+          LocalUser::create(&mut conn.into(), &local_user_form, language_ids).await?;
+
+        let form = OAuthAccountInsertForm {
+          local_user_id: local_user.id,
+          oauth_provider_id,
+          oauth_user_id: tx_user_info.oauth_user_id,
+        };
+        OAuthAccount::create(&mut conn.into(), &form).await?;
+
+        if require_registration_application {
+          let form = RegistrationApplicationInsertForm {
+            local_user_id: local_user.id,
+            // We already made sure answer was not null above
+            answer: tx_data.answer.clone().expect("must have an answer"),
+          };
+          RegistrationApplication::create(&mut conn.into(), &form).await?;
+        }
+        Ok((person, local_user))
+      }
+      .scope_boxed()
+    })
+    .await?;
+
+  let require_email_verification = local_site.require_email_verification && !email_verified;
+  if local_site.application_email_admins && !require_email_verification {
+    send_new_applicant_email_to_admins(&username, pool, context.settings()).await?;
+  }
+
+  let mut login_response = LoginResponse {
+    jwt: None,
+    registration_created: require_registration_application,
+    verify_email_sent: false,
+  };
+  if require_email_verification {
+    let local_user_view = LocalUserView {
+      local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person,
+      counts: PersonAggregates::default(),
+    };
+    // we check above that the provider returned an email
+    let email = local_user_view
+      .local_user
+      .email
+      .clone()
+      .expect("email was provided");
+    send_verification_email(
+      &local_user_view,
+      &email,
+      &mut context.pool(),
+      context.settings(),
+    )
+    .await?;
+    login_response.verify_email_sent = true;
+  } else if !require_registration_application {
+    login_response.jwt = Some(Claims::generate(local_user.id, req, &context).await?);
+  }
+
+  Ok(Json(login_response))
+}
+
+/// Applies the same checks as a login with password. The second factor can't be entered during the
+/// redirect to the provider, so users who enabled totp or WebAuthn have to log in with their
+/// password instead.
+async fn check_oauth_login(
+  local_user_view: &LocalUserView,
+  site_view: &SiteView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_user_valid(&local_user_view.person)?;
+  check_email_verified(local_user_view, site_view)?;
+  check_registration_application(local_user_view, &site_view.local_site, &mut context.pool())
+    .await?;
+
+  let local_user_id = local_user_view.local_user.id;
+  let passkeys = WebauthnCredential::list_passkeys(&mut context.pool(), local_user_id).await?;
+  if local_user_view.local_user.totp_2fa_enabled || !passkeys.is_empty() {
+    Err(LemmyErrorType::OauthLoginRequiresPassword)?
+  }
+  Ok(())
+}
+
+async fn oauth_login_response(
+  local_user_view: &LocalUserView,
+  req: HttpRequest,
+  context: &LemmyContext,
+) -> LemmyResult<Json<LoginResponse>> {
+  Ok(Json(LoginResponse {
+    jwt: Some(Claims::generate(local_user_view.local_user.id, req, context).await?),
+    verify_email_sent: false,
+    registration_created: false,
+  }))
+}
+
+/// A PKCE code verifier must have 43 to 128 unreserved characters, see RFC 7636.
+fn check_pkce_code_verifier(code_verifier: &str) -> LemmyResult<()> {
+  let valid_chars = code_verifier
+    .chars()
+    .all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c));
+  if !valid_chars || !(43..=128).contains(&code_verifier.len()) {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+  Ok(())
+}
+
+/// Get the user's preferred languages using the Accept-Language header
+fn get_language_tags(req: &HttpRequest) -> Vec<String> {
+  req
+    .headers()
+    .get("Accept-Language")
+    .map(|hdr| accept_language::parse(hdr.to_str().unwrap_or_default()))
+    .iter()
+    .flatten()
+    // Remove the optional region code
+    .map(|lang_str| lang_str.split('-').next().unwrap_or_default().to_string())
+    .collect()
+}
+
+/// The discussion languages of a new user.
+async fn get_language_ids(
+  language_tags: &[String],
+  site_id: SiteId,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<LanguageId>> {
+  let all_languages = Language::read_all(&mut context.pool()).await?;
+  // use hashset to avoid duplicates
+  let mut language_ids = HashSet::new();
+
+  // Enable site languages. Ignored if all languages are enabled.
+  let discussion_languages = SiteLanguage::read(&mut context.pool(), site_id).await?;
+
+  // Enable languages from `Accept-Language` header only if no site languages are set. Otherwise it
+  // is possible that browser languages are only set to e.g. French, and the user won't see any
+  // English posts.
+  if !discussion_languages.is_empty() {
+    for l in language_tags {
+      if let Some(found) = all_languages.iter().find(|all| &all.code == l) {
+        language_ids.insert(found.id);
+      }
+    }
+  }
+  language_ids.extend(discussion_languages);
+
+  Ok(language_ids.into_iter().collect())
+}
@@ -0,0 +1,47 @@
+use super::{check_webhook_permission, MAX_WEBHOOKS};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  request::check_url_is_public,
+  webhook::{CreateWebhook, CreateWebhookResponse},
+};
+use lemmy_db_schema::{
+  source::webhook::{Webhook, WebhookInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_webhook_url,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_webhook(
+  data: Json<CreateWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CreateWebhookResponse>> {
+  check_webhook_permission(&local_user_view, data.community_id, &mut context.pool()).await?;
+  is_valid_webhook_url(&data.url)?;
+  check_url_is_public(&data.url).await?;
+
+  let existing = Webhook::list(&mut context.pool(), data.community_id).await?;
+  if existing.len() >= MAX_WEBHOOKS {
+    Err(LemmyErrorType::TooManyWebhooks)?
+  }
+
+  let form = WebhookInsertForm::builder()
+    .creator_id(local_user_view.person.id)
+    .community_id(data.community_id)
+    .url(data.url.clone().into())
+    .build();
+  let webhook = Webhook::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateWebhook)?;
+
+  Ok(Json(CreateWebhookResponse {
+    secret: webhook.secret.clone(),
+    webhook,
+  }))
+}
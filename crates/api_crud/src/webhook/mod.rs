@@ -0,0 +1,27 @@
+use lemmy_api_common::utils::{check_community_mod_action, is_admin};
+use lemmy_db_schema::{newtypes::CommunityId, utils::DbPool};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+/// The maximum number of webhooks per community, and for the whole site.
+const MAX_WEBHOOKS: usize = 10;
+
+/// Community webhooks can be managed by the community's moderators, site-wide webhooks only by
+/// admins.
+async fn check_webhook_permission(
+  local_user_view: &LocalUserView,
+  community_id: Option<CommunityId>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  match community_id {
+    Some(community_id) => {
+      check_community_mod_action(&local_user_view.person, community_id, false, pool).await
+    }
+    None => is_admin(local_user_view),
+  }
+}
@@ -0,0 +1,56 @@
+use super::check_webhook_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  request::check_url_is_public,
+  webhook::{EditWebhook, WebhookResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    webhook::{Webhook, WebhookUpdateForm},
+    webhook_delivery::WebhookDelivery,
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_webhook_url,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_webhook(
+  data: Json<EditWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WebhookResponse>> {
+  let webhook = Webhook::read(&mut context.pool(), data.webhook_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindWebhook)?;
+  check_webhook_permission(&local_user_view, webhook.community_id, &mut context.pool()).await?;
+
+  if let Some(url) = &data.url {
+    is_valid_webhook_url(url)?;
+    check_url_is_public(url).await?;
+  }
+
+  let form = WebhookUpdateForm {
+    url: data.url.clone().map(Into::into),
+    enabled: data.enabled,
+    // Give a webhook which was failing a fresh start
+    fail_count: (data.enabled == Some(true)).then_some(0),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let webhook = Webhook::update(&mut context.pool(), webhook.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateWebhook)?;
+
+  if !webhook.enabled {
+    WebhookDelivery::delete_for_webhook(&mut context.pool(), webhook.id).await?;
+  }
+
+  Ok(Json(WebhookResponse { webhook }))
+}
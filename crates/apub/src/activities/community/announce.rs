@@ -219,7 +219,7 @@ async fn can_accept_activity_in_community(
       Err(LemmyErrorType::CommunityHasNoFollowers)?
     }
     // Local only community can't federate
-    if community.visibility != CommunityVisibility::Public {
+    if community.visibility == CommunityVisibility::LocalOnly {
       return Err(LemmyErrorType::CouldntFindCommunity.into());
     }
   }
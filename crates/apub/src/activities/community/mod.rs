@@ -42,15 +42,16 @@ pub(crate) async fn send_activity_in_community(
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
   // If community is local only, don't send anything out
-  if community.visibility != CommunityVisibility::Public {
+  if community.visibility == CommunityVisibility::LocalOnly {
     return Ok(());
   }
 
   // send to any users which are mentioned or affected directly
   let mut inboxes = extra_inboxes;
 
-  // send to user followers
-  if !is_mod_action {
+  // send to user followers, except for private communities where only approved community
+  // followers may see the content
+  if !is_mod_action && community.visibility == CommunityVisibility::Public {
     inboxes.add_inboxes(
       PersonFollower::list_followers(&mut context.pool(), actor.id)
         .await?
@@ -121,6 +121,8 @@ impl ActivityHandler for Report {
           original_post_url: post.url.clone(),
           reason,
           original_post_body: post.body.clone(),
+          category_id: None,
+          rule_id: None,
         };
         PostReport::report(&mut context.pool(), &report_form).await?;
       }
@@ -132,6 +134,8 @@ impl ActivityHandler for Report {
           comment_id: comment.id,
           original_comment_text: comment.content.clone(),
           reason,
+          category_id: None,
+          rule_id: None,
         };
         CommentReport::report(&mut context.pool(), &report_form).await?;
       }
@@ -8,7 +8,11 @@ use crate::{
   },
   activity_lists::AnnouncableActivities,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson, read_from_string_or_source_opt},
+  objects::{
+    community::{rule_forms, ApubCommunity},
+    person::ApubPerson,
+    read_from_string_or_source_opt,
+  },
   protocol::{activities::community::update::UpdateCommunity, objects::AttributedTo, InCommunity},
 };
 use activitypub_federation::{
@@ -22,6 +26,7 @@ use lemmy_db_schema::{
     activity::ActivitySendTargets,
     community::{Community, CommunityUpdateForm},
     person::Person,
+    rule::Rule,
   },
   traits::Crud,
   utils::naive_now,
@@ -89,6 +94,8 @@ impl ActivityHandler for UpdateCommunity {
   async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
     insert_received_activity(&self.id, context).await?;
     let community = self.community(context).await?;
+    // The visibility of a local community can only be changed through the api
+    let visibility = (!community.local).then(|| self.object.visibility());
 
     let community_update_form = CommunityUpdateForm {
       title: Some(self.object.name.unwrap_or(self.object.preferred_username)),
@@ -110,11 +117,17 @@ impl ActivityHandler for UpdateCommunity {
       shared_inbox_url: Some(self.object.endpoints.map(|e| e.shared_inbox.into())),
       moderators_url: self.object.attributed_to.and_then(AttributedTo::url),
       posting_restricted_to_mods: self.object.posting_restricted_to_mods,
+      visibility,
       featured_url: self.object.featured.map(Into::into),
       ..Default::default()
     };
 
     Community::update(&mut context.pool(), community.id, &community_update_form).await?;
+    // The rules of a local community can only be changed through the api
+    if !community.local {
+      let rules = rule_forms(self.object.rules, community.actor_id.inner(), context).await?;
+      Rule::replace_for_community(&mut context.pool(), community.id, rules).await?;
+    }
     Ok(())
   }
 }
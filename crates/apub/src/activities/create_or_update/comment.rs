@@ -25,6 +25,7 @@ use activitypub_federation::{
 use lemmy_api_common::{
   build_response::send_local_notifs,
   context::LemmyContext,
+  send_activity::SendActivityData,
   utils::{check_post_deleted_or_removed, is_mod_or_admin},
 };
 use lemmy_db_schema::{
@@ -47,6 +48,30 @@ use lemmy_utils::{
 use url::Url;
 
 impl CreateOrUpdateNote {
+  pub(crate) async fn new(
+    comment: ApubComment,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    kind: CreateOrUpdateType,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<CreateOrUpdateNote> {
+    let id = generate_activity_id(
+      kind.clone(),
+      &context.settings().get_protocol_and_hostname(),
+    )?;
+    let note = comment.into_json(context).await?;
+    Ok(CreateOrUpdateNote {
+      actor: actor.id().into(),
+      to: vec![public()],
+      cc: note.cc.clone(),
+      tag: note.tag.clone(),
+      object: note,
+      kind,
+      id,
+      audience: Some(community.id().into()),
+    })
+  }
+
   #[tracing::instrument(skip(comment, person_id, kind, context))]
   pub(crate) async fn send(
     comment: Comment,
@@ -69,22 +94,8 @@ impl CreateOrUpdateNote {
       .ok_or(LemmyErrorType::CouldntFindCommunity)?
       .into();
 
-    let id = generate_activity_id(
-      kind.clone(),
-      &context.settings().get_protocol_and_hostname(),
-    )?;
-    let note = ApubComment(comment).into_json(&context).await?;
-
-    let create_or_update = CreateOrUpdateNote {
-      actor: person.id().into(),
-      to: vec![public()],
-      cc: note.cc.clone(),
-      tag: note.tag.clone(),
-      object: note,
-      kind,
-      id: id.clone(),
-      audience: Some(community.id().into()),
-    };
+    let create_or_update =
+      CreateOrUpdateNote::new(ApubComment(comment), &person, &community, kind, &context).await?;
 
     let tagged_users: Vec<ObjectId<ApubPerson>> = create_or_update
       .tag
@@ -180,6 +191,13 @@ impl ActivityHandler for CreateOrUpdateNote {
     // tags
     let mentions = scrape_text_for_mentions(&comment.content);
     send_local_notifs(mentions, comment.id, &actor, do_send_email, context, None).await?;
+
+    if self.kind == CreateOrUpdateType::Create {
+      let data = SendActivityData::CreateComment(comment.0);
+      if let Err(e) = data.queue_webhooks(context).await {
+        tracing::warn!("error while queueing webhooks: {e}");
+      }
+    }
     Ok(())
   }
 }
@@ -20,7 +20,7 @@ use activitypub_federation::{
   protocol::verification::{verify_domains_match, verify_urls_match},
   traits::{ActivityHandler, Actor, Object},
 };
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{context::LemmyContext, send_activity::SendActivityData};
 use lemmy_db_schema::{
   aggregates::structs::PostAggregates,
   newtypes::PersonId,
@@ -132,6 +132,13 @@ impl ActivityHandler for CreateOrUpdatePage {
     // Calculate initial hot_rank for post
     PostAggregates::update_ranks(&mut context.pool(), post.id).await?;
 
+    if self.kind == CreateOrUpdateType::Create {
+      let data = SendActivityData::CreatePost(post.0);
+      if let Err(e) = data.queue_webhooks(context).await {
+        tracing::warn!("error while queueing webhooks: {e}");
+      }
+    }
+
     Ok(())
   }
 }
@@ -24,7 +24,9 @@ pub(crate) async fn send_create_or_update_pm(
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
   let actor: ApubPerson = pm_view.creator.into();
-  let recipient: ApubPerson = pm_view.recipient.into();
+  let private_message = ApubPrivateMessage(pm_view.private_message);
+  let recipients = private_message.recipients(&context).await?;
+  let object = private_message.into_json(&context).await?;
 
   let id = generate_activity_id(
     kind.clone(),
@@ -33,13 +35,12 @@ pub(crate) async fn send_create_or_update_pm(
   let create_or_update = CreateOrUpdateChatMessage {
     id: id.clone(),
     actor: actor.id().into(),
-    to: [recipient.id().into()],
-    object: ApubPrivateMessage(pm_view.private_message.clone())
-      .into_json(&context)
-      .await?,
+    to: object.to.clone(),
+    object,
     kind,
   };
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
+  let mut inbox = ActivitySendTargets::empty();
+  inbox.add_inboxes(recipients.iter().map(Actor::shared_inbox_or_inbox));
   send_lemmy_activity(&context, create_or_update, &actor, inbox, true).await
 }
 
@@ -60,7 +61,9 @@ impl ActivityHandler for CreateOrUpdateChatMessage {
   async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
     verify_person(&self.actor, context).await?;
     verify_domains_match(self.actor.inner(), self.object.id.inner())?;
-    verify_domains_match(self.to[0].inner(), self.object.to[0].inner())?;
+    for (to, object_to) in self.to.iter().zip(&self.object.to) {
+      verify_domains_match(to.inner(), object_to.inner())?;
+    }
     verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
     ApubPrivateMessage::verify(&self.object, self.actor.inner(), context).await?;
     Ok(())
@@ -144,6 +144,7 @@ pub(in crate::activities) async fn receive_remove_action(
         post_id: post.id,
         removed: Some(true),
         reason,
+        rule_id: None,
       };
       ModRemovePost::create(&mut context.pool(), &form).await?;
       Post::update(
@@ -163,6 +164,7 @@ pub(in crate::activities) async fn receive_remove_action(
         comment_id: comment.id,
         removed: Some(true),
         reason,
+        rule_id: None,
       };
       ModRemoveComment::create(&mut context.pool(), &form).await?;
       Comment::update(
@@ -39,7 +39,7 @@ use lemmy_db_schema::{
   },
   traits::Crud,
 };
-use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+use lemmy_utils::error::LemmyResult;
 use std::ops::Deref;
 use url::Url;
 
@@ -84,19 +84,25 @@ pub(crate) async fn send_apub_delete_private_message(
   deleted: bool,
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let recipient_id = pm.recipient_id;
-  let recipient: ApubPerson = Person::read(&mut context.pool(), recipient_id)
-    .await?
-    .ok_or(LemmyErrorType::CouldntFindPerson)?
-    .into();
+  let pm: ApubPrivateMessage = pm.into();
+  let recipients = pm.recipients(&context).await?;
+  let mut inbox = ActivitySendTargets::empty();
+  inbox.add_inboxes(recipients.iter().map(Actor::shared_inbox_or_inbox));
+  // Messages in a group conversation have multiple recipients
+  let mut to = recipients.iter().map(Actor::id);
+  let Some(first_recipient) = to.next() else {
+    return Ok(());
+  };
 
-  let deletable = DeletableObjects::PrivateMessage(pm.into());
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
+  let deletable = DeletableObjects::PrivateMessage(pm);
   if deleted {
-    let delete: Delete = Delete::new(actor, deletable, recipient.id(), None, None, &context)?;
+    let mut delete: Delete = Delete::new(actor, deletable, first_recipient, None, None, &context)?;
+    delete.to.extend(to);
     send_lemmy_activity(&context, delete, actor, inbox, true).await?;
   } else {
-    let undo = UndoDelete::new(actor, deletable, recipient.id(), None, None, &context)?;
+    let mut undo = UndoDelete::new(actor, deletable, first_recipient, None, None, &context)?;
+    undo.to.extend(to);
+    undo.object.to.clone_from(&undo.to);
     send_lemmy_activity(&context, undo, actor, inbox, true).await?;
   };
   Ok(())
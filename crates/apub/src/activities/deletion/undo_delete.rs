@@ -125,6 +125,7 @@ impl UndoDelete {
           post_id: post.id,
           removed: Some(false),
           reason: None,
+          rule_id: None,
         };
         ModRemovePost::create(&mut context.pool(), &form).await?;
         Post::update(
@@ -143,6 +144,7 @@ impl UndoDelete {
           comment_id: comment.id,
           removed: Some(false),
           reason: None,
+          rule_id: None,
         };
         ModRemoveComment::create(&mut context.pool(), &form).await?;
         Comment::update(
@@ -1,5 +1,6 @@
 use crate::{
   activities::{generate_activity_id, send_lemmy_activity},
+  fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
   protocol::activities::following::{accept::AcceptFollow, follow::Follow},
 };
@@ -11,7 +12,11 @@ use activitypub_federation::{
 };
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
-  source::{activity::ActivitySendTargets, community::CommunityFollower},
+  source::{
+    activity::ActivitySendTargets,
+    community::CommunityFollower,
+    person::PersonFollower,
+  },
   traits::Followable,
 };
 use lemmy_utils::error::{LemmyError, LemmyResult};
@@ -64,12 +69,17 @@ impl ActivityHandler for AcceptFollow {
   #[tracing::instrument(skip_all)]
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
     insert_received_activity(&self.id, context).await?;
-    let community = self.actor.dereference(context).await?;
+    let object = self.actor.dereference(context).await?;
     let person = self.object.actor.dereference(context).await?;
     // This will throw an error if no follow was requested
-    let community_id = community.id;
-    let person_id = person.id;
-    CommunityFollower::follow_accepted(&mut context.pool(), community_id, person_id).await?;
+    match object {
+      UserOrCommunity::User(u) => {
+        PersonFollower::follow_accepted_person(&mut context.pool(), u.id, person.id).await?;
+      }
+      UserOrCommunity::Community(c) => {
+        CommunityFollower::follow_accepted(&mut context.pool(), c.id, person.id).await?;
+      }
+    }
 
     Ok(())
   }
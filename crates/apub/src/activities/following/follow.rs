@@ -3,11 +3,11 @@ use crate::{
     generate_activity_id,
     send_lemmy_activity,
     verify_person,
-    verify_person_in_community,
+    verify_person_not_banned_in_community,
   },
   fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson},
+  objects::person::ApubPerson,
   protocol::activities::following::{accept::AcceptFollow, follow::Follow},
 };
 use activitypub_federation::{
@@ -32,13 +32,13 @@ use url::Url;
 impl Follow {
   pub(in crate::activities::following) fn new(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Follow> {
     Ok(Follow {
       actor: actor.id().into(),
-      object: community.id().into(),
-      to: Some([community.id().into()]),
+      object: target.id().into(),
+      to: Some([target.id().into()]),
       kind: FollowType::Follow,
       id: generate_activity_id(
         FollowType::Follow,
@@ -50,14 +50,14 @@ impl Follow {
   #[tracing::instrument(skip_all)]
   pub async fn send(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let follow = Follow::new(actor, community, context)?;
-    let inbox = if community.local {
+    let follow = Follow::new(actor, target, context)?;
+    let inbox = if target.local() {
       ActivitySendTargets::empty()
     } else {
-      ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox())
+      ActivitySendTargets::to_inbox(target.shared_inbox_or_inbox())
     };
     send_lemmy_activity(context, follow, actor, inbox, true).await
   }
@@ -81,7 +81,8 @@ impl ActivityHandler for Follow {
     verify_person(&self.actor, context).await?;
     let object = self.object.dereference(context).await?;
     if let UserOrCommunity::Community(c) = object {
-      verify_person_in_community(&self.actor, &c, context).await?;
+      // Followers of private communities are only approved later
+      verify_person_not_banned_in_community(&self.actor, &c, context).await?;
     }
     if let Some(to) = &self.to {
       verify_urls_match(to[0].inner(), self.object.inner())?;
@@ -105,15 +106,22 @@ impl ActivityHandler for Follow {
       }
       UserOrCommunity::Community(c) => {
         // Dont allow following local-only community via federation.
-        if c.visibility != CommunityVisibility::Public {
+        if c.visibility == CommunityVisibility::LocalOnly {
           return Err(LemmyErrorType::CouldntFindCommunity.into());
         }
+        // Follows of private communities stay pending until a mod approves them, then the
+        // accept activity is sent.
+        let pending = c.visibility == CommunityVisibility::Private
+          && !CommunityFollower::is_approved_follower(&mut context.pool(), c.id, actor.id).await?;
         let form = CommunityFollowerForm {
           community_id: c.id,
           person_id: actor.id,
-          pending: false,
+          pending,
         };
         CommunityFollower::follow(&mut context.pool(), &form).await?;
+        if pending {
+          return Ok(());
+        }
       }
     }
 
@@ -1,6 +1,12 @@
 use crate::{
-  objects::{community::ApubCommunity, person::ApubPerson},
-  protocol::activities::following::{follow::Follow, undo_follow::UndoFollow},
+  fetcher::user_or_community::UserOrCommunity,
+  objects::person::ApubPerson,
+  protocol::activities::following::{
+    accept::AcceptFollow,
+    follow::Follow,
+    reject::RejectFollow,
+    undo_follow::UndoFollow,
+  },
 };
 use activitypub_federation::config::Data;
 use lemmy_api_common::context::LemmyContext;
@@ -9,6 +15,7 @@ use lemmy_utils::error::LemmyResult;
 
 pub mod accept;
 pub mod follow;
+pub mod reject;
 pub mod undo_follow;
 
 pub async fn send_follow_community(
@@ -17,7 +24,7 @@ pub async fn send_follow_community(
   follow: bool,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let community: ApubCommunity = community.into();
+  let community = UserOrCommunity::Community(community.into());
   let actor: ApubPerson = person.into();
   if follow {
     Follow::send(&actor, &community, context).await
@@ -25,3 +32,36 @@ pub async fn send_follow_community(
     UndoFollow::send(&actor, &community, context).await
   }
 }
+
+pub async fn send_follow_person(
+  target: Person,
+  person: Person,
+  follow: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let target = UserOrCommunity::User(target.into());
+  let actor: ApubPerson = person.into();
+  if follow {
+    Follow::send(&actor, &target, context).await
+  } else {
+    UndoFollow::send(&actor, &target, context).await
+  }
+}
+
+/// Send the decision of a mod about a pending follow of a local private community to the remote
+/// follower.
+pub async fn send_approve_community_follower(
+  community: Community,
+  follower: Person,
+  approve: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let community = UserOrCommunity::Community(community.into());
+  let follower: ApubPerson = follower.into();
+  let follow = Follow::new(&follower, &community, context)?;
+  if approve {
+    AcceptFollow::send(follow, context).await
+  } else {
+    RejectFollow::send(follow, context).await
+  }
+}
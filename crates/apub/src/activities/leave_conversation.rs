@@ -0,0 +1,97 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity, verify_person},
+  insert_received_activity,
+  objects::person::ApubPerson,
+  protocol::activities::leave_conversation::LeaveConversation,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::activity::LeaveType,
+  protocol::verification::verify_domains_match,
+  traits::{ActivityHandler, Actor},
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    conversation::{Conversation, ConversationParticipant, ConversationParticipantUpdateForm},
+    person::Person,
+  },
+  utils::naive_now,
+};
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+/// Informs the remaining participants that the person left, so that they stop sending messages
+/// to them.
+pub async fn send_leave_conversation(
+  person: Person,
+  conversation: Conversation,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = person.into();
+  let recipients: Vec<ApubPerson> =
+    ConversationParticipant::list_active(&mut context.pool(), conversation.id)
+      .await?
+      .into_iter()
+      .map(|(person, _)| person.into())
+      .collect();
+
+  let leave = LeaveConversation {
+    actor: actor.id().into(),
+    to: recipients.iter().map(Actor::id).collect(),
+    object: conversation.ap_id.into(),
+    kind: LeaveType::Leave,
+    id: generate_activity_id(
+      LeaveType::Leave,
+      &context.settings().get_protocol_and_hostname(),
+    )?,
+  };
+  let mut inbox = ActivitySendTargets::empty();
+  inbox.add_inboxes(recipients.iter().map(Actor::shared_inbox_or_inbox));
+  send_lemmy_activity(&context, leave, &actor, inbox, true).await
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for LeaveConversation {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_domains_match(self.actor.inner(), &self.id)?;
+    verify_person(&self.actor, context).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    insert_received_activity(&self.id, context).await?;
+    let person = self.actor.dereference(context).await?;
+    let conversation =
+      Conversation::read_from_apub_id(&mut context.pool(), self.object.clone()).await?;
+    // Nothing to do for conversations which this instance doesn't know
+    let Some(conversation) = conversation else {
+      return Ok(());
+    };
+    let participant =
+      ConversationParticipant::read(&mut context.pool(), conversation.id, person.id).await?;
+    if participant.is_some_and(|p| p.left_at.is_none()) {
+      let form = ConversationParticipantUpdateForm {
+        left_at: Some(Some(naive_now())),
+        ..Default::default()
+      };
+      ConversationParticipant::update(&mut context.pool(), conversation.id, person.id, &form)
+        .await?;
+    }
+    Ok(())
+  }
+}
@@ -1,4 +1,4 @@
-use self::following::send_follow_community;
+use self::following::{send_approve_community_follower, send_follow_community, send_follow_person};
 use crate::{
   activities::{
     block::{send_ban_from_community, send_ban_from_site},
@@ -14,7 +14,9 @@ use crate::{
       send_apub_delete_user,
       DeletableObjects,
     },
-    voting::send_like_activity,
+    leave_conversation::send_leave_conversation,
+    move_user::send_move_user,
+    voting::{poll_vote::send_poll_vote, send_like_activity},
   },
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::activities::{
@@ -35,13 +37,19 @@ use lemmy_api_common::{
   send_activity::{ActivityChannel, SendActivityData},
 };
 use lemmy_db_schema::{
+  newtypes::PersonId,
   source::{
     activity::{ActivitySendTargets, ActorType, SentActivity, SentActivityForm},
-    community::Community,
+    community::{Community, CommunityFollower},
   },
   traits::Crud,
+  CommunityVisibility,
+};
+use lemmy_db_views_actor::structs::{
+  CommunityModeratorView,
+  CommunityPersonBanView,
+  CommunityView,
 };
-use lemmy_db_views_actor::structs::{CommunityPersonBanView, CommunityView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult};
 use serde::Serialize;
 use tracing::info;
@@ -53,6 +61,8 @@ pub mod community;
 pub mod create_or_update;
 pub mod deletion;
 pub mod following;
+pub mod leave_conversation;
+pub mod move_user;
 pub mod voting;
 
 /// Checks that the specified Url actually identifies a Person (by fetching it), and that the person
@@ -72,13 +82,37 @@ async fn verify_person(
 }
 
 /// Fetches the person and community to verify their type, then checks if person is banned from site
-/// or community.
+/// or community. For local private communities it also checks that the person is an approved
+/// follower.
 #[tracing::instrument(skip_all)]
 pub(crate) async fn verify_person_in_community(
   person_id: &ObjectId<ApubPerson>,
   community: &ApubCommunity,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
+  let person_id = verify_person_not_banned_in_community(person_id, community, context).await?;
+  if community.local && community.visibility == CommunityVisibility::Private {
+    let community_id = community.id;
+    let is_follower =
+      CommunityFollower::is_approved_follower(&mut context.pool(), community_id, person_id).await?;
+    let is_mod =
+      CommunityModeratorView::is_community_moderator(&mut context.pool(), community_id, person_id)
+        .await?;
+    if !is_follower && !is_mod {
+      Err(LemmyErrorType::NotApprovedFollower)?
+    }
+  }
+  Ok(())
+}
+
+/// Same as [verify_person_in_community], but without the check for private communities. Returns
+/// the local id of the person.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn verify_person_not_banned_in_community(
+  person_id: &ObjectId<ApubPerson>,
+  community: &ApubCommunity,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<PersonId> {
   let person = person_id.dereference(context).await?;
   if person.banned {
     Err(LemmyErrorType::PersonIsBannedFromSite(
@@ -91,7 +125,7 @@ pub(crate) async fn verify_person_in_community(
   if is_banned {
     Err(LemmyErrorType::PersonIsBannedFromCommunity)?
   } else {
-    Ok(())
+    Ok(person_id)
   }
 }
 
@@ -222,6 +256,9 @@ where
 
 pub async fn handle_outgoing_activities(context: Data<LemmyContext>) {
   while let Some(data) = ActivityChannel::retrieve_activity().await {
+    if let Err(e) = data.queue_webhooks(&context).await {
+      tracing::warn!("error while queueing webhooks: {e}");
+    }
     if let Err(e) = match_outgoing_activities(data, &context.reset_request_count()).await {
       tracing::warn!("error while saving outgoing activity to db: {e}");
     }
@@ -311,9 +348,21 @@ pub async fn match_outgoing_activities(
         community,
         score,
       } => send_like_activity(object_id, actor, community, score, context).await,
+      VoteInPoll {
+        post,
+        actor,
+        choices,
+        previous_choices,
+      } => send_poll_vote(post, actor, choices, previous_choices, context).await,
       FollowCommunity(community, person, follow) => {
         send_follow_community(community, person, follow, &context).await
       }
+      ApproveCommunityFollower(community, follower, approve) => {
+        send_approve_community_follower(community, follower, approve, &context).await
+      }
+      FollowPerson(target, person, follow) => {
+        send_follow_person(target, person, follow, &context).await
+      }
       UpdateCommunity(actor, community) => send_update_community(community, actor, context).await,
       DeleteCommunity(actor, community, removed) => {
         let deletable = DeletableObjects::Community(community.clone().into());
@@ -377,6 +426,10 @@ pub async fn match_outgoing_activities(
         send_apub_delete_private_message(&person.into(), pm, deleted, context).await
       }
       DeleteUser(person, remove_data) => send_apub_delete_user(person, remove_data, context).await,
+      MoveUser(person, target) => send_move_user(person, target, context).await,
+      LeaveConversation(person, conversation) => {
+        send_leave_conversation(person, conversation, context).await
+      }
       CreateReport {
         object_id,
         actor,
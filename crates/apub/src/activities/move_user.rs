@@ -0,0 +1,105 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity, verify_person},
+  insert_received_activity,
+  objects::person::ApubPerson,
+  protocol::activities::move_user::MoveUser,
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  kinds::{activity::MoveType, public},
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{ActivityHandler, Actor},
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    community::CommunityFollower,
+    person::{Person, PersonUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
+
+pub async fn send_move_user(
+  person: Person,
+  target: Person,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let person: ApubPerson = person.into();
+  let target: ApubPerson = target.into();
+  let move_user = MoveUser {
+    actor: person.id().into(),
+    to: vec![public()],
+    object: person.id().into(),
+    target: target.id().into(),
+    kind: MoveType::Move,
+    id: generate_activity_id(
+      MoveType::Move,
+      &context.settings().get_protocol_and_hostname(),
+    )?,
+  };
+  let inboxes = ActivitySendTargets::to_all_instances();
+  send_lemmy_activity(&context, move_user, &person, inboxes, true).await
+}
+
+/// A move is only valid if the new account confirms it by listing the old account in its
+/// `alsoKnownAs`.
+pub(crate) fn verify_move_target(old: &ApubPerson, new: &ApubPerson) -> LemmyResult<()> {
+  if old.actor_id == new.actor_id || !new.also_known_as.contains(&old.actor_id) {
+    Err(LemmyErrorType::InvalidMoveTarget)?
+  }
+  Ok(())
+}
+
+/// Fetches the target account without using the cache, so that a freshly added alias is seen.
+pub(crate) async fn fetch_move_target(
+  target: &ObjectId<ApubPerson>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ApubPerson> {
+  if target.is_local(context) {
+    target.dereference_local(context).await
+  } else {
+    target.dereference_forced(context).await
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for MoveUser {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    verify_urls_match(self.actor.inner(), self.object.inner())?;
+    verify_domains_match(self.actor.inner(), &self.id)?;
+    verify_person(&self.actor, context).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
+    insert_received_activity(&self.id, context).await?;
+    let old = self.object.dereference(context).await?;
+    let new = fetch_move_target(&self.target, context).await?;
+    verify_move_target(&old, &new)?;
+
+    let form = PersonUpdateForm {
+      moved_to: Some(Some(new.actor_id.clone())),
+      ..Default::default()
+    };
+    Person::update(&mut context.pool(), old.id, &form).await?;
+    CommunityFollower::migrate_follows(&mut context.pool(), old.id, new.id).await?;
+    Ok(())
+  }
+}
@@ -23,6 +23,7 @@ use lemmy_db_schema::{
 };
 use lemmy_utils::error::LemmyResult;
 
+pub mod poll_vote;
 pub mod undo_vote;
 pub mod vote;
 
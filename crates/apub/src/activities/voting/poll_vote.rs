@@ -0,0 +1,202 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity, verify_person},
+  insert_received_activity,
+  objects::person::ApubPerson,
+  protocol::activities::voting::poll_vote::{CreatePollVote, PollVoteNote, UndoPollVote},
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::{
+    activity::{CreateType, UndoType},
+    object::NoteType,
+  },
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{ActivityHandler, Actor},
+};
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{
+    check_bot_account,
+    check_community_user_action,
+    check_poll_choices,
+    check_post_deleted_or_removed,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    person::Person,
+    poll::{Poll, PollVote, PollVoteForm},
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use url::Url;
+
+/// Sends the vote to the creator of a remote poll. Votes in local polls are counted directly.
+/// Each choice is sent as a separate activity, and choices which are no longer part of the vote
+/// are retracted with an undo.
+pub(crate) async fn send_poll_vote(
+  post: Post,
+  actor: Person,
+  choices: Vec<i32>,
+  previous_choices: Vec<i32>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  if post.local {
+    return Ok(());
+  }
+  let poll = Poll::read(&mut context.pool(), post.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPoll)?;
+  let creator: ApubPerson = Person::read(&mut context.pool(), post.creator_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindPerson)?
+    .into();
+  let actor: ApubPerson = actor.into();
+  let inbox = ActivitySendTargets::to_inbox(creator.shared_inbox_or_inbox());
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+
+  for choice in previous_choices.iter().filter(|c| !choices.contains(c)) {
+    let vote = CreatePollVote::new(&poll, &post, &actor, &creator, *choice, &context)?;
+    let undo = UndoPollVote {
+      id: generate_activity_id(UndoType::Undo, &protocol_and_hostname)?,
+      actor: actor.id().into(),
+      to: [creator.id().into()],
+      object: vote,
+      kind: UndoType::Undo,
+    };
+    send_lemmy_activity(&context, undo, &actor, inbox.clone(), false).await?;
+  }
+  for choice in choices.iter().filter(|c| !previous_choices.contains(c)) {
+    let vote = CreatePollVote::new(&poll, &post, &actor, &creator, *choice, &context)?;
+    send_lemmy_activity(&context, vote, &actor, inbox.clone(), false).await?;
+  }
+  Ok(())
+}
+
+impl CreatePollVote {
+  fn new(
+    poll: &Poll,
+    post: &Post,
+    actor: &ApubPerson,
+    creator: &ApubPerson,
+    choice: i32,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Self> {
+    let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+    let name = usize::try_from(choice)
+      .ok()
+      .and_then(|c| poll.options.get(c))
+      .ok_or(LemmyErrorType::InvalidPollChoice)?;
+    Ok(CreatePollVote {
+      id: generate_activity_id(CreateType::Create, &protocol_and_hostname)?,
+      actor: actor.id().into(),
+      to: [creator.id().into()],
+      object: PollVoteNote {
+        r#type: NoteType::Note,
+        id: generate_activity_id(NoteType::Note, &protocol_and_hostname)?,
+        attributed_to: actor.id().into(),
+        to: [creator.id().into()],
+        name: name.clone(),
+        in_reply_to: post.ap_id.clone().into(),
+      },
+      kind: CreateType::Create,
+    })
+  }
+
+  /// Returns the local poll which the vote is for, and the index of the chosen option.
+  async fn poll_and_choice(&self, context: &Data<LemmyContext>) -> LemmyResult<(Post, Poll, i32)> {
+    // Votes in remote polls are counted by the instance of the poll
+    let post = self.object.in_reply_to.dereference_local(context).await?;
+    check_post_deleted_or_removed(&post)?;
+
+    let poll = Poll::read(&mut context.pool(), post.id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPoll)?;
+    let choice = poll
+      .options
+      .iter()
+      .position(|o| o == &self.object.name)
+      .ok_or(LemmyErrorType::InvalidPollChoice)?;
+    let choice = i32::try_from(choice)?;
+    check_poll_choices(&poll, &[choice])?;
+    Ok((post.0, poll, choice))
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for CreatePollVote {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), &self.object.id)?;
+    verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    insert_received_activity(&self.id, context).await?;
+    let actor = self.actor.dereference(context).await?;
+    check_bot_account(&actor.0)?;
+
+    let (post, poll, choice) = self.poll_and_choice(context).await?;
+    check_community_user_action(&actor, post.community_id, &mut context.pool()).await?;
+
+    // Each choice of a multiple choice vote is sent as a separate activity
+    if poll.multiple_choice {
+      let form = PollVoteForm {
+        post_id: post.id,
+        person_id: actor.id,
+        choice,
+      };
+      PollVote::add(&mut context.pool(), &form).await?;
+    } else {
+      PollVote::vote(&mut context.pool(), post.id, actor.id, vec![choice]).await?;
+    }
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for UndoPollVote {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    verify_urls_match(self.actor.inner(), self.object.actor.inner())?;
+    self.object.verify(context).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<Self::DataType>) -> LemmyResult<()> {
+    insert_received_activity(&self.id, context).await?;
+    let actor = self.actor.dereference(context).await?;
+    let (post, _, choice) = self.object.poll_and_choice(context).await?;
+    PollVote::remove(&mut context.pool(), post.id, actor.id, choice).await?;
+    Ok(())
+  }
+}
@@ -17,8 +17,19 @@ use crate::{
         page::CreateOrUpdatePage,
       },
       deletion::{delete::Delete, undo_delete::UndoDelete},
-      following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
-      voting::{undo_vote::UndoVote, vote::Vote},
+      following::{
+        accept::AcceptFollow,
+        follow::Follow,
+        reject::RejectFollow,
+        undo_follow::UndoFollow,
+      },
+      leave_conversation::LeaveConversation,
+      move_user::MoveUser,
+      voting::{
+        poll_vote::{CreatePollVote, UndoPollVote},
+        undo_vote::UndoVote,
+        vote::Vote,
+      },
     },
     objects::page::Page,
     InCommunity,
@@ -41,9 +52,14 @@ use url::Url;
 pub enum SharedInboxActivities {
   Follow(Follow),
   AcceptFollow(AcceptFollow),
+  RejectFollow(RejectFollow),
   UndoFollow(UndoFollow),
+  CreatePollVote(CreatePollVote),
+  UndoPollVote(UndoPollVote),
   CreateOrUpdatePrivateMessage(CreateOrUpdateChatMessage),
   Report(Report),
+  MoveUser(MoveUser),
+  LeaveConversation(LeaveConversation),
   AnnounceActivity(AnnounceActivity),
   /// This is a catch-all and needs to be last
   RawAnnouncableActivities(RawAnnouncableActivities),
@@ -68,10 +84,15 @@ pub enum GroupInboxActivities {
 pub enum PersonInboxActivities {
   Follow(Follow),
   AcceptFollow(AcceptFollow),
+  RejectFollow(RejectFollow),
   UndoFollow(UndoFollow),
+  CreatePollVote(CreatePollVote),
+  UndoPollVote(UndoPollVote),
   CreateOrUpdatePrivateMessage(CreateOrUpdateChatMessage),
   Delete(Delete),
   UndoDelete(UndoDelete),
+  MoveUser(MoveUser),
+  LeaveConversation(LeaveConversation),
   AnnounceActivity(AnnounceActivity),
   /// User can also receive some "announcable" activities, eg a comment mention.
   AnnouncableActivities(AnnouncableActivities),
@@ -153,6 +174,12 @@ mod tests {
       "assets/lemmy/activities/create_or_update/create_private_message.json",
     )?;
     test_json::<PersonInboxActivities>("assets/mastodon/activities/follow.json")?;
+    let poll_vote =
+      test_json::<PersonInboxActivities>("assets/mastodon/activities/poll_vote.json")?;
+    assert!(matches!(
+      poll_vote.inner(),
+      PersonInboxActivities::CreatePollVote(_)
+    ));
     Ok(())
   }
 
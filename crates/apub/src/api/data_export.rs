@@ -0,0 +1,277 @@
+use crate::{
+  activity_lists::AnnouncableActivities,
+  api::user_settings_backup::{build_settings_backup, UserSettingsBackup},
+  objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson},
+  protocol::{
+    activities::{
+      create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
+      CreateOrUpdateType,
+    },
+    collections::person_outbox::PersonOutbox,
+  },
+};
+use activitypub_federation::{config::Data, kinds::collection::OrderedCollectionType};
+use actix_web::web::Json;
+use chrono::{TimeDelta, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::DataExportResponse,
+  utils::{generate_outbox_url, send_data_export_email},
+};
+use lemmy_db_schema::{
+  impls::local_user::UserContentLists,
+  newtypes::{CommunityId, DataExportId, PostId},
+  source::{
+    community::Community,
+    data_export::DataExport,
+    local_site::LocalSite,
+    local_user::LocalUser,
+    person::Person,
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  spawn_try_task,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::info;
+
+/// Full archive of the data which a user created. Unlike [UserSettingsBackup] this can't be
+/// imported again.
+#[derive(Serialize)]
+struct UserDataExport {
+  person: Person,
+  settings: UserSettingsBackup,
+  #[serde(flatten)]
+  content: UserContentLists,
+}
+
+/// Starts generating an archive of the user's data in the background. An email is sent once it
+/// can be downloaded.
+#[tracing::instrument(skip(context))]
+pub async fn create_data_export(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<DataExportResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  if let Some(previous) = DataExport::read_for_user(&mut context.pool(), local_user_id).await? {
+    if previous.published > Utc::now() - TimeDelta::days(1) {
+      Err(LemmyErrorType::DataExportTooFrequent)?
+    }
+  }
+  let data_export = DataExport::create(&mut context.pool(), local_user_id).await?;
+
+  let export_id = data_export.id;
+  spawn_try_task(async move {
+    info!("Starting data export for {}", local_user_view.person.name);
+    if let Err(e) = generate_data_export(export_id, &local_user_view, &context).await {
+      // Delete the unfinished export, so that the user can try again right away
+      DataExport::delete(&mut context.pool(), export_id).await?;
+      return Err(e);
+    }
+    info!("Finished data export for {}", local_user_view.person.name);
+    send_data_export_email(&local_user_view, context.settings()).await
+  });
+
+  Ok(Json(DataExportResponse {
+    data_export: Some(data_export),
+  }))
+}
+
+async fn generate_data_export(
+  export_id: DataExportId,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let content = LocalUser::export_content(
+    &mut context.pool(),
+    local_user_view.person.id,
+    local_user_view.local_user.id,
+  )
+  .await?;
+  let outbox = build_outbox(&local_user_view.person, &content, context).await?;
+  let archive = UserDataExport {
+    person: local_user_view.person.clone(),
+    settings: build_settings_backup(local_user_view.clone(), &mut context.pool()).await?,
+    content,
+  };
+  let mut archive = serde_json::to_value(archive)?;
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  if local_site.hide_modlog_mod_names {
+    remove_mod_names(&mut archive);
+  }
+
+  DataExport::finish(
+    &mut context.pool(),
+    export_id,
+    archive,
+    serde_json::to_value(outbox)?,
+  )
+  .await?;
+  Ok(())
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn get_data_export(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<DataExportResponse>> {
+  let data_export =
+    DataExport::read_for_user(&mut context.pool(), local_user_view.local_user.id).await?;
+  Ok(Json(DataExportResponse { data_export }))
+}
+
+/// Download the JSON archive of a finished data export.
+#[tracing::instrument(skip(context))]
+pub async fn download_data_export(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<Value>> {
+  let archive = DataExport::read_archive(&mut context.pool(), local_user_view.local_user.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindDataExport)?;
+  Ok(Json(archive))
+}
+
+/// Download the ActivityPub outbox of a finished data export.
+#[tracing::instrument(skip(context))]
+pub async fn download_data_export_outbox(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<Value>> {
+  let outbox = DataExport::read_outbox(&mut context.pool(), local_user_view.local_user.id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindDataExport)?;
+  Ok(Json(outbox))
+}
+
+/// Removes the moderators from the modlog entries of an archive, for sites which hide them in the
+/// modlog.
+fn remove_mod_names(archive: &mut Value) {
+  let Some(Value::Object(modlog)) = archive.get_mut("modlog") else {
+    return;
+  };
+  for entries in modlog.values_mut() {
+    if let Value::Array(entries) = entries {
+      for entry in entries {
+        if let Value::Object(entry) = entry {
+          entry.remove("mod_person_id");
+        }
+      }
+    }
+  }
+}
+
+/// Wraps all posts and comments of the user which are still visible in create activities, the
+/// same way they are federated.
+async fn build_outbox(
+  person: &Person,
+  content: &UserContentLists,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<PersonOutbox> {
+  let actor: ApubPerson = person.clone().into();
+  let mut communities: HashMap<CommunityId, ApubCommunity> = HashMap::new();
+  let mut post_communities: HashMap<PostId, CommunityId> = HashMap::new();
+  let mut ordered_items = vec![];
+
+  for post in &content.posts {
+    post_communities.insert(post.id, post.community_id);
+    if post.deleted || post.removed {
+      continue;
+    }
+    let community = read_community(post.community_id, &mut communities, context).await?;
+    let create = CreateOrUpdatePage::new(
+      post.clone().into(),
+      &actor,
+      &community,
+      CreateOrUpdateType::Create,
+      context,
+    )
+    .await?;
+    ordered_items.push(AnnouncableActivities::CreateOrUpdatePost(create));
+  }
+
+  for comment in &content.comments {
+    if comment.deleted || comment.removed {
+      continue;
+    }
+    let community_id = match post_communities.get(&comment.post_id) {
+      Some(community_id) => *community_id,
+      None => {
+        let post = Post::read(&mut context.pool(), comment.post_id)
+          .await?
+          .ok_or(LemmyErrorType::CouldntFindPost)?;
+        post_communities.insert(post.id, post.community_id);
+        post.community_id
+      }
+    };
+    let community = read_community(community_id, &mut communities, context).await?;
+    let create = CreateOrUpdateNote::new(
+      ApubComment(comment.clone()),
+      &actor,
+      &community,
+      CreateOrUpdateType::Create,
+      context,
+    )
+    .await?;
+    ordered_items.push(AnnouncableActivities::CreateOrUpdateComment(create));
+  }
+
+  Ok(PersonOutbox {
+    r#type: OrderedCollectionType::OrderedCollection,
+    id: generate_outbox_url(&person.actor_id)?.into(),
+    total_items: ordered_items.len() as i32,
+    ordered_items,
+  })
+}
+
+async fn read_community(
+  community_id: CommunityId,
+  communities: &mut HashMap<CommunityId, ApubCommunity>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ApubCommunity> {
+  if let Some(community) = communities.get(&community_id) {
+    return Ok(community.clone());
+  }
+  let community: ApubCommunity = Community::read(&mut context.pool(), community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindCommunity)?
+    .into();
+  communities.insert(community_id, community.clone());
+  Ok(community)
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::remove_mod_names;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[test]
+  fn test_remove_mod_names() {
+    let mut archive = json!({
+      "posts": [{"id": 1}],
+      "modlog": {
+        "bans": [{"id": 2, "mod_person_id": 3, "reason": "spam"}],
+        "removed_posts": [],
+      },
+    });
+    remove_mod_names(&mut archive);
+    assert_eq!(
+      json!({
+        "posts": [{"id": 1}],
+        "modlog": {
+          "bans": [{"id": 2, "reason": "spam"}],
+          "removed_posts": [],
+        },
+      }),
+      archive
+    );
+  }
+}
@@ -10,7 +10,11 @@ use lemmy_api_common::{
   post::{GetPosts, GetPostsResponse},
   utils::check_private_instance,
 };
-use lemmy_db_schema::source::community::Community;
+use lemmy_db_schema::{
+  source::{community::Community, multi_community::MultiCommunity},
+  traits::Crud,
+  ListingType,
+};
 use lemmy_db_views::{
   post_view::PostQuery,
   structs::{LocalUserView, PaginationCursor, SiteView},
@@ -46,19 +50,51 @@ pub async fn list_posts(
   // Show nsfw content if param is true, or if content_warning exists
   let show_nsfw = data.show_nsfw;
 
+  // Scheduled posts are only listed for their creator, like a profile view
+  let scheduled_only = data.scheduled_only;
+  let creator_id = if scheduled_only.unwrap_or_default() {
+    Some(
+      local_user_view
+        .as_ref()
+        .ok_or(LemmyErrorType::NotLoggedIn)?
+        .person
+        .id,
+    )
+  } else {
+    None
+  };
+
   let liked_only = data.liked_only;
   let disliked_only = data.disliked_only;
   if liked_only.unwrap_or_default() && disliked_only.unwrap_or_default() {
     return Err(LemmyError::from(LemmyErrorType::ContradictingFilters));
   }
 
+  // Private feeds can only be used by their creator
+  let multi_community_id = data.multi_community_id;
+  if let Some(multi_community_id) = multi_community_id {
+    let multi_community = MultiCommunity::read(&mut context.pool(), multi_community_id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindMultiCommunity)?;
+    let is_creator = local_user_view
+      .as_ref()
+      .is_some_and(|l| l.person.id == multi_community.creator_id);
+    if !multi_community.public && !is_creator {
+      Err(LemmyErrorType::CouldntFindMultiCommunity)?
+    }
+  }
+
   let local_user = local_user_view.as_ref().map(|u| &u.local_user);
-  let listing_type = Some(listing_type_with_default(
-    data.type_,
-    local_user,
-    &local_site.local_site,
-    community_id,
-  ));
+  let listing_type = if creator_id.is_some() || multi_community_id.is_some() {
+    Some(ListingType::All)
+  } else {
+    Some(listing_type_with_default(
+      data.type_,
+      local_user,
+      &local_site.local_site,
+      community_id,
+    ))
+  };
 
   let sort = Some(sort_type_with_default(
     data.sort,
@@ -78,6 +114,9 @@ pub async fn list_posts(
     listing_type,
     sort,
     community_id,
+    creator_id,
+    multi_community_id,
+    tag_id: data.tag_id,
     saved_only,
     liked_only,
     disliked_only,
@@ -87,6 +126,7 @@ pub async fn list_posts(
     show_hidden,
     show_read,
     show_nsfw,
+    scheduled_only,
     ..Default::default()
   }
   .list(&local_site.site, &mut context.pool())
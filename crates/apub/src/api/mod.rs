@@ -5,8 +5,10 @@ use lemmy_db_schema::{
   SortType,
 };
 
+pub mod data_export;
 pub mod list_comments;
 pub mod list_posts;
+pub mod move_account;
 pub mod read_community;
 pub mod read_person;
 pub mod resolve_object;
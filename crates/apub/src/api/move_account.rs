@@ -0,0 +1,45 @@
+use crate::activities::move_user::{fetch_move_target, verify_move_target};
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::MoveAccount,
+  send_activity::{ActivityChannel, SendActivityData},
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    community::CommunityFollower,
+    person::{Person, PersonUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use url::Url;
+
+#[tracing::instrument(skip(context))]
+pub async fn move_account(
+  data: Json<MoveAccount>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let target = Url::parse(&data.target).with_lemmy_type(LemmyErrorType::InvalidUrl)?;
+  let target = fetch_move_target(&ObjectId::from(target), &context)
+    .await
+    .map_err(|_| LemmyErrorType::CouldntFindPerson)?;
+  let person = local_user_view.person;
+  verify_move_target(&person.clone().into(), &target)?;
+
+  let form = PersonUpdateForm {
+    moved_to: Some(Some(target.actor_id.clone())),
+    ..Default::default()
+  };
+  let person = Person::update(&mut context.pool(), person.id, &form).await?;
+  // Other instances do the same when they receive the activity
+  CommunityFollower::migrate_follows(&mut context.pool(), person.id, target.id).await?;
+
+  ActivityChannel::submit_activity(SendActivityData::MoveUser(person, target.0), &context).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
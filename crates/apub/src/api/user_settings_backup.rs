@@ -17,6 +17,7 @@ use lemmy_db_schema::{
     community_block::{CommunityBlock, CommunityBlockForm},
     instance::Instance,
     instance_block::{InstanceBlock, InstanceBlockForm},
+    keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
     local_user::{LocalUser, LocalUserUpdateForm},
     local_user_vote_display_mode::{LocalUserVoteDisplayMode, LocalUserVoteDisplayModeUpdateForm},
     person::{Person, PersonUpdateForm},
@@ -24,11 +25,14 @@ use lemmy_db_schema::{
     post::{PostSaved, PostSavedForm},
   },
   traits::{Blockable, Crud, Followable, Saveable},
+  utils::DbPool,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult, MAX_API_PARAM_ELEMENTS},
   spawn_try_task,
+  utils::validation::is_valid_keyword_filter,
+  MAX_KEYWORD_FILTERS,
 };
 use serde::{Deserialize, Serialize};
 use std::future::Future;
@@ -68,6 +72,17 @@ pub struct UserSettingsBackup {
   pub blocked_users: Vec<ObjectId<ApubPerson>>,
   #[serde(default)]
   pub blocked_instances: Vec<String>,
+  #[serde(default)]
+  pub keyword_filters: Vec<KeywordFilterBackup>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KeywordFilterBackup {
+  pub keyword: String,
+  #[serde(default)]
+  pub is_regex: bool,
+  /// The community which the filter is limited to, if any
+  pub community: Option<ObjectId<ApubCommunity>>,
 }
 
 #[tracing::instrument(skip(context))]
@@ -75,10 +90,20 @@ pub async fn export_settings(
   local_user_view: LocalUserView,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<UserSettingsBackup>> {
-  let lists = LocalUser::export_backup(&mut context.pool(), local_user_view.person.id).await?;
+  Ok(Json(
+    build_settings_backup(local_user_view, &mut context.pool()).await?,
+  ))
+}
+
+/// Collects the settings backup of the user, which is also part of the full data export.
+pub(crate) async fn build_settings_backup(
+  local_user_view: LocalUserView,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<UserSettingsBackup> {
+  let lists = LocalUser::export_backup(pool, local_user_view.person.id).await?;
 
   let vec_into = |vec: Vec<_>| vec.into_iter().map(Into::into).collect();
-  Ok(Json(UserSettingsBackup {
+  Ok(UserSettingsBackup {
     display_name: local_user_view.person.display_name,
     bio: local_user_view.person.bio,
     avatar: local_user_view.person.avatar,
@@ -93,7 +118,16 @@ pub async fn export_settings(
     blocked_users: lists.blocked_users.into_iter().map(Into::into).collect(),
     saved_posts: lists.saved_posts.into_iter().map(Into::into).collect(),
     saved_comments: lists.saved_comments.into_iter().map(Into::into).collect(),
-  }))
+    keyword_filters: lists
+      .keyword_filters
+      .into_iter()
+      .map(|(keyword, is_regex, community)| KeywordFilterBackup {
+        keyword,
+        is_regex,
+        community: community.map(Into::into),
+      })
+      .collect(),
+  })
 }
 
 #[tracing::instrument(skip(context))]
@@ -165,13 +199,15 @@ pub async fn import_settings(
     + data.blocked_users.len()
     + data.blocked_instances.len()
     + data.saved_posts.len()
-    + data.saved_comments.len();
+    + data.saved_comments.len()
+    + data.keyword_filters.len();
   if url_count > MAX_API_PARAM_ELEMENTS {
     Err(LemmyErrorType::TooManyItems)?;
   }
 
   spawn_try_task(async move {
     let person_id = local_user_view.person.id;
+    let local_user_id = local_user_view.local_user.id;
 
     info!(
       "Starting settings import for {}",
@@ -266,7 +302,38 @@ pub async fn import_settings(
     }))
     .await?;
 
-    info!("Settings import completed for {}, the following items failed: {failed_followed_communities}, {failed_saved_posts}, {failed_saved_comments}, {failed_community_blocks}, {failed_user_blocks}",
+    let mut failed_keyword_filters = vec![];
+    let mut keyword_filter_count = KeywordFilter::count(&mut context.pool(), local_user_id).await?;
+    for filter in &data.keyword_filters {
+      let context = context.reset_request_count();
+      let imported = async {
+        let keyword = filter.keyword.trim().to_string();
+        is_valid_keyword_filter(&keyword)?;
+        if keyword_filter_count >= MAX_KEYWORD_FILTERS {
+          Err(LemmyErrorType::TooManyKeywordFilters)?
+        }
+        let community_id = match &filter.community {
+          Some(community) => Some(community.dereference(&context).await?.id),
+          None => None,
+        };
+        let form = KeywordFilterInsertForm::builder()
+          .local_user_id(local_user_id)
+          .keyword(keyword)
+          .is_regex(Some(filter.is_regex))
+          .community_id(community_id)
+          .build();
+        KeywordFilter::create(&mut context.pool(), &form).await?;
+        LemmyResult::Ok(())
+      }
+      .await;
+      match imported {
+        Ok(()) => keyword_filter_count += 1,
+        Err(_) => failed_keyword_filters.push(filter.keyword.clone()),
+      }
+    }
+    let failed_keyword_filters = failed_keyword_filters.join(",");
+
+    info!("Settings import completed for {}, the following items failed: {failed_followed_communities}, {failed_saved_posts}, {failed_saved_comments}, {failed_community_blocks}, {failed_user_blocks}, {failed_keyword_filters}",
     local_user_view.person.name);
 
     Ok(())
@@ -319,6 +386,7 @@ mod tests {
     source::{
       community::{Community, CommunityFollower, CommunityFollowerForm, CommunityInsertForm},
       instance::Instance,
+      keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
       local_user::{LocalUser, LocalUserInsertForm},
       person::{Person, PersonInsertForm},
     },
@@ -375,6 +443,12 @@ mod tests {
       pending: false,
     };
     CommunityFollower::follow(&mut context.pool(), &follower_form).await?;
+    let keyword_filter_form = KeywordFilterInsertForm::builder()
+      .local_user_id(export_user.local_user.id)
+      .keyword("spoiler".to_string())
+      .community_id(Some(community.id))
+      .build();
+    KeywordFilter::create(&mut context.pool(), &keyword_filter_form).await?;
 
     let backup = export_settings(export_user.clone(), context.reset_request_count()).await?;
 
@@ -400,6 +474,12 @@ mod tests {
     assert_eq!(follows.len(), 1);
     assert_eq!(follows[0].community.actor_id, community.actor_id);
 
+    let keyword_filters =
+      KeywordFilter::list(&mut context.pool(), import_user.local_user.id).await?;
+    assert_eq!(1, keyword_filters.len());
+    assert_eq!("spoiler", keyword_filters[0].keyword);
+    assert_eq!(Some(community.id), keyword_filters[0].community_id);
+
     LocalUser::delete(&mut context.pool(), export_user.local_user.id).await?;
     LocalUser::delete(&mut context.pool(), import_user.local_user.id).await?;
     Ok(())
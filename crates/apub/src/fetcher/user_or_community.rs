@@ -123,6 +123,22 @@ impl Actor for UserOrCommunity {
       UserOrCommunity::Community(p) => p.inbox(),
     }
   }
+
+  fn shared_inbox(&self) -> Option<Url> {
+    match self {
+      UserOrCommunity::User(p) => p.shared_inbox(),
+      UserOrCommunity::Community(p) => p.shared_inbox(),
+    }
+  }
+}
+
+impl UserOrCommunity {
+  pub(crate) fn local(&self) -> bool {
+    match self {
+      UserOrCommunity::User(p) => p.local,
+      UserOrCommunity::Community(p) => p.local,
+    }
+  }
 }
 
 impl GetActorType for UserOrCommunity {
@@ -6,7 +6,12 @@ use crate::{
     community_moderators::ApubCommunityModerators,
     community_outbox::ApubCommunityOutbox,
   },
-  http::{check_community_public, create_apub_response, create_apub_tombstone_response},
+  http::{
+    check_community_fetchable,
+    check_community_public,
+    create_apub_response,
+    create_apub_tombstone_response,
+  },
   objects::{community::ApubCommunity, person::ApubPerson},
 };
 use activitypub_federation::{
@@ -45,7 +50,7 @@ pub(crate) async fn get_apub_community_http(
   if community.deleted || community.removed {
     return create_apub_tombstone_response(community.actor_id.clone());
   }
-  check_community_public(&community)?;
+  check_community_fetchable(&community)?;
 
   let apub = community.into_json(&context).await?;
   create_apub_response(&apub)
@@ -72,7 +77,7 @@ pub(crate) async fn get_apub_community_followers(
   let community = Community::read_from_name(&mut context.pool(), &info.community_name, false)
     .await?
     .ok_or(LemmyErrorType::CouldntFindCommunity)?;
-  check_community_public(&community)?;
+  check_community_fetchable(&community)?;
   let followers = ApubCommunityFollower::read_local(&community.into(), &context).await?;
   create_apub_response(&followers)
 }
@@ -103,7 +108,7 @@ pub(crate) async fn get_apub_community_moderators(
       .await?
       .ok_or(LemmyErrorType::CouldntFindCommunity)?
       .into();
-  check_community_public(&community)?;
+  check_community_fetchable(&community)?;
   let moderators = ApubCommunityModerators::read_local(&community, &context).await?;
   create_apub_response(&moderators)
 }
@@ -289,6 +294,33 @@ pub(crate) mod tests {
     Ok(())
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_get_private_community() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let (instance, _, query) = init(false, CommunityVisibility::Private, &context).await?;
+
+    // The actor is needed to send follow requests, but content is not available
+    let res = get_apub_community_http(query.clone().into(), context.reset_request_count()).await?;
+    assert_eq!(200, res.status());
+    let res_group: Group = decode_response(res).await?;
+    assert_eq!(Some(true), res_group.manually_approves_followers);
+    let res =
+      get_apub_community_followers(query.clone().into(), context.reset_request_count()).await?;
+    assert_eq!(200, res.status());
+    let res =
+      get_apub_community_moderators(query.clone().into(), context.reset_request_count()).await?;
+    assert_eq!(200, res.status());
+    let res =
+      get_apub_community_featured(query.clone().into(), context.reset_request_count()).await;
+    assert!(res.is_err());
+    let res = get_apub_community_outbox(query, context.reset_request_count()).await;
+    assert!(res.is_err());
+
+    Instance::delete(&mut context.pool(), instance.id).await?;
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_outbox_deleted_user() -> LemmyResult<()> {
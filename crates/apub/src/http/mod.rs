@@ -30,6 +30,7 @@ mod person;
 mod post;
 pub mod routes;
 pub mod site;
+mod tag;
 
 const INCOMING_ACTIVITY_TIMEOUT: Duration = Duration::from_secs(9);
 
@@ -129,3 +130,15 @@ fn check_community_public(community: &Community) -> LemmyResult<()> {
   }
   Ok(())
 }
+
+/// Same as [check_community_public], but also allows private communities. Use this for the actor
+/// and its metadata which remote users need to send a follow request, but not for content.
+fn check_community_fetchable(community: &Community) -> LemmyResult<()> {
+  if community.visibility == CommunityVisibility::Private
+    && !community.deleted
+    && !community.removed
+  {
+    return Ok(());
+  }
+  check_community_public(community)
+}
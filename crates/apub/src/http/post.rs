@@ -35,6 +35,10 @@ pub(crate) async fn get_apub_post(
     .await?
     .ok_or(LemmyErrorType::CouldntFindPost)?
     .into();
+  // Scheduled posts are not public until they are published
+  if post.scheduled_publish_time.is_some() {
+    Err(LemmyErrorType::CouldntFindPost)?
+  }
   let community = Community::read(&mut context.pool(), post.community_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindCommunity)?;
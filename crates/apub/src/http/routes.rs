@@ -13,6 +13,7 @@ use crate::http::{
   post::get_apub_post,
   shared_inbox,
   site::{get_apub_site_http, get_apub_site_outbox},
+  tag::get_apub_tag,
 };
 use actix_web::{
   guard::{Guard, GuardContext},
@@ -51,6 +52,7 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     )
     .route("/post/{post_id}", web::get().to(get_apub_post))
     .route("/comment/{comment_id}", web::get().to(get_apub_comment))
+    .route("/tag/{tag_id}", web::get().to(get_apub_tag))
     .route("/activities/{type_}/{id}", web::get().to(get_activity));
 
   cfg.service(
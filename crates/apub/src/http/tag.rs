@@ -0,0 +1,41 @@
+use crate::{
+  http::{check_community_public, create_apub_response, redirect_remote_object},
+  protocol::objects::page::CommunityPostTag,
+};
+use activitypub_federation::config::Data;
+use actix_web::{web::Path, HttpResponse};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::TagId,
+  source::{community::Community, tag::Tag},
+  traits::Crud,
+};
+use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct TagQuery {
+  tag_id: String,
+}
+
+/// Return the ActivityPub json representation of a local post tag over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_tag(
+  info: Path<TagQuery>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let id = TagId(info.tag_id.parse::<i32>()?);
+  let tag = Tag::read(&mut context.pool(), id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindTag)?;
+  let community = Community::read(&mut context.pool(), tag.community_id)
+    .await?
+    .ok_or(LemmyErrorType::CouldntFindCommunity)?;
+  check_community_public(&community)?;
+
+  if !tag.local {
+    Ok(redirect_remote_object(&tag.ap_id))
+  } else {
+    create_apub_response(&CommunityPostTag::from(tag))
+  }
+}
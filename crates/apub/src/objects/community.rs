@@ -4,7 +4,12 @@ use crate::{
   fetcher::user_or_community::PersonOrGroupType,
   objects::{instance::fetch_instance_actor_for_object, read_from_string_or_source_opt},
   protocol::{
-    objects::{group::Group, AttributedTo, Endpoints, LanguageTag},
+    objects::{
+      group::{CommunityRule, Group},
+      AttributedTo,
+      Endpoints,
+      LanguageTag,
+    },
     ImageObject,
     Source,
   },
@@ -13,6 +18,7 @@ use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
   kinds::actor::GroupType,
+  protocol::verification::verify_domains_match,
   traits::{Actor, Object},
 };
 use chrono::{DateTime, Utc};
@@ -35,14 +41,16 @@ use lemmy_db_schema::{
     actor_language::CommunityLanguage,
     community::{Community, CommunityInsertForm, CommunityUpdateForm},
     local_site::LocalSite,
+    rule::{Rule, RuleInsertForm},
   },
   traits::{ApubActor, Crud},
   utils::naive_now,
+  CommunityVisibility,
 };
 use lemmy_utils::{
   error::{LemmyError, LemmyResult},
   spawn_try_task,
-  utils::markdown::markdown_to_html,
+  utils::{markdown::markdown_to_html, slurs::check_slurs, validation::is_valid_rule_title},
 };
 use std::ops::Deref;
 use url::Url;
@@ -100,6 +108,18 @@ impl Object for ApubCommunity {
     let community_id = self.id;
     let langs = CommunityLanguage::read(&mut data.pool(), community_id).await?;
     let language = LanguageTag::new_multiple(langs, &mut data.pool()).await?;
+    let mut rules = vec![];
+    for rule in Rule::list(&mut data.pool(), Some(community_id)).await? {
+      let id = match rule.ap_id {
+        Some(ap_id) => ap_id.into(),
+        None => Url::parse(&format!("{}/rule/{}", self.actor_id, rule.id.0))?,
+      };
+      rules.push(CommunityRule {
+        id: Some(id),
+        name: rule.title,
+        content: rule.description,
+      });
+    }
 
     let group = Group {
       kind: GroupType::Group,
@@ -120,9 +140,11 @@ impl Object for ApubCommunity {
       }),
       public_key: self.public_key(),
       language,
+      rules,
       published: Some(self.published),
       updated: self.updated,
       posting_restricted_to_mods: Some(self.posting_restricted_to_mods),
+      manually_approves_followers: Some(self.visibility == CommunityVisibility::Private),
       attributed_to: Some(AttributedTo::Lemmy(
         generate_moderators_url(&self.actor_id)?.into(),
       )),
@@ -150,6 +172,8 @@ impl Object for ApubCommunity {
     let description = read_from_string_or_source_opt(&group.summary, &None, &group.source);
     let description =
       process_markdown_opt(&description, slur_regex, &url_blocklist, context).await?;
+    let visibility = group.visibility();
+    let rules = rule_forms(group.rules, group.id.inner(), context).await?;
     let icon = proxy_image_link_opt_apub(group.icon.map(|i| i.url), context).await?;
     let banner = proxy_image_link_opt_apub(group.image.map(|i| i.url), context).await?;
 
@@ -172,6 +196,7 @@ impl Object for ApubCommunity {
       shared_inbox_url: group.endpoints.map(|e| e.shared_inbox.into()),
       moderators_url: group.attributed_to.clone().and_then(AttributedTo::url),
       posting_restricted_to_mods: group.posting_restricted_to_mods,
+      visibility: Some(visibility),
       instance_id,
       featured_url: group.featured.clone().map(Into::into),
       ..Default::default()
@@ -184,6 +209,7 @@ impl Object for ApubCommunity {
       .await?
       .into();
     CommunityLanguage::update(&mut context.pool(), languages, community.id).await?;
+    Rule::replace_for_community(&mut context.pool(), community.id, rules).await?;
 
     // These collections are not necessary for Lemmy to work, so ignore errors.
     let community_ = community.clone();
@@ -248,6 +274,35 @@ impl GetActorType for ApubCommunity {
   }
 }
 
+/// Converts the received rules of a remote community, for [Rule::replace_for_community].
+pub(crate) async fn rule_forms(
+  rules: Vec<CommunityRule>,
+  group_id: &Url,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Vec<RuleInsertForm>> {
+  let local_site = LocalSite::read(&mut context.pool()).await.ok();
+  let slur_regex = &local_site_opt_to_slur_regex(&local_site);
+  let url_blocklist = get_url_blocklist(context).await?;
+  let mut forms = Vec::with_capacity(rules.len());
+  for (position, rule) in (1..).zip(rules) {
+    if let Some(rule_id) = &rule.id {
+      verify_domains_match(rule_id, group_id)?;
+    }
+    is_valid_rule_title(&rule.name)?;
+    check_slurs(&rule.name, slur_regex)?;
+    let description =
+      process_markdown_opt(&rule.content, slur_regex, &url_blocklist, context).await?;
+    let form = RuleInsertForm::builder()
+      .position(position)
+      .title(rule.name)
+      .description(description)
+      .ap_id(rule.id.map(Into::into))
+      .build();
+    forms.push(form);
+  }
+  Ok(forms)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
   use super::*;
@@ -293,6 +348,20 @@ pub(crate) mod tests {
       Some(132)
     );
 
+    let rules = Rule::list(&mut context.pool(), Some(community.id)).await?;
+    assert_eq!(2, rules.len());
+    assert_eq!(
+      vec!["Be respectful", "No shop talk"],
+      rules.iter().map(|r| r.title.as_str()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+      Some("https://enterprise.lemmy.ml/c/tenforward/rule/1".to_string()),
+      rules
+        .first()
+        .and_then(|r| r.ap_id.as_ref())
+        .map(ToString::to_string)
+    );
+
     Community::delete(&mut context.pool(), community.id).await?;
     Site::delete(&mut context.pool(), site.id).await?;
     Ok(())
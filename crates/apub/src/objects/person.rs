@@ -123,6 +123,12 @@ impl Object for ApubPerson {
       public_key: self.public_key(),
       updated: self.updated,
       inbox: self.inbox_url.clone().into(),
+      also_known_as: self
+        .also_known_as
+        .iter()
+        .map(|a| a.clone().into())
+        .collect(),
+      moved_to: self.moved_to.clone().map(Into::into),
     };
     Ok(person)
   }
@@ -184,6 +190,8 @@ impl Object for ApubPerson {
       shared_inbox_url: person.endpoints.map(|e| e.shared_inbox.into()),
       matrix_user_id: person.matrix_user_id,
       instance_id,
+      also_known_as: Some(person.also_known_as.into_iter().map(Into::into).collect()),
+      moved_to: person.moved_to.map(Into::into),
     };
     let person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
 
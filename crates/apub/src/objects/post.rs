@@ -2,10 +2,23 @@ use crate::{
   activities::{verify_is_public, verify_person_in_community},
   check_apub_id_valid_with_strictness,
   local_site_data_cached,
-  objects::{read_from_string_or_source_opt, verify_is_remote_object},
+  objects::{
+    community::ApubCommunity,
+    person::ApubPerson,
+    read_from_string_or_source_opt,
+    verify_is_remote_object,
+  },
   protocol::{
     objects::{
-      page::{Attachment, Hashtag, HashtagType, Page, PageType},
+      page::{
+        Attachment,
+        Hashtag,
+        HashtagType,
+        Page,
+        PageTag,
+        PageType,
+        QuestionOption,
+      },
       AttributedTo,
       LanguageTag,
     },
@@ -26,14 +39,17 @@ use html2text::{from_read_with_decorator, render::text_renderer::TrivialDecorato
 use lemmy_api_common::{
   context::LemmyContext,
   request::generate_post_link_metadata,
-  utils::{get_url_blocklist, local_site_opt_to_slur_regex, process_markdown_opt},
+  utils::{check_post_tags, get_url_blocklist, local_site_opt_to_slur_regex, process_markdown_opt},
 };
 use lemmy_db_schema::{
+  newtypes::TagId,
   source::{
     community::Community,
     local_site::LocalSite,
     person::Person,
+    poll::{Poll, PollInsertForm},
     post::{Post, PostInsertForm, PostUpdateForm},
+    tag::{PostTag, Tag, TagInsertForm},
   },
   traits::Crud,
   utils::naive_now,
@@ -44,8 +60,14 @@ use lemmy_utils::{
   spawn_try_task,
   utils::{
     markdown::markdown_to_html,
-    slurs::check_slurs_opt,
-    validation::{is_url_blocked, is_valid_url},
+    slurs::{check_slurs, check_slurs_opt},
+    validation::{
+      is_url_blocked,
+      is_valid_poll_options,
+      is_valid_tag_color,
+      is_valid_tag_name,
+      is_valid_url,
+    },
   },
 };
 use std::ops::Deref;
@@ -134,9 +156,39 @@ impl Object for ApubPost {
       name: format!("#{}", &community.name),
       kind: HashtagType::Hashtag,
     };
+    let mut tag = vec![PageTag::Hashtag(hashtag)];
+    let post_tags = Tag::list_for_post(&mut context.pool(), self.id).await?;
+    tag.extend(
+      post_tags
+        .into_iter()
+        .map(|t| PageTag::CommunityPostTag(t.into())),
+    );
+
+    // Polls are federated as Question, which is the type used by Mastodon
+    let poll = Poll::read(&mut context.pool(), self.id).await?;
+    let kind = if poll.is_some() {
+      PageType::Question
+    } else {
+      PageType::Page
+    };
+    let (mut one_of, mut any_of) = (None, None);
+    if let Some(poll) = &poll {
+      let options = poll
+        .options
+        .iter()
+        .zip(poll.vote_counts.iter())
+        .map(|(name, votes)| QuestionOption::new(name.clone(), *votes))
+        .collect();
+      if poll.multiple_choice {
+        any_of = Some(options);
+      } else {
+        one_of = Some(options);
+      }
+    }
+    let end_time = poll.as_ref().and_then(|p| p.end_time);
 
     let page = Page {
-      kind: PageType::Page,
+      kind,
       id: self.ap_id.clone().into(),
       attributed_to: AttributedTo::Lemmy(creator.actor_id.into()),
       to: vec![community.actor_id.clone().into(), public()],
@@ -153,7 +205,12 @@ impl Object for ApubPost {
       updated: self.updated,
       audience: Some(community.actor_id.into()),
       in_reply_to: None,
-      tag: vec![hashtag],
+      tag,
+      one_of,
+      any_of,
+      end_time,
+      closed: end_time.filter(|e| e < &Utc::now()),
+      voters_count: poll.map(|p| p.voters_count),
     };
     Ok(page)
   }
@@ -174,6 +231,13 @@ impl Object for ApubPost {
     let local_site_data = local_site_data_cached(&mut context.pool()).await?;
     let slur_regex = &local_site_opt_to_slur_regex(&local_site_data.local_site);
     check_slurs_opt(&page.name, slur_regex)?;
+    if let Some((options, _)) = page.poll_options() {
+      let names = options.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+      is_valid_poll_options(&names)?;
+      for name in &names {
+        check_slurs(name, slur_regex)?;
+      }
+    }
 
     verify_domains_match(page.creator()?.inner(), page.id.inner())?;
     verify_is_public(&page.to, &page.cc)?;
@@ -253,7 +317,7 @@ impl Object for ApubPost {
     let body = read_from_string_or_source_opt(&page.content, &page.media_type, &page.source);
     let body = process_markdown_opt(&body, slur_regex, &url_blocklist, context).await?;
     let language_id =
-      LanguageTag::to_language_id_single(page.language, &mut context.pool()).await?;
+      LanguageTag::to_language_id_single(page.language.clone(), &mut context.pool()).await?;
 
     let form = PostInsertForm::builder()
       .name(name)
@@ -273,6 +337,33 @@ impl Object for ApubPost {
 
     let timestamp = page.updated.or(page.published).unwrap_or_else(naive_now);
     let post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
+
+    if let Some((options, multiple_choice)) = page.poll_options() {
+      let names = options.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+      let vote_counts = options
+        .iter()
+        .map(|o| {
+          o.replies
+            .as_ref()
+            .map(|r| r.total_items)
+            .unwrap_or_default()
+        })
+        .collect();
+      let poll_form = PollInsertForm::builder()
+        .post_id(post.id)
+        .multiple_choice(Some(multiple_choice))
+        .options(names)
+        .vote_counts(vote_counts)
+        .voters_count(page.voters_count)
+        .end_time(page.end_time.or(page.closed))
+        .updated(Some(naive_now()))
+        .build();
+      Poll::upsert(&mut context.pool(), &poll_form).await?;
+    }
+
+    let tags = receive_post_tags(&page, &community, &post, &creator, context).await?;
+    PostTag::set(&mut context.pool(), post.id, &tags).await?;
+
     let post_ = post.clone();
     let context_ = context.reset_request_count();
 
@@ -286,6 +377,68 @@ impl Object for ApubPost {
   }
 }
 
+/// Post tags belong to the community, so tags from other domains are ignored. Tags of remote
+/// communities are stored when they are first seen. For local communities only existing tags can be
+/// used, and mod-only tags only by moderators.
+async fn receive_post_tags(
+  page: &Page,
+  community: &ApubCommunity,
+  post: &Post,
+  creator: &ApubPerson,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Vec<TagId>> {
+  let mut tags = vec![];
+  for page_tag in page.community_post_tags() {
+    if page_tag.id.domain() != community.actor_id.domain() {
+      continue;
+    }
+    if community.local {
+      let tag = Tag::read_from_apub_id(&mut context.pool(), &page_tag.id.clone().into()).await?;
+      if let Some(tag) = tag.filter(|t| t.community_id == community.id) {
+        tags.push(tag);
+      }
+    } else if is_valid_tag_name(&page_tag.name).is_ok() {
+      let color = page_tag
+        .color
+        .clone()
+        .filter(|c| is_valid_tag_color(c).is_ok());
+      let form = TagInsertForm::builder()
+        .community_id(community.id)
+        .name(page_tag.name.trim().to_string())
+        .color(color)
+        .ap_id(Some(page_tag.id.clone().into()))
+        .local(Some(false))
+        .updated(Some(naive_now()))
+        .build();
+      let tag = Tag::upsert(&mut context.pool(), &form).await?;
+      // The id may belong to a tag of another community on the same instance
+      if tag.community_id == community.id {
+        tags.push(tag);
+      }
+    }
+  }
+
+  if !community.local {
+    return Ok(tags.into_iter().map(|t| t.id).collect());
+  }
+  let is_mod =
+    CommunityModeratorView::is_community_moderator(&mut context.pool(), community.id, creator.id)
+      .await?;
+  let tag_ids = tags
+    .into_iter()
+    .filter(|t| is_mod || !t.mod_only)
+    .map(|t| t.id)
+    .collect::<Vec<_>>();
+  check_post_tags(
+    community.id,
+    Some(post.id),
+    &tag_ids,
+    is_mod,
+    &mut context.pool(),
+  )
+  .await
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -320,6 +473,11 @@ mod tests {
     assert!(!post.featured_community);
     assert_eq!(context.request_count(), 0);
 
+    let tags = Tag::list_for_post(&mut context.pool(), post.id).await?;
+    assert_eq!(1, tags.len());
+    assert_eq!(Some("News"), tags.first().map(|t| t.name.as_str()));
+    assert_eq!(Some(community.id), tags.first().map(|t| t.community_id));
+
     Post::delete(&mut context.pool(), post.id).await?;
     Person::delete(&mut context.pool(), person.id).await?;
     Community::delete(&mut context.pool(), community.id).await?;
@@ -341,6 +499,38 @@ mod tests {
 
     assert_eq!(post.name, "Variable never resetting at refresh");
 
+    Post::delete(&mut context.pool(), post.id).await?;
+    Person::delete(&mut context.pool(), person.id).await?;
+    Community::delete(&mut context.pool(), community.id).await?;
+    Ok(())
+  }
+  #[tokio::test]
+  #[serial]
+  async fn test_convert_mastodon_question() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let community = parse_lemmy_community(&context).await?;
+
+    let json = file_to_json_object("assets/mastodon/objects/person.json")?;
+    let person = ApubPerson::from_json(json, &context).await?;
+
+    let json = file_to_json_object("assets/mastodon/objects/question.json")?;
+    let post = ApubPost::from_json(json, &context).await?;
+    assert_eq!(post.name, "Which generator should I write next?");
+
+    let poll = Poll::read(&mut context.pool(), post.id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPoll)?;
+    assert!(poll.multiple_choice);
+    assert_eq!(vec!["Names", "Places", "Monsters"], poll.options);
+    assert_eq!(vec![2, 1, 1], poll.vote_counts);
+    assert_eq!(3, poll.voters_count);
+    assert!(poll.end_time.is_some());
+
+    // Polls are sent as Question
+    let page = post.clone().into_json(&context).await?;
+    assert_eq!(PageType::Question, page.kind);
+    assert_eq!(Some(3), page.any_of.map(|o| o.len()));
+
     Post::delete(&mut context.pool(), post.id).await?;
     Person::delete(&mut context.pool(), person.id).await?;
     Community::delete(&mut context.pool(), community.id).await?;
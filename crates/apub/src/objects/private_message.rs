@@ -1,7 +1,7 @@
 use super::verify_is_remote_object;
 use crate::{
   check_apub_id_valid_with_strictness,
-  objects::read_from_string_or_source,
+  objects::{person::ApubPerson, read_from_string_or_source},
   protocol::{
     objects::chat_message::{ChatMessage, ChatMessageType},
     Source,
@@ -19,12 +19,16 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   source::{
+    conversation::{Conversation, ConversationInsertForm, ConversationParticipant},
     local_site::LocalSite,
+    notification::{Notification, NotificationInsertForm},
     person::Person,
+    person_block::PersonBlock,
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
   traits::Crud,
   utils::naive_now,
+  NotificationType,
 };
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorType, LemmyResult},
@@ -49,6 +53,78 @@ impl From<PrivateMessage> for ApubPrivateMessage {
   }
 }
 
+impl ApubPrivateMessage {
+  /// The recipient of a direct message, or all other active participants of a group
+  /// conversation.
+  pub(crate) async fn recipients(
+    &self,
+    context: &Data<LemmyContext>,
+  ) -> LemmyResult<Vec<ApubPerson>> {
+    if let Some(conversation_id) = self.conversation_id {
+      let participants =
+        ConversationParticipant::list_active(&mut context.pool(), conversation_id).await?;
+      Ok(
+        participants
+          .into_iter()
+          .map(|(person, _)| person)
+          .filter(|person| person.id != self.creator_id)
+          .map(Into::into)
+          .collect(),
+      )
+    } else {
+      let recipient = Person::read(&mut context.pool(), self.recipient_id)
+        .await?
+        .ok_or(LemmyErrorType::CouldntFindPerson)?;
+      Ok(vec![recipient.into()])
+    }
+  }
+}
+
+/// Finds the group conversation of a received message, or creates it for the first message.
+///
+/// Only active participants can write to an existing conversation. New participants are only
+/// taken over for remote conversations, local ones are managed by this instance. Like in
+/// `create_conversation`, local persons who blocked the creator can't be added.
+async fn read_or_create_conversation(
+  conversation_ap_id: &Url,
+  creator: &ApubPerson,
+  recipients: &[ApubPerson],
+  context: &Data<LemmyContext>,
+) -> LemmyResult<Conversation> {
+  let participant_ids = recipients.iter().map(|r| r.id).collect::<Vec<_>>();
+  let existing =
+    Conversation::read_from_apub_id(&mut context.pool(), conversation_ap_id.clone()).await?;
+  if let Some(conversation) = existing {
+    let participant =
+      ConversationParticipant::read(&mut context.pool(), conversation.id, creator.id).await?;
+    if !participant.is_some_and(|p| p.left_at.is_none()) {
+      Err(LemmyErrorType::CouldntFindConversation)?
+    }
+    if !conversation.local {
+      for recipient in recipients.iter().filter(|r| r.local) {
+        let existing =
+          ConversationParticipant::read(&mut context.pool(), conversation.id, recipient.id).await?;
+        if existing.is_none() {
+          check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+        }
+      }
+      ConversationParticipant::add(&mut context.pool(), conversation.id, &participant_ids).await?;
+    }
+    Ok(conversation)
+  } else {
+    verify_domains_match(conversation_ap_id, creator.actor_id.inner())?;
+    for recipient in recipients.iter().filter(|r| r.local) {
+      check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+    }
+    let form = ConversationInsertForm::builder()
+      .creator_id(creator.id)
+      .ap_id(Some(conversation_ap_id.clone().into()))
+      .local(Some(false))
+      .build();
+    Ok(Conversation::create(&mut context.pool(), &form, &participant_ids).await?)
+  }
+}
+
 #[async_trait::async_trait]
 impl Object for ApubPrivateMessage {
   type DataType = LemmyContext;
@@ -83,21 +159,34 @@ impl Object for ApubPrivateMessage {
       .await?
       .ok_or(LemmyErrorType::CouldntFindPerson)?;
 
-    let recipient_id = self.recipient_id;
-    let recipient = Person::read(&mut context.pool(), recipient_id)
+    let to = self
+      .recipients(context)
       .await?
-      .ok_or(LemmyErrorType::CouldntFindPerson)?;
+      .into_iter()
+      .map(|recipient| recipient.actor_id.clone().into())
+      .collect();
+    let conversation_ap_id = match self.conversation_id {
+      Some(conversation_id) => Some(
+        Conversation::read(&mut context.pool(), conversation_id)
+          .await?
+          .ok_or(LemmyErrorType::CouldntFindConversation)?
+          .ap_id
+          .into(),
+      ),
+      None => None,
+    };
 
     let note = ChatMessage {
       r#type: ChatMessageType::ChatMessage,
       id: self.ap_id.clone().into(),
       attributed_to: creator.actor_id.into(),
-      to: [recipient.actor_id.into()],
+      to,
       content: markdown_to_html(&self.content),
       media_type: Some(MediaTypeHtml::Html),
       source: Some(Source::new(self.content.clone())),
       published: Some(self.published),
       updated: self.updated,
+      context: conversation_ap_id,
     };
     Ok(note)
   }
@@ -129,8 +218,22 @@ impl Object for ApubPrivateMessage {
     context: &Data<Self::DataType>,
   ) -> LemmyResult<ApubPrivateMessage> {
     let creator = note.attributed_to.dereference(context).await?;
-    let recipient = note.to[0].dereference(context).await?;
-    check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+    let mut recipients = Vec::with_capacity(note.to.len());
+    for to in &note.to {
+      recipients.push(to.dereference(context).await?);
+    }
+    let conversation = match &note.context {
+      Some(conversation_ap_id) => {
+        Some(read_or_create_conversation(conversation_ap_id, &creator, &recipients, context).await?)
+      }
+      None => {
+        let recipient = recipients
+          .first()
+          .ok_or(LemmyErrorType::CouldntFindPerson)?;
+        check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+        None
+      }
+    };
 
     let local_site = LocalSite::read(&mut context.pool()).await.ok();
     let slur_regex = &local_site_opt_to_slur_regex(&local_site);
@@ -138,9 +241,15 @@ impl Object for ApubPrivateMessage {
     let content = read_from_string_or_source(&note.content, &None, &note.source);
     let content = process_markdown(&content, slur_regex, &url_blocklist, context).await?;
 
+    // Messages in a group conversation are addressed to their creator, like in
+    // `send_conversation_message`
+    let recipient_id = match (&conversation, recipients.first()) {
+      (None, Some(recipient)) => recipient.id,
+      _ => creator.id,
+    };
     let form = PrivateMessageInsertForm {
       creator_id: creator.id,
-      recipient_id: recipient.id,
+      recipient_id,
       content,
       published: note.published.map(Into::into),
       updated: note.updated.map(Into::into),
@@ -148,9 +257,46 @@ impl Object for ApubPrivateMessage {
       read: None,
       ap_id: Some(note.id.into()),
       local: Some(false),
+      conversation_id: conversation.as_ref().map(|c| c.id),
     };
     let timestamp = note.updated.or(note.published).unwrap_or_else(naive_now);
     let pm = PrivateMessage::insert_apub(&mut context.pool(), timestamp, &form).await?;
+
+    let notify_ids = match &conversation {
+      Some(conversation) => {
+        let mut ids = vec![];
+        let participants =
+          ConversationParticipant::list_active(&mut context.pool(), conversation.id).await?;
+        for (person, participant) in participants {
+          if person.local
+            && person.id != creator.id
+            && !participant.muted
+            && !PersonBlock::read(&mut context.pool(), person.id, creator.id).await?
+          {
+            ids.push(person.id);
+          }
+        }
+        ids
+      }
+      None => recipients
+        .first()
+        .filter(|r| r.local)
+        .map(|r| r.id)
+        .into_iter()
+        .collect(),
+    };
+    for recipient_id in notify_ids {
+      let notification_form = NotificationInsertForm::builder()
+        .recipient_id(recipient_id)
+        .kind(NotificationType::PrivateMessage)
+        .creator_id(Some(creator.id))
+        .private_message_id(Some(pm.id))
+        .build();
+      // Edits of the message are ignored by the unique index
+      Notification::create(&mut context.pool(), &notification_form)
+        .await
+        .ok();
+    }
     Ok(pm.into())
   }
 }
@@ -1,11 +1,17 @@
 pub(crate) mod accept;
 pub mod follow;
+pub(crate) mod reject;
 pub mod undo_follow;
 
 #[cfg(test)]
 mod tests {
   use crate::protocol::{
-    activities::following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
+    activities::following::{
+      accept::AcceptFollow,
+      follow::Follow,
+      reject::RejectFollow,
+      undo_follow::UndoFollow,
+    },
     tests::test_parse_lemmy_item,
   };
   use lemmy_utils::error::LemmyResult;
@@ -14,6 +20,7 @@ mod tests {
   fn test_parse_lemmy_accept_follow() -> LemmyResult<()> {
     test_parse_lemmy_item::<Follow>("assets/lemmy/activities/following/follow.json")?;
     test_parse_lemmy_item::<AcceptFollow>("assets/lemmy/activities/following/accept.json")?;
+    test_parse_lemmy_item::<RejectFollow>("assets/lemmy/activities/following/reject.json")?;
     test_parse_lemmy_item::<UndoFollow>("assets/lemmy/activities/following/undo_follow.json")?;
     Ok(())
   }
@@ -0,0 +1,22 @@
+use crate::objects::person::ApubPerson;
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::activity::LeaveType,
+  protocol::helpers::deserialize_one_or_many,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Announces that a person left a group conversation. The object is the conversation, which is
+/// federated as the `context` of its messages.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveConversation {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one_or_many")]
+  pub(crate) to: Vec<Url>,
+  pub(crate) object: Url,
+  #[serde(rename = "type")]
+  pub(crate) kind: LeaveType,
+  pub(crate) id: Url,
+}
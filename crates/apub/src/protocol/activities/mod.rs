@@ -6,6 +6,8 @@ pub mod community;
 pub mod create_or_update;
 pub mod deletion;
 pub mod following;
+pub mod leave_conversation;
+pub mod move_user;
 pub mod voting;
 
 #[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
@@ -22,12 +24,26 @@ mod tests {
       create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
       deletion::delete::Delete,
       following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
-      voting::{undo_vote::UndoVote, vote::Vote},
+      leave_conversation::LeaveConversation,
+      move_user::MoveUser,
+      voting::{poll_vote::CreatePollVote, undo_vote::UndoVote, vote::Vote},
     },
-    tests::test_json,
+    tests::{test_json, test_parse_lemmy_item},
   };
   use lemmy_utils::error::LemmyResult;
 
+  #[test]
+  fn test_parse_lemmy_move_user() -> LemmyResult<()> {
+    test_parse_lemmy_item::<MoveUser>("assets/lemmy/activities/move_user.json")?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_parse_lemmy_leave_conversation() -> LemmyResult<()> {
+    test_parse_lemmy_item::<LeaveConversation>("assets/lemmy/activities/leave_conversation.json")?;
+    Ok(())
+  }
+
   #[test]
   fn test_parse_smithereen_activities() -> LemmyResult<()> {
     test_json::<CreateOrUpdateNote>("assets/smithereen/activities/create_note.json")?;
@@ -51,6 +67,7 @@ mod tests {
     test_json::<Vote>("assets/mastodon/activities/like_page.json")?;
     test_json::<UndoVote>("assets/mastodon/activities/undo_like_page.json")?;
     test_json::<Report>("assets/mastodon/activities/flag.json")?;
+    test_json::<CreatePollVote>("assets/mastodon/activities/poll_vote.json")?;
     Ok(())
   }
 
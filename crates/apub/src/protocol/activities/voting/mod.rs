@@ -1,10 +1,15 @@
+pub mod poll_vote;
 pub mod undo_vote;
 pub mod vote;
 
 #[cfg(test)]
 mod tests {
   use crate::protocol::{
-    activities::voting::{undo_vote::UndoVote, vote::Vote},
+    activities::voting::{
+      poll_vote::{CreatePollVote, UndoPollVote},
+      undo_vote::UndoVote,
+      vote::Vote,
+    },
     tests::test_parse_lemmy_item,
   };
   use lemmy_utils::error::LemmyResult;
@@ -16,6 +21,9 @@ mod tests {
 
     test_parse_lemmy_item::<UndoVote>("assets/lemmy/activities/voting/undo_like_note.json")?;
     test_parse_lemmy_item::<UndoVote>("assets/lemmy/activities/voting/undo_dislike_page.json")?;
+
+    test_parse_lemmy_item::<CreatePollVote>("assets/lemmy/activities/voting/poll_vote.json")?;
+    test_parse_lemmy_item::<UndoPollVote>("assets/lemmy/activities/voting/undo_poll_vote.json")?;
     Ok(())
   }
 }
@@ -0,0 +1,52 @@
+use crate::objects::{person::ApubPerson, post::ApubPost};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::{
+    activity::{CreateType, UndoType},
+    object::NoteType,
+  },
+  protocol::helpers::deserialize_one,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A vote in a poll, in the format used by Mastodon. It is sent directly to the creator of the
+/// poll, with one activity for each chosen option.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePollVote {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: PollVoteNote,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateType,
+}
+
+/// Unlike a comment, this has a name (the chosen option) and no content.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollVoteNote {
+  pub(crate) r#type: NoteType,
+  pub(crate) id: Url,
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) name: String,
+  pub(crate) in_reply_to: ObjectId<ApubPost>,
+}
+
+/// Retracts one choice of a vote. Mastodon doesn't allow changing votes, so this is only
+/// understood by Lemmy.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoPollVote {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: CreatePollVote,
+  #[serde(rename = "type")]
+  pub(crate) kind: UndoType,
+}
@@ -0,0 +1,15 @@
+use crate::activity_lists::AnnouncableActivities;
+use activitypub_federation::kinds::collection::OrderedCollectionType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Outbox with all posts and comments of a user, which is included in their data export. The
+/// outbox served over federation is always empty.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonOutbox {
+  pub(crate) r#type: OrderedCollectionType,
+  pub(crate) id: Url,
+  pub(crate) total_items: i32,
+  pub(crate) ordered_items: Vec<AnnouncableActivities>,
+}
@@ -25,6 +25,7 @@ use activitypub_federation::{
 };
 use chrono::{DateTime, Utc};
 use lemmy_api_common::{context::LemmyContext, utils::local_site_opt_to_slur_regex};
+use lemmy_db_schema::CommunityVisibility;
 use lemmy_utils::{
   error::LemmyResult,
   utils::slurs::{check_slurs, check_slurs_opt},
@@ -63,11 +64,16 @@ pub struct Group {
   pub(crate) attributed_to: Option<AttributedTo>,
   // lemmy extension
   pub(crate) posting_restricted_to_mods: Option<bool>,
+  /// Set for private communities, where follows need to be approved by a mod
+  pub(crate) manually_approves_followers: Option<bool>,
   pub(crate) outbox: CollectionId<ApubCommunityOutbox>,
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) featured: Option<CollectionId<ApubCommunityFeatured>>,
   #[serde(default)]
   pub(crate) language: Vec<LanguageTag>,
+  // lemmy extension
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) rules: Vec<CommunityRule>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
 }
@@ -88,6 +94,31 @@ impl Group {
     check_slurs_opt(&self.name, slur_regex)?;
     let description = read_from_string_or_source_opt(&self.summary, &None, &self.source);
     check_slurs_opt(&description, slur_regex)?;
+    for rule in &self.rules {
+      if let Some(rule_id) = &rule.id {
+        verify_domains_match(rule_id, self.id.inner())?;
+      }
+      check_slurs(&rule.name, slur_regex)?;
+    }
     Ok(())
   }
+
+  /// Local only communities don't federate, so a remote community is either public or private.
+  pub(crate) fn visibility(&self) -> CommunityVisibility {
+    if self.manually_approves_followers.unwrap_or_default() {
+      CommunityVisibility::Private
+    } else {
+      CommunityVisibility::Public
+    }
+  }
+}
+
+/// A rule of the community. Rules are listed in order, and the content is markdown.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct CommunityRule {
+  /// Keeps a rule recognizable when its text is edited. Older versions of Lemmy don't send it.
+  pub(crate) id: Option<Url>,
+  pub(crate) name: String,
+  pub(crate) content: Option<String>,
 }
@@ -173,6 +173,7 @@ mod tests {
     test_parse_lemmy_item::<Group>("assets/lemmy/objects/group.json")?;
     test_parse_lemmy_item::<Person>("assets/lemmy/objects/person.json")?;
     test_parse_lemmy_item::<Page>("assets/lemmy/objects/page.json")?;
+    test_parse_lemmy_item::<Page>("assets/lemmy/objects/question.json")?;
     test_parse_lemmy_item::<Note>("assets/lemmy/objects/note.json")?;
     test_parse_lemmy_item::<ChatMessage>("assets/lemmy/objects/chat_message.json")?;
     test_parse_lemmy_item::<Tombstone>("assets/lemmy/objects/tombstone.json")?;
@@ -200,6 +201,7 @@ mod tests {
     test_json::<Note>("assets/mastodon/objects/note_1.json")?;
     test_json::<Note>("assets/mastodon/objects/note_2.json")?;
     test_json::<Page>("assets/mastodon/objects/page.json")?;
+    test_json::<Page>("assets/mastodon/objects/question.json")?;
     Ok(())
   }
 
@@ -13,8 +13,9 @@ use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
   kinds::{
+    collection::CollectionType,
     link::LinkType,
-    object::{DocumentType, ImageType},
+    object::{DocumentType, ImageType, NoteType},
   },
   protocol::{
     helpers::{deserialize_one_or_many, deserialize_skip_error},
@@ -25,11 +26,13 @@ use activitypub_federation::{
 use chrono::{DateTime, Utc};
 use itertools::Itertools;
 use lemmy_api_common::{context::LemmyContext, utils::proxy_image_link};
+use lemmy_db_schema::source::tag::Tag;
 use lemmy_utils::{
   error::{LemmyError, LemmyResult},
   LemmyErrorType,
 };
 use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 use url::Url;
 
@@ -40,6 +43,8 @@ pub enum PageType {
   Note,
   Video,
   Event,
+  /// Used for polls
+  Question,
 }
 
 #[skip_serializing_none]
@@ -74,7 +79,32 @@ pub struct Page {
   pub(crate) language: Option<LanguageTag>,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
   #[serde(deserialize_with = "deserialize_skip_error", default)]
-  pub(crate) tag: Vec<Hashtag>,
+  pub(crate) tag: Vec<PageTag>,
+  // Only used for polls. Single choice polls use `one_of`, multiple choice polls use `any_of`.
+  pub(crate) one_of: Option<Vec<QuestionOption>>,
+  pub(crate) any_of: Option<Vec<QuestionOption>>,
+  pub(crate) end_time: Option<DateTime<Utc>>,
+  /// Mastodon sets this once the poll has ended
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) closed: Option<DateTime<Utc>>,
+  pub(crate) voters_count: Option<i32>,
+}
+
+/// A poll option, in the format used by Mastodon.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionOption {
+  pub(crate) r#type: NoteType,
+  pub(crate) name: String,
+  pub(crate) replies: Option<QuestionOptionReplies>,
+}
+
+/// Only contains the number of votes for the option.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionOptionReplies {
+  pub(crate) r#type: CollectionType,
+  pub(crate) total_items: i32,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -156,6 +186,16 @@ impl Attachment {
   }
 }
 
+/// Lemmy sends the community as hashtag, and the post tags of the community. Other entries such as
+/// mentions are ignored.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PageTag {
+  Hashtag(Hashtag),
+  CommunityPostTag(CommunityPostTag),
+  Value(Value),
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Hashtag {
   pub(crate) href: Url,
@@ -169,6 +209,34 @@ pub enum HashtagType {
   Hashtag,
 }
 
+/// A tag which is defined by the moderators of the community.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommunityPostTag {
+  #[serde(rename = "type")]
+  pub(crate) kind: CommunityPostTagType,
+  pub(crate) id: Url,
+  pub(crate) name: String,
+  /// A hex color like `#ff0000`.
+  pub(crate) color: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum CommunityPostTagType {
+  CommunityPostTag,
+}
+
+impl From<Tag> for CommunityPostTag {
+  fn from(tag: Tag) -> Self {
+    CommunityPostTag {
+      kind: CommunityPostTagType::CommunityPostTag,
+      id: tag.ap_id.into(),
+      name: tag.name,
+      color: tag.color,
+    }
+  }
+}
+
 impl Page {
   pub(crate) fn creator(&self) -> LemmyResult<ObjectId<ApubPerson>> {
     match &self.attributed_to {
@@ -180,6 +248,36 @@ impl Page {
         .ok_or_else(|| LemmyErrorType::PageDoesNotSpecifyCreator.into()),
     }
   }
+
+  /// The post tags of the community which are attached to this page.
+  pub(crate) fn community_post_tags(&self) -> impl Iterator<Item = &CommunityPostTag> {
+    self.tag.iter().filter_map(|t| match t {
+      PageTag::CommunityPostTag(t) => Some(t),
+      _ => None,
+    })
+  }
+
+  /// Returns the poll options if this is a Question, and whether multiple choices are allowed.
+  pub(crate) fn poll_options(&self) -> Option<(&Vec<QuestionOption>, bool)> {
+    match (&self.one_of, &self.any_of) {
+      (Some(one_of), _) => Some((one_of, false)),
+      (None, Some(any_of)) => Some((any_of, true)),
+      (None, None) => None,
+    }
+  }
+}
+
+impl QuestionOption {
+  pub(crate) fn new(name: String, votes: i32) -> QuestionOption {
+    QuestionOption {
+      r#type: Default::default(),
+      name,
+      replies: Some(QuestionOptionReplies {
+        r#type: Default::default(),
+        total_items: votes,
+      }),
+    }
+  }
 }
 
 impl Attachment {
@@ -46,4 +46,9 @@ pub struct Person {
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
+  /// previous accounts of this user, needed to accept an incoming `Move`
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) also_known_as: Vec<Url>,
+  /// new account of this user, if it was migrated away
+  pub(crate) moved_to: Option<Url>,
 }
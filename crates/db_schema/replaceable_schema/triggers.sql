@@ -382,6 +382,67 @@ END;
 
 $$);
 
+-- Count followers of persons. For remote persons only local followers are known. Pending follows
+-- are counted once they are approved.
+CALL r.create_triggers ('person_follower', $$
+BEGIN
+    UPDATE
+        person_aggregates AS a
+    SET
+        followers = a.followers + diff.followers
+    FROM (
+        SELECT
+            (person_follower).person_id, coalesce(sum(count_diff) FILTER (WHERE NOT (person_follower).pending), 0) AS followers
+        FROM select_old_and_new_rows AS old_and_new_rows
+    GROUP BY (person_follower).person_id) AS diff
+WHERE
+    a.person_id = diff.person_id
+        AND diff.followers != 0;
+
+RETURN NULL;
+
+END;
+
+$$);
+
+-- Count the votes of local polls. The counts of remote polls are copied from the federated object
+-- instead, so votes of local users on remote polls don't change them.
+CALL r.create_triggers ('poll_vote', $$
+BEGIN
+    UPDATE
+        poll AS a
+    SET
+        vote_counts = ARRAY (
+            SELECT
+                count(poll_vote.choice)::int
+            FROM
+                generate_series(0, cardinality(a.options) - 1) AS poll_option (choice)
+            LEFT JOIN poll_vote ON poll_vote.post_id = a.post_id
+                AND poll_vote.choice = poll_option.choice
+        GROUP BY
+            poll_option.choice
+        ORDER BY
+            poll_option.choice), voters_count = (
+            SELECT
+                count(DISTINCT poll_vote.person_id)
+            FROM
+                poll_vote
+            WHERE
+                poll_vote.post_id = a.post_id)
+    FROM
+        post
+    WHERE
+        post.id = a.post_id
+        AND post.local
+        AND a.post_id IN (
+            SELECT
+                (poll_vote).post_id
+            FROM
+                select_old_and_new_rows AS old_and_new_rows);
+    RETURN NULL;
+END;
+$$);
+
 -- These triggers create and update rows in each aggregates table to match its associated table's rows.
 -- Deleting rows and updating IDs are already handled by `CASCADE` in foreign key constraints.
 CREATE FUNCTION r.comment_aggregates_from_comment ()
@@ -480,13 +541,19 @@ BEGIN
         post_aggregates
     SET
         featured_community = new_post.featured_community,
-        featured_local = new_post.featured_local
+        featured_local = new_post.featured_local,
+        -- Scheduled posts get a new published time when they are published
+        published = new_post.published,
+        newest_comment_time = greatest (post_aggregates.newest_comment_time, new_post.published),
+        newest_comment_time_necro = greatest (post_aggregates.newest_comment_time_necro, new_post.published)
     FROM
         new_post
         INNER JOIN old_post ON old_post.id = new_post.id
             AND (old_post.featured_community,
-                old_post.featured_local) != (new_post.featured_community,
-                new_post.featured_local)
+                old_post.featured_local,
+                old_post.published) != (new_post.featured_community,
+                new_post.featured_local,
+                new_post.published)
     WHERE
         post_aggregates.post_id = new_post.id;
     RETURN NULL;
@@ -613,3 +680,271 @@ CREATE TRIGGER change_values
     FOR EACH ROW
     EXECUTE FUNCTION r.private_message_change_values ();
 
+CREATE FUNCTION r.conversation_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/conversation/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER change_values
+    BEFORE INSERT ON conversation
+    FOR EACH ROW
+    EXECUTE FUNCTION r.conversation_change_values ();
+
+CREATE FUNCTION r.tag_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id
+    IF NEW.local THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/tag/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER change_values
+    BEFORE INSERT ON tag
+    FOR EACH ROW
+    EXECUTE FUNCTION r.tag_change_values ();
+
+
+-- Send new notifications and posts to all Lemmy processes which are listening, so they can push
+-- them to connected clients. The payload only contains ids because it's limited to 8000 bytes.
+CREATE FUNCTION r.notification_push ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    PERFORM
+        pg_notify('lemmy_notification', json_build_object('notification_id', NEW.id, 'recipient_id', NEW.recipient_id)::text);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER push
+    AFTER INSERT ON notification
+    FOR EACH ROW
+    EXECUTE FUNCTION r.notification_push ();
+
+CREATE FUNCTION r.post_push ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    PERFORM
+        pg_notify('lemmy_post', json_build_object('post_id', NEW.id, 'community_id', NEW.community_id)::text);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER push
+    AFTER INSERT ON post
+    FOR EACH ROW
+    WHEN (NEW.scheduled_publish_time IS NULL)
+    EXECUTE FUNCTION r.post_push ();
+
+-- Scheduled posts are pushed once they are published
+CREATE TRIGGER push_scheduled
+    AFTER UPDATE OF scheduled_publish_time ON post
+    FOR EACH ROW
+    WHEN (OLD.scheduled_publish_time IS NOT NULL AND NEW.scheduled_publish_time IS NULL)
+    EXECUTE FUNCTION r.post_push ();
+
+-- Keep the previous version of edited posts and comments. Edits always change `updated`, unlike
+-- other updates of the content such as fetching link metadata. Permanent deletion is not an edit.
+CREATE FUNCTION r.post_revision_from_post ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO post_revision (post_id, name, url, body, published)
+        VALUES (OLD.id, OLD.name, OLD.url, OLD.body, coalesce(OLD.updated, OLD.published));
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER revision
+    AFTER UPDATE OF name, url, body ON post
+    FOR EACH ROW
+    WHEN ((OLD.name, OLD.url, OLD.body) IS DISTINCT FROM (NEW.name, NEW.url, NEW.body) AND OLD.updated IS DISTINCT FROM NEW.updated AND NOT NEW.deleted)
+    EXECUTE FUNCTION r.post_revision_from_post ();
+
+CREATE FUNCTION r.comment_revision_from_comment ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO comment_revision (comment_id, content, published)
+        VALUES (OLD.id, OLD.content, coalesce(OLD.updated, OLD.published));
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER revision
+    AFTER UPDATE OF content ON comment
+    FOR EACH ROW
+    WHEN (OLD.content IS DISTINCT FROM NEW.content AND OLD.updated IS DISTINCT FROM NEW.updated AND NOT NEW.deleted)
+    EXECUTE FUNCTION r.comment_revision_from_comment ();
+
+-- Record the reasons of bans and removals as moderator notes about the affected person
+CREATE FUNCTION r.mod_note_from_mod_ban ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO mod_note (person_id, creator_id, content)
+        VALUES (NEW.other_person_id, NEW.mod_person_id, 'Banned: ' || NEW.reason);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER mod_note
+    AFTER INSERT ON mod_ban
+    FOR EACH ROW
+    WHEN (NEW.banned AND NEW.reason IS NOT NULL)
+    EXECUTE FUNCTION r.mod_note_from_mod_ban ();
+
+CREATE FUNCTION r.mod_note_from_mod_ban_from_community ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO mod_note (person_id, creator_id, community_id, content)
+        VALUES (NEW.other_person_id, NEW.mod_person_id, NEW.community_id, 'Banned from community: ' || NEW.reason);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER mod_note
+    AFTER INSERT ON mod_ban_from_community
+    FOR EACH ROW
+    WHEN (NEW.banned AND NEW.reason IS NOT NULL)
+    EXECUTE FUNCTION r.mod_note_from_mod_ban_from_community ();
+
+CREATE FUNCTION r.mod_note_from_mod_remove_post ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO mod_note (person_id, creator_id, community_id, content)
+    SELECT
+        p.creator_id,
+        NEW.mod_person_id,
+        p.community_id,
+        'Post removed' || coalesce(' for breaking rule "' || rl.title || '"', '') || coalesce(': ' || NEW.reason, '')
+    FROM
+        post AS p
+        LEFT JOIN rule AS rl ON rl.id = NEW.rule_id
+    WHERE
+        p.id = NEW.post_id;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER mod_note
+    AFTER INSERT ON mod_remove_post
+    FOR EACH ROW
+    WHEN (NEW.removed AND (NEW.reason IS NOT NULL OR NEW.rule_id IS NOT NULL))
+    EXECUTE FUNCTION r.mod_note_from_mod_remove_post ();
+
+CREATE FUNCTION r.mod_note_from_mod_remove_comment ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO mod_note (person_id, creator_id, community_id, content)
+    SELECT
+        c.creator_id,
+        NEW.mod_person_id,
+        p.community_id,
+        'Comment removed' || coalesce(' for breaking rule "' || rl.title || '"', '') || coalesce(': ' || NEW.reason, '')
+    FROM
+        comment AS c
+        INNER JOIN post AS p ON c.post_id = p.id
+        LEFT JOIN rule AS rl ON rl.id = NEW.rule_id
+    WHERE
+        c.id = NEW.comment_id;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER mod_note
+    AFTER INSERT ON mod_remove_comment
+    FOR EACH ROW
+    WHEN (NEW.removed AND (NEW.reason IS NOT NULL OR NEW.rule_id IS NOT NULL))
+    EXECUTE FUNCTION r.mod_note_from_mod_remove_comment ();
+
+-- Add new reports to the combined report queue
+CREATE FUNCTION r.report_combined_from_post_report ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO report_combined (published, community_id, post_report_id)
+    SELECT
+        new_report.published,
+        p.community_id,
+        new_report.id
+    FROM
+        new_report
+        INNER JOIN post AS p ON new_report.post_id = p.id;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER report_combined
+    AFTER INSERT ON post_report REFERENCING NEW TABLE AS new_report
+    FOR EACH STATEMENT
+    EXECUTE FUNCTION r.report_combined_from_post_report ();
+
+CREATE FUNCTION r.report_combined_from_comment_report ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO report_combined (published, community_id, comment_report_id)
+    SELECT
+        new_report.published,
+        p.community_id,
+        new_report.id
+    FROM
+        new_report
+        INNER JOIN comment AS c ON new_report.comment_id = c.id
+        INNER JOIN post AS p ON c.post_id = p.id;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER report_combined
+    AFTER INSERT ON comment_report REFERENCING NEW TABLE AS new_report
+    FOR EACH STATEMENT
+    EXECUTE FUNCTION r.report_combined_from_comment_report ();
+
+CREATE FUNCTION r.report_combined_from_private_message_report ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO report_combined (published, private_message_report_id)
+    SELECT
+        published,
+        id
+    FROM
+        new_report;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER report_combined
+    AFTER INSERT ON private_message_report REFERENCING NEW TABLE AS new_report
+    FOR EACH STATEMENT
+    EXECUTE FUNCTION r.report_combined_from_private_message_report ();
@@ -64,6 +64,16 @@ CREATE FUNCTION r.local_url (url_path text)
 current_setting('lemmy.protocol_and_hostname') || url_path
 );
 
+-- Used for keyword filters. Plain keywords are matched as case-insensitive substrings.
+CREATE FUNCTION r.keyword_filter_matches (content text, keyword text, is_regex boolean)
+    RETURNS boolean
+    LANGUAGE sql
+    IMMUTABLE PARALLEL SAFE RETURN CASE WHEN is_regex THEN
+        content ~* keyword
+    ELSE
+        strpos(lower(content), lower(keyword)) > 0
+    END;
+
 -- This function creates statement-level triggers for all operation types. It's designed this way
 -- because of these limitations:
 --   * A trigger that uses transition tables can only handle 1 operation type.
@@ -30,10 +30,10 @@ mod tests {
       comment::{Comment, CommentInsertForm, CommentLike, CommentLikeForm, CommentUpdateForm},
       community::{Community, CommunityInsertForm},
       instance::Instance,
-      person::{Person, PersonInsertForm},
+      person::{Person, PersonFollower, PersonFollowerForm, PersonInsertForm},
       post::{Post, PostInsertForm, PostLike, PostLikeForm},
     },
-    traits::{Crud, Likeable},
+    traits::{Crud, Followable, Likeable},
     utils::build_db_pool_for_tests,
   };
   use pretty_assertions::assert_eq;
@@ -229,4 +229,53 @@ mod tests {
 
     Instance::delete(pool, inserted_instance.id).await.unwrap();
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_followers() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "followed_user_agg");
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let follower = PersonInsertForm::test_form(inserted_instance.id, "follower_user_agg");
+    let inserted_follower = Person::create(pool, &follower).await.unwrap();
+
+    let follow_form = PersonFollowerForm {
+      person_id: inserted_person.id,
+      follower_id: inserted_follower.id,
+      pending: false,
+    };
+    PersonFollower::follow(pool, &follow_form).await.unwrap();
+
+    let after_follow = PersonAggregates::read(pool, inserted_person.id)
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(1, after_follow.followers);
+
+    // Following again only updates the existing row
+    PersonFollower::follow(pool, &follow_form).await.unwrap();
+    let after_refollow = PersonAggregates::read(pool, inserted_person.id)
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(1, after_refollow.followers);
+
+    PersonFollower::unfollow(pool, &follow_form).await.unwrap();
+    let after_unfollow = PersonAggregates::read(pool, inserted_person.id)
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(0, after_unfollow.followers);
+
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Person::delete(pool, inserted_follower.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
 }
@@ -90,6 +90,8 @@ pub struct PersonAggregates {
   pub comment_count: i64,
   #[serde(skip)]
   pub comment_score: i64,
+  /// Number of people following this person, as far as known to this instance.
+  pub followers: i64,
 }
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
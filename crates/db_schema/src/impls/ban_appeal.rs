@@ -0,0 +1,204 @@
+use crate::{
+  newtypes::{BanAppealId, CommunityId, PersonId},
+  schema::{ban_appeal, community_person_ban, mod_ban},
+  source::ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  ExpressionMethods,
+  OptionalExtension,
+  PgExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for BanAppeal {
+  type InsertForm = BanAppealInsertForm;
+  type UpdateForm = BanAppealUpdateForm;
+  type IdType = BanAppealId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_appeal::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    ban_appeal_id: Self::IdType,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(ban_appeal::table.find(ban_appeal_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl BanAppeal {
+  /// Resolves the appeal, unless it was already resolved by someone else in the meantime.
+  pub async fn resolve(
+    pool: &mut DbPool<'_>,
+    ban_appeal_id: BanAppealId,
+    form: &BanAppealUpdateForm,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      ban_appeal::table
+        .find(ban_appeal_id)
+        .filter(ban_appeal::resolver_id.is_null()),
+    )
+    .set(form)
+    .get_result::<Self>(conn)
+    .await
+    .optional()
+  }
+
+  /// When the current ban of the person in the community started, or `None` if they aren't
+  /// banned there. Without a community, the time of the latest site ban in the modlog is
+  /// returned, as the person table doesn't store it.
+  pub async fn ban_published(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> Result<Option<DateTime<Utc>>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    if let Some(community_id) = community_id {
+      community_person_ban::table
+        .find((person_id, community_id))
+        .select(community_person_ban::published)
+        .first(conn)
+        .await
+        .optional()
+    } else {
+      mod_ban::table
+        .filter(mod_ban::other_person_id.eq(person_id))
+        .filter(mod_ban::banned.eq(true))
+        .order_by(mod_ban::when_.desc())
+        .select(mod_ban::when_)
+        .first(conn)
+        .await
+        .optional()
+    }
+  }
+
+  /// Whether the person appealed their community or site ban after the given time. Each ban can
+  /// only be appealed once.
+  pub async fn exists_since(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+    since: DateTime<Utc>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      ban_appeal::table
+        .filter(ban_appeal::person_id.eq(person_id))
+        .filter(ban_appeal::community_id.is_not_distinct_from(community_id))
+        .filter(ban_appeal::published.ge(since)),
+    ))
+    .get_result(conn)
+    .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+      community::{Community, CommunityInsertForm, CommunityPersonBan, CommunityPersonBanForm},
+      instance::Instance,
+      moderator::{ModBan, ModBanForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::{Bannable, Crud},
+    utils::build_db_pool_for_tests,
+  };
+  use chrono::{Days, Utc};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_appeal() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_mod");
+    let moderator = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_user");
+    let person = Person::create(pool, &form).await?;
+    let form = CommunityInsertForm::builder()
+      .name("test_community_ban_appeal".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+
+    // Not banned yet
+    let published = BanAppeal::ban_published(pool, person.id, Some(community.id)).await?;
+    assert_eq!(None, published);
+    let published = BanAppeal::ban_published(pool, person.id, None).await?;
+    assert_eq!(None, published);
+
+    let form = CommunityPersonBanForm {
+      community_id: community.id,
+      person_id: person.id,
+      expires: None,
+    };
+    let ban = CommunityPersonBan::ban(pool, &form).await?;
+    let form = ModBanForm {
+      mod_person_id: moderator.id,
+      other_person_id: person.id,
+      reason: None,
+      banned: Some(true),
+      expires: None,
+    };
+    let mod_ban = ModBan::create(pool, &form).await?;
+    let published = BanAppeal::ban_published(pool, person.id, Some(community.id)).await?;
+    assert_eq!(Some(ban.published), published);
+    let published = BanAppeal::ban_published(pool, person.id, None).await?;
+    assert_eq!(Some(mod_ban.when_), published);
+
+    let form = BanAppealInsertForm {
+      person_id: person.id,
+      community_id: Some(community.id),
+      reason: "it was a joke".to_string(),
+    };
+    let appeal = BanAppeal::create(pool, &form).await?;
+    assert!(BanAppeal::exists_since(pool, person.id, Some(community.id), ban.published).await?);
+    // The site ban isn't appealed yet, and a later ban can be appealed again
+    assert!(!BanAppeal::exists_since(pool, person.id, None, mod_ban.when_).await?);
+    let later = Utc::now() + Days::new(1);
+    assert!(!BanAppeal::exists_since(pool, person.id, Some(community.id), later).await?);
+
+    let form = BanAppealUpdateForm {
+      resolver_id: Some(Some(moderator.id)),
+      accepted: Some(false),
+      deny_reason: Some(Some("no".to_string())),
+      updated: Some(Some(Utc::now())),
+    };
+    let resolved = BanAppeal::resolve(pool, appeal.id, &form).await?;
+    assert_eq!(Some(Some(moderator.id)), resolved.map(|a| a.resolver_id));
+    let resolved_again = BanAppeal::resolve(pool, appeal.id, &form).await?;
+    assert_eq!(None, resolved_again);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -10,9 +10,11 @@ use diesel::{
   result::Error,
   select,
   ExpressionMethods,
+  OptionalExtension,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
+use uuid::Uuid;
 
 impl CaptchaAnswer {
   pub async fn insert(pool: &mut DbPool<'_>, captcha: &CaptchaAnswerForm) -> Result<Self, Error> {
@@ -45,6 +47,16 @@ impl CaptchaAnswer {
 
     Ok(captcha_exists)
   }
+
+  /// Deletes the stored answer and returns it, so that each challenge can only be answered once.
+  pub async fn take(pool: &mut DbPool<'_>, uuid: Uuid) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    delete(captcha_answer.find(uuid))
+      .get_result::<Self>(conn)
+      .await
+      .optional()
+  }
 }
 
 #[cfg(test)]
@@ -122,4 +134,26 @@ mod tests {
     assert!(result_repeat.is_ok());
     assert!(!result_repeat.unwrap());
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_captcha_take() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted = CaptchaAnswer::insert(
+      pool,
+      &CaptchaAnswerForm {
+        answer: "XYZ".to_string(),
+      },
+    )
+    .await
+    .expect("should not fail to insert captcha");
+
+    let taken = CaptchaAnswer::take(pool, inserted.uuid).await.unwrap();
+    assert_eq!(Some(inserted.clone()), taken);
+
+    let taken_repeat = CaptchaAnswer::take(pool, inserted.uuid).await.unwrap();
+    assert!(taken_repeat.is_none());
+  }
 }
@@ -2,11 +2,12 @@ use crate::{
   newtypes::{CommentId, CommentReportId, PersonId},
   schema::comment_report::{
     comment_id,
-    dsl::{comment_report, resolved, resolver_id, updated},
+    dsl::{comment_report, resolved, resolver_id, status, updated},
   },
   source::comment_report::{CommentReport, CommentReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportStatus,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -50,6 +51,7 @@ impl Reportable for CommentReport {
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(true),
+        status.eq(ReportStatus::Resolved),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -66,6 +68,7 @@ impl Reportable for CommentReport {
     update(comment_report.filter(comment_id.eq(comment_id_)))
       .set((
         resolved.eq(true),
+        status.eq(ReportStatus::Resolved),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -87,6 +90,7 @@ impl Reportable for CommentReport {
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(false),
+        status.eq(ReportStatus::Open),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -0,0 +1,38 @@
+use crate::{
+  newtypes::{CommentId, PersonId},
+  schema::{comment, comment_revision},
+  source::comment_revision::CommentRevision,
+  utils::{get_conn, DbPool},
+};
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl CommentRevision {
+  /// Lists the previous versions of a comment, from oldest to newest.
+  pub async fn list_for_comment(
+    pool: &mut DbPool<'_>,
+    for_comment_id: CommentId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    comment_revision::table
+      .filter(comment_revision::comment_id.eq(for_comment_id))
+      .order_by(comment_revision::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Deletes the previous versions of all comments by a person, so that they are gone together
+  /// with the comments when an account is deleted.
+  pub async fn delete_for_creator(
+    pool: &mut DbPool<'_>,
+    for_creator_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let comments = comment::table
+      .filter(comment::creator_id.eq(for_creator_id))
+      .select(comment::id);
+    diesel::delete(comment_revision::table.filter(comment_revision::comment_id.eq_any(comments)))
+      .execute(conn)
+      .await
+  }
+}
@@ -48,7 +48,7 @@ use diesel::{
   QueryDsl,
   Queryable,
 };
-use diesel_async::RunQueryDsl;
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
 #[async_trait]
@@ -333,6 +333,93 @@ impl CommunityFollower {
     .get_result(conn)
     .await
   }
+
+  /// Check if the person is a follower of the community, and the follow is not pending approval.
+  pub async fn is_approved_follower(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      community_follower::table
+        .find((person_id, community_id))
+        .filter(community_follower::pending.eq(false)),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  /// The communities which the person follows, without pending follows.
+  pub async fn list_followed_community_ids(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<Vec<CommunityId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_follower::table
+      .filter(community_follower::person_id.eq(person_id))
+      .filter(community_follower::pending.eq(false))
+      .select(community_follower::community_id)
+      .load::<CommunityId>(conn)
+      .await
+  }
+
+  /// Approve a pending follow of a private community.
+  ///
+  /// The published time is reset so that the federation queue picks up the new follower in its
+  /// incremental fetch.
+  pub async fn approve(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_follower::table.find((person_id, community_id)))
+      .filter(community_follower::pending)
+      .set((
+        community_follower::pending.eq(false),
+        community_follower::published.eq(Utc::now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Moves all community follows of a migrated account over to its new account. Communities which
+  /// the new account already follows keep their existing follow, and the old rows are dropped.
+  pub async fn migrate_follows(
+    pool: &mut DbPool<'_>,
+    old_person_id: PersonId,
+    new_person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let already_followed = community_follower::table
+            .filter(community_follower::person_id.eq(new_person_id))
+            .select(community_follower::community_id)
+            .load::<CommunityId>(conn)
+            .await?;
+          let migrated = update(
+            community_follower::table
+              .filter(community_follower::person_id.eq(old_person_id))
+              .filter(community_follower::community_id.ne_all(already_followed)),
+          )
+          .set(community_follower::person_id.eq(new_person_id))
+          .execute(conn)
+          .await?;
+
+          diesel::delete(
+            community_follower::table.filter(community_follower::person_id.eq(old_person_id)),
+          )
+          .execute(conn)
+          .await?;
+          Ok(migrated)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
 }
 
 impl Queryable<sql_types::Nullable<sql_types::Bool>, Pg> for SubscribedType {
@@ -628,4 +715,56 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_migrate_follows() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let old_person = PersonInsertForm::test_form(inserted_instance.id, "old_account");
+    let inserted_old = Person::create(pool, &old_person).await?;
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "new_account");
+    let inserted_new = Person::create(pool, &new_person).await?;
+
+    let community_form = |name: &str| {
+      CommunityInsertForm::builder()
+        .name(name.into())
+        .title("nada".to_owned())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build()
+    };
+    let community_a = Community::create(pool, &community_form("migrate_a")).await?;
+    let community_b = Community::create(pool, &community_form("migrate_b")).await?;
+
+    // The old account follows both communities, the new one already follows b
+    for (community_id, person_id) in [
+      (community_a.id, inserted_old.id),
+      (community_b.id, inserted_old.id),
+      (community_b.id, inserted_new.id),
+    ] {
+      let form = CommunityFollowerForm {
+        community_id,
+        person_id,
+        pending: false,
+      };
+      CommunityFollower::follow(pool, &form).await?;
+    }
+
+    let migrated =
+      CommunityFollower::migrate_follows(pool, inserted_old.id, inserted_new.id).await?;
+    assert_eq!(1, migrated);
+
+    assert!(CommunityFollower::is_approved_follower(pool, community_a.id, inserted_new.id).await?);
+    assert!(CommunityFollower::is_approved_follower(pool, community_b.id, inserted_new.id).await?);
+    assert!(!CommunityFollower::is_approved_follower(pool, community_a.id, inserted_old.id).await?);
+    assert!(!CommunityFollower::is_approved_follower(pool, community_b.id, inserted_old.id).await?);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
 }
@@ -0,0 +1,231 @@
+use crate::{
+  newtypes::{ConversationId, DbUrl, PersonId},
+  schema::{conversation, conversation_participant, person},
+  source::{
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationParticipant,
+      ConversationParticipantForm,
+      ConversationParticipantUpdateForm,
+    },
+    person::Person,
+  },
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::{insert_into, update},
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+use url::Url;
+
+impl Conversation {
+  /// Creates a conversation, the creator is added as participant together with the given persons.
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &ConversationInsertForm,
+    participant_ids: &[PersonId],
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let conversation = insert_into(conversation::table)
+            .values(form)
+            .get_result::<Self>(conn)
+            .await?;
+          let forms = participant_ids
+            .iter()
+            .chain(std::iter::once(&form.creator_id))
+            .map(|person_id| ConversationParticipantForm {
+              conversation_id: conversation.id,
+              person_id: *person_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(conversation_participant::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(conversation)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conversation::table
+      .find(conversation_id)
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    conversation::table
+      .filter(conversation::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+  }
+}
+
+impl ConversationParticipant {
+  /// Adds new participants. Persons who already left the conversation are not added again.
+  pub async fn add(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_ids: &[PersonId],
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = person_ids
+      .iter()
+      .map(|person_id| ConversationParticipantForm {
+        conversation_id,
+        person_id: *person_id,
+      })
+      .collect::<Vec<_>>();
+    insert_into(conversation_participant::table)
+      .values(forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .find((conversation_id, person_id))
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+    form: &ConversationParticipantUpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(conversation_participant::table.find((conversation_id, person_id)))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// All persons who are currently part of the conversation, messages are delivered to them.
+  pub async fn list_active(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+  ) -> Result<Vec<(Person, Self)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .inner_join(person::table)
+      .filter(conversation_participant::conversation_id.eq(conversation_id))
+      .filter(conversation_participant::left_at.is_null())
+      .order_by(conversation_participant::published)
+      .select((person::all_columns, conversation_participant::all_columns))
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      conversation::{
+        Conversation,
+        ConversationInsertForm,
+        ConversationParticipant,
+        ConversationParticipantUpdateForm,
+      },
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_conversation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_alice");
+    let alice = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_bob");
+    let bob = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_carol");
+    let carol = Person::create(pool, &form).await?;
+
+    let form = ConversationInsertForm::builder()
+      .creator_id(alice.id)
+      .build();
+    let conversation = Conversation::create(pool, &form, &[bob.id, carol.id]).await?;
+    assert!(conversation.local);
+    assert!(conversation
+      .ap_id
+      .to_string()
+      .ends_with(&format!("/conversation/{}", conversation.id.0)));
+
+    let read = Conversation::read_from_apub_id(pool, conversation.ap_id.clone().into()).await?;
+    assert_eq!(Some(&conversation), read.as_ref());
+
+    let participants = ConversationParticipant::list_active(pool, conversation.id).await?;
+    let ids = participants.iter().map(|p| p.0.id).collect::<Vec<_>>();
+    assert_eq!(3, ids.len());
+    assert!(ids.contains(&alice.id));
+
+    // Carol leaves, and isn't added again
+    let form = ConversationParticipantUpdateForm {
+      left_at: Some(Some(naive_now())),
+      ..Default::default()
+    };
+    ConversationParticipant::update(pool, conversation.id, carol.id, &form).await?;
+    ConversationParticipant::add(pool, conversation.id, &[carol.id]).await?;
+    let participants = ConversationParticipant::list_active(pool, conversation.id).await?;
+    assert_eq!(2, participants.len());
+    assert!(participants.iter().all(|p| p.0.id != carol.id));
+
+    let form = ConversationParticipantUpdateForm {
+      muted: Some(true),
+      ..Default::default()
+    };
+    let bob_participant =
+      ConversationParticipant::update(pool, conversation.id, bob.id, &form).await?;
+    assert!(bob_participant.muted);
+
+    Person::delete(pool, alice.id).await?;
+    Person::delete(pool, bob.id).await?;
+    Person::delete(pool, carol.id).await?;
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,173 @@
+use crate::{
+  newtypes::{DataExportId, LocalUserId},
+  schema::data_export,
+  source::data_export::DataExport,
+  utils::{get_conn, DbPool},
+};
+use chrono::Utc;
+use diesel::{
+  dsl::{delete, insert_into, update},
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  SelectableHelper,
+};
+use diesel_async::RunQueryDsl;
+use serde_json::Value;
+
+impl DataExport {
+  /// Starts a new export for the user. Previous exports are deleted, so that only one archive is
+  /// stored per user.
+  pub async fn create(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(data_export::table.filter(data_export::local_user_id.eq(local_user_id)))
+      .execute(conn)
+      .await?;
+    insert_into(data_export::table)
+      .values(data_export::local_user_id.eq(local_user_id))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+  }
+
+  /// Stores the generated files and marks the export as ready for download.
+  pub async fn finish(
+    pool: &mut DbPool<'_>,
+    id: DataExportId,
+    archive: Value,
+    outbox: Value,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(data_export::table.find(id))
+      .set((
+        data_export::archive.eq(archive),
+        data_export::outbox.eq(outbox),
+        data_export::finished.eq(Utc::now()),
+      ))
+      .returning(Self::as_returning())
+      .get_result(conn)
+      .await
+  }
+
+  /// Deletes an export, eg when generating it failed.
+  pub async fn delete(pool: &mut DbPool<'_>, id: DataExportId) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(data_export::table.find(id)).execute(conn).await
+  }
+
+  pub async fn read_for_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    data_export::table
+      .filter(data_export::local_user_id.eq(local_user_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  /// The JSON archive of the user's finished export, if any.
+  pub async fn read_archive(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Option<Value>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    data_export::table
+      .filter(data_export::local_user_id.eq(local_user_id))
+      .select(data_export::archive)
+      .first::<Option<Value>>(conn)
+      .await
+      .optional()
+      .map(Option::flatten)
+  }
+
+  /// The ActivityPub outbox of the user's finished export, if any.
+  pub async fn read_outbox(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Option<Value>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    data_export::table
+      .filter(data_export::local_user_id.eq(local_user_id))
+      .select(data_export::outbox)
+      .first::<Option<Value>>(conn)
+      .await
+      .optional()
+      .map(Option::flatten)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      data_export::DataExport,
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_data_export() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "exporter");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+
+    let content = LocalUser::export_content(pool, person.id, local_user.id).await?;
+    assert!(content.posts.is_empty());
+    assert!(content.modlog.bans.is_empty());
+
+    // Nothing can be downloaded while the export is running
+    let export = DataExport::create(pool, local_user.id).await?;
+    assert!(export.finished.is_none());
+    assert_eq!(None, DataExport::read_archive(pool, local_user.id).await?);
+
+    let finished = DataExport::finish(pool, export.id, json!({"a": 1}), json!({"b": 2})).await?;
+    assert!(finished.finished.is_some());
+    assert_eq!(
+      Some(finished.clone()),
+      DataExport::read_for_user(pool, local_user.id).await?
+    );
+    assert_eq!(
+      Some(json!({"a": 1})),
+      DataExport::read_archive(pool, local_user.id).await?
+    );
+    assert_eq!(
+      Some(json!({"b": 2})),
+      DataExport::read_outbox(pool, local_user.id).await?
+    );
+
+    // A new export replaces the previous one
+    let second = DataExport::create(pool, local_user.id).await?;
+    assert_eq!(
+      Some(second.clone()),
+      DataExport::read_for_user(pool, local_user.id).await?
+    );
+    assert_eq!(None, DataExport::read_outbox(pool, local_user.id).await?);
+
+    // Failed exports are deleted
+    DataExport::delete(pool, second.id).await?;
+    assert_eq!(None, DataExport::read_for_user(pool, local_user.id).await?);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
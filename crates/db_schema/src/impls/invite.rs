@@ -0,0 +1,236 @@
+use crate::{
+  newtypes::{InviteId, LocalUserId, PersonId},
+  schema::{invite, local_user, person},
+  source::{
+    invite::{Invite, InviteInsertForm, InvitedPerson},
+    person::Person,
+  },
+  utils::{get_conn, now, DbPool},
+};
+use diesel::{
+  dsl::{count_star, delete, insert_into, update},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+  SelectableHelper,
+};
+use diesel_async::RunQueryDsl;
+use std::collections::HashMap;
+
+impl Invite {
+  pub async fn create(pool: &mut DbPool<'_>, form: &InviteInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(invite::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Deletes an invite, but only if it was created by the given user.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    creator_id: LocalUserId,
+    invite_id: InviteId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(invite::table.find(invite_id))
+      .filter(invite::creator_id.eq(creator_id))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn delete_for_creators(
+    pool: &mut DbPool<'_>,
+    creator_ids: Vec<LocalUserId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(invite::table.filter(invite::creator_id.eq_any(creator_ids)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, creator_id: LocalUserId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    invite::table
+      .filter(invite::creator_id.eq(creator_id))
+      .order_by(invite::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Number of invites by the user which can still be used.
+  pub async fn count_usable(pool: &mut DbPool<'_>, creator_id: LocalUserId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    invite::table
+      .filter(invite::creator_id.eq(creator_id))
+      .filter(invite::uses.lt(invite::max_uses))
+      .filter(
+        invite::expires
+          .is_null()
+          .or(invite::expires.gt(now().nullable())),
+      )
+      .select(count_star())
+      .first::<i64>(conn)
+      .await
+  }
+
+  /// Counts a use of the invite code. Returns nothing if the code is unknown, expired or used up.
+  pub async fn use_code(pool: &mut DbPool<'_>, code: &str) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      invite::table
+        .filter(invite::code.eq(code))
+        .filter(invite::uses.lt(invite::max_uses))
+        .filter(
+          invite::expires
+            .is_null()
+            .or(invite::expires.gt(now().nullable())),
+        ),
+    )
+    .set(invite::uses.eq(invite::uses + 1))
+    .get_result::<Self>(conn)
+    .await
+    .optional()
+  }
+
+  /// All users who signed up with an invite from the given user, or from someone they invited,
+  /// and so on. Returned together with their local user ids.
+  pub async fn invite_tree(
+    pool: &mut DbPool<'_>,
+    root: LocalUserId,
+  ) -> Result<Vec<(LocalUserId, InvitedPerson)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut inviters: HashMap<LocalUserId, PersonId> = local_user::table
+      .find(root)
+      .select((local_user::id, local_user::person_id))
+      .load(conn)
+      .await?
+      .into_iter()
+      .collect();
+    let mut tree = vec![];
+
+    // Go through the tree level by level
+    while !inviters.is_empty() {
+      let invited = local_user::table
+        .inner_join(person::table)
+        .filter(local_user::invited_by.eq_any(inviters.keys().copied().collect::<Vec<_>>()))
+        .select((
+          local_user::id,
+          local_user::invited_by.assume_not_null(),
+          Person::as_select(),
+        ))
+        .load::<(LocalUserId, LocalUserId, Person)>(conn)
+        .await?;
+
+      let mut next_inviters = HashMap::new();
+      for (local_user_id, invited_by, person) in invited {
+        if let Some(invited_by) = inviters.get(&invited_by) {
+          next_inviters.insert(local_user_id, person.id);
+          tree.push((
+            local_user_id,
+            InvitedPerson {
+              person,
+              invited_by: *invited_by,
+            },
+          ));
+        }
+      }
+      inviters = next_inviters;
+    }
+    Ok(tree)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      invite::{Invite, InviteInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_invites() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "inviter");
+    let inviter_person = Person::create(pool, &person_form).await?;
+    let inviter = LocalUser::create(
+      pool,
+      &LocalUserInsertForm::test_form(inviter_person.id),
+      vec![],
+    )
+    .await?;
+
+    let form = InviteInsertForm::builder()
+      .code(Some("invitecode".to_string()))
+      .creator_id(inviter.id)
+      .max_uses(Some(2))
+      .build();
+    let invite = Invite::create(pool, &form).await?;
+    assert_eq!(1, Invite::count_usable(pool, inviter.id).await?);
+
+    // The code can be used twice
+    assert!(Invite::use_code(pool, "wrongcode").await?.is_none());
+    assert!(Invite::use_code(pool, "invitecode").await?.is_some());
+    assert!(Invite::use_code(pool, "invitecode").await?.is_some());
+    assert!(Invite::use_code(pool, "invitecode").await?.is_none());
+    assert_eq!(0, Invite::count_usable(pool, inviter.id).await?);
+
+    // Build a tree inviter -> invited -> invited_2
+    let person_form = PersonInsertForm::test_form(instance.id, "invited");
+    let invited_person = Person::create(pool, &person_form).await?;
+    let invited_form = LocalUserInsertForm {
+      invited_by: Some(inviter.id),
+      ..LocalUserInsertForm::test_form(invited_person.id)
+    };
+    let invited = LocalUser::create(pool, &invited_form, vec![]).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "invited_2");
+    let invited_person_2 = Person::create(pool, &person_form).await?;
+    let invited_form_2 = LocalUserInsertForm {
+      invited_by: Some(invited.id),
+      ..LocalUserInsertForm::test_form(invited_person_2.id)
+    };
+    LocalUser::create(pool, &invited_form_2, vec![]).await?;
+
+    let tree = Invite::invite_tree(pool, inviter.id).await?;
+    assert_eq!(2, tree.len());
+    let tree: Vec<_> = tree.into_iter().map(|(_, i)| i).collect();
+    assert_eq!(
+      Some(inviter_person.id),
+      tree
+        .iter()
+        .find(|i| i.person.id == invited_person.id)
+        .map(|i| i.invited_by)
+    );
+    assert_eq!(
+      Some(invited_person.id),
+      tree
+        .iter()
+        .find(|i| i.person.id == invited_person_2.id)
+        .map(|i| i.invited_by)
+    );
+    assert_eq!(1, Invite::invite_tree(pool, invited.id).await?.len());
+
+    assert_eq!(1, Invite::delete(pool, inviter.id, invite.id).await?);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,126 @@
+use crate::{
+  newtypes::{KeywordFilterId, LocalUserId},
+  schema::keyword_filter,
+  source::keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::{count_star, delete, insert_into},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl KeywordFilter {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &KeywordFilterInsertForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(keyword_filter::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Deletes a filter, but only if it belongs to the given user.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    keyword_filter_id: KeywordFilterId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(keyword_filter::table.find(keyword_filter_id))
+      .filter(keyword_filter::local_user_id.eq(local_user_id))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    keyword_filter::table
+      .filter(keyword_filter::local_user_id.eq(local_user_id))
+      .order_by(keyword_filter::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn count(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    keyword_filter::table
+      .filter(keyword_filter::local_user_id.eq(local_user_id))
+      .select(count_star())
+      .first::<i64>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_keyword_filters() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "keyword_filterer");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let local_user_id = inserted_local_user.id;
+
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("spoiler".to_string())
+      .build();
+    let plain = KeywordFilter::create(pool, &form).await?;
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("^breaking(news)?$".to_string())
+      .is_regex(Some(true))
+      .build();
+    let regex = KeywordFilter::create(pool, &form).await?;
+    assert_eq!(
+      vec![plain.clone(), regex.clone()],
+      KeywordFilter::list(pool, local_user_id).await?
+    );
+    assert_eq!(2, KeywordFilter::count(pool, local_user_id).await?);
+
+    // Invalid regexes are rejected by the database
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("(unclosed".to_string())
+      .is_regex(Some(true))
+      .build();
+    assert!(KeywordFilter::create(pool, &form).await.is_err());
+
+    assert_eq!(
+      1,
+      KeywordFilter::delete(pool, local_user_id, plain.id).await?
+    );
+    assert_eq!(vec![regex], KeywordFilter::list(pool, local_user_id).await?);
+
+    Person::delete(pool, inserted_person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
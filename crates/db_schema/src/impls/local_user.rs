@@ -3,8 +3,16 @@ use crate::{
   schema::{community, community_moderator, local_user, person, registration_application},
   source::{
     actor_language::LocalUserLanguage,
+    comment::Comment,
+    comment_report::CommentReport,
+    images::LocalImage,
     local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     local_user_vote_display_mode::{LocalUserVoteDisplayMode, LocalUserVoteDisplayModeInsertForm},
+    moderator::{ModBan, ModBanFromCommunity, ModLockPost, ModRemoveComment, ModRemovePost},
+    post::Post,
+    post_report::PostReport,
+    private_message::PrivateMessage,
+    private_message_report::PrivateMessageReport,
     site::Site,
   },
   utils::{
@@ -16,16 +24,21 @@ use crate::{
   CommunityVisibility,
 };
 use bcrypt::{hash, DEFAULT_COST};
+use chrono::{DateTime, Utc};
 use diesel::{
   dsl::{insert_into, not, IntervalDsl},
   result::Error,
   CombineDsl,
   ExpressionMethods,
   JoinOnDsl,
+  NullableExpressionMethods,
   QueryDsl,
+  Queryable,
+  SelectableHelper,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use serde::Serialize;
 
 impl LocalUser {
   pub async fn create(
@@ -159,6 +172,7 @@ impl LocalUser {
       community_follower,
       instance,
       instance_block,
+      keyword_filter,
       person_block,
       post,
       post_saved,
@@ -207,6 +221,19 @@ impl LocalUser {
       .get_results(conn)
       .await?;
 
+    let keyword_filters = keyword_filter::table
+      .inner_join(local_user::table)
+      .left_join(community::table)
+      .filter(local_user::person_id.eq(person_id_))
+      .select((
+        keyword_filter::keyword,
+        keyword_filter::is_regex,
+        community::actor_id.nullable(),
+      ))
+      .order_by(keyword_filter::id)
+      .get_results(conn)
+      .await?;
+
     // TODO: use join for parallel queries?
 
     Ok(UserBackupLists {
@@ -216,6 +243,156 @@ impl LocalUser {
       blocked_communities,
       blocked_users,
       blocked_instances,
+      keyword_filters,
+    })
+  }
+
+  /// Everything which the user created, for a full data export. Comments and posts include
+  /// deleted ones, as they are still stored.
+  pub async fn export_content(
+    pool: &mut DbPool<'_>,
+    person_id_: PersonId,
+    local_user_id_: LocalUserId,
+  ) -> Result<UserContentLists, Error> {
+    use crate::schema::{
+      comment,
+      comment_like,
+      comment_report,
+      local_image,
+      mod_ban,
+      mod_ban_from_community,
+      mod_lock_post,
+      mod_remove_comment,
+      mod_remove_post,
+      post,
+      post_like,
+      post_report,
+      private_message,
+      private_message_report,
+    };
+    let conn = &mut get_conn(pool).await?;
+
+    let posts = post::table
+      .filter(post::creator_id.eq(person_id_))
+      .order_by(post::id)
+      .select(Post::as_select())
+      .load(conn)
+      .await?;
+
+    let comments = comment::table
+      .filter(comment::creator_id.eq(person_id_))
+      .order_by(comment::id)
+      .select(Comment::as_select())
+      .load(conn)
+      .await?;
+
+    let private_messages = private_message::table
+      .filter(private_message::creator_id.eq(person_id_))
+      .order_by(private_message::id)
+      .select(PrivateMessage::as_select())
+      .load(conn)
+      .await?;
+
+    let post_votes = post_like::table
+      .inner_join(post::table)
+      .filter(post_like::person_id.eq(person_id_))
+      .order_by(post_like::published)
+      .select((post::ap_id, post_like::score, post_like::published))
+      .load(conn)
+      .await?;
+
+    let comment_votes = comment_like::table
+      .inner_join(comment::table)
+      .filter(comment_like::person_id.eq(person_id_))
+      .order_by(comment_like::published)
+      .select((comment::ap_id, comment_like::score, comment_like::published))
+      .load(conn)
+      .await?;
+
+    let images = local_image::table
+      .filter(local_image::local_user_id.eq(local_user_id_))
+      .order_by(local_image::published)
+      .select(LocalImage::as_select())
+      .load(conn)
+      .await?;
+
+    let post_reports = post_report::table
+      .filter(post_report::creator_id.eq(person_id_))
+      .order_by(post_report::id)
+      .select(PostReport::as_select())
+      .load(conn)
+      .await?;
+
+    let comment_reports = comment_report::table
+      .filter(comment_report::creator_id.eq(person_id_))
+      .order_by(comment_report::id)
+      .select(CommentReport::as_select())
+      .load(conn)
+      .await?;
+
+    let private_message_reports = private_message_report::table
+      .filter(private_message_report::creator_id.eq(person_id_))
+      .order_by(private_message_report::id)
+      .select(PrivateMessageReport::as_select())
+      .load(conn)
+      .await?;
+
+    // Modlog entries about the user or their content
+    let bans = mod_ban::table
+      .filter(mod_ban::other_person_id.eq(person_id_))
+      .order_by(mod_ban::id)
+      .select(ModBan::as_select())
+      .load(conn)
+      .await?;
+
+    let community_bans = mod_ban_from_community::table
+      .filter(mod_ban_from_community::other_person_id.eq(person_id_))
+      .order_by(mod_ban_from_community::id)
+      .select(ModBanFromCommunity::as_select())
+      .load(conn)
+      .await?;
+
+    let removed_posts = mod_remove_post::table
+      .inner_join(post::table)
+      .filter(post::creator_id.eq(person_id_))
+      .order_by(mod_remove_post::id)
+      .select(ModRemovePost::as_select())
+      .load(conn)
+      .await?;
+
+    let locked_posts = mod_lock_post::table
+      .inner_join(post::table)
+      .filter(post::creator_id.eq(person_id_))
+      .order_by(mod_lock_post::id)
+      .select(ModLockPost::as_select())
+      .load(conn)
+      .await?;
+
+    let removed_comments = mod_remove_comment::table
+      .inner_join(comment::table)
+      .filter(comment::creator_id.eq(person_id_))
+      .order_by(mod_remove_comment::id)
+      .select(ModRemoveComment::as_select())
+      .load(conn)
+      .await?;
+
+    Ok(UserContentLists {
+      posts,
+      comments,
+      private_messages,
+      post_votes,
+      comment_votes,
+      images,
+      post_reports,
+      comment_reports,
+      private_message_reports,
+      modlog: UserModlog {
+        bans,
+        community_bans,
+        removed_posts,
+        locked_posts,
+        removed_comments,
+      },
     })
   }
 
@@ -364,6 +541,42 @@ pub struct UserBackupLists {
   pub blocked_communities: Vec<DbUrl>,
   pub blocked_users: Vec<DbUrl>,
   pub blocked_instances: Vec<String>,
+  /// Keyword, whether it is a regex, and the community which the filter is limited to
+  pub keyword_filters: Vec<(String, bool, Option<DbUrl>)>,
+}
+
+/// All content created by a user, see [LocalUser::export_content].
+#[derive(Serialize)]
+pub struct UserContentLists {
+  pub posts: Vec<Post>,
+  pub comments: Vec<Comment>,
+  pub private_messages: Vec<PrivateMessage>,
+  pub post_votes: Vec<ExportedVote>,
+  pub comment_votes: Vec<ExportedVote>,
+  pub images: Vec<LocalImage>,
+  pub post_reports: Vec<PostReport>,
+  pub comment_reports: Vec<CommentReport>,
+  pub private_message_reports: Vec<PrivateMessageReport>,
+  pub modlog: UserModlog,
+}
+
+/// A vote on a post or comment.
+#[derive(Queryable, Serialize)]
+pub struct ExportedVote {
+  /// The ActivityPub id of the voted post or comment
+  pub object_id: DbUrl,
+  pub score: i16,
+  pub published: DateTime<Utc>,
+}
+
+/// Moderation actions against a user or their content.
+#[derive(Serialize)]
+pub struct UserModlog {
+  pub bans: Vec<ModBan>,
+  pub community_bans: Vec<ModBanFromCommunity>,
+  pub removed_posts: Vec<ModRemovePost>,
+  pub locked_posts: Vec<ModLockPost>,
+  pub removed_comments: Vec<ModRemoveComment>,
 }
 
 #[cfg(test)]
@@ -1,12 +1,55 @@
 use crate::{
-  diesel::{ExpressionMethods, QueryDsl},
-  newtypes::LocalUserId,
-  schema::login_token::{dsl::login_token, user_id},
+  diesel::{BoolExpressionMethods, ExpressionMethods, PgExpressionMethods, QueryDsl},
+  newtypes::{LocalUserId, LoginTokenId},
+  schema::login_token::{
+    dsl::login_token,
+    expires,
+    id,
+    ip,
+    last_used,
+    name,
+    published,
+    token,
+    user_agent,
+    user_id,
+  },
   source::login_token::{LoginToken, LoginTokenCreateForm},
-  utils::{get_conn, DbPool},
+  utils::{functions::coalesce, get_conn, now, DbPool},
+  ApiTokenScope,
+};
+use diesel::{
+  delete,
+  deserialize::FromSql,
+  dsl::{exists, IntervalDsl},
+  insert_into,
+  pg::{Pg, PgValue},
+  result::Error,
+  select,
+  serialize::{Output, ToSql},
+  sql_types::Text,
+  update,
+  NullableExpressionMethods,
+  OptionalExtension,
 };
-use diesel::{delete, dsl::exists, insert_into, result::Error, select};
 use diesel_async::RunQueryDsl;
+use std::str::FromStr;
+
+impl ToSql<Text, Pg> for ApiTokenScope {
+  fn to_sql(&self, out: &mut Output<Pg>) -> diesel::serialize::Result {
+    <String as ToSql<Text, Pg>>::to_sql(&self.to_string(), &mut out.reborrow())
+  }
+}
+
+/// Scopes are stored as text, so reject any value which doesn't name a known scope.
+impl FromSql<Text, Pg> for ApiTokenScope {
+  fn from_sql(value: PgValue<'_>) -> diesel::deserialize::Result<Self> {
+    let str = String::from_sql(value)?;
+    Ok(ApiTokenScope::from_str(&str)?)
+  }
+}
+
+/// Login sessions which weren't used for this many days are no longer valid.
+const SESSION_IDLE_DAYS: i32 = 90;
 
 impl LoginToken {
   pub async fn create(pool: &mut DbPool<'_>, form: LoginTokenCreateForm) -> Result<Self, Error> {
@@ -17,18 +60,25 @@ impl LoginToken {
       .await
   }
 
-  /// Check if the given token is valid for user.
+  /// Returns the token if it is valid for user, not expired and, for login sessions, not idle.
   pub async fn validate(
     pool: &mut DbPool<'_>,
     user_id_: LocalUserId,
     token_: &str,
-  ) -> Result<bool, Error> {
+  ) -> Result<Option<Self>, Error> {
     let conn = &mut get_conn(pool).await?;
-    select(exists(
-      login_token.find(token_).filter(user_id.eq(user_id_)),
-    ))
-    .get_result(conn)
-    .await
+    login_token
+      .find(token_)
+      .filter(user_id.eq(user_id_))
+      .filter(expires.is_null().or(expires.gt(now().nullable())))
+      .filter(
+        name
+          .is_not_null()
+          .or(coalesce(last_used, published).ge(now() - SESSION_IDLE_DAYS.days())),
+      )
+      .first(conn)
+      .await
+      .optional()
   }
 
   pub async fn list(
@@ -49,6 +99,93 @@ impl LoginToken {
     delete(login_token.find(token_)).execute(conn).await
   }
 
+  /// Revoke a specific login or API token.
+  pub async fn invalidate_by_id(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    id_: LoginTokenId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(login_token.filter(user_id.eq(user_id_)).filter(id.eq(id_)))
+      .execute(conn)
+      .await
+  }
+
+  /// Invalidate all login sessions of the user except for the current one. API tokens are kept.
+  pub async fn invalidate_other_sessions(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    current_token: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      login_token
+        .filter(user_id.eq(user_id_))
+        .filter(name.is_null())
+        .filter(token.ne(current_token)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  /// Revoke an API token by its name.
+  pub async fn invalidate_api_token(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    name_: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      login_token
+        .filter(user_id.eq(user_id_))
+        .filter(name.eq(name_)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  /// Check if the user has logged in with this IP address and user agent before.
+  pub async fn is_known_device(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    ip_: Option<&str>,
+    user_agent_: Option<&str>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      login_token
+        .filter(user_id.eq(user_id_))
+        .filter(name.is_null())
+        .filter(ip.is_not_distinct_from(ip_))
+        .filter(user_agent.is_not_distinct_from(user_agent_)),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  pub async fn update_last_used(pool: &mut DbPool<'_>, token_: &str) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(login_token.find(token_))
+      .set(last_used.eq(now().nullable()))
+      .execute(conn)
+      .await
+  }
+
+  /// Delete login sessions which weren't used for a long time, and expired API tokens.
+  pub async fn delete_idle(pool: &mut DbPool<'_>) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      login_token.filter(
+        name
+          .is_null()
+          .and(coalesce(last_used, published).lt(now() - SESSION_IDLE_DAYS.days()))
+          .or(expires.lt(now().nullable())),
+      ),
+    )
+    .execute(conn)
+    .await
+  }
+
   /// Invalidate all logins of given user on password reset/change, account deletion or site ban.
   pub async fn invalidate_all(
     pool: &mut DbPool<'_>,
@@ -60,3 +197,132 @@ impl LoginToken {
       .await
   }
 }
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      login_token::{LoginToken, LoginTokenCreateForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+    ApiTokenScope,
+  };
+  use chrono::{Duration, Utc};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_api_tokens() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "api_token_user");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let form = |token: &str, expires| LoginTokenCreateForm {
+      token: token.to_string().into(),
+      user_id: local_user.id,
+      ip: None,
+      user_agent: None,
+      name: Some(token.to_string()),
+      scopes: Some(vec![ApiTokenScope::Read, ApiTokenScope::Vote]),
+      expires,
+    };
+    LoginToken::create(pool, form("valid_token", None)).await?;
+    let expired = Some(Utc::now() - Duration::minutes(1));
+    LoginToken::create(pool, form("expired_token", expired)).await?;
+
+    // Names are unique per user
+    assert!(LoginToken::create(pool, form("valid_token", None))
+      .await
+      .is_err());
+
+    let token = LoginToken::validate(pool, local_user.id, "valid_token").await?;
+    assert_eq!(
+      Some(vec![ApiTokenScope::Read, ApiTokenScope::Vote]),
+      token.and_then(|t| t.scopes)
+    );
+    let token = LoginToken::validate(pool, local_user.id, "expired_token").await?;
+    assert!(token.is_none());
+    assert_eq!(2, LoginToken::list(pool, local_user.id).await?.len());
+
+    let revoked = LoginToken::invalidate_api_token(pool, local_user.id, "valid_token").await?;
+    assert_eq!(1, revoked);
+    let token = LoginToken::validate(pool, local_user.id, "valid_token").await?;
+    assert!(token.is_none());
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_sessions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "session_user");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let form = |token: &str, user_agent: &str| LoginTokenCreateForm {
+      token: token.to_string().into(),
+      user_id: local_user.id,
+      ip: Some("127.0.0.1".to_string()),
+      user_agent: Some(user_agent.to_string()),
+      name: None,
+      scopes: None,
+      expires: None,
+    };
+    assert!(!LoginToken::is_known_device(pool, local_user.id, Some("127.0.0.1"), None).await?);
+    let phone = LoginToken::create(pool, form("phone_token", "phone")).await?;
+    LoginToken::create(pool, form("laptop_token", "laptop")).await?;
+    LoginToken::create(pool, form("desktop_token", "desktop")).await?;
+    assert!(
+      LoginToken::is_known_device(pool, local_user.id, Some("127.0.0.1"), Some("phone")).await?
+    );
+    assert!(
+      !LoginToken::is_known_device(pool, local_user.id, Some("10.0.0.1"), Some("phone")).await?
+    );
+
+    LoginToken::update_last_used(pool, "laptop_token").await?;
+    let laptop = LoginToken::validate(pool, local_user.id, "laptop_token").await?;
+    assert!(laptop.and_then(|l| l.last_used).is_some());
+
+    // Recently used sessions are kept
+    LoginToken::delete_idle(pool).await?;
+    assert_eq!(3, LoginToken::list(pool, local_user.id).await?.len());
+
+    assert_eq!(
+      1,
+      LoginToken::invalidate_by_id(pool, local_user.id, phone.id).await?
+    );
+    assert_eq!(
+      1,
+      LoginToken::invalidate_other_sessions(pool, local_user.id, "laptop_token").await?
+    );
+    let logins = LoginToken::list(pool, local_user.id).await?;
+    assert_eq!(1, logins.len());
+    assert_eq!(
+      Some("laptop"),
+      logins.first().and_then(|l| l.user_agent.as_deref())
+    );
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
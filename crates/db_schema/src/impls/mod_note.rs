@@ -0,0 +1,181 @@
+use crate::{
+  newtypes::{CommunityId, ModNoteId, PersonId},
+  schema::mod_note,
+  source::mod_note::{ModNote, ModNoteInsertForm, ModNotes},
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  deserialize::{self, FromSql},
+  dsl::{delete, insert_into},
+  pg::{Pg, PgValue},
+  result::Error,
+  serialize::{self, Output, ToSql},
+  sql_types::{Json, Nullable},
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl ModNote {
+  pub async fn create(pool: &mut DbPool<'_>, form: &ModNoteInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_note::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, mod_note_id: ModNoteId) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    mod_note::table
+      .find(mod_note_id)
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, mod_note_id: ModNoteId) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(mod_note::table.find(mod_note_id))
+      .execute(conn)
+      .await
+  }
+
+  /// Lists the notes about a person, newest first. Without a community, the site-wide notes and
+  /// those of all communities are returned.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = mod_note::table
+      .filter(mod_note::person_id.eq(person_id))
+      .into_boxed();
+    if let Some(community_id) = community_id {
+      query = query.filter(mod_note::community_id.eq(community_id));
+    }
+    query
+      .order_by(mod_note::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl FromSql<Nullable<Json>, Pg> for ModNotes {
+  fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<ModNotes>(value)?)
+  }
+
+  // Persons without notes have null instead of an empty array
+  fn from_nullable_sql(bytes: Option<PgValue>) -> deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self::default()),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for ModNotes {
+  fn to_sql(&self, out: &mut Output<Pg>) -> serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      mod_note::{ModNote, ModNoteInsertForm},
+      moderator::{ModBan, ModBanForm, ModBanFromCommunity, ModBanFromCommunityForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_mod_notes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "mod_note_mod");
+    let moderator = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "mod_note_user");
+    let person = Person::create(pool, &form).await?;
+    let form = CommunityInsertForm::builder()
+      .name("test_community_mod_note".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+
+    let form = ModNoteInsertForm::builder()
+      .person_id(person.id)
+      .creator_id(moderator.id)
+      .content("warned twice for brigading".to_string())
+      .build();
+    let site_note = ModNote::create(pool, &form).await?;
+    let form = ModNoteInsertForm::builder()
+      .person_id(person.id)
+      .creator_id(moderator.id)
+      .community_id(Some(community.id))
+      .content("asked to tag spoilers".to_string())
+      .build();
+    let community_note = ModNote::create(pool, &form).await?;
+
+    // Without a community, all notes are listed with the newest first
+    let notes = ModNote::list(pool, person.id, None).await?;
+    assert_eq!(vec![community_note.clone(), site_note.clone()], notes);
+    let notes = ModNote::list(pool, person.id, Some(community.id)).await?;
+    assert_eq!(vec![community_note.clone()], notes);
+
+    // Bans with a reason are added as notes, unbans are not
+    let form = ModBanFromCommunityForm {
+      mod_person_id: moderator.id,
+      other_person_id: person.id,
+      community_id: community.id,
+      reason: Some("spam".to_string()),
+      banned: Some(true),
+      expires: None,
+    };
+    ModBanFromCommunity::create(pool, &form).await?;
+    let form = ModBanForm {
+      mod_person_id: moderator.id,
+      other_person_id: person.id,
+      reason: Some("still spam".to_string()),
+      banned: Some(false),
+      expires: None,
+    };
+    ModBan::create(pool, &form).await?;
+    let notes = ModNote::list(pool, person.id, Some(community.id)).await?;
+    assert_eq!(2, notes.len());
+    assert_eq!(
+      Some("Banned from community: spam"),
+      notes.first().map(|n| n.content.as_str())
+    );
+    assert_eq!(3, ModNote::list(pool, person.id, None).await?.len());
+
+    assert_eq!(1, ModNote::delete(pool, site_note.id).await?);
+    assert_eq!(None, ModNote::read(pool, site_note.id).await?);
+
+    Community::delete(pool, community.id).await?;
+    Person::delete(pool, person.id).await?;
+    Person::delete(pool, moderator.id).await?;
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -1,4 +1,6 @@
 use crate::{
+  newtypes::{CommunityId, PersonId},
+  schema::{mod_resolve_ban_appeal, mod_warning},
   source::moderator::{
     AdminPurgeComment,
     AdminPurgeCommentForm,
@@ -28,14 +30,25 @@ use crate::{
     ModRemoveCommunityForm,
     ModRemovePost,
     ModRemovePostForm,
+    ModResolveBanAppeal,
+    ModResolveBanAppealForm,
     ModTransferCommunity,
     ModTransferCommunityForm,
+    ModWarning,
+    ModWarningForm,
   },
   traits::Crud,
-  utils::{get_conn, DbPool},
+  utils::{get_conn, now, DbPool},
+};
+use diesel::{
+  dsl::insert_into,
+  result::Error,
+  ExpressionMethods,
+  PgExpressionMethods,
+  QueryDsl,
 };
-use diesel::{dsl::insert_into, result::Error, QueryDsl};
 use diesel_async::RunQueryDsl;
+use std::collections::{HashMap, HashSet};
 
 #[async_trait]
 impl Crud for ModRemovePost {
@@ -464,6 +477,114 @@ impl Crud for AdminPurgeComment {
   }
 }
 
+#[async_trait]
+impl Crud for ModWarning {
+  type InsertForm = ModWarningForm;
+  type UpdateForm = ModWarningForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModWarningForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_warning::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModWarningForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_warning::table.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[async_trait]
+impl Crud for ModResolveBanAppeal {
+  type InsertForm = ModResolveBanAppealForm;
+  type UpdateForm = ModResolveBanAppealForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModResolveBanAppealForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_resolve_ban_appeal::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModResolveBanAppealForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_resolve_ban_appeal::table.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl ModWarning {
+  /// Finds everyone who has at least `strike_limit` unexpired warnings in a community or site-wide,
+  /// which didn't lead to a ban yet. Returns the latest of these warnings for each of them.
+  pub async fn list_strike_limit_reached(
+    pool: &mut DbPool<'_>,
+    strike_limit: usize,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let active = mod_warning::table
+      .filter(mod_warning::escalated.eq(false))
+      .filter(mod_warning::expires.gt(now()))
+      .order_by(mod_warning::when_.desc())
+      .load::<Self>(conn)
+      .await?;
+
+    let mut strikes = HashMap::<(PersonId, Option<CommunityId>), usize>::new();
+    for warning in &active {
+      *strikes
+        .entry((warning.other_person_id, warning.community_id))
+        .or_default() += 1;
+    }
+    let mut seen = HashSet::new();
+    Ok(
+      active
+        .into_iter()
+        .filter(|w| {
+          let key = (w.other_person_id, w.community_id);
+          strikes.get(&key).is_some_and(|s| *s >= strike_limit) && seen.insert(key)
+        })
+        .collect(),
+    )
+  }
+
+  /// Marks the unexpired warnings of a person in the community, or the site-wide ones, as
+  /// escalated so that they don't lead to another ban.
+  pub async fn mark_escalated(
+    pool: &mut DbPool<'_>,
+    other_person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      mod_warning::table
+        .filter(mod_warning::other_person_id.eq(other_person_id))
+        .filter(mod_warning::community_id.is_not_distinct_from(community_id))
+        .filter(mod_warning::escalated.eq(false))
+        .filter(mod_warning::expires.gt(now())),
+    )
+    .set(mod_warning::escalated.eq(true))
+    .execute(conn)
+    .await
+  }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::indexing_slicing)]
@@ -493,6 +614,8 @@ mod tests {
         ModRemoveCommunityForm,
         ModRemovePost,
         ModRemovePostForm,
+        ModWarning,
+        ModWarningForm,
       },
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
@@ -500,6 +623,8 @@ mod tests {
     traits::Crud,
     utils::build_db_pool_for_tests,
   };
+  use chrono::{Days, Utc};
+  use lemmy_utils::error::LemmyResult;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -554,6 +679,7 @@ mod tests {
       post_id: inserted_post.id,
       reason: None,
       removed: None,
+      rule_id: None,
     };
     let inserted_mod_remove_post = ModRemovePost::create(pool, &mod_remove_post_form)
       .await
@@ -569,6 +695,7 @@ mod tests {
       reason: None,
       removed: true,
       when_: inserted_mod_remove_post.when_,
+      rule_id: None,
     };
 
     // lock post
@@ -624,6 +751,7 @@ mod tests {
       comment_id: inserted_comment.id,
       reason: None,
       removed: None,
+      rule_id: None,
     };
     let inserted_mod_remove_comment = ModRemoveComment::create(pool, &mod_remove_comment_form)
       .await
@@ -639,6 +767,7 @@ mod tests {
       reason: None,
       removed: true,
       when_: inserted_mod_remove_comment.when_,
+      rule_id: None,
     };
 
     // community
@@ -784,4 +913,58 @@ mod tests {
     assert_eq!(expected_mod_add_community, read_mod_add_community);
     assert_eq!(expected_mod_add, read_mod_add);
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_strikes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "strike_mod");
+    let moderator = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "strike_user");
+    let person = Person::create(pool, &form).await?;
+    let form = CommunityInsertForm::builder()
+      .name("strike_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+
+    let next_month = Utc::now() + Days::new(30);
+    let warn = |community_id, reason: &str, expires| ModWarningForm {
+      mod_person_id: moderator.id,
+      other_person_id: person.id,
+      community_id,
+      reason: reason.to_string(),
+      expires,
+    };
+    ModWarning::create(pool, &warn(Some(community.id), "first", next_month)).await?;
+    // Expired warnings and those in other scopes don't count
+    let expired = Utc::now() - Days::new(1);
+    ModWarning::create(pool, &warn(Some(community.id), "expired", expired)).await?;
+    ModWarning::create(pool, &warn(None, "site", next_month)).await?;
+    assert!(ModWarning::list_strike_limit_reached(pool, 2)
+      .await?
+      .is_empty());
+
+    let latest = ModWarning::create(pool, &warn(Some(community.id), "second", next_month)).await?;
+    let reached = ModWarning::list_strike_limit_reached(pool, 2).await?;
+    assert_eq!(vec![latest], reached);
+
+    let escalated = ModWarning::mark_escalated(pool, person.id, Some(community.id)).await?;
+    assert_eq!(2, escalated);
+    assert!(ModWarning::list_strike_limit_reached(pool, 2)
+      .await?
+      .is_empty());
+    let reached = ModWarning::list_strike_limit_reached(pool, 1).await?;
+    assert_eq!(1, reached.len());
+    assert_eq!(None, reached.first().and_then(|w| w.community_id));
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
 }
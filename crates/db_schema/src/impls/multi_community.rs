@@ -0,0 +1,226 @@
+use crate::{
+  newtypes::{CommunityId, MultiCommunityId, PersonId},
+  schema::{community, multi_community, multi_community_entry},
+  source::{
+    community::Community,
+    multi_community::{
+      MultiCommunity,
+      MultiCommunityEntry,
+      MultiCommunityInsertForm,
+      MultiCommunityUpdateForm,
+    },
+  },
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use diesel::{
+  dsl::{delete, insert_into},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::{
+  scoped_futures::ScopedFutureExt,
+  AsyncConnection,
+  RunQueryDsl,
+};
+
+#[async_trait]
+impl Crud for MultiCommunity {
+  type InsertForm = MultiCommunityInsertForm;
+  type UpdateForm = MultiCommunityUpdateForm;
+  type IdType = MultiCommunityId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(multi_community::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(multi_community::table.find(multi_community_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl MultiCommunity {
+  /// Lists the feeds which the given person can see, that is all public feeds and their own. If
+  /// `for_creator_id` is set, only the feeds of that person are listed.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    my_person_id: Option<PersonId>,
+    for_creator_id: Option<PersonId>,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let mut query = multi_community::table.into_boxed();
+    query = match my_person_id {
+      Some(my_person_id) => query.filter(
+        multi_community::public
+          .eq(true)
+          .or(multi_community::creator_id.eq(my_person_id)),
+      ),
+      None => query.filter(multi_community::public.eq(true)),
+    };
+    if let Some(for_creator_id) = for_creator_id {
+      query = query.filter(multi_community::creator_id.eq(for_creator_id));
+    }
+    query
+      .order_by(multi_community::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The communities whose posts are shown in the feed, ordered by name.
+  pub async fn list_communities(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+  ) -> Result<Vec<Community>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    multi_community_entry::table
+      .inner_join(community::table)
+      .filter(multi_community_entry::multi_community_id.eq(multi_community_id))
+      .select(community::all_columns)
+      .order_by(community::name)
+      .load::<Community>(conn)
+      .await
+  }
+
+  /// Replaces the communities of the feed with the given list.
+  pub async fn update_entries(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+    community_ids: &[CommunityId],
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = community_ids
+      .iter()
+      .map(|&community_id| MultiCommunityEntry {
+        multi_community_id,
+        community_id,
+      })
+      .collect::<Vec<_>>();
+
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          delete(multi_community_entry::table)
+            .filter(multi_community_entry::multi_community_id.eq(multi_community_id))
+            .filter(multi_community_entry::community_id.ne_all(community_ids))
+            .execute(conn)
+            .await?;
+
+          insert_into(multi_community_entry::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      multi_community::{MultiCommunity, MultiCommunityInsertForm, MultiCommunityUpdateForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "multi_creator");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let other_person = PersonInsertForm::test_form(inserted_instance.id, "multi_other");
+    let other_person = Person::create(pool, &other_person).await?;
+
+    let mut communities = vec![];
+    for name in ["multi_b", "multi_a", "multi_c"] {
+      let form = CommunityInsertForm::builder()
+        .name(name.to_string())
+        .title("nada".to_owned())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build();
+      communities.push(Community::create(pool, &form).await?);
+    }
+    let community_ids = communities.iter().map(|c| c.id).collect::<Vec<_>>();
+
+    let form = MultiCommunityInsertForm::builder()
+      .creator_id(inserted_person.id)
+      .name("news".to_string())
+      .build();
+    let multi = MultiCommunity::create(pool, &form).await?;
+    assert!(!multi.public);
+
+    // Entries are replaced, and listed by name
+    MultiCommunity::update_entries(pool, multi.id, &community_ids).await?;
+    MultiCommunity::update_entries(pool, multi.id, community_ids.get(..2).unwrap_or_default())
+      .await?;
+    let names = MultiCommunity::list_communities(pool, multi.id)
+      .await?
+      .into_iter()
+      .map(|c| c.name)
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["multi_a", "multi_b"], names);
+
+    // Private feeds are only listed for their creator
+    let creator_list =
+      MultiCommunity::list(pool, Some(inserted_person.id), None, None, None).await?;
+    assert_eq!(vec![multi.clone()], creator_list);
+    let other_list = MultiCommunity::list(pool, Some(other_person.id), None, None, None).await?;
+    assert!(other_list.is_empty());
+    let anonymous_list = MultiCommunity::list(pool, None, None, None, None).await?;
+    assert!(anonymous_list.is_empty());
+
+    let update_form = MultiCommunityUpdateForm {
+      public: Some(true),
+      ..Default::default()
+    };
+    let multi = MultiCommunity::update(pool, multi.id, &update_form).await?;
+    let list = MultiCommunity::list(pool, None, Some(inserted_person.id), None, None).await?;
+    assert_eq!(vec![multi], list);
+
+    Person::delete(pool, inserted_person.id).await?;
+    Person::delete(pool, other_person.id).await?;
+    for community in communities {
+      Community::delete(pool, community.id).await?;
+    }
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
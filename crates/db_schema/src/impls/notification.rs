@@ -0,0 +1,183 @@
+use crate::{
+  newtypes::{CommentId, ConversationId, NotificationId, PersonId, PrivateMessageId},
+  schema::{comment_reply, notification, person_mention, private_message},
+  source::notification::{Notification, NotificationInsertForm, NotificationUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+  NotificationType,
+};
+use diesel::{
+  dsl::insert_into,
+  result::Error,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+
+#[async_trait]
+impl Crud for Notification {
+  type InsertForm = NotificationInsertForm;
+  type UpdateForm = NotificationUpdateForm;
+  type IdType = NotificationId;
+
+  /// Replies, mentions and private messages are only stored once per recipient. Creating them
+  /// again (eg because a comment was edited) returns `NotFound`.
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(notification::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    notification_id: NotificationId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(notification::table.find(notification_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Notification {
+  /// Marks the given notifications of a person as read or unread. If `notification_ids` is None,
+  /// all of their notifications are changed.
+  ///
+  /// The read state of the matching comment replies, mentions and private messages is updated as
+  /// well, so that the older per-type endpoints stay consistent.
+  pub async fn mark_as_read(
+    pool: &mut DbPool<'_>,
+    for_recipient_id: PersonId,
+    notification_ids: Option<Vec<NotificationId>>,
+    read: bool,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let mut query = diesel::update(notification::table)
+            .filter(notification::recipient_id.eq(for_recipient_id))
+            .filter(notification::read.ne(read))
+            .into_boxed();
+          if let Some(notification_ids) = notification_ids {
+            query = query.filter(notification::id.eq_any(notification_ids));
+          }
+          let updated = query
+            .set(notification::read.eq(read))
+            .get_results::<Self>(conn)
+            .await?;
+
+          let comment_ids = |kind: NotificationType| {
+            updated
+              .iter()
+              .filter(|n| n.kind == kind)
+              .filter_map(|n| n.comment_id)
+              .collect::<Vec<CommentId>>()
+          };
+          let private_message_ids = updated
+            .iter()
+            .filter(|n| n.kind == NotificationType::PrivateMessage)
+            .filter_map(|n| n.private_message_id)
+            .collect::<Vec<PrivateMessageId>>();
+
+          diesel::update(
+            comment_reply::table
+              .filter(comment_reply::recipient_id.eq(for_recipient_id))
+              .filter(comment_reply::comment_id.eq_any(comment_ids(NotificationType::Reply))),
+          )
+          .set(comment_reply::read.eq(read))
+          .execute(conn)
+          .await?;
+
+          diesel::update(
+            person_mention::table
+              .filter(person_mention::recipient_id.eq(for_recipient_id))
+              .filter(person_mention::comment_id.eq_any(comment_ids(NotificationType::Mention))),
+          )
+          .set(person_mention::read.eq(read))
+          .execute(conn)
+          .await?;
+
+          diesel::update(
+            private_message::table
+              .filter(private_message::recipient_id.eq(for_recipient_id))
+              .filter(private_message::id.eq_any(private_message_ids)),
+          )
+          .set(private_message::read.eq(read))
+          .execute(conn)
+          .await?;
+
+          Ok(updated)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Updates the read state of the reply or mention notification for a comment.
+  pub async fn update_read_for_comment(
+    pool: &mut DbPool<'_>,
+    for_recipient_id: PersonId,
+    for_comment_id: CommentId,
+    for_kind: NotificationType,
+    read: bool,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      notification::table
+        .filter(notification::recipient_id.eq(for_recipient_id))
+        .filter(notification::comment_id.eq(for_comment_id))
+        .filter(notification::kind.eq(for_kind)),
+    )
+    .set(notification::read.eq(read))
+    .execute(conn)
+    .await
+  }
+
+  /// Updates the read state of the notification for a private message.
+  pub async fn update_read_for_private_message(
+    pool: &mut DbPool<'_>,
+    for_recipient_id: PersonId,
+    for_private_message_id: PrivateMessageId,
+    read: bool,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      notification::table
+        .filter(notification::recipient_id.eq(for_recipient_id))
+        .filter(notification::private_message_id.eq(for_private_message_id))
+        .filter(notification::kind.eq(NotificationType::PrivateMessage)),
+    )
+    .set(notification::read.eq(read))
+    .execute(conn)
+    .await
+  }
+
+  /// Marks the notifications for all messages of a group conversation as read.
+  pub async fn mark_read_for_conversation(
+    pool: &mut DbPool<'_>,
+    for_recipient_id: PersonId,
+    for_conversation_id: ConversationId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let message_ids = private_message::table
+      .filter(private_message::conversation_id.eq(for_conversation_id))
+      .select(private_message::id.nullable());
+    diesel::update(
+      notification::table
+        .filter(notification::recipient_id.eq(for_recipient_id))
+        .filter(notification::private_message_id.eq_any(message_ids))
+        .filter(notification::read.eq(false)),
+    )
+    .set(notification::read.eq(true))
+    .execute(conn)
+    .await
+  }
+}
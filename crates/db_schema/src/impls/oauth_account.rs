@@ -0,0 +1,17 @@
+use crate::{
+  schema::oauth_account,
+  source::oauth_account::{OAuthAccount, OAuthAccountInsertForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error};
+use diesel_async::RunQueryDsl;
+
+impl OAuthAccount {
+  pub async fn create(pool: &mut DbPool<'_>, form: &OAuthAccountInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(oauth_account::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,118 @@
+use crate::{
+  newtypes::OAuthProviderId,
+  schema::oauth_provider,
+  source::oauth_provider::{
+    OAuthProvider,
+    OAuthProviderInsertForm,
+    OAuthProviderUpdateForm,
+    PublicOAuthProvider,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl, SelectableHelper};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for OAuthProvider {
+  type InsertForm = OAuthProviderInsertForm;
+  type UpdateForm = OAuthProviderUpdateForm;
+  type IdType = OAuthProviderId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(oauth_provider::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    oauth_provider_id: OAuthProviderId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(oauth_provider::table.find(oauth_provider_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl OAuthProvider {
+  pub async fn get_all(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    oauth_provider::table
+      .order_by(oauth_provider::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The enabled providers, which are shown to all users on the login page.
+  pub async fn get_all_public(pool: &mut DbPool<'_>) -> Result<Vec<PublicOAuthProvider>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    oauth_provider::table
+      .filter(oauth_provider::enabled.eq(true))
+      .order_by(oauth_provider::id)
+      .select(PublicOAuthProvider::as_select())
+      .load::<PublicOAuthProvider>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::oauth_provider::{OAuthProvider, OAuthProviderInsertForm, OAuthProviderUpdateForm},
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_oauth_providers() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let issuer = Url::parse("https://auth.example.com")?;
+    let form = OAuthProviderInsertForm::builder()
+      .display_name("Example SSO".to_string())
+      .issuer(issuer.clone().into())
+      .authorization_endpoint(issuer.join("/authorize")?.into())
+      .token_endpoint(issuer.join("/token")?.into())
+      .userinfo_endpoint(issuer.join("/userinfo")?.into())
+      .id_claim("sub".to_string())
+      .client_id("lemmy".to_string())
+      .client_secret("secret".to_string())
+      .scopes("openid email".to_string())
+      .build();
+    let provider = OAuthProvider::create(pool, &form).await?;
+    assert!(provider.use_pkce);
+    assert_eq!("secret", &*provider.client_secret);
+
+    let public = OAuthProvider::get_all_public(pool).await?;
+    assert_eq!(
+      vec![provider.id],
+      public.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+
+    // Disabled providers are only listed for admins
+    let form = OAuthProviderUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    OAuthProvider::update(pool, provider.id, &form).await?;
+    assert!(OAuthProvider::get_all_public(pool).await?.is_empty());
+    assert_eq!(1, OAuthProvider::get_all(pool).await?.len());
+
+    OAuthProvider::delete(pool, provider.id).await?;
+
+    Ok(())
+  }
+}
@@ -192,7 +192,7 @@ impl Followable for PersonFollower {
       .await
   }
 
-  /// Currently no user following
+  /// Person follows are keyed by person id, see [PersonFollower::follow_accepted_person]
   async fn follow_accepted(_: &mut DbPool<'_>, _: CommunityId, _: PersonId) -> Result<Self, Error> {
     Err(Error::NotFound)
   }
@@ -206,6 +206,19 @@ impl Followable for PersonFollower {
 }
 
 impl PersonFollower {
+  /// Mark the pending follow of a remote person as accepted.
+  pub async fn follow_accepted_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    follower_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(person_follower::table.find((follower_id, person_id)))
+      .set(person_follower::pending.eq(false))
+      .get_result::<Self>(conn)
+      .await
+  }
+
   pub async fn list_followers(
     pool: &mut DbPool<'_>,
     for_person_id: PersonId,
@@ -270,6 +283,8 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      also_known_as: vec![],
+      moved_to: None,
     };
 
     let read_person = Person::read(pool, inserted_person.id)
@@ -0,0 +1,278 @@
+use crate::{
+  newtypes::{PersonId, PostId, TagId},
+  schema::{poll, poll_vote, post},
+  source::{
+    poll::{Poll, PollInsertForm, PollUpdateForm, PollVote, PollVoteForm},
+    post::{Post, PostInsertForm},
+    tag::PostTag,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+
+#[async_trait]
+impl Crud for Poll {
+  type InsertForm = PollInsertForm;
+  type UpdateForm = PollUpdateForm;
+  type IdType = PostId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(poll::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(poll::table.find(post_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Poll {
+  /// Creates a new local post together with its poll and tags, so that a poll post never exists
+  /// without its options.
+  pub async fn create_with_post(
+    pool: &mut DbPool<'_>,
+    post_form: &PostInsertForm,
+    tag_ids: &[TagId],
+    options: Vec<String>,
+    multiple_choice: Option<bool>,
+    end_time: Option<DateTime<Utc>>,
+  ) -> Result<(Post, Self), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let post = insert_into(post::table)
+            .values(post_form)
+            .get_result::<Post>(conn)
+            .await?;
+          if !tag_ids.is_empty() {
+            PostTag::set(&mut conn.into(), post.id, tag_ids).await?;
+          }
+          let poll_form = PollInsertForm::builder()
+            .post_id(post.id)
+            .multiple_choice(multiple_choice)
+            .vote_counts(vec![0; options.len()])
+            .options(options)
+            .end_time(end_time)
+            .build();
+          let poll = insert_into(poll::table)
+            .values(poll_form)
+            .get_result::<Self>(conn)
+            .await?;
+          Ok((post, poll))
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Creates or replaces the poll of a federated post.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &PollInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(poll::table)
+      .values(form)
+      .on_conflict(poll::post_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl PollVote {
+  /// Replaces the previous votes of the person in this poll. An empty list of choices removes the
+  /// vote.
+  pub async fn vote(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    for_person_id: PersonId,
+    choices: Vec<i32>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          diesel::delete(
+            poll_vote::table
+              .filter(poll_vote::post_id.eq(for_post_id))
+              .filter(poll_vote::person_id.eq(for_person_id)),
+          )
+          .execute(conn)
+          .await?;
+
+          if choices.is_empty() {
+            return Ok(vec![]);
+          }
+          let forms = choices
+            .into_iter()
+            .map(|choice| PollVoteForm {
+              post_id: for_post_id,
+              person_id: for_person_id,
+              choice,
+            })
+            .collect::<Vec<_>>();
+          insert_into(poll_vote::table)
+            .values(forms)
+            .get_results::<Self>(conn)
+            .await
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Adds a single choice to the votes of the person, which is how multiple choice votes are
+  /// federated.
+  pub async fn add(pool: &mut DbPool<'_>, form: &PollVoteForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(poll_vote::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  /// Removes a single choice from the votes of the person, which is how retracting a multiple
+  /// choice vote is federated.
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    for_person_id: PersonId,
+    for_choice: i32,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      poll_vote::table
+        .filter(poll_vote::post_id.eq(for_post_id))
+        .filter(poll_vote::person_id.eq(for_person_id))
+        .filter(poll_vote::choice.eq(for_choice)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  /// Returns the indexes of the options which the person voted for.
+  pub async fn list_choices(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    for_person_id: PersonId,
+  ) -> Result<Vec<i32>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll_vote::table
+      .filter(poll_vote::post_id.eq(for_post_id))
+      .filter(poll_vote::person_id.eq(for_person_id))
+      .order_by(poll_vote::choice)
+      .select(poll_vote::choice)
+      .load::<i32>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      poll::{Poll, PollVote},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_vote_counts() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "poll_voter");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let new_person_2 = PersonInsertForm::test_form(inserted_instance.id, "poll_voter_2");
+    let inserted_person_2 = Person::create(pool, &new_person_2).await?;
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community poll".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let new_post = PostInsertForm::builder()
+      .name("A test poll".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let options = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let (inserted_post, inserted_poll) =
+      Poll::create_with_post(pool, &new_post, &[], options, Some(true), None).await?;
+    assert_eq!(inserted_post.id, inserted_poll.post_id);
+    assert_eq!(vec![0, 0, 0], inserted_poll.vote_counts);
+
+    PollVote::vote(pool, inserted_post.id, inserted_person.id, vec![0, 2]).await?;
+    PollVote::vote(pool, inserted_post.id, inserted_person_2.id, vec![2]).await?;
+    let poll = Poll::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some(vec![1, 0, 2]),
+      poll.as_ref().map(|p| p.vote_counts.clone())
+    );
+    assert_eq!(Some(2), poll.map(|p| p.voters_count));
+
+    // Voting again replaces the previous votes
+    PollVote::vote(pool, inserted_post.id, inserted_person.id, vec![1]).await?;
+    assert_eq!(
+      vec![1],
+      PollVote::list_choices(pool, inserted_post.id, inserted_person.id).await?
+    );
+    let poll = Poll::read(pool, inserted_post.id).await?;
+    assert_eq!(Some(vec![0, 1, 1]), poll.map(|p| p.vote_counts));
+
+    // Removing a single choice of a multiple choice vote
+    PollVote::vote(pool, inserted_post.id, inserted_person.id, vec![0, 1]).await?;
+    PollVote::remove(pool, inserted_post.id, inserted_person.id, 0).await?;
+    assert_eq!(
+      vec![1],
+      PollVote::list_choices(pool, inserted_post.id, inserted_person.id).await?
+    );
+
+    // Removing the vote
+    PollVote::vote(pool, inserted_post.id, inserted_person_2.id, vec![]).await?;
+    let poll = Poll::read(pool, inserted_post.id).await?;
+    assert_eq!(
+      Some(vec![0, 1, 0]),
+      poll.as_ref().map(|p| p.vote_counts.clone())
+    );
+    assert_eq!(Some(1), poll.map(|p| p.voters_count));
+
+    Post::delete(pool, inserted_post.id).await?;
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Person::delete(pool, inserted_person_2.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
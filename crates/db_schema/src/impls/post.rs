@@ -1,19 +1,22 @@
 use crate::{
   diesel::OptionalExtension,
-  newtypes::{CommunityId, DbUrl, PersonId, PostId},
+  newtypes::{CommunityId, DbUrl, PersonId, PostId, TagId},
   schema::{post, post_hide, post_like, post_read, post_saved},
-  source::post::{
-    Post,
-    PostHide,
-    PostHideForm,
-    PostInsertForm,
-    PostLike,
-    PostLikeForm,
-    PostRead,
-    PostReadForm,
-    PostSaved,
-    PostSavedForm,
-    PostUpdateForm,
+  source::{
+    post::{
+      Post,
+      PostHide,
+      PostHideForm,
+      PostInsertForm,
+      PostLike,
+      PostLikeForm,
+      PostRead,
+      PostReadForm,
+      PostSaved,
+      PostSavedForm,
+      PostUpdateForm,
+    },
+    tag::PostTag,
   },
   traits::{Crud, Likeable, Saveable},
   utils::{
@@ -30,7 +33,7 @@ use crate::{
 use ::url::Url;
 use chrono::{DateTime, Utc};
 use diesel::{dsl::insert_into, result::Error, DecoratableTarget, ExpressionMethods, QueryDsl};
-use diesel_async::RunQueryDsl;
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
 use std::collections::HashSet;
 
 #[async_trait]
@@ -61,6 +64,30 @@ impl Crud for Post {
 }
 
 impl Post {
+  /// Creates a new local post together with its tags.
+  pub async fn create_with_tags(
+    pool: &mut DbPool<'_>,
+    form: &PostInsertForm,
+    tag_ids: &[TagId],
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let post = insert_into(post::table)
+            .values(form)
+            .get_result::<Self>(conn)
+            .await?;
+          if !tag_ids.is_empty() {
+            PostTag::set(&mut conn.into(), post.id, tag_ids).await?;
+          }
+          Ok(post)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
   pub async fn insert_apub(
     pool: &mut DbPool<'_>,
     timestamp: DateTime<Utc>,
@@ -87,6 +114,7 @@ impl Post {
       .filter(post::deleted.eq(false))
       .filter(post::removed.eq(false))
       .filter(post::featured_community.eq(true))
+      .filter(post::scheduled_publish_time.is_null())
       .then_order_by(post::published.desc())
       .limit(FETCH_LIMIT_MAX)
       .load::<Self>(conn)
@@ -102,6 +130,7 @@ impl Post {
       .filter(post::local.eq(true))
       .filter(post::deleted.eq(false))
       .filter(post::removed.eq(false))
+      .filter(post::scheduled_publish_time.is_null())
       .filter(
         post::published.ge(Utc::now().naive_utc() - SITEMAP_DAYS.expect("TimeDelta out of bounds")),
       )
@@ -150,6 +179,22 @@ impl Post {
       .await
   }
 
+  /// Returns the number of posts by the person which are still waiting to be published.
+  pub async fn user_scheduled_post_count(
+    pool: &mut DbPool<'_>,
+    for_creator_id: PersonId,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post::table
+      .filter(post::creator_id.eq(for_creator_id))
+      .filter(post::scheduled_publish_time.is_not_null())
+      .filter(post::deleted.eq(false))
+      .filter(post::removed.eq(false))
+      .count()
+      .get_result::<i64>(conn)
+      .await
+  }
+
   pub fn is_post_creator(person_id: PersonId, post_creator_id: PersonId) -> bool {
     person_id == post_creator_id
   }
@@ -360,6 +405,7 @@ mod tests {
       url: None,
       body: None,
       alt_text: None,
+      scheduled_publish_time: None,
       creator_id: inserted_person.id,
       community_id: inserted_community.id,
       published: inserted_post.published,
@@ -1,12 +1,13 @@
 use crate::{
   newtypes::{PersonId, PostId, PostReportId},
   schema::post_report::{
-    dsl::{post_report, resolved, resolver_id, updated},
+    dsl::{post_report, resolved, resolver_id, status, updated},
     post_id,
   },
   source::post_report::{PostReport, PostReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportStatus,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -39,6 +40,7 @@ impl Reportable for PostReport {
     update(post_report.find(report_id))
       .set((
         resolved.eq(true),
+        status.eq(ReportStatus::Resolved),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -55,6 +57,7 @@ impl Reportable for PostReport {
     update(post_report.filter(post_id.eq(post_id_)))
       .set((
         resolved.eq(true),
+        status.eq(ReportStatus::Resolved),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -71,6 +74,7 @@ impl Reportable for PostReport {
     update(post_report.find(report_id))
       .set((
         resolved.eq(false),
+        status.eq(ReportStatus::Open),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
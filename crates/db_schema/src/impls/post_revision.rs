@@ -0,0 +1,165 @@
+use crate::{
+  newtypes::{PersonId, PostId},
+  schema::{post, post_revision},
+  source::post_revision::PostRevision,
+  utils::{get_conn, DbPool},
+};
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl PostRevision {
+  /// Lists the previous versions of a post, from oldest to newest.
+  pub async fn list_for_post(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_revision::table
+      .filter(post_revision::post_id.eq(for_post_id))
+      .order_by(post_revision::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Deletes the previous versions of all posts by a person, so that they are gone together with
+  /// the posts when an account is deleted.
+  pub async fn delete_for_creator(
+    pool: &mut DbPool<'_>,
+    for_creator_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let posts = post::table
+      .filter(post::creator_id.eq(for_creator_id))
+      .select(post::id);
+    diesel::delete(post_revision::table.filter(post_revision::post_id.eq_any(posts)))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      comment::{Comment, CommentInsertForm, CommentUpdateForm},
+      comment_revision::CommentRevision,
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm, PostUpdateForm},
+      post_revision::PostRevision,
+    },
+    traits::Crud,
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_revisions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "reviser");
+    let inserted_person = Person::create(pool, &new_person).await?;
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community revisions".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let new_post = PostInsertForm::builder()
+      .name("Original title".into())
+      .body(Some("Original body".into()))
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await?;
+
+    // An edit keeps the previous version
+    let edit_form = PostUpdateForm {
+      name: Some("Edited title".into()),
+      updated: Some(Some(naive_now())),
+      ..Default::default()
+    };
+    Post::update(pool, inserted_post.id, &edit_form).await?;
+    let revisions = PostRevision::list_for_post(pool, inserted_post.id).await?;
+    assert_eq!(1, revisions.len());
+    let revision = revisions.first();
+    assert_eq!(Some("Original title"), revision.map(|r| r.name.as_str()));
+    assert_eq!(
+      Some("Original body"),
+      revision.and_then(|r| r.body.as_deref())
+    );
+    assert_eq!(Some(inserted_post.published), revision.map(|r| r.published));
+
+    // Changes which are not edits, eg from fetching link metadata, don't create revisions
+    let url = Url::parse("https://example.com/image.png")?;
+    let metadata_form = PostUpdateForm {
+      url: Some(Some(url.into())),
+      ..Default::default()
+    };
+    Post::update(pool, inserted_post.id, &metadata_form).await?;
+    assert_eq!(
+      1,
+      PostRevision::list_for_post(pool, inserted_post.id)
+        .await?
+        .len()
+    );
+
+    let comment_form = CommentInsertForm::builder()
+      .content("Original comment".into())
+      .creator_id(inserted_person.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &comment_form, None).await?;
+    let comment_edit_form = CommentUpdateForm {
+      content: Some("Edited comment".into()),
+      updated: Some(Some(naive_now())),
+      ..Default::default()
+    };
+    Comment::update(pool, inserted_comment.id, &comment_edit_form).await?;
+    let comment_revisions = CommentRevision::list_for_comment(pool, inserted_comment.id).await?;
+    assert_eq!(
+      vec!["Original comment"],
+      comment_revisions
+        .iter()
+        .map(|r| r.content.as_str())
+        .collect::<Vec<_>>()
+    );
+
+    // Permanently deleting the account also deletes the revisions
+    Comment::permadelete_for_creator(pool, inserted_person.id).await?;
+    Post::permadelete_for_creator(pool, inserted_person.id).await?;
+    PostRevision::delete_for_creator(pool, inserted_person.id).await?;
+    CommentRevision::delete_for_creator(pool, inserted_person.id).await?;
+    assert_eq!(
+      0,
+      PostRevision::list_for_post(pool, inserted_post.id)
+        .await?
+        .len()
+    );
+    assert_eq!(
+      0,
+      CommentRevision::list_for_comment(pool, inserted_comment.id)
+        .await?
+        .len()
+    );
+
+    Post::delete(pool, inserted_post.id).await?;
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
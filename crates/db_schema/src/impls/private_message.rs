@@ -162,6 +162,7 @@ mod tests {
       .into(),
       local: true,
       removed: false,
+      conversation_id: None,
     };
 
     let read_private_message = PrivateMessage::read(pool, inserted_private_message.id)
@@ -1,9 +1,16 @@
 use crate::{
   newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId},
-  schema::private_message_report::dsl::{private_message_report, resolved, resolver_id, updated},
+  schema::private_message_report::dsl::{
+    private_message_report,
+    resolved,
+    resolver_id,
+    status,
+    updated,
+  },
   source::private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportStatus,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -39,6 +46,7 @@ impl Reportable for PrivateMessageReport {
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(true),
+        status.eq(ReportStatus::Resolved),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -64,6 +72,7 @@ impl Reportable for PrivateMessageReport {
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(false),
+        status.eq(ReportStatus::Open),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
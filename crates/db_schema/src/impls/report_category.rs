@@ -0,0 +1,91 @@
+use crate::{
+  newtypes::ReportCategoryId,
+  schema::report_category,
+  source::report_category::{ReportCategory, ReportCategoryForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for ReportCategory {
+  type InsertForm = ReportCategoryForm;
+  type UpdateForm = ReportCategoryForm;
+  type IdType = ReportCategoryId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(report_category::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    report_category_id: Self::IdType,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_category::table.find(report_category_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl ReportCategory {
+  /// All report categories, ordered by name.
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_category::table
+      .order_by(report_category::name)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::report_category::{ReportCategory, ReportCategoryForm},
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_report_category() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let form = ReportCategoryForm {
+      name: "Spam".to_string(),
+    };
+    let spam = ReportCategory::create(pool, &form).await?;
+    let form = ReportCategoryForm {
+      name: "Harassment".to_string(),
+    };
+    let harassment = ReportCategory::create(pool, &form).await?;
+
+    // Names are unique
+    let form = ReportCategoryForm {
+      name: "Spam".to_string(),
+    };
+    assert!(ReportCategory::create(pool, &form).await.is_err());
+
+    let categories = ReportCategory::list(pool).await?;
+    assert_eq!(vec![harassment.clone(), spam.clone()], categories);
+
+    ReportCategory::delete(pool, spam.id).await?;
+    ReportCategory::delete(pool, harassment.id).await?;
+    assert!(ReportCategory::list(pool).await?.is_empty());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,191 @@
+use crate::{
+  newtypes::{PersonId, ReportCombinedId},
+  schema::{comment_report, post_report, private_message_report, report_combined},
+  source::report_combined::{ReportCombined, ReportTriageForm},
+  utils::{get_conn, naive_now, DbPool},
+  ReportStatus,
+};
+use diesel::{dsl::update, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl ReportCombined {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    report_id: ReportCombinedId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_combined::table
+      .find(report_id)
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// Updates the category, assignee and status of the post, comment or private message report.
+  /// A new status also marks the report as resolved or unresolved by the given person.
+  pub async fn triage(
+    &self,
+    pool: &mut DbPool<'_>,
+    by_person_id: PersonId,
+    form: &ReportTriageForm,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let resolved = form.status.map(|s| s == ReportStatus::Resolved);
+    let resolver_id = form.status.map(|_| Some(by_person_id));
+
+    if let Some(report_id) = self.post_report_id {
+      update(post_report::table.find(report_id))
+        .set((
+          form.category_id.map(|c| post_report::category_id.eq(c)),
+          form.assignee_id.map(|a| post_report::assignee_id.eq(a)),
+          form.status.map(|s| post_report::status.eq(s)),
+          resolved.map(|r| post_report::resolved.eq(r)),
+          resolver_id.map(|r| post_report::resolver_id.eq(r)),
+          post_report::updated.eq(naive_now()),
+        ))
+        .execute(conn)
+        .await
+    } else if let Some(report_id) = self.comment_report_id {
+      update(comment_report::table.find(report_id))
+        .set((
+          form.category_id.map(|c| comment_report::category_id.eq(c)),
+          form.assignee_id.map(|a| comment_report::assignee_id.eq(a)),
+          form.status.map(|s| comment_report::status.eq(s)),
+          resolved.map(|r| comment_report::resolved.eq(r)),
+          resolver_id.map(|r| comment_report::resolver_id.eq(r)),
+          comment_report::updated.eq(naive_now()),
+        ))
+        .execute(conn)
+        .await
+    } else if let Some(report_id) = self.private_message_report_id {
+      update(private_message_report::table.find(report_id))
+        .set((
+          form
+            .category_id
+            .map(|c| private_message_report::category_id.eq(c)),
+          form
+            .assignee_id
+            .map(|a| private_message_report::assignee_id.eq(a)),
+          form.status.map(|s| private_message_report::status.eq(s)),
+          resolved.map(|r| private_message_report::resolved.eq(r)),
+          resolver_id.map(|r| private_message_report::resolver_id.eq(r)),
+          private_message_report::updated.eq(naive_now()),
+        ))
+        .execute(conn)
+        .await
+    } else {
+      Err(Error::NotFound)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    newtypes::PostReportId,
+    schema::{post_report, report_combined},
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+      report_category::{ReportCategory, ReportCategoryForm},
+      report_combined::{ReportCombined, ReportTriageForm},
+    },
+    traits::{Crud, Reportable},
+    utils::{build_db_pool_for_tests, get_conn, DbPool},
+    ReportStatus,
+  };
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  async fn read_post_report(
+    pool: &mut DbPool<'_>,
+    report_id: PostReportId,
+  ) -> LemmyResult<PostReport> {
+    let conn = &mut get_conn(pool).await?;
+    Ok(post_report::table.find(report_id).first(conn).await?)
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_report_triage() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "report_triage_mod");
+    let moderator = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "report_triage_reporter");
+    let reporter = Person::create(pool, &form).await?;
+    let form = CommunityInsertForm::builder()
+      .name("test_community_report_triage".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+    let form = PostInsertForm::builder()
+      .name("A test post".into())
+      .creator_id(moderator.id)
+      .community_id(community.id)
+      .build();
+    let post = Post::create(pool, &form).await?;
+    let form = ReportCategoryForm {
+      name: "Report triage".to_string(),
+    };
+    let category = ReportCategory::create(pool, &form).await?;
+
+    let form = PostReportForm {
+      post_id: post.id,
+      creator_id: reporter.id,
+      reason: "my reason".to_string(),
+      category_id: Some(category.id),
+      ..Default::default()
+    };
+    let report = PostReport::report(pool, &form).await?;
+    assert_eq!(ReportStatus::Open, report.status);
+
+    // The report was added to the combined queue
+    let combined = report_combined::table
+      .filter(report_combined::post_report_id.eq(report.id))
+      .first::<ReportCombined>(&mut get_conn(pool).await?)
+      .await?;
+    assert_eq!(Some(community.id), combined.community_id);
+    assert_eq!(report.published, combined.published);
+    let read = ReportCombined::read(pool, combined.id).await?;
+    assert_eq!(Some(combined.clone()), read);
+
+    let form = ReportTriageForm {
+      assignee_id: Some(Some(moderator.id)),
+      status: Some(ReportStatus::InProgress),
+      ..Default::default()
+    };
+    combined.triage(pool, moderator.id, &form).await?;
+    let report = read_post_report(pool, report.id).await?;
+    assert_eq!(Some(moderator.id), report.assignee_id);
+    assert_eq!(Some(category.id), report.category_id);
+    assert_eq!(ReportStatus::InProgress, report.status);
+    assert!(!report.resolved);
+
+    // Resolving keeps the status in sync
+    PostReport::resolve(pool, report.id, moderator.id).await?;
+    let report = read_post_report(pool, report.id).await?;
+    assert_eq!(ReportStatus::Resolved, report.status);
+    assert!(report.resolved);
+
+    // Deleting the category removes it from the report
+    ReportCategory::delete(pool, category.id).await?;
+    let report = read_post_report(pool, report.id).await?;
+    assert_eq!(None, report.category_id);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
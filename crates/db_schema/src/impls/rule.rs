@@ -0,0 +1,327 @@
+use crate::{
+  newtypes::{CommunityId, PersonId, RuleId},
+  schema::{comment, mod_remove_comment, mod_remove_post, post, rule},
+  source::rule::{Rule, RuleInsertForm, RuleUpdateForm, RuleViolationCount},
+  traits::Crud,
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{
+  dsl::{count_star, delete, insert_into, max},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+use std::collections::HashMap;
+
+#[async_trait]
+impl Crud for Rule {
+  type InsertForm = RuleInsertForm;
+  type UpdateForm = RuleUpdateForm;
+  type IdType = RuleId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    rule_id: RuleId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(rule::table.find(rule_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Rule {
+  /// The rules of a community, or the site-wide rules if no community is given, in order.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = rule::table.into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(rule::community_id.eq(community_id)),
+      None => query.filter(rule::community_id.is_null()),
+    };
+    query
+      .order_by((rule::position, rule::id))
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The position after the last rule of a community or the site, for appending a new rule.
+  pub async fn next_position(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> Result<i32, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = rule::table.select(max(rule::position)).into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(rule::community_id.eq(community_id)),
+      None => query.filter(rule::community_id.is_null()),
+    };
+    let last = query.first::<Option<i32>>(conn).await?;
+    Ok(last.map_or(1, |p| p + 1))
+  }
+
+  /// Replaces the rules of a remote community with the ones received through federation. Rules
+  /// are matched by their ap_id, so that reports and removals which cite a rule keep pointing to
+  /// it when its text is edited. Rules without an ap_id are only matched if their text is
+  /// unchanged, otherwise the old rule and its citations are removed.
+  pub async fn replace_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    forms: Vec<RuleInsertForm>,
+  ) -> Result<Vec<Self>, Error> {
+    let existing = Self::list(pool, Some(community_id)).await?;
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          let mut existing = existing;
+          let mut rules = Vec::with_capacity(forms.len());
+          for form in forms {
+            let form = RuleInsertForm {
+              community_id: Some(community_id),
+              ..form
+            };
+            let same_text =
+              |old: &Self| old.title == form.title && old.description == form.description;
+            let matching = existing.iter().position(|old| match &form.ap_id {
+              Some(ap_id) => old.ap_id.as_ref() == Some(ap_id),
+              None => same_text(old),
+            });
+            let rule = match matching.map(|i| existing.swap_remove(i)) {
+              Some(old) => {
+                let update_form = RuleUpdateForm {
+                  position: Some(form.position),
+                  updated: (!same_text(&old)).then(|| Some(naive_now())),
+                  title: Some(form.title),
+                  description: Some(form.description),
+                };
+                diesel::update(rule::table.find(old.id))
+                  .set(update_form)
+                  .get_result::<Self>(conn)
+                  .await?
+              }
+              None => {
+                insert_into(rule::table)
+                  .values(form)
+                  .get_result::<Self>(conn)
+                  .await?
+              }
+            };
+            rules.push(rule);
+          }
+
+          let removed_ids = existing.into_iter().map(|r| r.id).collect::<Vec<_>>();
+          delete(rule::table.filter(rule::id.eq_any(removed_ids)))
+            .execute(conn)
+            .await?;
+          Ok(rules)
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  /// Counts how often posts and comments of the person were removed for breaking each rule,
+  /// optionally only in the given community. Rules which were never broken are left out.
+  pub async fn violation_counts(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<RuleViolationCount>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    let mut post_query = mod_remove_post::table
+      .inner_join(post::table)
+      .filter(post::creator_id.eq(person_id))
+      .filter(mod_remove_post::removed)
+      .filter(mod_remove_post::rule_id.is_not_null())
+      .group_by(mod_remove_post::rule_id)
+      .select((mod_remove_post::rule_id, count_star()))
+      .into_boxed();
+    let mut comment_query = mod_remove_comment::table
+      .inner_join(comment::table.inner_join(post::table))
+      .filter(comment::creator_id.eq(person_id))
+      .filter(mod_remove_comment::removed)
+      .filter(mod_remove_comment::rule_id.is_not_null())
+      .group_by(mod_remove_comment::rule_id)
+      .select((mod_remove_comment::rule_id, count_star()))
+      .into_boxed();
+    if let Some(community_id) = community_id {
+      post_query = post_query.filter(post::community_id.eq(community_id));
+      comment_query = comment_query.filter(post::community_id.eq(community_id));
+    }
+
+    let mut counts = HashMap::<RuleId, i64>::new();
+    let post_counts = post_query.load::<(Option<RuleId>, i64)>(conn).await?;
+    let comment_counts = comment_query.load::<(Option<RuleId>, i64)>(conn).await?;
+    for (rule_id, count) in post_counts.into_iter().chain(comment_counts) {
+      if let Some(rule_id) = rule_id {
+        *counts.entry(rule_id).or_default() += count;
+      }
+    }
+
+    let rules = rule::table
+      .filter(rule::id.eq_any(counts.keys().copied().collect::<Vec<_>>()))
+      // Community rules first, then site-wide rules
+      .order_by((rule::community_id, rule::position, rule::id))
+      .load::<Self>(conn)
+      .await?;
+    Ok(
+      rules
+        .into_iter()
+        .map(|rule| RuleViolationCount {
+          count: counts.get(&rule.id).copied().unwrap_or_default(),
+          rule,
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    newtypes::DbUrl,
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      moderator::{ModRemovePost, ModRemovePostForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      rule::{Rule, RuleInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_rule() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "rule_person");
+    let person = Person::create(pool, &form).await?;
+    let form = CommunityInsertForm::builder()
+      .name("test_community_rule".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+
+    let form = RuleInsertForm::builder()
+      .position(1)
+      .title("Be nice".to_string())
+      .build();
+    let site_rule = Rule::create(pool, &form).await?;
+    assert_eq!(1, Rule::next_position(pool, Some(community.id)).await?);
+    let form = RuleInsertForm::builder()
+      .community_id(Some(community.id))
+      .position(2)
+      .title("No spam".to_string())
+      .build();
+    let second = Rule::create(pool, &form).await?;
+    let form = RuleInsertForm::builder()
+      .community_id(Some(community.id))
+      .position(1)
+      .title("Stay on topic".to_string())
+      .description(Some("Posts must be about cats".to_string()))
+      .build();
+    let first = Rule::create(pool, &form).await?;
+    assert_eq!(3, Rule::next_position(pool, Some(community.id)).await?);
+
+    assert_eq!(vec![site_rule.clone()], Rule::list(pool, None).await?);
+    assert_eq!(
+      vec![first.clone(), second.clone()],
+      Rule::list(pool, Some(community.id)).await?
+    );
+
+    // Federated rules without an ap_id keep their ids only while their text is unchanged
+    let forms = vec![
+      RuleInsertForm::builder()
+        .position(1)
+        .title("No spam, please".to_string())
+        .build(),
+      RuleInsertForm::builder()
+        .position(2)
+        .title("Stay on topic".to_string())
+        .description(Some("Posts must be about cats".to_string()))
+        .build(),
+    ];
+    let rules = Rule::replace_for_community(pool, community.id, forms).await?;
+    assert_eq!(2, rules.len());
+    assert_ne!(second.id, rules[0].id);
+    assert_eq!((first.id, 2), (rules[1].id, rules[1].position));
+    assert_eq!(rules, Rule::list(pool, Some(community.id)).await?);
+
+    // Rules with an ap_id keep their ids when the text changes, extra rules are deleted
+    let ap_id: DbUrl = Url::parse("https://example.com/c/cats/rule/1")?.into();
+    let form = RuleInsertForm::builder()
+      .position(1)
+      .title("Be kind".to_string())
+      .ap_id(Some(ap_id))
+      .build();
+    let rules = Rule::replace_for_community(pool, community.id, vec![form.clone()]).await?;
+    let form = RuleInsertForm {
+      title: "Be kind, please".to_string(),
+      ..form
+    };
+    let edited = Rule::replace_for_community(pool, community.id, vec![form]).await?;
+    assert_eq!(1, edited.len());
+    assert_eq!(rules[0].id, edited[0].id);
+    assert_eq!("Be kind, please", edited[0].title);
+    assert!(edited[0].updated.is_some());
+    assert_eq!(edited, Rule::list(pool, Some(community.id)).await?);
+    let cited = edited[0].clone();
+
+    let form = PostInsertForm::builder()
+      .name("A test post rule".into())
+      .creator_id(person.id)
+      .community_id(community.id)
+      .build();
+    let post = Post::create(pool, &form).await?;
+    for rule_id in [Some(cited.id), Some(cited.id), Some(site_rule.id), None] {
+      let form = ModRemovePostForm {
+        mod_person_id: person.id,
+        post_id: post.id,
+        reason: None,
+        removed: Some(true),
+        rule_id,
+      };
+      ModRemovePost::create(pool, &form).await?;
+    }
+
+    let counts = Rule::violation_counts(pool, person.id, Some(community.id)).await?;
+    assert_eq!(2, counts.len());
+    assert_eq!((cited.id, 2), (counts[0].rule.id, counts[0].count));
+    assert_eq!((site_rule.id, 1), (counts[1].rule.id, counts[1].count));
+
+    Rule::delete(pool, site_rule.id).await?;
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,279 @@
+use crate::{
+  newtypes::{CommunityId, DbUrl, PostId, TagId},
+  schema::{post_tag, tag},
+  source::tag::{PostTag, PostTags, Tag, TagInsertForm, TagUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  deserialize::{self, FromSql},
+  dsl::{delete, insert_into},
+  pg::{Pg, PgValue},
+  result::Error,
+  serialize::{self, Output, ToSql},
+  sql_types::{Json, Nullable},
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+
+#[async_trait]
+impl Crud for Tag {
+  type InsertForm = TagInsertForm;
+  type UpdateForm = TagUpdateForm;
+  type IdType = TagId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(tag::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    tag_id: TagId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(tag::table.find(tag_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Tag {
+  /// Creates or updates a tag of a remote community. An existing tag keeps its community.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &TagInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let update_form = TagUpdateForm {
+      name: Some(form.name.clone()),
+      color: Some(form.color.clone()),
+      mod_only: form.mod_only,
+      updated: Some(form.updated),
+    };
+    insert_into(tag::table)
+      .values(form)
+      .on_conflict(tag::ap_id)
+      .do_update()
+      .set(update_form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: &DbUrl,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    tag::table
+      .filter(tag::ap_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// The tags of a community, ordered by name.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    tag::table
+      .filter(tag::community_id.eq(community_id))
+      .order_by(tag::name)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_post(pool: &mut DbPool<'_>, post_id: PostId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_tag::table
+      .inner_join(tag::table)
+      .filter(post_tag::post_id.eq(post_id))
+      .select(tag::all_columns)
+      .order_by(tag::name)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PostTag {
+  /// Replaces the tags of the post with the given list.
+  pub async fn set(pool: &mut DbPool<'_>, post_id: PostId, tag_ids: &[TagId]) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = tag_ids
+      .iter()
+      .map(|&tag_id| PostTag { post_id, tag_id })
+      .collect::<Vec<_>>();
+
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          delete(post_tag::table)
+            .filter(post_tag::post_id.eq(post_id))
+            .filter(post_tag::tag_id.ne_all(tag_ids))
+            .execute(conn)
+            .await?;
+
+          insert_into(post_tag::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+}
+
+impl FromSql<Nullable<Json>, Pg> for PostTags {
+  fn from_sql(bytes: PgValue) -> deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Json, Pg>>::from_sql(bytes)?;
+    Ok(serde_json::from_value::<PostTags>(value)?)
+  }
+
+  // Posts without tags have null instead of an empty array
+  fn from_nullable_sql(bytes: Option<PgValue>) -> deserialize::Result<Self> {
+    match bytes {
+      Some(bytes) => Self::from_sql(bytes),
+      None => Ok(Self::default()),
+    }
+  }
+}
+
+impl ToSql<Nullable<Json>, Pg> for PostTags {
+  fn to_sql(&self, out: &mut Output<Pg>) -> serialize::Result {
+    let value = serde_json::to_value(self)?;
+    <serde_json::Value as ToSql<Json, Pg>>::to_sql(&value, &mut out.reborrow())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      tag::{PostTag, Tag, TagInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_post_tags() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "tag_poster");
+    let inserted_person = Person::create(pool, &new_person).await?;
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_tag".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let mut tags = vec![];
+    for name in ["news", "discussion"] {
+      let form = TagInsertForm::builder()
+        .community_id(inserted_community.id)
+        .name(name.to_string())
+        .build();
+      tags.push(Tag::create(pool, &form).await?);
+    }
+    let tag_ids = tags.iter().map(|t| t.id).collect::<Vec<_>>();
+    // Local tags get an ap_id from the database
+    assert!(tags.iter().all(|t| t.ap_id.to_string().contains("/tag/")));
+
+    let new_post = PostInsertForm::builder()
+      .name("A tagged post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await?;
+
+    // Tags are replaced, and listed by name
+    PostTag::set(pool, inserted_post.id, &tag_ids).await?;
+    let names = Tag::list_for_post(pool, inserted_post.id)
+      .await?
+      .into_iter()
+      .map(|t| t.name)
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["discussion", "news"], names);
+    PostTag::set(pool, inserted_post.id, tag_ids.get(..1).unwrap_or_default()).await?;
+    let names = Tag::list_for_post(pool, inserted_post.id)
+      .await?
+      .into_iter()
+      .map(|t| t.name)
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["news"], names);
+
+    // Remote tags are updated by ap_id
+    let ap_id: Url = Url::parse("https://example.com/tag/1")?;
+    let remote_form = TagInsertForm::builder()
+      .community_id(inserted_community.id)
+      .name("remote".to_string())
+      .ap_id(Some(ap_id.clone().into()))
+      .local(Some(false))
+      .build();
+    let remote_tag = Tag::upsert(pool, &remote_form).await?;
+    let remote_form = TagInsertForm {
+      name: "renamed".to_string(),
+      ..remote_form
+    };
+    let renamed_tag = Tag::upsert(pool, &remote_form).await?;
+    assert_eq!(remote_tag.id, renamed_tag.id);
+    assert_eq!(
+      Some(renamed_tag),
+      Tag::read_from_apub_id(pool, &ap_id.into()).await?
+    );
+    assert_eq!(
+      3,
+      Tag::list_for_community(pool, inserted_community.id)
+        .await?
+        .len()
+    );
+
+    // The same ap_id can't move a tag into another community
+    let other_form = CommunityInsertForm::builder()
+      .name("test_community_tag_2".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let other_community = Community::create(pool, &other_form).await?;
+    let remote_form = TagInsertForm {
+      community_id: other_community.id,
+      ..remote_form
+    };
+    let moved_tag = Tag::upsert(pool, &remote_form).await?;
+    assert_eq!(inserted_community.id, moved_tag.community_id);
+    Community::delete(pool, other_community.id).await?;
+
+    Post::delete(pool, inserted_post.id).await?;
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
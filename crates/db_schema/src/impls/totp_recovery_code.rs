@@ -0,0 +1,152 @@
+use crate::{
+  newtypes::LocalUserId,
+  schema::totp_recovery_code,
+  source::totp_recovery_code::{TotpRecoveryCode, TotpRecoveryCodeInsertForm},
+  utils::{get_conn, DbPool},
+};
+use bcrypt::{hash, verify};
+use diesel::{
+  dsl::{count_star, delete, insert_into},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::{scoped_futures::ScopedFutureExt, AsyncConnection, RunQueryDsl};
+
+/// Recovery codes are long random strings, so a low bcrypt cost is sufficient. This keeps
+/// generating and checking a whole set of codes fast.
+const RECOVERY_CODE_HASH_COST: u32 = 6;
+
+impl TotpRecoveryCode {
+  /// Replaces all recovery codes of the user with the given ones. Only their hashes are stored.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    codes: &[String],
+  ) -> Result<(), Error> {
+    let forms = codes
+      .iter()
+      .map(|code| TotpRecoveryCodeInsertForm {
+        local_user_id,
+        code_hash: hash(code, RECOVERY_CODE_HASH_COST).expect("Couldn't hash recovery code"),
+      })
+      .collect::<Vec<_>>();
+
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .transaction::<_, Error, _>(|conn| {
+        async move {
+          delete(totp_recovery_code::table)
+            .filter(totp_recovery_code::local_user_id.eq(local_user_id))
+            .execute(conn)
+            .await?;
+          insert_into(totp_recovery_code::table)
+            .values(forms)
+            .execute(conn)
+            .await?;
+          Ok(())
+        }
+        .scope_boxed()
+      })
+      .await
+  }
+
+  pub async fn delete_all(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(totp_recovery_code::table)
+      .filter(totp_recovery_code::local_user_id.eq(local_user_id))
+      .execute(conn)
+      .await
+  }
+
+  /// Checks the code against the unused recovery codes of the user. A matching code is deleted, so
+  /// that each code can only be used once.
+  pub async fn consume(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    code: &str,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let codes = totp_recovery_code::table
+      .filter(totp_recovery_code::local_user_id.eq(local_user_id))
+      .load::<Self>(conn)
+      .await?;
+    let Some(matching) = codes
+      .into_iter()
+      .find(|c| verify(code, &c.code_hash).unwrap_or(false))
+    else {
+      return Ok(false);
+    };
+
+    // Only succeed if this request deleted the code, so that concurrent logins can't reuse it
+    let deleted = delete(totp_recovery_code::table.find(matching.id))
+      .execute(conn)
+      .await?;
+    Ok(deleted == 1)
+  }
+
+  pub async fn count(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    totp_recovery_code::table
+      .filter(totp_recovery_code::local_user_id.eq(local_user_id))
+      .select(count_star())
+      .first::<i64>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      totp_recovery_code::TotpRecoveryCode,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_totp_recovery_codes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "recovery_code_user");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let codes = vec!["firstcode".to_string(), "secondcode".to_string()];
+    TotpRecoveryCode::replace(pool, local_user.id, &codes).await?;
+    assert_eq!(2, TotpRecoveryCode::count(pool, local_user.id).await?);
+
+    // Each code works exactly once
+    assert!(!TotpRecoveryCode::consume(pool, local_user.id, "wrongcode").await?);
+    assert!(TotpRecoveryCode::consume(pool, local_user.id, "firstcode").await?);
+    assert!(!TotpRecoveryCode::consume(pool, local_user.id, "firstcode").await?);
+    assert_eq!(1, TotpRecoveryCode::count(pool, local_user.id).await?);
+
+    // Replacing invalidates the old codes
+    TotpRecoveryCode::replace(pool, local_user.id, &["thirdcode".to_string()]).await?;
+    assert!(!TotpRecoveryCode::consume(pool, local_user.id, "secondcode").await?);
+    assert!(TotpRecoveryCode::consume(pool, local_user.id, "thirdcode").await?);
+
+    TotpRecoveryCode::replace(pool, local_user.id, &codes).await?;
+    assert_eq!(2, TotpRecoveryCode::delete_all(pool, local_user.id).await?);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
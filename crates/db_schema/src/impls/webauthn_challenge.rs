@@ -0,0 +1,58 @@
+use crate::{
+  newtypes::LocalUserId,
+  schema::webauthn_challenge,
+  source::webauthn_challenge::{WebauthnChallenge, WebauthnChallengeForm},
+  utils::{get_conn, DbPool},
+};
+use chrono::{TimeDelta, Utc};
+use diesel::{
+  dsl::{delete, insert_into},
+  result::Error,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use serde_json::Value;
+
+/// How long users have to finish a WebAuthn ceremony.
+const CHALLENGE_TIMEOUT: TimeDelta = TimeDelta::minutes(5);
+
+impl WebauthnChallenge {
+  /// Stores the state of a new ceremony, replacing any previous one of the user.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    state: Value,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = WebauthnChallengeForm {
+      local_user_id,
+      state,
+      published: Utc::now(),
+    };
+    insert_into(webauthn_challenge::table)
+      .values(&form)
+      .on_conflict(webauthn_challenge::local_user_id)
+      .do_update()
+      .set(&form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Removes the pending ceremony of the user, and returns its state if it didn't time out yet.
+  pub async fn take(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Option<Value>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let challenge = delete(webauthn_challenge::table.find(local_user_id))
+      .get_result::<Self>(conn)
+      .await
+      .optional()?;
+    Ok(
+      challenge
+        .filter(|c| c.published > Utc::now() - CHALLENGE_TIMEOUT)
+        .map(|c| c.state),
+    )
+  }
+}
@@ -0,0 +1,162 @@
+use crate::{
+  newtypes::{LocalUserId, WebauthnCredentialId},
+  schema::webauthn_credential,
+  source::webauthn_credential::{WebauthnCredential, WebauthnCredentialInsertForm},
+  utils::{get_conn, DbPool},
+};
+use chrono::Utc;
+use diesel::{
+  dsl::{delete, insert_into, update},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+};
+use diesel_async::RunQueryDsl;
+use serde_json::Value;
+
+impl WebauthnCredential {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &WebauthnCredentialInsertForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webauthn_credential::table)
+      .values(form)
+      .returning(Self::as_returning())
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Deletes a credential, but only if it belongs to the given user.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    webauthn_credential_id: WebauthnCredentialId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(webauthn_credential::table.find(webauthn_credential_id))
+      .filter(webauthn_credential::local_user_id.eq(local_user_id))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, local_user_id: LocalUserId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webauthn_credential::table
+      .filter(webauthn_credential::local_user_id.eq(local_user_id))
+      .order_by(webauthn_credential::id)
+      .select(Self::as_select())
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The serialized public keys of the user's credentials, which are needed for authentication.
+  pub async fn list_passkeys(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Vec<(WebauthnCredentialId, Value)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webauthn_credential::table
+      .filter(webauthn_credential::local_user_id.eq(local_user_id))
+      .order_by(webauthn_credential::id)
+      .select((webauthn_credential::id, webauthn_credential::passkey))
+      .load(conn)
+      .await
+  }
+
+  /// Stores the updated signature counter after a successful authentication.
+  pub async fn mark_used(
+    pool: &mut DbPool<'_>,
+    webauthn_credential_id: WebauthnCredentialId,
+    passkey: Value,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(webauthn_credential::table.find(webauthn_credential_id))
+      .set((
+        webauthn_credential::passkey.eq(passkey),
+        webauthn_credential::last_used.eq(Utc::now()),
+      ))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      webauthn_challenge::WebauthnChallenge,
+      webauthn_credential::{WebauthnCredential, WebauthnCredentialInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webauthn_credentials() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "webauthn_user");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let other_person_form = PersonInsertForm::test_form(instance.id, "webauthn_other");
+    let other_person = Person::create(pool, &other_person_form).await?;
+    let other_local_user_form = LocalUserInsertForm::test_form(other_person.id);
+    let other_local_user = LocalUser::create(pool, &other_local_user_form, vec![]).await?;
+
+    let form = WebauthnCredentialInsertForm {
+      local_user_id: local_user.id,
+      name: "security key".to_string(),
+      passkey: json!({"counter": 0}),
+    };
+    let credential = WebauthnCredential::create(pool, &form).await?;
+    assert_eq!("security key", credential.name);
+    assert_eq!(None, credential.last_used);
+
+    WebauthnCredential::mark_used(pool, credential.id, json!({"counter": 1})).await?;
+    let passkeys = WebauthnCredential::list_passkeys(pool, local_user.id).await?;
+    assert_eq!(vec![(credential.id, json!({"counter": 1}))], passkeys);
+    let list = WebauthnCredential::list(pool, local_user.id).await?;
+    assert_eq!(1, list.len());
+    assert!(list.first().is_some_and(|c| c.last_used.is_some()));
+
+    // Other users can't delete the credential
+    assert_eq!(
+      0,
+      WebauthnCredential::delete(pool, other_local_user.id, credential.id).await?
+    );
+    assert_eq!(
+      1,
+      WebauthnCredential::delete(pool, local_user.id, credential.id).await?
+    );
+    assert!(WebauthnCredential::list(pool, local_user.id)
+      .await?
+      .is_empty());
+
+    // Challenges can only be taken once, and a new one replaces the old one
+    WebauthnChallenge::upsert(pool, local_user.id, json!("first")).await?;
+    WebauthnChallenge::upsert(pool, local_user.id, json!("second")).await?;
+    assert_eq!(
+      Some(json!("second")),
+      WebauthnChallenge::take(pool, local_user.id).await?
+    );
+    assert_eq!(None, WebauthnChallenge::take(pool, local_user.id).await?);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
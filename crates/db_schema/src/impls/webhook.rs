@@ -0,0 +1,271 @@
+use crate::{
+  newtypes::{CommunityId, WebhookDeliveryId, WebhookId},
+  schema::{webhook, webhook_delivery},
+  source::{
+    webhook::{Webhook, WebhookInsertForm, WebhookUpdateForm},
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryForm},
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use serde_json::Value;
+
+#[async_trait]
+impl Crud for Webhook {
+  type InsertForm = WebhookInsertForm;
+  type UpdateForm = WebhookUpdateForm;
+  type IdType = WebhookId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webhook::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    webhook_id: WebhookId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webhook::table.find(webhook_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Webhook {
+  /// Lists the webhooks of a community, or the site-wide webhooks if `for_community_id` is None.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    for_community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = webhook::table.into_boxed();
+    query = match for_community_id {
+      Some(for_community_id) => query.filter(webhook::community_id.eq(for_community_id)),
+      None => query.filter(webhook::community_id.is_null()),
+    };
+    query.order_by(webhook::id).load::<Self>(conn).await
+  }
+
+  /// Lists the enabled webhooks which receive events from the given community. Site-wide
+  /// webhooks receive events from all communities, and events without a community.
+  pub async fn list_for_event(
+    pool: &mut DbPool<'_>,
+    for_community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = webhook::table
+      .filter(webhook::enabled.eq(true))
+      .into_boxed();
+    query = match for_community_id {
+      Some(for_community_id) => query.filter(
+        webhook::community_id
+          .is_null()
+          .or(webhook::community_id.eq(for_community_id)),
+      ),
+      None => query.filter(webhook::community_id.is_null()),
+    };
+    query.load::<Self>(conn).await
+  }
+
+  /// Lists the enabled webhooks which have payloads waiting to be delivered.
+  pub async fn list_with_pending_deliveries(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webhook::table
+      .filter(webhook::enabled.eq(true))
+      .filter(exists(
+        webhook_delivery::table.filter(webhook_delivery::webhook_id.eq(webhook::id)),
+      ))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl WebhookDelivery {
+  /// Queues the same payload for each of the given webhooks.
+  pub async fn queue(
+    pool: &mut DbPool<'_>,
+    webhook_ids: Vec<WebhookId>,
+    payload: Value,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = webhook_ids
+      .into_iter()
+      .map(|webhook_id| WebhookDeliveryForm {
+        webhook_id,
+        payload: payload.clone(),
+      })
+      .collect::<Vec<_>>();
+    insert_into(webhook_delivery::table)
+      .values(forms)
+      .execute(conn)
+      .await
+  }
+
+  /// Returns the oldest payload which still needs to be delivered to the webhook.
+  pub async fn read_next(
+    pool: &mut DbPool<'_>,
+    for_webhook_id: WebhookId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webhook_delivery::table
+      .filter(webhook_delivery::webhook_id.eq(for_webhook_id))
+      .order_by(webhook_delivery::id)
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    delivery_id: WebhookDeliveryId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(webhook_delivery::table.find(delivery_id))
+      .execute(conn)
+      .await
+  }
+
+  /// Drops all payloads which are waiting for a webhook, eg because it was disabled.
+  pub async fn delete_for_webhook(
+    pool: &mut DbPool<'_>,
+    for_webhook_id: WebhookId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(webhook_delivery::table.filter(webhook_delivery::webhook_id.eq(for_webhook_id)))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      webhook::{Webhook, WebhookInsertForm, WebhookUpdateForm},
+      webhook_delivery::WebhookDelivery,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "webhook_creator");
+    let inserted_person = Person::create(pool, &new_person).await?;
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community webhook".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let site_form = WebhookInsertForm::builder()
+      .creator_id(inserted_person.id)
+      .url(Url::parse("https://example.com/site_hook")?.into())
+      .build();
+    let site_webhook = Webhook::create(pool, &site_form).await?;
+
+    let community_form = WebhookInsertForm::builder()
+      .creator_id(inserted_person.id)
+      .community_id(Some(inserted_community.id))
+      .url(Url::parse("https://example.com/community_hook")?.into())
+      .build();
+    let community_webhook = Webhook::create(pool, &community_form).await?;
+    assert!(!community_webhook.secret.is_empty());
+
+    assert_eq!(vec![site_webhook.clone()], Webhook::list(pool, None).await?);
+    assert_eq!(
+      vec![community_webhook.clone()],
+      Webhook::list(pool, Some(inserted_community.id)).await?
+    );
+
+    // Community events go to both webhooks, site events only to the site webhook
+    assert_eq!(
+      2,
+      Webhook::list_for_event(pool, Some(inserted_community.id))
+        .await?
+        .len()
+    );
+    assert_eq!(1, Webhook::list_for_event(pool, None).await?.len());
+
+    WebhookDelivery::queue(
+      pool,
+      vec![site_webhook.id, community_webhook.id],
+      json!({"event": "test"}),
+    )
+    .await?;
+    WebhookDelivery::queue(pool, vec![site_webhook.id], json!({"event": "test2"})).await?;
+    assert_eq!(2, Webhook::list_with_pending_deliveries(pool).await?.len());
+
+    // Deliveries are returned in order
+    let next = WebhookDelivery::read_next(pool, site_webhook.id)
+      .await?
+      .expect("pending delivery");
+    assert_eq!(json!({"event": "test"}), next.payload);
+    WebhookDelivery::delete(pool, next.id).await?;
+    let next = WebhookDelivery::read_next(pool, site_webhook.id)
+      .await?
+      .expect("pending delivery");
+    assert_eq!(json!({"event": "test2"}), next.payload);
+
+    // Disabled webhooks don't receive events
+    let disable_form = WebhookUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    Webhook::update(pool, community_webhook.id, &disable_form).await?;
+    WebhookDelivery::delete_for_webhook(pool, community_webhook.id).await?;
+    assert_eq!(
+      1,
+      Webhook::list_for_event(pool, Some(inserted_community.id))
+        .await?
+        .len()
+    );
+    assert_eq!(
+      vec![site_webhook.id],
+      Webhook::list_with_pending_deliveries(pool)
+        .await?
+        .into_iter()
+        .map(|w| w.id)
+        .collect::<Vec<_>>()
+    );
+
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -122,6 +122,8 @@ pub enum ListingType {
   Subscribed,
   /// Content that you can moderate (because you are a moderator of the community it is posted to)
   ModeratorView,
+  /// Content from people you follow.
+  FollowedPeople,
 }
 
 #[derive(
@@ -143,6 +145,8 @@ pub enum RegistrationMode {
   /// Open to all.
   #[default]
   Open,
+  /// Only with an invite code from an existing user.
+  InviteOnly,
 }
 
 #[derive(
@@ -210,6 +214,8 @@ pub enum ModlogActionType {
   AdminPurgeCommunity,
   AdminPurgePost,
   AdminPurgeComment,
+  ModWarning,
+  ModResolveBanAppeal,
 }
 
 #[derive(
@@ -237,14 +243,114 @@ pub enum PostFeatureType {
 #[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
 #[cfg_attr(feature = "full", ts(export))]
 /// Defines who can browse and interact with content in a community.
-///
-/// TODO: Also use this to define private communities
 pub enum CommunityVisibility {
   /// Public community, any local or federated user can interact.
   #[default]
   Public,
   /// Unfederated community, only local users can interact.
   LocalOnly,
+  /// Content is only visible to followers which were approved by a moderator, and only they can
+  /// interact.
+  Private,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::NotificationTypeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The kind of event a notification was created for.
+pub enum NotificationType {
+  /// Someone replied to your post or comment.
+  Reply,
+  /// Someone mentioned you in a comment.
+  Mention,
+  /// Someone sent you a private message.
+  PrivateMessage,
+  /// A moderator removed your post.
+  ModRemovePost,
+  /// A moderator removed your comment.
+  ModRemoveComment,
+  /// A moderator locked your post.
+  ModLockPost,
+  /// A moderator banned you from a community.
+  ModBanFromCommunity,
+  /// A report you filed was resolved.
+  ReportResolved,
+  /// A moderator or admin gave you a formal warning.
+  ModWarning,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(AsExpression, FromSqlRow, TS))]
+#[cfg_attr(feature = "full", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Limits what an API token can be used for. Each route declares the scope which it needs.
+///
+/// Stored as text, see the ToSql and FromSql impls in `impls::login_token`.
+pub enum ApiTokenScope {
+  /// Read-only requests which don't need one of the other scopes.
+  Read,
+  /// Create and edit posts.
+  Post,
+  /// Create and edit comments and private messages.
+  Comment,
+  /// Vote on posts, comments and polls.
+  Vote,
+  /// Moderation actions in communities.
+  Moderate,
+  /// Site administration.
+  Admin,
+}
+
+/// Marks routes which can't be used by API tokens with any scope, only by login sessions. Like
+/// [ApiTokenScope] it is declared with `app_data`.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionOnly;
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ChallengeTypeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The kind of challenge which new users have to solve to keep out bots.
+pub enum ChallengeType {
+  /// An image or audio captcha.
+  #[default]
+  Captcha,
+  /// The client has to find a nonce for which the sha256 hash of the challenge and nonce starts
+  /// with a number of zero bits.
+  ProofOfWork,
+}
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ReportStatusEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// Where a report is in the moderation workflow.
+pub enum ReportStatus {
+  /// Nobody has looked at the report yet.
+  #[default]
+  Open,
+  /// A moderator is handling the report.
+  InProgress,
+  /// The moderators handed the report over to the admins.
+  Escalated,
+  Resolved,
 }
 
 /// Wrapper for assert_eq! macro. Checks that vec matches the given length, and prints the
@@ -5,6 +5,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "actor_type_enum"))]
     pub struct ActorTypeEnum;
 
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "challenge_type_enum"))]
+    pub struct ChallengeTypeEnum;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "community_visibility"))]
     pub struct CommunityVisibility;
@@ -17,6 +21,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "ltree"))]
     pub struct Ltree;
 
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "notification_type_enum"))]
+    pub struct NotificationTypeEnum;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "post_listing_mode_enum"))]
     pub struct PostListingModeEnum;
@@ -25,6 +33,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "registration_mode_enum"))]
     pub struct RegistrationModeEnum;
 
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "report_status_enum"))]
+    pub struct ReportStatusEnum;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "sort_type_enum"))]
     pub struct SortTypeEnum;
@@ -68,6 +80,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    ban_appeal (id) {
+        id -> Int4,
+        person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        reason -> Text,
+        resolver_id -> Nullable<Int4>,
+        accepted -> Bool,
+        deny_reason -> Nullable<Text>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     captcha_answer (uuid) {
         uuid -> Uuid,
@@ -132,6 +158,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReportStatusEnum;
+
     comment_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -142,6 +171,19 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        category_id -> Nullable<Int4>,
+        assignee_id -> Nullable<Int4>,
+        status -> ReportStatusEnum,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    comment_revision (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
     }
 }
 
@@ -251,6 +293,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    conversation (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        creator_id -> Int4,
+        local -> Bool,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    conversation_participant (conversation_id, person_id) {
+        conversation_id -> Int4,
+        person_id -> Int4,
+        published -> Timestamptz,
+        last_read -> Timestamptz,
+        muted -> Bool,
+        left_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     custom_emoji (id) {
         id -> Int4,
@@ -273,6 +337,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    data_export (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        published -> Timestamptz,
+        finished -> Nullable<Timestamptz>,
+        archive -> Nullable<Jsonb>,
+        outbox -> Nullable<Jsonb>,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -340,6 +415,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    invite (id) {
+        id -> Int4,
+        #[max_length = 32]
+        code -> Varchar,
+        creator_id -> Int4,
+        max_uses -> Int4,
+        uses -> Int4,
+        expires -> Nullable<Timestamptz>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    keyword_filter (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        #[max_length = 255]
+        keyword -> Varchar,
+        is_regex -> Bool,
+        community_id -> Nullable<Int4>,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     language (id) {
         id -> Int4,
@@ -364,6 +464,7 @@ diesel::table! {
     use super::sql_types::RegistrationModeEnum;
     use super::sql_types::PostListingModeEnum;
     use super::sql_types::SortTypeEnum;
+    use super::sql_types::ChallengeTypeEnum;
 
     local_site (id) {
         id -> Int4,
@@ -393,6 +494,15 @@ diesel::table! {
         federation_signed_fetch -> Bool,
         default_post_listing_mode -> PostListingModeEnum,
         default_sort_type -> SortTypeEnum,
+        oauth_registration -> Bool,
+        invites_per_user -> Int4,
+        invite_min_account_age_days -> Int4,
+        challenge_type -> ChallengeTypeEnum,
+        proof_of_work_difficulty -> Int4,
+        new_account_post_challenge_hours -> Int4,
+        strike_limit -> Int4,
+        strike_expiry_days -> Int4,
+        strike_ban_days -> Int4,
     }
 }
 
@@ -463,6 +573,8 @@ diesel::table! {
         enable_animated_images -> Bool,
         collapse_bot_comments -> Bool,
         last_donation_notification -> Timestamptz,
+        send_login_alerts_to_email -> Bool,
+        invited_by -> Nullable<Int4>,
     }
 }
 
@@ -490,6 +602,12 @@ diesel::table! {
         published -> Timestamptz,
         ip -> Nullable<Text>,
         user_agent -> Nullable<Text>,
+        #[max_length = 255]
+        name -> Nullable<Varchar>,
+        scopes -> Nullable<Array<Text>>,
+        expires -> Nullable<Timestamptz>,
+        id -> Int4,
+        last_used -> Nullable<Timestamptz>,
     }
 }
 
@@ -571,6 +689,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_note (id) {
+        id -> Int4,
+        person_id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        content -> Text,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     mod_remove_comment (id) {
         id -> Int4,
@@ -579,6 +708,7 @@ diesel::table! {
         reason -> Nullable<Text>,
         removed -> Bool,
         when_ -> Timestamptz,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -601,6 +731,19 @@ diesel::table! {
         reason -> Nullable<Text>,
         removed -> Bool,
         when_ -> Timestamptz,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    mod_resolve_ban_appeal (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        accepted -> Bool,
+        reason -> Nullable<Text>,
+        when_ -> Timestamptz,
     }
 }
 
@@ -614,6 +757,88 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_warning (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        reason -> Text,
+        expires -> Timestamptz,
+        escalated -> Bool,
+        when_ -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    multi_community (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        public -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    multi_community_entry (multi_community_id, community_id) {
+        multi_community_id -> Int4,
+        community_id -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::NotificationTypeEnum;
+
+    notification (id) {
+        id -> Int4,
+        recipient_id -> Int4,
+        kind -> NotificationTypeEnum,
+        creator_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        community_id -> Nullable<Int4>,
+        private_message_id -> Nullable<Int4>,
+        reason -> Nullable<Text>,
+        read -> Bool,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    oauth_account (oauth_provider_id, local_user_id) {
+        local_user_id -> Int4,
+        oauth_provider_id -> Int4,
+        oauth_user_id -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    oauth_provider (id) {
+        id -> Int4,
+        display_name -> Text,
+        issuer -> Text,
+        authorization_endpoint -> Text,
+        token_endpoint -> Text,
+        userinfo_endpoint -> Text,
+        id_claim -> Text,
+        client_id -> Text,
+        client_secret -> Text,
+        scopes -> Text,
+        auto_verify_email -> Bool,
+        account_linking_enabled -> Bool,
+        use_pkce -> Bool,
+        enabled -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     password_reset_request (id) {
         id -> Int4,
@@ -651,6 +876,9 @@ diesel::table! {
         bot_account -> Bool,
         ban_expires -> Nullable<Timestamptz>,
         instance_id -> Int4,
+        also_known_as -> Array<Text>,
+        #[max_length = 255]
+        moved_to -> Nullable<Varchar>,
     }
 }
 
@@ -661,6 +889,7 @@ diesel::table! {
         post_score -> Int8,
         comment_count -> Int8,
         comment_score -> Int8,
+        followers -> Int8,
     }
 }
 
@@ -707,6 +936,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    poll (post_id) {
+        post_id -> Int4,
+        multiple_choice -> Bool,
+        options -> Array<Text>,
+        vote_counts -> Array<Int4>,
+        voters_count -> Int4,
+        end_time -> Nullable<Timestamptz>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    poll_vote (post_id, person_id, choice) {
+        post_id -> Int4,
+        person_id -> Int4,
+        choice -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     post (id) {
         id -> Int4,
@@ -735,6 +986,7 @@ diesel::table! {
         featured_local -> Bool,
         url_content_type -> Nullable<Text>,
         alt_text -> Nullable<Text>,
+        scheduled_publish_time -> Nullable<Timestamptz>,
     }
 }
 
@@ -786,6 +1038,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReportStatusEnum;
+
     post_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -799,6 +1054,23 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        category_id -> Nullable<Int4>,
+        assignee_id -> Nullable<Int4>,
+        status -> ReportStatusEnum,
+        rule_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    post_revision (id) {
+        id -> Int4,
+        post_id -> Int4,
+        #[max_length = 200]
+        name -> Varchar,
+        #[max_length = 2000]
+        url -> Nullable<Varchar>,
+        body -> Nullable<Text>,
+        published -> Timestamptz,
     }
 }
 
@@ -810,6 +1082,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    post_tag (post_id, tag_id) {
+        post_id -> Int4,
+        tag_id -> Int4,
+    }
+}
+
 diesel::table! {
     private_message (id) {
         id -> Int4,
@@ -824,10 +1103,14 @@ diesel::table! {
         ap_id -> Varchar,
         local -> Bool,
         removed -> Bool,
+        conversation_id -> Nullable<Int4>,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReportStatusEnum;
+
     private_message_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -838,6 +1121,9 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        category_id -> Nullable<Int4>,
+        assignee_id -> Nullable<Int4>,
+        status -> ReportStatusEnum,
     }
 }
 
@@ -866,6 +1152,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    report_category (id) {
+        id -> Int4,
+        name -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    report_combined (id) {
+        id -> Int4,
+        published -> Timestamptz,
+        community_id -> Nullable<Int4>,
+        post_report_id -> Nullable<Int4>,
+        comment_report_id -> Nullable<Int4>,
+        private_message_report_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    rule (id) {
+        id -> Int4,
+        community_id -> Nullable<Int4>,
+        position -> Int4,
+        title -> Text,
+        description -> Nullable<Text>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+        #[max_length = 255]
+        ap_id -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     secret (id) {
         id -> Int4,
@@ -936,6 +1255,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tag (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        community_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 7]
+        color -> Nullable<Varchar>,
+        mod_only -> Bool,
+        local -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     tagline (id) {
         id -> Int4,
@@ -946,12 +1282,67 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    totp_recovery_code (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        code_hash -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webauthn_challenge (local_user_id) {
+        local_user_id -> Int4,
+        state -> Jsonb,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webauthn_credential (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        passkey -> Jsonb,
+        published -> Timestamptz,
+        last_used -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webhook (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        url -> Text,
+        secret -> Text,
+        enabled -> Bool,
+        fail_count -> Int4,
+        last_successful_delivery -> Nullable<Timestamptz>,
+        last_failed_delivery -> Nullable<Timestamptz>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Int8,
+        webhook_id -> Int4,
+        payload -> Jsonb,
+        published -> Timestamptz,
+    }
+}
+
 diesel::joinable!(admin_purge_comment -> person (admin_person_id));
 diesel::joinable!(admin_purge_comment -> post (post_id));
 diesel::joinable!(admin_purge_community -> person (admin_person_id));
 diesel::joinable!(admin_purge_person -> person (admin_person_id));
 diesel::joinable!(admin_purge_post -> community (community_id));
 diesel::joinable!(admin_purge_post -> person (admin_person_id));
+diesel::joinable!(ban_appeal -> community (community_id));
 diesel::joinable!(comment -> language (language_id));
 diesel::joinable!(comment -> person (creator_id));
 diesel::joinable!(comment -> post (post_id));
@@ -962,6 +1353,9 @@ diesel::joinable!(comment_like -> post (post_id));
 diesel::joinable!(comment_reply -> comment (comment_id));
 diesel::joinable!(comment_reply -> person (recipient_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_report -> report_category (category_id));
+diesel::joinable!(comment_report -> rule (rule_id));
+diesel::joinable!(comment_revision -> comment (comment_id));
 diesel::joinable!(comment_saved -> comment (comment_id));
 diesel::joinable!(comment_saved -> person (person_id));
 diesel::joinable!(community -> instance (instance_id));
@@ -976,14 +1370,21 @@ diesel::joinable!(community_moderator -> community (community_id));
 diesel::joinable!(community_moderator -> person (person_id));
 diesel::joinable!(community_person_ban -> community (community_id));
 diesel::joinable!(community_person_ban -> person (person_id));
+diesel::joinable!(conversation -> person (creator_id));
+diesel::joinable!(conversation_participant -> conversation (conversation_id));
+diesel::joinable!(conversation_participant -> person (person_id));
 diesel::joinable!(custom_emoji -> local_site (local_site_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
+diesel::joinable!(data_export -> local_user (local_user_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
 diesel::joinable!(federation_blocklist -> instance (instance_id));
 diesel::joinable!(federation_queue_state -> instance (instance_id));
 diesel::joinable!(instance_block -> instance (instance_id));
 diesel::joinable!(instance_block -> person (person_id));
+diesel::joinable!(invite -> local_user (creator_id));
+diesel::joinable!(keyword_filter -> community (community_id));
+diesel::joinable!(keyword_filter -> local_user (local_user_id));
 diesel::joinable!(local_image -> local_user (local_user_id));
 diesel::joinable!(local_site -> site (site_id));
 diesel::joinable!(local_site_rate_limit -> local_site (local_site_id));
@@ -1000,13 +1401,27 @@ diesel::joinable!(mod_hide_community -> community (community_id));
 diesel::joinable!(mod_hide_community -> person (mod_person_id));
 diesel::joinable!(mod_lock_post -> person (mod_person_id));
 diesel::joinable!(mod_lock_post -> post (post_id));
+diesel::joinable!(mod_note -> community (community_id));
 diesel::joinable!(mod_remove_comment -> comment (comment_id));
 diesel::joinable!(mod_remove_comment -> person (mod_person_id));
+diesel::joinable!(mod_remove_comment -> rule (rule_id));
 diesel::joinable!(mod_remove_community -> community (community_id));
 diesel::joinable!(mod_remove_community -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> post (post_id));
+diesel::joinable!(mod_remove_post -> rule (rule_id));
+diesel::joinable!(mod_resolve_ban_appeal -> community (community_id));
 diesel::joinable!(mod_transfer_community -> community (community_id));
+diesel::joinable!(mod_warning -> community (community_id));
+diesel::joinable!(multi_community -> person (creator_id));
+diesel::joinable!(multi_community_entry -> community (community_id));
+diesel::joinable!(multi_community_entry -> multi_community (multi_community_id));
+diesel::joinable!(notification -> comment (comment_id));
+diesel::joinable!(notification -> community (community_id));
+diesel::joinable!(notification -> post (post_id));
+diesel::joinable!(notification -> private_message (private_message_id));
+diesel::joinable!(oauth_account -> local_user (local_user_id));
+diesel::joinable!(oauth_account -> oauth_provider (oauth_provider_id));
 diesel::joinable!(password_reset_request -> local_user (local_user_id));
 diesel::joinable!(person -> instance (instance_id));
 diesel::joinable!(person_aggregates -> person (person_id));
@@ -1015,6 +1430,9 @@ diesel::joinable!(person_mention -> comment (comment_id));
 diesel::joinable!(person_mention -> person (recipient_id));
 diesel::joinable!(person_post_aggregates -> person (person_id));
 diesel::joinable!(person_post_aggregates -> post (post_id));
+diesel::joinable!(poll -> post (post_id));
+diesel::joinable!(poll_vote -> person (person_id));
+diesel::joinable!(poll_vote -> poll (post_id));
 diesel::joinable!(post -> community (community_id));
 diesel::joinable!(post -> language (language_id));
 diesel::joinable!(post -> person (creator_id));
@@ -1029,28 +1447,49 @@ diesel::joinable!(post_like -> post (post_id));
 diesel::joinable!(post_read -> person (person_id));
 diesel::joinable!(post_read -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_report -> report_category (category_id));
+diesel::joinable!(post_report -> rule (rule_id));
+diesel::joinable!(post_revision -> post (post_id));
 diesel::joinable!(post_saved -> person (person_id));
 diesel::joinable!(post_saved -> post (post_id));
+diesel::joinable!(post_tag -> post (post_id));
+diesel::joinable!(post_tag -> tag (tag_id));
+diesel::joinable!(private_message -> conversation (conversation_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(private_message_report -> report_category (category_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
+diesel::joinable!(report_combined -> comment_report (comment_report_id));
+diesel::joinable!(report_combined -> community (community_id));
+diesel::joinable!(report_combined -> post_report (post_report_id));
+diesel::joinable!(report_combined -> private_message_report (private_message_report_id));
+diesel::joinable!(rule -> community (community_id));
 diesel::joinable!(site -> instance (instance_id));
 diesel::joinable!(site_aggregates -> site (site_id));
 diesel::joinable!(site_language -> language (language_id));
 diesel::joinable!(site_language -> site (site_id));
+diesel::joinable!(tag -> community (community_id));
 diesel::joinable!(tagline -> local_site (local_site_id));
+diesel::joinable!(totp_recovery_code -> local_user (local_user_id));
+diesel::joinable!(webauthn_challenge -> local_user (local_user_id));
+diesel::joinable!(webauthn_credential -> local_user (local_user_id));
+diesel::joinable!(webhook -> community (community_id));
+diesel::joinable!(webhook -> person (creator_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     admin_purge_comment,
     admin_purge_community,
     admin_purge_person,
     admin_purge_post,
+    ban_appeal,
     captcha_answer,
     comment,
     comment_aggregates,
     comment_like,
     comment_reply,
     comment_report,
+    comment_revision,
     comment_saved,
     community,
     community_aggregates,
@@ -1059,8 +1498,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     community_language,
     community_moderator,
     community_person_ban,
+    conversation,
+    conversation_participant,
     custom_emoji,
     custom_emoji_keyword,
+    data_export,
     email_verification,
     federation_allowlist,
     federation_blocklist,
@@ -1068,6 +1510,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     image_details,
     instance,
     instance_block,
+    invite,
+    keyword_filter,
     language,
     local_image,
     local_site,
@@ -1084,10 +1528,18 @@ diesel::allow_tables_to_appear_in_same_query!(
     mod_feature_post,
     mod_hide_community,
     mod_lock_post,
+    mod_note,
     mod_remove_comment,
     mod_remove_community,
     mod_remove_post,
+    mod_resolve_ban_appeal,
     mod_transfer_community,
+    mod_warning,
+    multi_community,
+    multi_community_entry,
+    notification,
+    oauth_account,
+    oauth_provider,
     password_reset_request,
     person,
     person_aggregates,
@@ -1096,22 +1548,35 @@ diesel::allow_tables_to_appear_in_same_query!(
     person_follower,
     person_mention,
     person_post_aggregates,
+    poll,
+    poll_vote,
     post,
     post_aggregates,
     post_hide,
     post_like,
     post_read,
     post_report,
+    post_revision,
     post_saved,
+    post_tag,
     private_message,
     private_message_report,
     received_activity,
     registration_application,
     remote_image,
+    report_category,
+    report_combined,
+    rule,
     secret,
     sent_activity,
     site,
     site_aggregates,
     site_language,
+    tag,
     tagline,
+    totp_recovery_code,
+    webauthn_challenge,
+    webauthn_credential,
+    webhook,
+    webhook_delivery,
 );
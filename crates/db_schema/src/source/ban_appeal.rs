@@ -0,0 +1,46 @@
+use crate::newtypes::{BanAppealId, CommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::ban_appeal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An appeal of a banned person against their ban.
+pub struct BanAppeal {
+  pub id: BanAppealId,
+  pub person_id: PersonId,
+  /// The community of a community ban. Without it, the site ban is appealed.
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  /// The moderator or admin who resolved the appeal. Unresolved appeals don't have one.
+  pub resolver_id: Option<PersonId>,
+  pub accepted: bool,
+  pub deny_reason: Option<String>,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealInsertForm {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+}
+
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealUpdateForm {
+  pub resolver_id: Option<Option<PersonId>>,
+  pub accepted: Option<bool>,
+  pub deny_reason: Option<Option<String>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
@@ -1,4 +1,7 @@
-use crate::newtypes::{CommentId, CommentReportId, PersonId};
+use crate::{
+  newtypes::{CommentId, CommentReportId, PersonId, ReportCategoryId, RuleId},
+  ReportStatus,
+};
 #[cfg(feature = "full")]
 use crate::schema::comment_report;
 use chrono::{DateTime, Utc};
@@ -28,6 +31,12 @@ pub struct CommentReport {
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  pub category_id: Option<ReportCategoryId>,
+  /// The moderator or admin who is handling the report.
+  pub assignee_id: Option<PersonId>,
+  pub status: ReportStatus,
+  /// The rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone)]
@@ -38,4 +47,6 @@ pub struct CommentReportForm {
   pub comment_id: CommentId,
   pub original_comment_text: String,
   pub reason: String,
+  pub category_id: Option<ReportCategoryId>,
+  pub rule_id: Option<RuleId>,
 }
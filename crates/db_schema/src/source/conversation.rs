@@ -0,0 +1,82 @@
+use crate::newtypes::{ConversationId, DbUrl, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{conversation, conversation_participant};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A group conversation between multiple persons.
+///
+/// Its messages are private messages with `conversation_id` set. Federated as the `context` of
+/// those messages.
+pub struct Conversation {
+  pub id: ConversationId,
+  pub ap_id: DbUrl,
+  pub creator_id: PersonId,
+  pub local: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+pub struct ConversationInsertForm {
+  #[builder(!default)]
+  pub creator_id: PersonId,
+  pub ap_id: Option<DbUrl>,
+  pub local: Option<bool>,
+  pub published: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Associations, Identifiable, TS)
+)]
+#[cfg_attr(
+  feature = "full",
+  diesel(belongs_to(crate::source::conversation::Conversation))
+)]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+#[cfg_attr(feature = "full", diesel(primary_key(conversation_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A participant of a group conversation, with their own read state and settings.
+pub struct ConversationParticipant {
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+  pub published: DateTime<Utc>,
+  /// Messages published after this time are unread.
+  pub last_read: DateTime<Utc>,
+  /// Don't send notifications for new messages.
+  pub muted: bool,
+  /// Set once the participant left, they don't receive any further messages.
+  pub left_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+pub struct ConversationParticipantForm {
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+pub struct ConversationParticipantUpdateForm {
+  pub last_read: Option<DateTime<Utc>>,
+  pub muted: Option<bool>,
+  pub left_at: Option<Option<DateTime<Utc>>>,
+}
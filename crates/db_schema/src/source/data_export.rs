@@ -0,0 +1,24 @@
+use crate::newtypes::{DataExportId, LocalUserId};
+#[cfg(feature = "full")]
+use crate::schema::data_export;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = data_export))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An archive of all the data which a user created. The archive itself is only loaded when it is
+/// downloaded.
+pub struct DataExport {
+  pub id: DataExportId,
+  pub local_user_id: LocalUserId,
+  pub published: DateTime<Utc>,
+  /// When the archive was ready for download. Empty while it is still being generated.
+  pub finished: Option<DateTime<Utc>>,
+}
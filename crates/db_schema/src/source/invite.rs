@@ -0,0 +1,52 @@
+#[cfg(feature = "full")]
+use crate::schema::invite;
+use crate::{
+  newtypes::{InviteId, LocalUserId, PersonId},
+  source::person::Person,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = invite))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An invite code which lets others sign up when the site is invite-only.
+pub struct Invite {
+  pub id: InviteId,
+  pub code: String,
+  pub creator_id: LocalUserId,
+  /// How many users can sign up with this code.
+  pub max_uses: i32,
+  pub uses: i32,
+  pub expires: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = invite))]
+pub struct InviteInsertForm {
+  /// Generated randomly if not given.
+  pub code: Option<String>,
+  #[builder(!default)]
+  pub creator_id: LocalUserId,
+  pub max_uses: Option<i32>,
+  pub expires: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A user who signed up with an invite, directly or indirectly from the root of an invite tree.
+pub struct InvitedPerson {
+  pub person: Person,
+  pub invited_by: PersonId,
+}
@@ -0,0 +1,40 @@
+use crate::newtypes::{CommunityId, KeywordFilterId, LocalUserId};
+#[cfg(feature = "full")]
+use crate::schema::keyword_filter;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = keyword_filter))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A keyword or regex which hides matching posts and comments from your listings.
+pub struct KeywordFilter {
+  pub id: KeywordFilterId,
+  pub local_user_id: LocalUserId,
+  pub keyword: String,
+  /// Regexes are matched case-insensitively, using the Postgres regex syntax.
+  pub is_regex: bool,
+  /// If set, the filter only applies to this community.
+  pub community_id: Option<CommunityId>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = keyword_filter))]
+pub struct KeywordFilterInsertForm {
+  #[builder(!default)]
+  pub local_user_id: LocalUserId,
+  #[builder(!default)]
+  pub keyword: String,
+  pub is_regex: Option<bool>,
+  pub community_id: Option<CommunityId>,
+}
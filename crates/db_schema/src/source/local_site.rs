@@ -2,6 +2,7 @@
 use crate::schema::local_site;
 use crate::{
   newtypes::{LocalSiteId, SiteId},
+  ChallengeType,
   ListingType,
   PostListingMode,
   RegistrationMode,
@@ -70,6 +71,25 @@ pub struct LocalSite {
   pub default_post_listing_mode: PostListingMode,
   /// Default value for [LocalUser.post_listing_mode]
   pub default_sort_type: SortType,
+  /// Whether new users can sign up through an OAuth provider.
+  pub oauth_registration: bool,
+  /// How many unused invites each user can have in invite-only mode. Admins aren't limited.
+  pub invites_per_user: i32,
+  /// How old an account needs to be before it can create invites.
+  pub invite_min_account_age_days: i32,
+  /// Which kind of challenge has to be solved by new users.
+  pub challenge_type: ChallengeType,
+  /// How many leading zero bits the proof of work hash needs.
+  pub proof_of_work_difficulty: i32,
+  /// Accounts younger than this many hours need to solve a challenge to create a post. Disabled
+  /// if zero.
+  pub new_account_post_challenge_hours: i32,
+  /// How many active warnings lead to an automatic ban. Disabled if zero.
+  pub strike_limit: i32,
+  /// After how many days a warning expires and stops counting as strike.
+  pub strike_expiry_days: i32,
+  /// How many days the automatic ban lasts.
+  pub strike_ban_days: i32,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -101,6 +121,15 @@ pub struct LocalSiteInsertForm {
   pub federation_signed_fetch: Option<bool>,
   pub default_post_listing_mode: Option<PostListingMode>,
   pub default_sort_type: Option<SortType>,
+  pub oauth_registration: Option<bool>,
+  pub invites_per_user: Option<i32>,
+  pub invite_min_account_age_days: Option<i32>,
+  pub challenge_type: Option<ChallengeType>,
+  pub proof_of_work_difficulty: Option<i32>,
+  pub new_account_post_challenge_hours: Option<i32>,
+  pub strike_limit: Option<i32>,
+  pub strike_expiry_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
 }
 
 #[derive(Clone, Default)]
@@ -130,4 +159,13 @@ pub struct LocalSiteUpdateForm {
   pub federation_signed_fetch: Option<bool>,
   pub default_post_listing_mode: Option<PostListingMode>,
   pub default_sort_type: Option<SortType>,
+  pub oauth_registration: Option<bool>,
+  pub invites_per_user: Option<i32>,
+  pub invite_min_account_age_days: Option<i32>,
+  pub challenge_type: Option<ChallengeType>,
+  pub proof_of_work_difficulty: Option<i32>,
+  pub new_account_post_challenge_hours: Option<i32>,
+  pub strike_limit: Option<i32>,
+  pub strike_expiry_days: Option<i32>,
+  pub strike_ban_days: Option<i32>,
 }
@@ -70,6 +70,10 @@ pub struct LocalUser {
   /// The last time a donation request was shown to this user. If this is more than a year ago,
   /// a new notification request should be shown.
   pub last_donation_notification: DateTime<Utc>,
+  /// Send an email when someone logs in from a new IP address or device.
+  pub send_login_alerts_to_email: bool,
+  /// The user whose invite was used to sign up.
+  pub invited_by: Option<LocalUserId>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -128,6 +132,10 @@ pub struct LocalUserInsertForm {
   pub collapse_bot_comments: Option<bool>,
   #[new(default)]
   pub last_donation_notification: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub send_login_alerts_to_email: Option<bool>,
+  #[new(default)]
+  pub invited_by: Option<LocalUserId>,
 }
 
 #[derive(Clone, Default)]
@@ -160,4 +168,5 @@ pub struct LocalUserUpdateForm {
   pub enable_animated_images: Option<bool>,
   pub collapse_bot_comments: Option<bool>,
   pub last_donation_notification: Option<DateTime<Utc>>,
+  pub send_login_alerts_to_email: Option<bool>,
 }
@@ -1,13 +1,17 @@
 #[cfg(feature = "full")]
 use crate::schema::login_token;
-use crate::{newtypes::LocalUserId, sensitive::SensitiveString};
+use crate::{
+  newtypes::{LocalUserId, LoginTokenId},
+  sensitive::SensitiveString,
+  ApiTokenScope,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use ts_rs::TS;
 
-/// Stores data related to a specific user login session.
+/// Stores data related to a specific user login session, or to a personal API token.
 #[skip_serializing_none]
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
@@ -26,6 +30,16 @@ pub struct LoginToken {
   /// Could be stored in truncated format, or store derived information for better privacy.
   pub ip: Option<String>,
   pub user_agent: Option<String>,
+  /// Name of the API token, not set for normal login sessions.
+  pub name: Option<String>,
+  /// What the API token can be used for. Login sessions have full access.
+  pub scopes: Option<Vec<ApiTokenScope>>,
+  /// When the API token stops being valid.
+  pub expires: Option<DateTime<Utc>>,
+  /// Used to revoke a specific login, as the token itself is never shown.
+  pub id: LoginTokenId,
+  /// Updated at most every few minutes. Idle logins are removed after a while.
+  pub last_used: Option<DateTime<Utc>>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -35,4 +49,7 @@ pub struct LoginTokenCreateForm {
   pub user_id: LocalUserId,
   pub ip: Option<String>,
   pub user_agent: Option<String>,
+  pub name: Option<String>,
+  pub scopes: Option<Vec<ApiTokenScope>>,
+  pub expires: Option<DateTime<Utc>>,
 }
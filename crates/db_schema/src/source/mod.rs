@@ -4,14 +4,18 @@ use url::Url;
 #[cfg(feature = "full")]
 pub mod activity;
 pub mod actor_language;
+pub mod ban_appeal;
 pub mod captcha_answer;
 pub mod comment;
 pub mod comment_reply;
 pub mod comment_report;
+pub mod comment_revision;
 pub mod community;
 pub mod community_block;
+pub mod conversation;
 pub mod custom_emoji;
 pub mod custom_emoji_keyword;
+pub mod data_export;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
@@ -19,6 +23,8 @@ pub mod federation_queue_state;
 pub mod images;
 pub mod instance;
 pub mod instance_block;
+pub mod invite;
+pub mod keyword_filter;
 pub mod language;
 pub mod local_site;
 pub mod local_site_rate_limit;
@@ -26,19 +32,38 @@ pub mod local_site_url_blocklist;
 pub mod local_user;
 pub mod local_user_vote_display_mode;
 pub mod login_token;
+pub mod mod_note;
 pub mod moderator;
+pub mod multi_community;
+pub mod notification;
+pub mod oauth_account;
+pub mod oauth_provider;
 pub mod password_reset_request;
 pub mod person;
 pub mod person_block;
 pub mod person_mention;
+pub mod poll;
 pub mod post;
 pub mod post_report;
+pub mod post_revision;
 pub mod private_message;
 pub mod private_message_report;
 pub mod registration_application;
+pub mod report_category;
+pub mod report_combined;
+pub mod rule;
 pub mod secret;
 pub mod site;
+pub mod tag;
 pub mod tagline;
+#[cfg(feature = "full")]
+pub mod totp_recovery_code;
+#[cfg(feature = "full")]
+pub mod webauthn_challenge;
+pub mod webauthn_credential;
+pub mod webhook;
+#[cfg(feature = "full")]
+pub mod webhook_delivery;
 
 /// Default value for columns like [community::Community.inbox_url] which are marked as serde(skip).
 ///
@@ -0,0 +1,54 @@
+use crate::newtypes::{CommunityId, ModNoteId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::mod_note;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use diesel::sql_types::Json;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A private note about a person, written by a moderator or admin.
+pub struct ModNote {
+  pub id: ModNoteId,
+  /// The person which the note is about.
+  pub person_id: PersonId,
+  pub creator_id: PersonId,
+  /// Notes for a community are visible to its moderators, site-wide notes only to admins.
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_note))]
+pub struct ModNoteInsertForm {
+  #[builder(!default)]
+  pub person_id: PersonId,
+  #[builder(!default)]
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  #[builder(!default)]
+  pub content: String,
+}
+
+/// The notes about the creator of reported content, which are loaded as a JSON array in the
+/// report views.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "full", derive(TS, FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<Json>))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(transparent)]
+pub struct ModNotes {
+  pub notes: Vec<ModNote>,
+}
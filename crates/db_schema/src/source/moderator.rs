@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommunityId, PersonId, PostId};
+use crate::newtypes::{CommentId, CommunityId, PersonId, PostId, RuleId};
 #[cfg(feature = "full")]
 use crate::schema::{
   admin_purge_comment,
@@ -15,7 +15,9 @@ use crate::schema::{
   mod_remove_comment,
   mod_remove_community,
   mod_remove_post,
+  mod_resolve_ban_appeal,
   mod_transfer_community,
+  mod_warning,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -37,6 +39,8 @@ pub struct ModRemovePost {
   pub reason: Option<String>,
   pub removed: bool,
   pub when_: DateTime<Utc>,
+  /// The rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -46,6 +50,7 @@ pub struct ModRemovePostForm {
   pub post_id: PostId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -108,6 +113,8 @@ pub struct ModRemoveComment {
   pub reason: Option<String>,
   pub removed: bool,
   pub when_: DateTime<Utc>,
+  /// The rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -117,6 +124,7 @@ pub struct ModRemoveCommentForm {
   pub comment_id: CommentId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub rule_id: Option<RuleId>,
 }
 
 #[skip_serializing_none]
@@ -380,3 +388,62 @@ pub struct AdminPurgeCommentForm {
   pub post_id: PostId,
   pub reason: Option<String>,
 }
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warning))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a moderator or admin formally warns someone. Until it expires, the warning counts as a
+/// strike.
+pub struct ModWarning {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  /// Site-wide warnings from admins have no community.
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub expires: DateTime<Utc>,
+  /// Whether the warning already led to an automatic ban.
+  pub escalated: bool,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warning))]
+pub struct ModWarningForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub expires: DateTime<Utc>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a moderator or admin accepts or denies a ban appeal.
+pub struct ModResolveBanAppeal {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  /// Appeals against site bans have no community.
+  pub community_id: Option<CommunityId>,
+  pub accepted: bool,
+  pub reason: Option<String>,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_ban_appeal))]
+pub struct ModResolveBanAppealForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub accepted: bool,
+  pub reason: Option<String>,
+}
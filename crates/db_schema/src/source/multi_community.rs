@@ -0,0 +1,60 @@
+use crate::newtypes::{CommunityId, MultiCommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{multi_community, multi_community_entry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A named feed which combines the posts of several communities.
+pub struct MultiCommunity {
+  pub id: MultiCommunityId,
+  pub creator_id: PersonId,
+  pub name: String,
+  pub description: Option<String>,
+  /// Public feeds can be viewed by anyone who has the link, private ones only by their creator.
+  pub public: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+pub struct MultiCommunityInsertForm {
+  #[builder(!default)]
+  pub creator_id: PersonId,
+  #[builder(!default)]
+  pub name: String,
+  pub description: Option<String>,
+  pub public: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+pub struct MultiCommunityUpdateForm {
+  pub name: Option<String>,
+  pub description: Option<Option<String>>,
+  pub public: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community_entry))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// A community which is part of a multi-community feed.
+pub struct MultiCommunityEntry {
+  pub multi_community_id: MultiCommunityId,
+  pub community_id: CommunityId,
+}
@@ -0,0 +1,63 @@
+use crate::{
+  newtypes::{CommentId, CommunityId, NotificationId, PersonId, PostId, PrivateMessageId},
+  NotificationType,
+};
+#[cfg(feature = "full")]
+use crate::schema::notification;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = notification))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A notification in the inbox of a local user.
+///
+/// Depending on the kind, it references the comment, post, community or private message which it
+/// was created for.
+pub struct Notification {
+  pub id: NotificationId,
+  pub recipient_id: PersonId,
+  pub kind: NotificationType,
+  /// The person who caused the notification, eg the commenter or the moderator.
+  pub creator_id: Option<PersonId>,
+  pub comment_id: Option<CommentId>,
+  pub post_id: Option<PostId>,
+  pub community_id: Option<CommunityId>,
+  pub private_message_id: Option<PrivateMessageId>,
+  /// The reason given for a mod action.
+  pub reason: Option<String>,
+  pub read: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = notification))]
+pub struct NotificationInsertForm {
+  #[builder(!default)]
+  pub recipient_id: PersonId,
+  #[builder(!default)]
+  pub kind: NotificationType,
+  pub creator_id: Option<PersonId>,
+  pub comment_id: Option<CommentId>,
+  pub post_id: Option<PostId>,
+  pub community_id: Option<CommunityId>,
+  pub private_message_id: Option<PrivateMessageId>,
+  pub reason: Option<String>,
+  pub read: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = notification))]
+pub struct NotificationUpdateForm {
+  pub read: Option<bool>,
+}
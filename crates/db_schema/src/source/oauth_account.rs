@@ -0,0 +1,30 @@
+use crate::newtypes::{LocalUserId, OAuthProviderId};
+#[cfg(feature = "full")]
+use crate::schema::oauth_account;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_account))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A local user's account at an OAuth provider.
+pub struct OAuthAccount {
+  pub local_user_id: LocalUserId,
+  pub oauth_provider_id: OAuthProviderId,
+  /// The user id at the provider, taken from the provider's `id_claim`.
+  pub oauth_user_id: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_account))]
+pub struct OAuthAccountInsertForm {
+  pub local_user_id: LocalUserId,
+  pub oauth_provider_id: OAuthProviderId,
+  pub oauth_user_id: String,
+}
@@ -0,0 +1,110 @@
+use crate::{
+  newtypes::{DbUrl, OAuthProviderId},
+  sensitive::SensitiveString,
+};
+#[cfg(feature = "full")]
+use crate::schema::oauth_provider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_provider))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An OpenID Connect provider which users can log in with. Only visible to admins.
+pub struct OAuthProvider {
+  pub id: OAuthProviderId,
+  /// The name shown on the login button.
+  pub display_name: String,
+  pub issuer: DbUrl,
+  /// Where users are redirected to authorize the login.
+  pub authorization_endpoint: DbUrl,
+  /// Where the authorization code is exchanged for an access token.
+  pub token_endpoint: DbUrl,
+  /// Where the user's claims are fetched with the access token.
+  pub userinfo_endpoint: DbUrl,
+  /// The claim which uniquely identifies a user at the provider, usually `sub`.
+  pub id_claim: String,
+  pub client_id: String,
+  #[serde(skip)]
+  pub client_secret: SensitiveString,
+  /// Space separated list of scopes to request, for example `openid email`.
+  pub scopes: String,
+  /// Trust the email addresses which the provider marks as verified, and skip email verification.
+  pub auto_verify_email: bool,
+  /// Link logins to existing local users which have the same email address.
+  pub account_linking_enabled: bool,
+  /// Require clients to use PKCE for the authorization code flow.
+  pub use_pkce: bool,
+  pub enabled: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_provider))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The parts of an OAuth provider which clients need to start a login.
+pub struct PublicOAuthProvider {
+  pub id: OAuthProviderId,
+  pub display_name: String,
+  pub authorization_endpoint: DbUrl,
+  pub client_id: String,
+  pub scopes: String,
+  pub use_pkce: bool,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_provider))]
+pub struct OAuthProviderInsertForm {
+  #[builder(!default)]
+  pub display_name: String,
+  #[builder(!default)]
+  pub issuer: DbUrl,
+  #[builder(!default)]
+  pub authorization_endpoint: DbUrl,
+  #[builder(!default)]
+  pub token_endpoint: DbUrl,
+  #[builder(!default)]
+  pub userinfo_endpoint: DbUrl,
+  #[builder(!default)]
+  pub id_claim: String,
+  #[builder(!default)]
+  pub client_id: String,
+  #[builder(!default)]
+  pub client_secret: String,
+  #[builder(!default)]
+  pub scopes: String,
+  pub auto_verify_email: Option<bool>,
+  pub account_linking_enabled: Option<bool>,
+  pub use_pkce: Option<bool>,
+  pub enabled: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = oauth_provider))]
+pub struct OAuthProviderUpdateForm {
+  pub display_name: Option<String>,
+  pub authorization_endpoint: Option<DbUrl>,
+  pub token_endpoint: Option<DbUrl>,
+  pub userinfo_endpoint: Option<DbUrl>,
+  pub id_claim: Option<String>,
+  pub client_secret: Option<String>,
+  pub scopes: Option<String>,
+  pub auto_verify_email: Option<bool>,
+  pub account_linking_enabled: Option<bool>,
+  pub use_pkce: Option<bool>,
+  pub enabled: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
@@ -57,6 +57,11 @@ pub struct Person {
   /// When their ban, if it exists, expires, if at all.
   pub ban_expires: Option<DateTime<Utc>>,
   pub instance_id: InstanceId,
+  /// Other accounts of the same person. A move to one of them is only accepted if that account
+  /// lists this one here.
+  pub also_known_as: Vec<DbUrl>,
+  /// The account which this person moved to. Clients should redirect there.
+  pub moved_to: Option<DbUrl>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -100,6 +105,10 @@ pub struct PersonInsertForm {
   pub bot_account: Option<bool>,
   #[new(default)]
   pub ban_expires: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub also_known_as: Option<Vec<DbUrl>>,
+  #[new(default)]
+  pub moved_to: Option<DbUrl>,
 }
 
 #[derive(Clone, Default)]
@@ -123,6 +132,8 @@ pub struct PersonUpdateForm {
   pub matrix_user_id: Option<Option<String>>,
   pub bot_account: Option<bool>,
   pub ban_expires: Option<Option<DateTime<Utc>>>,
+  pub also_known_as: Option<Vec<DbUrl>>,
+  pub moved_to: Option<Option<DbUrl>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -0,0 +1,87 @@
+use crate::newtypes::{PersonId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{poll, poll_vote};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll, which is attached to a post.
+pub struct Poll {
+  pub post_id: PostId,
+  /// Whether users can vote for more than one option.
+  pub multiple_choice: bool,
+  pub options: Vec<String>,
+  /// The number of votes for each option, in the same order as `options`.
+  pub vote_counts: Vec<i32>,
+  /// The number of users who voted.
+  pub voters_count: i32,
+  /// No more votes are accepted after this time.
+  pub end_time: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+pub struct PollInsertForm {
+  #[builder(!default)]
+  pub post_id: PostId,
+  pub multiple_choice: Option<bool>,
+  #[builder(!default)]
+  pub options: Vec<String>,
+  #[builder(!default)]
+  pub vote_counts: Vec<i32>,
+  pub voters_count: Option<i32>,
+  pub end_time: Option<DateTime<Utc>>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+pub struct PollUpdateForm {
+  pub multiple_choice: Option<bool>,
+  pub options: Option<Vec<String>>,
+  pub vote_counts: Option<Vec<i32>>,
+  pub voters_count: Option<i32>,
+  pub end_time: Option<Option<DateTime<Utc>>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(
+  feature = "full",
+  derive(Identifiable, Queryable, Selectable, Associations)
+)]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::poll::Poll, foreign_key = post_id)))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id, person_id, choice)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PollVote {
+  pub post_id: PostId,
+  pub person_id: PersonId,
+  /// The index of the chosen option in [Poll.options].
+  pub choice: i32,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+pub struct PollVoteForm {
+  pub post_id: PostId,
+  pub person_id: PersonId,
+  pub choice: i32,
+}
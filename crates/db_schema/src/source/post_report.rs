@@ -1,4 +1,7 @@
-use crate::newtypes::{DbUrl, PersonId, PostId, PostReportId};
+use crate::{
+  newtypes::{DbUrl, PersonId, PostId, PostReportId, ReportCategoryId, RuleId},
+  ReportStatus,
+};
 #[cfg(feature = "full")]
 use crate::schema::post_report;
 use chrono::{DateTime, Utc};
@@ -33,6 +36,12 @@ pub struct PostReport {
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  pub category_id: Option<ReportCategoryId>,
+  /// The moderator or admin who is handling the report.
+  pub assignee_id: Option<PersonId>,
+  pub status: ReportStatus,
+  /// The rule which was broken.
+  pub rule_id: Option<RuleId>,
 }
 
 #[derive(Clone, Default)]
@@ -45,4 +54,6 @@ pub struct PostReportForm {
   pub original_post_url: Option<DbUrl>,
   pub original_post_body: Option<String>,
   pub reason: String,
+  pub category_id: Option<ReportCategoryId>,
+  pub rule_id: Option<RuleId>,
 }
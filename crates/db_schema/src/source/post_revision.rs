@@ -0,0 +1,30 @@
+use crate::newtypes::{DbUrl, PostId, PostRevisionId};
+#[cfg(feature = "full")]
+use crate::schema::post_revision;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(
+  feature = "full",
+  derive(Queryable, Selectable, Associations, Identifiable, TS)
+)]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
+#[cfg_attr(feature = "full", diesel(table_name = post_revision))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A previous version of an edited post. These are saved automatically when the post is edited.
+pub struct PostRevision {
+  pub id: PostRevisionId,
+  pub post_id: PostId,
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<DbUrl>,
+  pub body: Option<String>,
+  /// When this version of the post was written.
+  pub published: DateTime<Utc>,
+}
@@ -1,4 +1,4 @@
-use crate::newtypes::{DbUrl, PersonId, PrivateMessageId};
+use crate::newtypes::{ConversationId, DbUrl, PersonId, PrivateMessageId};
 #[cfg(feature = "full")]
 use crate::schema::private_message;
 use chrono::{DateTime, Utc};
@@ -25,6 +25,8 @@ use typed_builder::TypedBuilder;
 pub struct PrivateMessage {
   pub id: PrivateMessageId,
   pub creator_id: PersonId,
+  /// For messages in a group conversation this is the creator, the actual recipients are the
+  /// conversation participants.
   pub recipient_id: PersonId,
   pub content: String,
   pub deleted: bool,
@@ -34,6 +36,7 @@ pub struct PrivateMessage {
   pub ap_id: DbUrl,
   pub local: bool,
   pub removed: bool,
+  pub conversation_id: Option<ConversationId>,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -53,6 +56,7 @@ pub struct PrivateMessageInsertForm {
   pub updated: Option<DateTime<Utc>>,
   pub ap_id: Option<DbUrl>,
   pub local: Option<bool>,
+  pub conversation_id: Option<ConversationId>,
 }
 
 #[derive(Clone, Default)]
@@ -1,4 +1,7 @@
-use crate::newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId};
+use crate::{
+  newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId, ReportCategoryId},
+  ReportStatus,
+};
 #[cfg(feature = "full")]
 use crate::schema::private_message_report;
 use chrono::{DateTime, Utc};
@@ -32,6 +35,10 @@ pub struct PrivateMessageReport {
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  pub category_id: Option<ReportCategoryId>,
+  /// The moderator or admin who is handling the report.
+  pub assignee_id: Option<PersonId>,
+  pub status: ReportStatus,
 }
 
 #[derive(Clone)]
@@ -42,4 +49,5 @@ pub struct PrivateMessageReportForm {
   pub private_message_id: PrivateMessageId,
   pub original_pm_text: String,
   pub reason: String,
+  pub category_id: Option<ReportCategoryId>,
 }
@@ -0,0 +1,26 @@
+use crate::newtypes::ReportCategoryId;
+#[cfg(feature = "full")]
+use crate::schema::report_category;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_category))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A category which admins define for reports, like spam or harassment.
+pub struct ReportCategory {
+  pub id: ReportCategoryId,
+  pub name: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_category))]
+pub struct ReportCategoryForm {
+  pub name: String,
+}
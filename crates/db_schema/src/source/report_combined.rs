@@ -0,0 +1,44 @@
+use crate::newtypes::{
+  CommentReportId,
+  CommunityId,
+  PersonId,
+  PostReportId,
+  PrivateMessageReportId,
+  ReportCategoryId,
+  ReportCombinedId,
+};
+#[cfg(feature = "full")]
+use crate::schema::report_combined;
+use crate::ReportStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_combined))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A post, comment or private message report in the combined report queue. Exactly one of the
+/// report ids is set.
+pub struct ReportCombined {
+  pub id: ReportCombinedId,
+  pub published: DateTime<Utc>,
+  /// Private message reports don't have a community.
+  pub community_id: Option<CommunityId>,
+  pub post_report_id: Option<PostReportId>,
+  pub comment_report_id: Option<CommentReportId>,
+  pub private_message_report_id: Option<PrivateMessageReportId>,
+}
+
+#[derive(Clone, Default)]
+/// Changes the triage state of the report behind a combined report. Setting a status also sets
+/// whether the report is resolved.
+pub struct ReportTriageForm {
+  pub category_id: Option<Option<ReportCategoryId>>,
+  pub assignee_id: Option<Option<PersonId>>,
+  pub status: Option<ReportStatus>,
+}
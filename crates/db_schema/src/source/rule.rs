@@ -0,0 +1,63 @@
+use crate::newtypes::{CommunityId, DbUrl, RuleId};
+#[cfg(feature = "full")]
+use crate::schema::rule;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A rule of a community, or a site-wide rule if there is no community. Reports and removals can
+/// cite a rule.
+pub struct Rule {
+  pub id: RuleId,
+  pub community_id: Option<CommunityId>,
+  /// Rules are listed in ascending order of their position.
+  pub position: i32,
+  pub title: String,
+  pub description: Option<String>,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+  /// Only set for rules of remote communities.
+  pub ap_id: Option<DbUrl>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleInsertForm {
+  pub community_id: Option<CommunityId>,
+  #[builder(!default)]
+  pub position: i32,
+  #[builder(!default)]
+  pub title: String,
+  pub description: Option<String>,
+  pub ap_id: Option<DbUrl>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = rule))]
+pub struct RuleUpdateForm {
+  pub position: Option<i32>,
+  pub title: Option<String>,
+  pub description: Option<Option<String>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// How often the posts and comments of a person were removed for breaking a rule.
+pub struct RuleViolationCount {
+  pub rule: Rule,
+  pub count: i64,
+}
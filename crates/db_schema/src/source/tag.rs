@@ -0,0 +1,78 @@
+use crate::newtypes::{CommunityId, DbUrl, PostId, TagId};
+#[cfg(feature = "full")]
+use crate::schema::{post_tag, tag};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "full")]
+use diesel::sql_types::Json;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = tag))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A tag which is defined by the moderators of a community, and can be attached to posts.
+pub struct Tag {
+  pub id: TagId,
+  pub ap_id: DbUrl,
+  pub community_id: CommunityId,
+  pub name: String,
+  /// A hex color like `#ff0000`.
+  pub color: Option<String>,
+  /// Only moderators can attach this tag to posts.
+  pub mod_only: bool,
+  pub local: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = tag))]
+pub struct TagInsertForm {
+  #[builder(!default)]
+  pub community_id: CommunityId,
+  #[builder(!default)]
+  pub name: String,
+  pub ap_id: Option<DbUrl>,
+  pub color: Option<String>,
+  pub mod_only: Option<bool>,
+  pub local: Option<bool>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = tag))]
+pub struct TagUpdateForm {
+  pub name: Option<String>,
+  pub color: Option<Option<String>>,
+  pub mod_only: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = post_tag))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// A tag which is attached to a post.
+pub struct PostTag {
+  pub post_id: PostId,
+  pub tag_id: TagId,
+}
+
+/// The tags of a post, which are loaded as a JSON array in the post view.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "full", derive(TS, FromSqlRow, AsExpression))]
+#[cfg_attr(feature = "full", diesel(sql_type = Nullable<Json>))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(transparent)]
+pub struct PostTags {
+  pub tags: Vec<Tag>,
+}
@@ -0,0 +1,23 @@
+use crate::{newtypes::LocalUserId, schema::totp_recovery_code};
+use chrono::{DateTime, Utc};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", diesel(table_name = totp_recovery_code))]
+/// A one-time code which can be used instead of a TOTP token.
+pub struct TotpRecoveryCode {
+  pub id: i32,
+  pub local_user_id: LocalUserId,
+  /// Bcrypt hash of the code.
+  pub code_hash: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = totp_recovery_code))]
+pub struct TotpRecoveryCodeInsertForm {
+  pub local_user_id: LocalUserId,
+  pub code_hash: String,
+}
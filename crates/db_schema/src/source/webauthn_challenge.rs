@@ -0,0 +1,25 @@
+use crate::{newtypes::LocalUserId, schema::webauthn_challenge};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+#[cfg_attr(feature = "full", diesel(primary_key(local_user_id)))]
+/// The server side state of a WebAuthn ceremony which was started but not finished yet. Each user
+/// can only have one at a time.
+pub struct WebauthnChallenge {
+  pub local_user_id: LocalUserId,
+  pub state: Value,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+pub struct WebauthnChallengeForm {
+  pub local_user_id: LocalUserId,
+  pub state: Value,
+  pub published: DateTime<Utc>,
+}
@@ -0,0 +1,34 @@
+use crate::newtypes::{LocalUserId, WebauthnCredentialId};
+#[cfg(feature = "full")]
+use crate::schema::webauthn_credential;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_credential))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A security key or passkey which the user can log in with as second factor.
+pub struct WebauthnCredential {
+  pub id: WebauthnCredentialId,
+  pub local_user_id: LocalUserId,
+  /// A name chosen by the user, to tell their credentials apart.
+  pub name: String,
+  pub published: DateTime<Utc>,
+  pub last_used: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "full")]
+#[derive(Clone, Insertable)]
+#[diesel(table_name = webauthn_credential)]
+pub struct WebauthnCredentialInsertForm {
+  pub local_user_id: LocalUserId,
+  pub name: String,
+  /// The serialized public key, which is only used on the server.
+  pub passkey: serde_json::Value,
+}
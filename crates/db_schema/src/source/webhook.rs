@@ -0,0 +1,63 @@
+use crate::{
+  newtypes::{CommunityId, DbUrl, PersonId, WebhookId},
+  sensitive::SensitiveString,
+};
+#[cfg(feature = "full")]
+use crate::schema::webhook;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A URL which receives signed JSON payloads for new content and moderation actions.
+pub struct Webhook {
+  pub id: WebhookId,
+  pub creator_id: PersonId,
+  /// The community whose events are sent. If empty, it is a site-wide webhook which receives the
+  /// events of all communities.
+  pub community_id: Option<CommunityId>,
+  pub url: DbUrl,
+  /// Used to sign the payloads. Only returned when the webhook is created.
+  #[serde(skip)]
+  pub secret: SensitiveString,
+  /// Webhooks are disabled automatically after too many failed deliveries in a row.
+  pub enabled: bool,
+  /// The number of failed deliveries since the last successful one.
+  pub fail_count: i32,
+  pub last_successful_delivery: Option<DateTime<Utc>>,
+  pub last_failed_delivery: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookInsertForm {
+  #[builder(!default)]
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  #[builder(!default)]
+  pub url: DbUrl,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookUpdateForm {
+  pub url: Option<DbUrl>,
+  pub enabled: Option<bool>,
+  pub fail_count: Option<i32>,
+  pub last_successful_delivery: Option<Option<DateTime<Utc>>>,
+  pub last_failed_delivery: Option<Option<DateTime<Utc>>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
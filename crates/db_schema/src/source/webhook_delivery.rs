@@ -0,0 +1,27 @@
+use crate::{
+  newtypes::{WebhookDeliveryId, WebhookId},
+  schema::webhook_delivery,
+};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::fmt::Debug;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook_delivery))]
+/// A payload which still needs to be sent to a webhook.
+pub struct WebhookDelivery {
+  pub id: WebhookDeliveryId,
+  pub webhook_id: WebhookId,
+  pub payload: Value,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook_delivery))]
+pub struct WebhookDeliveryForm {
+  pub webhook_id: WebhookId,
+  pub payload: Value,
+}
@@ -47,10 +47,17 @@ use rustls::{
   SignatureScheme,
 };
 use std::{
+  future::poll_fn,
   ops::{Deref, DerefMut},
   sync::{Arc, LazyLock},
   time::Duration,
 };
+use tokio::{
+  io::{AsyncRead, AsyncWrite},
+  sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+};
+use tokio_postgres::{AsyncMessage, NoTls};
+use tokio_postgres_rustls::MakeRustlsConnect;
 use tracing::error;
 use url::Url;
 
@@ -326,14 +333,7 @@ fn establish_connection(config: &str) -> BoxFuture<ConnectionResult<AsyncPgConne
   let fut = async {
     // We only support TLS with sslmode=require currently
     let mut conn = if config.contains("sslmode=require") {
-      let rustls_config = DangerousClientConfigBuilder {
-        cfg: ClientConfig::builder(),
-      }
-      .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
-      .with_no_client_auth();
-
-      let tls = tokio_postgres_rustls::MakeRustlsConnect::new(rustls_config);
-      let (client, conn) = tokio_postgres::connect(config, tls)
+      let (client, conn) = tokio_postgres::connect(config, tls_connector())
         .await
         .map_err(|e| ConnectionError::BadConnection(e.to_string()))?;
       tokio::spawn(async move {
@@ -369,6 +369,80 @@ fn establish_connection(config: &str) -> BoxFuture<ConnectionResult<AsyncPgConne
   fut.boxed()
 }
 
+fn tls_connector() -> MakeRustlsConnect {
+  let rustls_config = DangerousClientConfigBuilder {
+    cfg: ClientConfig::builder(),
+  }
+  .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
+  .with_no_client_auth();
+
+  MakeRustlsConnect::new(rustls_config)
+}
+
+/// A dedicated database connection which receives the messages sent with `pg_notify()` to the
+/// channels it listens on. This is used to pass events between multiple Lemmy processes.
+pub struct PgListener {
+  // Dropping the client closes the connection
+  _client: tokio_postgres::Client,
+  receiver: UnboundedReceiver<tokio_postgres::Notification>,
+}
+
+impl PgListener {
+  pub async fn connect(channels: &[&str]) -> LemmyResult<Self> {
+    let db_url = SETTINGS.get_database_url();
+    let (sender, receiver) = mpsc::unbounded_channel();
+    // The connection isn't pooled, so it also needs to handle TLS itself
+    let client = if db_url.contains("sslmode=require") {
+      let (client, conn) = tokio_postgres::connect(&db_url, tls_connector()).await?;
+      forward_notifications(conn, sender);
+      client
+    } else {
+      let (client, conn) = tokio_postgres::connect(&db_url, NoTls).await?;
+      forward_notifications(conn, sender);
+      client
+    };
+    for channel in channels {
+      client.batch_execute(&format!("LISTEN {channel}")).await?;
+    }
+    Ok(Self {
+      _client: client,
+      receiver,
+    })
+  }
+
+  /// Waits for the next message. Returns `None` if the connection was closed.
+  pub async fn recv(&mut self) -> Option<tokio_postgres::Notification> {
+    self.receiver.recv().await
+  }
+}
+
+/// Drives the connection in a background task, and passes all received messages to `sender`.
+fn forward_notifications<S, T>(
+  mut conn: tokio_postgres::Connection<S, T>,
+  sender: UnboundedSender<tokio_postgres::Notification>,
+) where
+  S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+  T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+  tokio::spawn(async move {
+    loop {
+      match poll_fn(|cx| conn.poll_message(cx)).await {
+        Some(Ok(AsyncMessage::Notification(n))) => {
+          if sender.send(n).is_err() {
+            break;
+          }
+        }
+        Some(Ok(_)) => {}
+        Some(Err(e)) => {
+          error!("Database listener connection failed: {e}");
+          break;
+        }
+        None => break,
+      }
+    }
+  });
+}
+
 #[derive(Debug)]
 struct NoCertVerifier {}
 
@@ -483,7 +557,7 @@ static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 pub mod functions {
-  use diesel::sql_types::{BigInt, Bool, Text, Timestamptz};
+  use diesel::sql_types::{BigInt, Bool, Nullable, Text, Timestamptz};
 
   sql_function! {
     #[sql_name = "r.hot_rank"]
@@ -500,6 +574,15 @@ pub mod functions {
     fn controversy_rank(upvotes: BigInt, downvotes: BigInt, score: BigInt) -> Double;
   }
 
+  sql_function! {
+    #[sql_name = "r.keyword_filter_matches"]
+    fn keyword_filter_matches(
+      content: Nullable<Text>,
+      keyword: Text,
+      is_regex: Bool,
+    ) -> Nullable<Bool>;
+  }
+
   sql_function!(fn reverse_timestamp_sort(time: Timestamptz) -> BigInt);
 
   sql_function!(fn lower(x: Text) -> Text);
@@ -508,6 +591,11 @@ pub mod functions {
   sql_function!(fn coalesce<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: diesel::sql_types::Nullable<T>, y: T) -> T);
 
   sql_function!(fn set_config(setting_name: Text, new_value: Text, is_local: Bool) -> Text);
+
+  sql_function! {
+    #[aggregate]
+    fn array_agg<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: T) -> diesel::sql_types::Array<T>;
+  }
 }
 
 pub const DELETED_REPLACEMENT_TEXT: &str = "*Permanently Deleted*";
@@ -0,0 +1,223 @@
+use crate::structs::{BanAppealView, LocalUserView};
+use diesel::{
+  dsl::exists,
+  pg::Pg,
+  result::Error,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  aliases,
+  newtypes::{BanAppealId, CommunityId},
+  schema::{ban_appeal, community, community_moderator, person},
+  utils::{limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+};
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, BanAppealView, BanAppealId>,
+  impl ListFn<'a, BanAppealView, (BanAppealQuery, &'a LocalUserView)>,
+> {
+  let all_joins = |query: ban_appeal::BoxedQuery<'a, Pg>| {
+    query
+      .inner_join(person::table.on(ban_appeal::person_id.eq(person::id)))
+      .left_join(community::table)
+      .left_join(
+        aliases::person1
+          .on(ban_appeal::resolver_id.eq(aliases::person1.field(person::id).nullable())),
+      )
+      .select((
+        ban_appeal::all_columns,
+        person::all_columns,
+        community::all_columns.nullable(),
+        aliases::person1.fields(person::all_columns).nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>, ban_appeal_id: BanAppealId| async move {
+    all_joins(ban_appeal::table.into_boxed())
+      .filter(ban_appeal::id.eq(ban_appeal_id))
+      .first(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, (options, user): (BanAppealQuery, &'a LocalUserView)| async move {
+    let mut query = all_joins(ban_appeal::table.into_boxed());
+
+    if let Some(community_id) = options.community_id {
+      query = query.filter(ban_appeal::community_id.eq(community_id));
+    }
+
+    // Appeals against site bans are only for admins, mods get the ones of their communities
+    if !user.local_user.admin {
+      query = query.filter(exists(
+        community_moderator::table
+          .filter(
+            community_moderator::community_id
+              .nullable()
+              .eq(ban_appeal::community_id),
+          )
+          .filter(community_moderator::person_id.eq(user.person.id)),
+      ));
+    }
+
+    // If viewing all appeals, order by newest, but if viewing unresolved only, show the oldest
+    // first (FIFO)
+    if options.unresolved_only {
+      query = query
+        .filter(ban_appeal::resolver_id.is_null())
+        .order_by(ban_appeal::published.asc());
+    } else {
+      query = query.order_by(ban_appeal::published.desc());
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query = query.limit(limit).offset(offset);
+
+    query.load::<BanAppealView>(&mut conn).await
+  };
+
+  Queries::new(read, list)
+}
+
+impl BanAppealView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    ban_appeal_id: BanAppealId,
+  ) -> Result<Option<Self>, Error> {
+    queries().read(pool, ban_appeal_id).await
+  }
+}
+
+#[derive(Default)]
+pub struct BanAppealQuery {
+  pub community_id: Option<CommunityId>,
+  pub unresolved_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl BanAppealQuery {
+  /// Lists the appeals which the user can resolve.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    user: &LocalUserView,
+  ) -> Result<Vec<BanAppealView>, Error> {
+    queries().list(pool, (self, user)).await
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{ban_appeal_view::BanAppealQuery, structs::LocalUserView};
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      ban_appeal::{BanAppeal, BanAppealInsertForm},
+      community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::{Crud, Joinable},
+    utils::{build_db_pool_for_tests, DbPool},
+  };
+  use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  async fn create_local_user(
+    pool: &mut DbPool<'_>,
+    person: &Person,
+    admin: bool,
+  ) -> LemmyResult<LocalUserView> {
+    let form = if admin {
+      LocalUserInsertForm::test_form_admin(person.id)
+    } else {
+      LocalUserInsertForm::test_form(person.id)
+    };
+    LocalUser::create(pool, &form, vec![]).await?;
+    Ok(
+      LocalUserView::read_person(pool, person.id)
+        .await?
+        .ok_or(LemmyErrorType::CouldntFindPerson)?,
+    )
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_appeal_view() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_view_admin");
+    let admin_person = Person::create(pool, &form).await?;
+    let admin = create_local_user(pool, &admin_person, true).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_view_mod");
+    let mod_person = Person::create(pool, &form).await?;
+    let moderator = create_local_user(pool, &mod_person, false).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_view_other");
+    let other_person = Person::create(pool, &form).await?;
+    let other = create_local_user(pool, &other_person, false).await?;
+    let form = PersonInsertForm::test_form(instance.id, "ban_appeal_view_appellant");
+    let appellant = Person::create(pool, &form).await?;
+
+    let form = CommunityInsertForm::builder()
+      .name("test_community_bav".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+    let form = CommunityModeratorForm {
+      community_id: community.id,
+      person_id: mod_person.id,
+    };
+    CommunityModerator::join(pool, &form).await?;
+
+    let form = BanAppealInsertForm {
+      person_id: appellant.id,
+      community_id: None,
+      reason: "it was a joke".to_string(),
+    };
+    let site_appeal = BanAppeal::create(pool, &form).await?;
+    let form = BanAppealInsertForm {
+      person_id: appellant.id,
+      community_id: Some(community.id),
+      reason: "i read the rules now".to_string(),
+    };
+    let community_appeal = BanAppeal::create(pool, &form).await?;
+
+    // Admins see all appeals, newest first
+    let appeals = BanAppealQuery::default().list(pool, &admin).await?;
+    assert_length!(2, appeals);
+    assert_eq!(community_appeal.id, appeals[0].ban_appeal.id);
+    assert_eq!(site_appeal.id, appeals[1].ban_appeal.id);
+    assert_eq!(appellant.id, appeals[1].appellant.id);
+    assert!(appeals[1].community.is_none());
+    assert!(appeals[1].resolver.is_none());
+
+    // Mods only see the appeals of their communities
+    let appeals = BanAppealQuery::default().list(pool, &moderator).await?;
+    assert_length!(1, appeals);
+    assert_eq!(community_appeal.id, appeals[0].ban_appeal.id);
+    assert_eq!(
+      Some(community.id),
+      appeals[0].community.as_ref().map(|c| c.id)
+    );
+
+    let appeals = BanAppealQuery::default().list(pool, &other).await?;
+    assert_length!(0, appeals);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -1,8 +1,9 @@
 use crate::structs::{CommentReportView, LocalUserView};
 use diesel::{
-  dsl::now,
+  dsl::{exists, now, sql},
   pg::Pg,
   result::Error,
+  sql_types,
   BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
@@ -24,6 +25,7 @@ use lemmy_db_schema::{
     community_moderator,
     community_person_ban,
     local_user,
+    mod_note,
     person,
     person_block,
     post,
@@ -35,7 +37,29 @@ fn queries<'a>() -> Queries<
   impl ReadFn<'a, CommentReportView, (CommentReportId, PersonId)>,
   impl ListFn<'a, CommentReportView, (CommentReportQuery, &'a LocalUserView)>,
 > {
-  let all_joins = |query: comment_report::BoxedQuery<'a, Pg>, my_person_id: PersonId| {
+  // Notes about the comment creator. Site-wide notes are only visible to admins.
+  let creator_mod_notes = |my_person_id: PersonId| {
+    // The outer query already joins local_user for the creator, so an alias is needed here
+    let my_local_user = diesel::alias!(local_user as my_local_user);
+    let is_admin = exists(
+      my_local_user
+        .filter(my_local_user.field(local_user::person_id).eq(my_person_id))
+        .filter(my_local_user.field(local_user::admin)),
+    );
+    mod_note::table
+      .filter(mod_note::person_id.eq(comment::creator_id))
+      .filter(
+        mod_note::community_id
+          .eq(post::community_id.nullable())
+          .or(mod_note::community_id.is_null().and(is_admin)),
+      )
+      .select(sql::<sql_types::Json>(
+        "json_agg(mod_note.* ORDER BY mod_note.published DESC)",
+      ))
+      .single_value()
+  };
+
+  let all_joins = move |query: comment_report::BoxedQuery<'a, Pg>, my_person_id: PersonId| {
     query
       .inner_join(comment::table)
       .inner_join(post::table.on(comment::post_id.eq(post::id)))
@@ -126,6 +150,7 @@ fn queries<'a>() -> Queries<
         comment_saved::published.nullable().is_not_null(),
         comment_like::score.nullable(),
         aliases::person2.fields(person::all_columns).nullable(),
+        creator_mod_notes(my_person_id),
       ))
   };
 
@@ -150,6 +175,10 @@ fn queries<'a>() -> Queries<
       query = query.filter(comment_report::comment_id.eq(comment_id));
     }
 
+    if let Some(report_ids) = options.report_ids {
+      query = query.filter(comment_report::id.eq_any(report_ids));
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
     // first (FIFO)
     if options.unresolved_only {
@@ -243,6 +272,8 @@ impl CommentReportView {
 pub struct CommentReportQuery {
   pub community_id: Option<CommunityId>,
   pub comment_id: Option<CommentId>,
+  /// Only list these reports, eg for the combined report queue.
+  pub report_ids: Option<Vec<CommentReportId>>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
@@ -276,12 +307,14 @@ mod tests {
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
+      mod_note::ModNotes,
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
     },
     traits::{Crud, Joinable, Reportable},
     utils::{build_db_pool_for_tests, RANK_DEFAULT},
     CommunityVisibility,
+    ReportStatus,
     SubscribedType,
   };
   use pretty_assertions::assert_eq;
@@ -362,6 +395,8 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
+      category_id: None,
+      rule_id: None,
     };
 
     let inserted_sara_report = CommentReport::report(pool, &sara_report_form)
@@ -374,6 +409,8 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
+      category_id: None,
+      rule_id: None,
     };
 
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form)
@@ -445,6 +482,8 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: inserted_instance.id,
+        also_known_as: vec![],
+        moved_to: None,
         private_key: inserted_jessica.private_key,
         public_key: inserted_jessica.public_key,
         last_refreshed_at: inserted_jessica.last_refreshed_at,
@@ -468,6 +507,8 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: inserted_instance.id,
+        also_known_as: vec![],
+        moved_to: None,
         private_key: inserted_timmy.private_key.clone(),
         public_key: inserted_timmy.public_key.clone(),
         last_refreshed_at: inserted_timmy.last_refreshed_at,
@@ -485,6 +526,7 @@ mod tests {
       },
       my_vote: None,
       resolver: None,
+      comment_creator_mod_notes: ModNotes::default(),
     };
 
     assert_eq!(read_jessica_report_view, expected_jessica_report_view);
@@ -510,6 +552,8 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      also_known_as: vec![],
+      moved_to: None,
       private_key: inserted_sara.private_key,
       public_key: inserted_sara.public_key,
       last_refreshed_at: inserted_sara.last_refreshed_at,
@@ -552,6 +596,9 @@ mod tests {
     expected_jessica_report_view_after_resolve
       .comment_report
       .resolver_id = Some(inserted_timmy.id);
+    expected_jessica_report_view_after_resolve
+      .comment_report
+      .status = ReportStatus::Resolved;
     expected_jessica_report_view_after_resolve
       .comment_report
       .updated = read_jessica_report_view_after_resolve
@@ -579,6 +626,8 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      also_known_as: vec![],
+      moved_to: None,
     });
 
     assert_eq!(
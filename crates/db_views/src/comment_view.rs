@@ -29,15 +29,27 @@ use lemmy_db_schema::{
     community_moderator,
     community_person_ban,
     instance_block,
+    keyword_filter,
     local_user,
     local_user_language,
     person,
     person_block,
+    person_follower,
     post,
   },
   source::local_user::LocalUser,
-  utils::{fuzzy_search, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{
+    functions::keyword_filter_matches,
+    fuzzy_search,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
   CommentSortType,
+  CommunityVisibility,
   ListingType,
 };
 
@@ -74,6 +86,27 @@ fn queries<'a>() -> Queries<
       .single_value()
   };
 
+  let is_approved_follower = |person_id| {
+    exists(
+      community_follower::table.filter(
+        community::id
+          .eq(community_follower::community_id)
+          .and(community_follower::person_id.eq(person_id))
+          .and(community_follower::pending.eq(false)),
+      ),
+    )
+  };
+
+  let is_community_moderator = |person_id| {
+    exists(
+      community_moderator::table.filter(
+        community::id
+          .eq(community_moderator::community_id)
+          .and(community_moderator::person_id.eq(person_id)),
+      ),
+    )
+  };
+
   let is_creator_blocked = |person_id| {
     exists(
       person_block::table.filter(
@@ -179,6 +212,17 @@ fn queries<'a>() -> Queries<
       my_local_user.person_id(),
     );
     query = my_local_user.visible_communities_only(query);
+
+    // Comments in private communities are only visible to approved followers and mods
+    if !my_local_user.is_admin() {
+      let person_id_join = my_local_user.person_id().unwrap_or(PersonId(-1));
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(is_approved_follower(person_id_join))
+          .or(is_community_moderator(person_id_join)),
+      );
+    }
     query.first(&mut conn).await
   };
 
@@ -242,6 +286,18 @@ fn queries<'a>() -> Queries<
             ),
           ));
         }
+        ListingType::FollowedPeople => {
+          query = query
+            .filter(exists(
+              person_follower::table.filter(
+                comment::creator_id
+                  .eq(person_follower::person_id)
+                  .and(person_follower::follower_id.eq(person_id_join))
+                  .and(person_follower::pending.eq(false)),
+              ),
+            ))
+            .filter(community::hidden.eq(false).or(is_subscribed))
+        }
       }
     }
 
@@ -293,10 +349,37 @@ fn queries<'a>() -> Queries<
         ),
       )));
       query = query.filter(not(is_creator_blocked(person_id_join)));
+
+      // Hide comments which match one of the user's keyword filters
+      query = query.filter(not(exists(
+        keyword_filter::table.filter(
+          keyword_filter::local_user_id
+            .eq(local_user_id_join)
+            .and(
+              keyword_filter::community_id
+                .is_null()
+                .or(keyword_filter::community_id.eq(community::id.nullable())),
+            )
+            .and(keyword_filter_matches(
+              comment::content.nullable(),
+              keyword_filter::keyword,
+              keyword_filter::is_regex,
+            )),
+        ),
+      )));
     };
 
     query = options.local_user.visible_communities_only(query);
 
+    if !options.local_user.is_admin() {
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(is_approved_follower(person_id_join))
+          .or(is_community_moderator(person_id_join)),
+      );
+    }
+
     // A Max depth given means its a tree fetch
     let (limit, offset) = if let Some(max_depth) = options.max_depth {
       let depth_limit = if let Some(parent_path) = options.parent_path.as_ref() {
@@ -456,6 +539,7 @@ mod tests {
         CommunityUpdateForm,
       },
       instance::Instance,
+      keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
@@ -993,6 +1077,53 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_keyword_filters() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let local_user_id = data.timmy_local_user_view.local_user.id;
+
+    let unfiltered = list_contents(pool, &data).await?;
+    assert!(unfiltered.contains(&data.inserted_comment_0.content));
+
+    // Plain keywords match case-insensitively anywhere in the comment
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("COMMENT 0".to_string())
+      .build();
+    KeywordFilter::create(pool, &form).await?;
+    // Regexes can be limited to a community
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("^comment [2-5]$".to_string())
+      .is_regex(Some(true))
+      .community_id(Some(data.inserted_community.id))
+      .build();
+    KeywordFilter::create(pool, &form).await?;
+
+    let filtered = list_contents(pool, &data).await?;
+    assert!(filtered.is_empty());
+
+    cleanup(data, pool).await
+  }
+
+  async fn list_contents(pool: &mut DbPool<'_>, data: &Data) -> LemmyResult<Vec<String>> {
+    Ok(
+      CommentQuery {
+        local_user: Some(&data.timmy_local_user_view.local_user),
+        post_id: Some(data.inserted_post.id),
+        ..Default::default()
+      }
+      .list(pool)
+      .await?
+      .into_iter()
+      .map(|c| c.comment.content)
+      .collect(),
+    )
+  }
+
   async fn cleanup(data: Data, pool: &mut DbPool<'_>) -> LemmyResult<()> {
     CommentLike::remove(
       pool,
@@ -1059,6 +1190,8 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: data.inserted_instance.id,
+        also_known_as: vec![],
+        moved_to: None,
         private_key: data.timmy_local_user_view.person.private_key.clone(),
         public_key: data.timmy_local_user_view.person.public_key.clone(),
         last_refreshed_at: data.timmy_local_user_view.person.last_refreshed_at,
@@ -1070,6 +1203,7 @@ mod tests {
         url: None,
         body: None,
         alt_text: None,
+        scheduled_publish_time: None,
         published: data.inserted_post.published,
         updated: None,
         community_id: data.inserted_community.id,
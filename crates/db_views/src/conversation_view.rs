@@ -0,0 +1,251 @@
+use crate::structs::ConversationView;
+use diesel::{
+  dsl::{count, max},
+  result::Error,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{ConversationId, PersonId},
+  schema::{conversation, conversation_participant, private_message},
+  source::{
+    conversation::{Conversation, ConversationParticipant},
+    private_message::PrivateMessage,
+  },
+  utils::{functions::coalesce, get_conn, limit_and_offset, DbPool},
+};
+
+impl ConversationView {
+  /// Returns `None` if the person is not a participant of the conversation.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    my_person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    let Some(participant) =
+      ConversationParticipant::read(pool, conversation_id, my_person_id).await?
+    else {
+      return Ok(None);
+    };
+    let Some(conversation) = Conversation::read(pool, conversation_id).await? else {
+      return Ok(None);
+    };
+    let participants = ConversationParticipant::list_active(pool, conversation_id)
+      .await?
+      .into_iter()
+      .map(|(person, _)| person)
+      .collect();
+
+    let conn = &mut get_conn(pool).await?;
+    // Participants who left don't see the messages which were sent afterwards
+    let left_at = participant.left_at;
+    let visible_messages = || {
+      let mut query = private_message::table
+        .filter(private_message::conversation_id.eq(conversation_id))
+        .filter(private_message::deleted.eq(false))
+        .filter(private_message::removed.eq(false))
+        .into_boxed();
+      if let Some(left_at) = left_at {
+        query = query.filter(private_message::published.le(left_at));
+      }
+      query
+    };
+    let last_message = visible_messages()
+      .order_by(private_message::published.desc())
+      .first::<PrivateMessage>(conn)
+      .await
+      .optional()?;
+    let unread_count = visible_messages()
+      .filter(private_message::published.gt(participant.last_read))
+      .filter(private_message::creator_id.ne(my_person_id))
+      .select(count(private_message::id))
+      .first::<i64>(conn)
+      .await?;
+
+    Ok(Some(ConversationView {
+      conversation,
+      participant,
+      participants,
+      last_message,
+      unread_count,
+    }))
+  }
+
+  /// Conversations which the person is part of, with the most recently active first.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let conversation_ids = {
+      let conn = &mut get_conn(pool).await?;
+      let last_message_published = private_message::table
+        .filter(private_message::conversation_id.eq(conversation::id.nullable()))
+        .select(max(private_message::published))
+        .single_value();
+      conversation_participant::table
+        .inner_join(conversation::table)
+        .filter(conversation_participant::person_id.eq(my_person_id))
+        .filter(conversation_participant::left_at.is_null())
+        .order_by(coalesce(last_message_published, conversation::published).desc())
+        .limit(limit)
+        .offset(offset)
+        .select(conversation::id)
+        .load::<ConversationId>(conn)
+        .await?
+    };
+
+    let mut conversations = Vec::with_capacity(conversation_ids.len());
+    for conversation_id in conversation_ids {
+      if let Some(view) = Self::read(pool, conversation_id, my_person_id).await? {
+        conversations.push(view);
+      }
+    }
+    Ok(conversations)
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    private_message_view::PrivateMessageQuery,
+    structs::{ConversationView, PrivateMessageView},
+  };
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      conversation::{
+        Conversation,
+        ConversationInsertForm,
+        ConversationParticipant,
+        ConversationParticipantUpdateForm,
+      },
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+    },
+    traits::Crud,
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_conversation_view() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_view_anna");
+    let anna = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_view_ben");
+    let ben = Person::create(pool, &form).await?;
+    let form = PersonInsertForm::test_form(instance.id, "conversation_view_cleo");
+    let cleo = Person::create(pool, &form).await?;
+
+    let form = ConversationInsertForm::builder()
+      .creator_id(anna.id)
+      .build();
+    let conversation = Conversation::create(pool, &form, &[ben.id, cleo.id]).await?;
+
+    for content in ["first", "second"] {
+      let form = PrivateMessageInsertForm::builder()
+        .creator_id(anna.id)
+        .recipient_id(anna.id)
+        .content(content.to_string())
+        .conversation_id(Some(conversation.id))
+        .build();
+      PrivateMessage::create(pool, &form).await?;
+    }
+
+    let ben_conversations = ConversationView::list(pool, ben.id, None, None).await?;
+    assert_length!(1, &ben_conversations);
+    let view = &ben_conversations[0];
+    assert_eq!(3, view.participants.len());
+    assert_eq!(2, view.unread_count);
+    assert_eq!(
+      Some("second"),
+      view.last_message.as_ref().map(|m| m.content.as_str())
+    );
+
+    // Own messages are never unread
+    let anna_view = ConversationView::read(pool, conversation.id, anna.id).await?;
+    assert_eq!(Some(0), anna_view.map(|v| v.unread_count));
+
+    let form = ConversationParticipantUpdateForm {
+      last_read: Some(naive_now()),
+      ..Default::default()
+    };
+    ConversationParticipant::update(pool, conversation.id, ben.id, &form).await?;
+    let ben_view = ConversationView::read(pool, conversation.id, ben.id).await?;
+    assert_eq!(Some(0), ben_view.map(|v| v.unread_count));
+
+    // Group messages are only listed in the conversation, not as normal private messages
+    let anna_messages = PrivateMessageQuery::default().list(pool, anna.id).await?;
+    assert_length!(0, &anna_messages);
+    let unread = PrivateMessageView::get_unread_messages(pool, anna.id).await?;
+    assert_eq!(0, unread);
+    let conversation_messages = PrivateMessageQuery {
+      conversation_id: Some(conversation.id),
+      ..Default::default()
+    }
+    .list(pool, cleo.id)
+    .await?;
+    assert_length!(2, &conversation_messages);
+
+    // After leaving, the conversation isn't listed anymore
+    let form = ConversationParticipantUpdateForm {
+      left_at: Some(Some(naive_now())),
+      ..Default::default()
+    };
+    ConversationParticipant::update(pool, conversation.id, cleo.id, &form).await?;
+    let cleo_conversations = ConversationView::list(pool, cleo.id, None, None).await?;
+    assert_length!(0, &cleo_conversations);
+    let ben_view = ConversationView::read(pool, conversation.id, ben.id).await?;
+    assert_eq!(Some(2), ben_view.map(|v| v.participants.len()));
+
+    // Messages which were sent after leaving can't be read
+    let form = PrivateMessageInsertForm::builder()
+      .creator_id(anna.id)
+      .recipient_id(anna.id)
+      .content("third".to_string())
+      .conversation_id(Some(conversation.id))
+      .build();
+    PrivateMessage::create(pool, &form).await?;
+    let cleo_view = ConversationView::read(pool, conversation.id, cleo.id).await?;
+    assert_eq!(
+      Some("second"),
+      cleo_view
+        .as_ref()
+        .and_then(|v| v.last_message.as_ref())
+        .map(|m| m.content.as_str())
+    );
+    let cleo_messages = PrivateMessageQuery {
+      conversation_id: Some(conversation.id),
+      ..Default::default()
+    }
+    .list(pool, cleo.id)
+    .await?;
+    assert_length!(2, &cleo_messages);
+    let ben_messages = PrivateMessageQuery {
+      conversation_id: Some(conversation.id),
+      ..Default::default()
+    }
+    .list(pool, ben.id)
+    .await?;
+    assert_length!(3, &ben_messages);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
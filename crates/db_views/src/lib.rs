@@ -1,11 +1,15 @@
 #[cfg(test)]
 extern crate serial_test;
 
+#[cfg(feature = "full")]
+pub mod ban_appeal_view;
 #[cfg(feature = "full")]
 pub mod comment_report_view;
 #[cfg(feature = "full")]
 pub mod comment_view;
 #[cfg(feature = "full")]
+pub mod conversation_view;
+#[cfg(feature = "full")]
 pub mod custom_emoji_view;
 #[cfg(feature = "full")]
 pub mod local_image_view;
@@ -22,6 +26,8 @@ pub mod private_message_view;
 #[cfg(feature = "full")]
 pub mod registration_application_view;
 #[cfg(feature = "full")]
+pub mod report_combined_view;
+#[cfg(feature = "full")]
 pub mod site_view;
 pub mod structs;
 #[cfg(feature = "full")]
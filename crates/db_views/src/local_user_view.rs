@@ -1,10 +1,18 @@
 use crate::structs::LocalUserView;
-use actix_web::{dev::Payload, FromRequest, HttpMessage, HttpRequest};
-use diesel::{result::Error, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl};
+use actix_web::{dev::Payload, http::Method, FromRequest, HttpMessage, HttpRequest};
+use diesel::{
+  dsl::exists,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  QueryDsl,
+};
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
-  newtypes::{LocalUserId, PersonId},
-  schema::{local_user, local_user_vote_display_mode, person, person_aggregates},
+  newtypes::{LocalUserId, OAuthProviderId, PersonId},
+  schema::{local_user, local_user_vote_display_mode, oauth_account, person, person_aggregates},
+  source::login_token::LoginToken,
   utils::{
     functions::{coalesce, lower},
     DbConn,
@@ -13,8 +21,10 @@ use lemmy_db_schema::{
     Queries,
     ReadFn,
   },
+  ApiTokenScope,
+  SessionOnly,
 };
-use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
 use std::future::{ready, Ready};
 
 enum ReadBy<'a> {
@@ -23,6 +33,7 @@ enum ReadBy<'a> {
   Name(&'a str),
   NameOrEmail(&'a str),
   Email(&'a str),
+  OAuthId(OAuthProviderId, &'a str),
 }
 
 enum ListMode {
@@ -45,6 +56,14 @@ fn queries<'a>(
       ReadBy::Email(from_email) => {
         query.filter(lower(coalesce(local_user::email, "")).eq(from_email.to_lowercase()))
       }
+      ReadBy::OAuthId(oauth_provider_id, oauth_user_id) => query.filter(exists(
+        oauth_account::table.filter(
+          oauth_account::local_user_id
+            .eq(local_user::id)
+            .and(oauth_account::oauth_provider_id.eq(oauth_provider_id))
+            .and(oauth_account::oauth_user_id.eq(oauth_user_id)),
+        ),
+      )),
       _ => query,
     };
     let mut query = query.inner_join(person::table);
@@ -120,6 +139,17 @@ impl LocalUserView {
     queries().read(pool, ReadBy::Email(from_email)).await
   }
 
+  /// Finds the local user which is linked to the given account at an OAuth provider.
+  pub async fn find_by_oauth_id(
+    pool: &mut DbPool<'_>,
+    oauth_provider_id: OAuthProviderId,
+    oauth_user_id: &str,
+  ) -> Result<Option<Self>, Error> {
+    queries()
+      .read(pool, ReadBy::OAuthId(oauth_provider_id, oauth_user_id))
+      .await
+  }
+
   pub async fn list_admins_with_emails(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
     queries().list(pool, ListMode::AdminsWithEmails).await
   }
@@ -131,8 +161,122 @@ impl FromRequest for LocalUserView {
 
   fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
     ready(match req.extensions().get::<LocalUserView>() {
-      Some(c) => Ok(c.clone()),
+      Some(c) => check_api_token_scope(req).map(|()| c.clone()),
       None => Err(LemmyErrorType::IncorrectLogin.into()),
     })
   }
 }
+
+/// API tokens can only use routes which declare a scope with `app_data` which the token has.
+/// Read-only routes without a scope need the `Read` scope, other routes without a scope need a
+/// login session. Login sessions can do everything.
+fn check_api_token_scope(req: &HttpRequest) -> LemmyResult<()> {
+  let extensions = req.extensions();
+  let Some(scopes) = extensions
+    .get::<LoginToken>()
+    .and_then(|t| t.scopes.as_ref())
+  else {
+    return Ok(());
+  };
+  if req.app_data::<SessionOnly>().is_some() {
+    Err(LemmyErrorType::ApiTokenScopeNotAllowed)?
+  }
+  let required_scope = match req.app_data::<ApiTokenScope>() {
+    Some(scope) => Some(*scope),
+    None if matches!(*req.method(), Method::GET | Method::HEAD) => Some(ApiTokenScope::Read),
+    None => None,
+  };
+  match required_scope {
+    Some(scope) if scopes.contains(&scope) => Ok(()),
+    _ => Err(LemmyErrorType::ApiTokenScopeNotAllowed.into()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::local_user_view::check_api_token_scope;
+  use actix_web::{test::TestRequest, HttpMessage};
+  use chrono::Utc;
+  use lemmy_db_schema::{
+    newtypes::{LocalUserId, LoginTokenId},
+    source::login_token::LoginToken,
+    ApiTokenScope,
+    SessionOnly,
+  };
+
+  fn login_token(scopes: Option<Vec<ApiTokenScope>>) -> LoginToken {
+    LoginToken {
+      token: "token".to_string().into(),
+      user_id: LocalUserId(1),
+      published: Utc::now(),
+      ip: None,
+      user_agent: None,
+      name: scopes.as_ref().map(|_| "bot".to_string()),
+      scopes,
+      expires: None,
+      id: LoginTokenId(1),
+      last_used: None,
+    }
+  }
+
+  #[test]
+  fn test_api_token_scope() {
+    let vote_token = login_token(Some(vec![ApiTokenScope::Vote]));
+
+    // Login sessions can use all routes
+    let req = TestRequest::post().to_http_request();
+    req.extensions_mut().insert(login_token(None));
+    assert!(check_api_token_scope(&req).is_ok());
+
+    // Reading needs the read scope, or the scope of the route
+    let read_token = login_token(Some(vec![ApiTokenScope::Read]));
+    let req = TestRequest::get().to_http_request();
+    req.extensions_mut().insert(read_token.clone());
+    assert!(check_api_token_scope(&req).is_ok());
+
+    let req = TestRequest::get().to_http_request();
+    req.extensions_mut().insert(vote_token.clone());
+    assert!(check_api_token_scope(&req).is_err());
+
+    let req = TestRequest::get()
+      .app_data(ApiTokenScope::Moderate)
+      .to_http_request();
+    req.extensions_mut().insert(read_token);
+    assert!(check_api_token_scope(&req).is_err());
+
+    let req = TestRequest::post()
+      .app_data(ApiTokenScope::Vote)
+      .to_http_request();
+    req.extensions_mut().insert(vote_token.clone());
+    assert!(check_api_token_scope(&req).is_ok());
+
+    let req = TestRequest::post()
+      .app_data(ApiTokenScope::Post)
+      .to_http_request();
+    req.extensions_mut().insert(vote_token.clone());
+    assert!(check_api_token_scope(&req).is_err());
+
+    // Routes without a scope, like account settings, need a login session
+    let req = TestRequest::put().to_http_request();
+    req.extensions_mut().insert(vote_token);
+    assert!(check_api_token_scope(&req).is_err());
+
+    // Session only routes can't be used with any scope
+    let all_scopes = login_token(Some(vec![
+      ApiTokenScope::Read,
+      ApiTokenScope::Post,
+      ApiTokenScope::Comment,
+      ApiTokenScope::Vote,
+      ApiTokenScope::Moderate,
+      ApiTokenScope::Admin,
+    ]));
+    let req = TestRequest::get().app_data(SessionOnly).to_http_request();
+    req.extensions_mut().insert(all_scopes);
+    assert!(check_api_token_scope(&req).is_err());
+
+    let req = TestRequest::get().app_data(SessionOnly).to_http_request();
+    req.extensions_mut().insert(login_token(None));
+    assert!(check_api_token_scope(&req).is_ok());
+  }
+}
@@ -1,7 +1,9 @@
 use crate::structs::{LocalUserView, PostReportView};
 use diesel::{
+  dsl::{exists, sql},
   pg::Pg,
   result::Error,
+  sql_types,
   BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
@@ -18,6 +20,7 @@ use lemmy_db_schema::{
     community_moderator,
     community_person_ban,
     local_user,
+    mod_note,
     person,
     person_block,
     person_post_aggregates,
@@ -45,7 +48,29 @@ fn queries<'a>() -> Queries<
   impl ReadFn<'a, PostReportView, (PostReportId, PersonId)>,
   impl ListFn<'a, PostReportView, (PostReportQuery, &'a LocalUserView)>,
 > {
-  let all_joins = |query: post_report::BoxedQuery<'a, Pg>, my_person_id: PersonId| {
+  // Notes about the post creator. Site-wide notes are only visible to admins.
+  let creator_mod_notes = |my_person_id: PersonId| {
+    // The outer query already joins local_user for the creator, so an alias is needed here
+    let my_local_user = diesel::alias!(local_user as my_local_user);
+    let is_admin = exists(
+      my_local_user
+        .filter(my_local_user.field(local_user::person_id).eq(my_person_id))
+        .filter(my_local_user.field(local_user::admin)),
+    );
+    mod_note::table
+      .filter(mod_note::person_id.eq(post::creator_id))
+      .filter(
+        mod_note::community_id
+          .eq(post::community_id.nullable())
+          .or(mod_note::community_id.is_null().and(is_admin)),
+      )
+      .select(sql::<sql_types::Json>(
+        "json_agg(mod_note.* ORDER BY mod_note.published DESC)",
+      ))
+      .single_value()
+  };
+
+  let all_joins = move |query: post_report::BoxedQuery<'a, Pg>, my_person_id: PersonId| {
     query
       .inner_join(post::table)
       .inner_join(community::table.on(post::community_id.eq(community::id)))
@@ -155,6 +180,7 @@ fn queries<'a>() -> Queries<
         ),
         post_aggregates::all_columns,
         aliases::person2.fields(person::all_columns.nullable()),
+        creator_mod_notes(my_person_id),
       ))
   };
 
@@ -178,6 +204,10 @@ fn queries<'a>() -> Queries<
       query = query.filter(post::id.eq(post_id));
     }
 
+    if let Some(report_ids) = options.report_ids {
+      query = query.filter(post_report::id.eq_any(report_ids));
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
     // first (FIFO)
     if options.unresolved_only {
@@ -268,6 +298,8 @@ impl PostReportView {
 pub struct PostReportQuery {
   pub community_id: Option<CommunityId>,
   pub post_id: Option<PostId>,
+  /// Only list these reports, eg for the combined report queue.
+  pub report_ids: Option<Vec<PostReportId>>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
@@ -299,6 +331,7 @@ mod tests {
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
+      mod_note::{ModNote, ModNoteInsertForm},
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
       post_report::{PostReport, PostReportForm},
@@ -378,6 +411,8 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from sara".into(),
+      category_id: None,
+      rule_id: None,
     };
 
     PostReport::report(pool, &sara_report_form).await.unwrap();
@@ -398,12 +433,25 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from jessica".into(),
+      category_id: None,
+      rule_id: None,
     };
 
     let inserted_jessica_report = PostReport::report(pool, &jessica_report_form)
       .await
       .unwrap();
 
+    // Site-wide notes are only visible to admins
+    for community_id in [Some(inserted_community.id), None] {
+      let mod_note_form = ModNoteInsertForm::builder()
+        .person_id(inserted_timmy.id)
+        .creator_id(inserted_sara.id)
+        .community_id(community_id)
+        .content("posts off-topic".to_string())
+        .build();
+      ModNote::create(pool, &mod_note_form).await.unwrap();
+    }
+
     let read_jessica_report_view =
       PostReportView::read(pool, inserted_jessica_report.id, inserted_timmy.id)
         .await
@@ -420,6 +468,12 @@ mod tests {
     assert_eq!(read_jessica_report_view.post_creator.id, inserted_timmy.id);
     assert_eq!(read_jessica_report_view.my_vote, None);
     assert_eq!(read_jessica_report_view.resolver, None);
+    let mod_notes = &read_jessica_report_view.post_creator_mod_notes.notes;
+    assert_length!(1, mod_notes);
+    assert_eq!(
+      Some(Some(inserted_community.id)),
+      mod_notes.first().map(|n| n.community_id)
+    );
 
     // Do a batch read of timmys reports
     let reports = PostReportQuery::default()
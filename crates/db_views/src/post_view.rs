@@ -1,7 +1,7 @@
 use crate::structs::{PaginationCursor, PostView};
 use diesel::{
   debug_query,
-  dsl::{exists, not, IntervalDsl},
+  dsl::{exists, not, sql, IntervalDsl},
   pg::Pg,
   query_builder::AsQuery,
   result::Error,
@@ -21,7 +21,7 @@ use i_love_jesus::PaginatedQueryBuilder;
 use lemmy_db_schema::{
   aggregates::structs::{post_aggregates_keys as key, PostAggregates},
   impls::local_user::LocalUserOptionHelper,
-  newtypes::{CommunityId, LocalUserId, PersonId, PostId},
+  newtypes::{CommunityId, LocalUserId, MultiCommunityId, PersonId, PostId, TagId},
   schema::{
     community,
     community_block,
@@ -30,21 +30,28 @@ use lemmy_db_schema::{
     community_person_ban,
     image_details,
     instance_block,
+    keyword_filter,
     local_user,
     local_user_language,
+    multi_community_entry,
     person,
     person_block,
+    person_follower,
     person_post_aggregates,
+    poll,
+    poll_vote,
     post,
     post_aggregates,
     post_hide,
     post_like,
     post_read,
     post_saved,
+    post_tag,
+    tag,
   },
   source::{local_user::LocalUser, site::Site},
   utils::{
-    functions::coalesce,
+    functions::{array_agg, coalesce, keyword_filter_matches},
     fuzzy_search,
     get_conn,
     limit_and_offset,
@@ -57,6 +64,7 @@ use lemmy_db_schema::{
     ReadFn,
     ReverseTimestampKey,
   },
+  CommunityVisibility,
   ListingType,
   SortType,
 };
@@ -100,6 +108,27 @@ fn queries<'a>() -> Queries<
     ),
   );
 
+  let is_approved_follower = |person_id| {
+    exists(
+      community_follower::table.filter(
+        post_aggregates::community_id
+          .eq(community_follower::community_id)
+          .and(community_follower::person_id.eq(person_id))
+          .and(community_follower::pending.eq(false)),
+      ),
+    )
+  };
+
+  let is_community_moderator = |person_id| {
+    exists(
+      community_moderator::table.filter(
+        post_aggregates::community_id
+          .eq(community_moderator::community_id)
+          .and(community_moderator::person_id.eq(person_id)),
+      ),
+    )
+  };
+
   let is_read = |person_id| {
     exists(
       post_read::table.filter(
@@ -141,6 +170,26 @@ fn queries<'a>() -> Queries<
       .single_value()
   };
 
+  let my_poll_choices = |person_id| {
+    poll_vote::table
+      .filter(
+        post_aggregates::post_id
+          .eq(poll_vote::post_id)
+          .and(poll_vote::person_id.eq(person_id)),
+      )
+      .select(array_agg(poll_vote::choice))
+      .single_value()
+  };
+
+  // All tags of the post as a JSON array, or null if it has none
+  let post_tags = || {
+    post_tag::table
+      .inner_join(tag::table)
+      .filter(post_tag::post_id.eq(post_aggregates::post_id))
+      .select(sql::<sql_types::Json>("json_agg(tag.* ORDER BY tag.name)"))
+      .single_value()
+  };
+
   // TODO maybe this should go to localuser also
   let all_joins = move |query: post_aggregates::BoxedQuery<'a, Pg>,
                         my_person_id: Option<PersonId>| {
@@ -215,11 +264,26 @@ fn queries<'a>() -> Queries<
       Box::new(None::<i64>.into_sql::<sql_types::Nullable<sql_types::BigInt>>())
     };
 
+    let my_poll_choices_selection: Box<
+      dyn BoxableExpression<
+        _,
+        Pg,
+        SqlType = sql_types::Nullable<sql_types::Array<sql_types::Int4>>,
+      >,
+    > = if let Some(person_id) = my_person_id {
+      Box::new(my_poll_choices(person_id))
+    } else {
+      Box::new(
+        None::<Vec<i32>>.into_sql::<sql_types::Nullable<sql_types::Array<sql_types::Int4>>>(),
+      )
+    };
+
     query
       .inner_join(person::table)
       .inner_join(community::table)
       .inner_join(post::table)
       .left_join(image_details::table.on(post::thumbnail_url.eq(image_details::link.nullable())))
+      .left_join(poll::table.on(post_aggregates::post_id.eq(poll::post_id)))
       .left_join(
         post_saved::table.on(
           post_aggregates::post_id
@@ -247,6 +311,9 @@ fn queries<'a>() -> Queries<
           post_aggregates::comments.nullable() - read_comments,
           post_aggregates::comments,
         ),
+        poll::all_columns.nullable(),
+        my_poll_choices_selection,
+        post_tags(),
       ))
   };
 
@@ -293,8 +360,24 @@ fn queries<'a>() -> Queries<
         );
     }
 
+    // Scheduled posts are only visible to their creator until they are published
+    query = query.filter(
+      post::scheduled_publish_time
+        .is_null()
+        .or(post::creator_id.eq(person_id_join)),
+    );
+
     query = my_local_user.visible_communities_only(query);
 
+    // Posts in private communities are only visible to approved followers
+    if !is_mod_or_admin {
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(is_approved_follower(person_id_join)),
+      );
+    }
+
     Commented::new(query)
       .text("PostView::read")
       .first(&mut conn)
@@ -330,10 +413,36 @@ fn queries<'a>() -> Queries<
         .filter(community::removed.eq(false))
         .filter(post::removed.eq(false));
     }
+    // Scheduled posts are only listed for their creator, separately from published posts
+    if options.scheduled_only.unwrap_or_default() {
+      query = query
+        .filter(post::scheduled_publish_time.is_not_null())
+        .filter(post_aggregates::creator_id.eq(person_id_join));
+    } else {
+      query = query.filter(post::scheduled_publish_time.is_null());
+    }
+
     if let Some(community_id) = options.community_id {
       query = query.filter(post_aggregates::community_id.eq(community_id));
     }
 
+    if let Some(multi_community_id) = options.multi_community_id {
+      let multi_community_ids = multi_community_entry::table
+        .filter(multi_community_entry::multi_community_id.eq(multi_community_id))
+        .select(multi_community_entry::community_id);
+      query = query.filter(post_aggregates::community_id.eq_any(multi_community_ids));
+    }
+
+    if let Some(tag_id) = options.tag_id {
+      query = query.filter(exists(
+        post_tag::table.filter(
+          post_tag::post_id
+            .eq(post_aggregates::post_id)
+            .and(post_tag::tag_id.eq(tag_id)),
+        ),
+      ));
+    }
+
     if let Some(creator_id) = options.creator_id {
       query = query.filter(post_aggregates::creator_id.eq(creator_id));
     }
@@ -364,6 +473,18 @@ fn queries<'a>() -> Queries<
               ),
             ));
           }
+          ListingType::FollowedPeople => {
+            query = query
+              .filter(exists(
+                person_follower::table.filter(
+                  post_aggregates::creator_id
+                    .eq(person_follower::person_id)
+                    .and(person_follower::follower_id.eq(person_id))
+                    .and(person_follower::pending.eq(false)),
+                ),
+              ))
+              .filter(community::hidden.eq(false).or(is_subscribed));
+          }
         }
       }
       // If your person_id is missing, only show local
@@ -448,6 +569,16 @@ fn queries<'a>() -> Queries<
 
     query = options.local_user.visible_communities_only(query);
 
+    // Posts in private communities are only visible to approved followers and mods
+    if !options.local_user.is_admin() {
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(is_approved_follower(person_id_join))
+          .or(is_community_moderator(person_id_join)),
+      );
+    }
+
     // Dont filter blocks or missing languages for moderator view type
     if let (Some(person_id), false) = (
       options.local_user.person_id(),
@@ -478,6 +609,31 @@ fn queries<'a>() -> Queries<
         ),
       )));
       query = query.filter(not(is_creator_blocked(person_id)));
+
+      // Hide posts whose title or body match one of the user's keyword filters
+      query = query.filter(not(exists(
+        keyword_filter::table.filter(
+          keyword_filter::local_user_id
+            .eq(local_user_id_join)
+            .and(
+              keyword_filter::community_id
+                .is_null()
+                .or(keyword_filter::community_id.eq(post_aggregates::community_id.nullable())),
+            )
+            .and(
+              keyword_filter_matches(
+                post::name.nullable(),
+                keyword_filter::keyword,
+                keyword_filter::is_regex,
+              )
+              .or(keyword_filter_matches(
+                post::body,
+                keyword_filter::keyword,
+                keyword_filter::is_regex,
+              )),
+            ),
+        ),
+      )));
     }
 
     let (limit, offset) = limit_and_offset(options.page, options.limit)?;
@@ -613,6 +769,10 @@ pub struct PostQuery<'a> {
   // if true, the query should be handled as if community_id was not given except adding the
   // literal filter
   pub community_id_just_for_prefetch: bool,
+  /// Only show posts from the communities of this feed
+  pub multi_community_id: Option<MultiCommunityId>,
+  /// Only show posts which have this tag
+  pub tag_id: Option<TagId>,
   pub local_user: Option<&'a LocalUser>,
   pub search_term: Option<String>,
   pub url_search: Option<String>,
@@ -628,6 +788,7 @@ pub struct PostQuery<'a> {
   pub show_hidden: Option<bool>,
   pub show_read: Option<bool>,
   pub show_nsfw: Option<bool>,
+  pub scheduled_only: Option<bool>,
 }
 
 impl<'a> PostQuery<'a> {
@@ -748,6 +909,8 @@ mod tests {
       comment::{Comment, CommentInsertForm},
       community::{
         Community,
+        CommunityFollower,
+        CommunityFollowerForm,
         CommunityInsertForm,
         CommunityModerator,
         CommunityModeratorForm,
@@ -758,15 +921,18 @@ mod tests {
       community_block::{CommunityBlock, CommunityBlockForm},
       instance::Instance,
       instance_block::{InstanceBlock, InstanceBlockForm},
+      keyword_filter::{KeywordFilter, KeywordFilterInsertForm},
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
+      multi_community::{MultiCommunity, MultiCommunityInsertForm},
       person::{Person, PersonInsertForm},
       person_block::{PersonBlock, PersonBlockForm},
       post::{Post, PostHide, PostInsertForm, PostLike, PostLikeForm, PostRead, PostUpdateForm},
       site::Site,
+      tag::{PostTag, PostTags, Tag, TagInsertForm},
     },
-    traits::{Bannable, Blockable, Crud, Joinable, Likeable},
+    traits::{Bannable, Blockable, Crud, Followable, Joinable, Likeable},
     utils::{build_db_pool, build_db_pool_for_tests, DbPool, RANK_DEFAULT},
     CommunityVisibility,
     SortType,
@@ -1336,6 +1502,66 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listings_scheduled() -> LemmyResult<()> {
+    let pool = &build_db_pool().await?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    Post::update(
+      pool,
+      data.inserted_post.id,
+      &PostUpdateForm {
+        scheduled_publish_time: Some(Some(Utc::now() + chrono::TimeDelta::hours(1))),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Scheduled post is hidden from normal listings, even for the creator
+    for local_user in [
+      None,
+      Some(&data.blocked_local_user_view.local_user),
+      Some(&data.local_user_view.local_user),
+    ] {
+      let contains_scheduled = PostQuery {
+        local_user,
+        ..data.default_post_query()
+      }
+      .list(&data.site, pool)
+      .await?
+      .iter()
+      .any(|p| p.post.id == data.inserted_post.id);
+      assert!(!contains_scheduled);
+    }
+
+    // Only the creator can list and read it
+    for (local_user, expect_contains_scheduled) in [
+      (&data.blocked_local_user_view.local_user, false),
+      (&data.local_user_view.local_user, true),
+    ] {
+      let scheduled_posts = PostQuery {
+        local_user: Some(local_user),
+        scheduled_only: Some(true),
+        ..data.default_post_query()
+      }
+      .list(&data.site, pool)
+      .await?;
+      assert_eq!(
+        expect_contains_scheduled,
+        scheduled_posts
+          .iter()
+          .any(|p| p.post.id == data.inserted_post.id)
+      );
+
+      let read_post = PostView::read(pool, data.inserted_post.id, Some(local_user), false).await?;
+      assert_eq!(expect_contains_scheduled, read_post.is_some());
+    }
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listing_instance_block() -> LemmyResult<()> {
@@ -1668,6 +1894,7 @@ mod tests {
         url: None,
         body: None,
         alt_text: None,
+        scheduled_publish_time: None,
         published: inserted_post.published,
         updated: None,
         community_id: inserted_community.id,
@@ -1688,6 +1915,9 @@ mod tests {
       },
       my_vote: None,
       unread_comments: 0,
+      poll: None,
+      my_poll_choices: None,
+      tags: PostTags::default(),
       creator: Person {
         id: inserted_person.id,
         name: inserted_person.name.clone(),
@@ -1707,6 +1937,8 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: data.inserted_instance.id,
+        also_known_as: vec![],
+        moved_to: None,
         private_key: inserted_person.private_key.clone(),
         public_key: inserted_person.public_key.clone(),
         last_refreshed_at: inserted_person.last_refreshed_at,
@@ -1770,6 +2002,205 @@ mod tests {
     })
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_multi_community() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = MultiCommunityInsertForm::builder()
+      .creator_id(data.local_user_view.person.id)
+      .name("multi".to_string())
+      .build();
+    let multi = MultiCommunity::create(pool, &form).await?;
+
+    // An empty feed has no posts
+    let empty = PostQuery {
+      multi_community_id: Some(multi.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert!(empty.is_empty());
+
+    MultiCommunity::update_entries(pool, multi.id, &[data.inserted_community.id]).await?;
+    let feed = PostQuery {
+      multi_community_id: Some(multi.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    let all = data.default_post_query().list(&data.site, pool).await?;
+    assert_eq!(names(&all), names(&feed));
+
+    MultiCommunity::delete(pool, multi.id).await?;
+    cleanup(data, pool).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_tag() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = TagInsertForm::builder()
+      .community_id(data.inserted_community.id)
+      .name("news".to_string())
+      .color(Some("#ff0000".to_string()))
+      .build();
+    let tag = Tag::create(pool, &form).await?;
+    PostTag::set(pool, data.inserted_post.id, &[tag.id]).await?;
+
+    let tagged = PostQuery {
+      tag_id: Some(tag.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST], names(&tagged));
+    assert_eq!(
+      Some(&vec![tag.clone()]),
+      tagged.first().map(|p| &p.tags.tags)
+    );
+
+    // Posts without tags have an empty list
+    let all = data.default_post_query().list(&data.site, pool).await?;
+    assert!(all
+      .iter()
+      .filter(|p| p.post.id != data.inserted_post.id)
+      .all(|p| p.tags.tags.is_empty()));
+
+    Tag::delete(pool, tag.id).await?;
+    cleanup(data, pool).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_keyword_filter() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let local_user_id = data.local_user_view.local_user.id;
+
+    // Plain keywords match case-insensitively anywhere in the title
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("BY BOT".to_string())
+      .build();
+    KeywordFilter::create(pool, &form).await?;
+    let post_listings = data.default_post_query().list(&data.site, pool).await?;
+    assert_eq!(vec![POST], names(&post_listings));
+
+    // Regexes can be limited to a community
+    let form = KeywordFilterInsertForm::builder()
+      .local_user_id(local_user_id)
+      .keyword("^po.t$".to_string())
+      .is_regex(Some(true))
+      .community_id(Some(data.inserted_community.id))
+      .build();
+    KeywordFilter::create(pool, &form).await?;
+    let post_listings = data.default_post_query().list(&data.site, pool).await?;
+    assert!(post_listings.is_empty());
+
+    cleanup(data, pool).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn private_community() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let follower_query = PostQuery {
+      local_user: Some(&data.blocked_local_user_view.local_user),
+      ..Default::default()
+    };
+    let public_posts = follower_query.clone().list(&data.site, pool).await?;
+    assert!(!public_posts.is_empty());
+
+    Community::update(
+      pool,
+      data.inserted_community.id,
+      &CommunityUpdateForm {
+        visibility: Some(CommunityVisibility::Private),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Not a follower, so the posts are hidden
+    let not_following = follower_query.clone().list(&data.site, pool).await?;
+    assert!(not_following.is_empty());
+    let not_following_post = PostView::read(
+      pool,
+      data.inserted_post.id,
+      Some(&data.blocked_local_user_view.local_user),
+      false,
+    )
+    .await?;
+    assert!(not_following_post.is_none());
+
+    // Mods of the community see the posts without following it
+    let moderator_form = CommunityModeratorForm {
+      community_id: data.inserted_community.id,
+      person_id: data.blocked_local_user_view.person.id,
+    };
+    CommunityModerator::join(pool, &moderator_form).await?;
+    let moderator_posts = follower_query.clone().list(&data.site, pool).await?;
+    assert_eq!(public_posts.len(), moderator_posts.len());
+    CommunityModerator::leave(pool, &moderator_form).await?;
+
+    // A pending follow doesn't give access yet
+    let follower_form = CommunityFollowerForm {
+      community_id: data.inserted_community.id,
+      person_id: data.blocked_local_user_view.person.id,
+      pending: true,
+    };
+    CommunityFollower::follow(pool, &follower_form).await?;
+    let pending = follower_query.clone().list(&data.site, pool).await?;
+    assert!(pending.is_empty());
+    let is_approved = CommunityFollower::is_approved_follower(
+      pool,
+      data.inserted_community.id,
+      data.blocked_local_user_view.person.id,
+    )
+    .await?;
+    assert!(!is_approved);
+
+    // Once approved, the follower sees everything again
+    CommunityFollower::approve(
+      pool,
+      data.inserted_community.id,
+      data.blocked_local_user_view.person.id,
+    )
+    .await?;
+    let approved = follower_query.clone().list(&data.site, pool).await?;
+    assert_eq!(public_posts.len(), approved.len());
+    let approved_post = PostView::read(
+      pool,
+      data.inserted_post.id,
+      Some(&data.blocked_local_user_view.local_user),
+      false,
+    )
+    .await?;
+    assert!(approved_post.is_some());
+
+    // Logged out users never see private content, admins always do
+    let unauthenticated_query = PostQuery::default().list(&data.site, pool).await?;
+    assert!(unauthenticated_query.is_empty());
+    let admin_query = data.default_post_query().list(&data.site, pool).await?;
+    assert!(!admin_query.is_empty());
+
+    cleanup(data, pool).await?;
+    Ok(())
+  }
+
   #[tokio::test]
   #[serial]
   async fn local_only_instance() -> LemmyResult<()> {
@@ -49,6 +49,10 @@ fn queries<'a>() -> Queries<
   let list = move |mut conn: DbConn<'a>, options: PrivateMessageReportQuery| async move {
     let mut query = all_joins(private_message_report::table.into_boxed());
 
+    if let Some(report_ids) = options.report_ids {
+      query = query.filter(private_message_report::id.eq_any(report_ids));
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
     // first (FIFO)
     if options.unresolved_only {
@@ -99,6 +103,8 @@ impl PrivateMessageReportView {
 
 #[derive(Default)]
 pub struct PrivateMessageReportQuery {
+  /// Only list these reports, eg for the combined report queue.
+  pub report_ids: Option<Vec<PrivateMessageReportId>>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
@@ -160,6 +166,7 @@ mod tests {
       original_pm_text: pm.content.clone(),
       private_message_id: pm.id,
       reason: "its offensive".to_string(),
+      category_id: None,
     };
     let pm_report = PrivateMessageReport::report(pool, &pm_report_form)
       .await
@@ -1,18 +1,20 @@
 use crate::structs::PrivateMessageView;
 use diesel::{
   debug_query,
+  dsl::{exists, not},
   pg::Pg,
   result::Error,
   BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
+  NullableExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aliases,
-  newtypes::{PersonId, PrivateMessageId},
-  schema::{instance_block, person, person_block, private_message},
+  newtypes::{ConversationId, PersonId, PrivateMessageId},
+  schema::{conversation_participant, instance_block, person, person_block, private_message},
   utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
 };
 use tracing::debug;
@@ -66,9 +68,39 @@ fn queries<'a>() -> Queries<
       // Dont show replies from blocked instances
       .filter(instance_block::person_id.is_null());
 
+    // Messages of a group conversation, access is checked by the caller. Participants who left
+    // don't see the messages which were sent afterwards.
+    if let Some(conversation_id) = options.conversation_id {
+      // The outer query already joins person_block for the recipient, so an alias is needed here
+      let my_person_block = diesel::alias!(person_block as my_person_block);
+      query = query
+        .filter(private_message::conversation_id.eq(conversation_id))
+        .filter(not(exists(
+          my_person_block.filter(
+            my_person_block
+              .field(person_block::person_id)
+              .eq(recipient_id)
+              .and(
+                my_person_block
+                  .field(person_block::target_id)
+                  .eq(private_message::creator_id),
+              ),
+          ),
+        )))
+        .filter(not(exists(
+          conversation_participant::table.filter(
+            conversation_participant::conversation_id
+              .eq(conversation_id)
+              .and(conversation_participant::person_id.eq(recipient_id))
+              .and(conversation_participant::left_at.lt(private_message::published.nullable())),
+          ),
+        )));
+    }
     // If its unread, I only want the ones to me
-    if options.unread_only {
-      query = query.filter(private_message::read.eq(false));
+    else if options.unread_only {
+      query = query
+        .filter(private_message::conversation_id.is_null())
+        .filter(private_message::read.eq(false));
       if let Some(i) = options.creator_id {
         query = query.filter(private_message::creator_id.eq(i))
       }
@@ -76,11 +108,13 @@ fn queries<'a>() -> Queries<
     }
     // Otherwise, I want the ALL view to show both sent and received
     else {
-      query = query.filter(
-        private_message::recipient_id
-          .eq(recipient_id)
-          .or(private_message::creator_id.eq(recipient_id)),
-      );
+      query = query
+        .filter(private_message::conversation_id.is_null())
+        .filter(
+          private_message::recipient_id
+            .eq(recipient_id)
+            .or(private_message::creator_id.eq(recipient_id)),
+        );
       if let Some(i) = options.creator_id {
         query = query.filter(
           private_message::creator_id
@@ -148,6 +182,7 @@ impl PrivateMessageView {
       .filter(instance_block::person_id.is_null())
       .filter(private_message::read.eq(false))
       .filter(private_message::recipient_id.eq(my_person_id))
+      .filter(private_message::conversation_id.is_null())
       .filter(private_message::deleted.eq(false))
       .filter(private_message::removed.eq(false))
       .select(count(private_message::id))
@@ -162,6 +197,8 @@ pub struct PrivateMessageQuery {
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub creator_id: Option<PersonId>,
+  /// Only list messages of this group conversation.
+  pub conversation_id: Option<ConversationId>,
 }
 
 impl PrivateMessageQuery {
@@ -267,6 +267,8 @@ mod tests {
         enable_animated_images: inserted_sara_local_user.enable_animated_images,
         collapse_bot_comments: inserted_sara_local_user.collapse_bot_comments,
         last_donation_notification: inserted_sara_local_user.last_donation_notification,
+        send_login_alerts_to_email: inserted_sara_local_user.send_login_alerts_to_email,
+        invited_by: None,
       },
       creator: Person {
         id: inserted_sara_person.id,
@@ -290,6 +292,8 @@ mod tests {
         private_key: inserted_sara_person.private_key,
         public_key: inserted_sara_person.public_key,
         last_refreshed_at: inserted_sara_person.last_refreshed_at,
+        also_known_as: vec![],
+        moved_to: None,
       },
       admin: None,
     };
@@ -369,6 +373,8 @@ mod tests {
       private_key: inserted_timmy_person.private_key,
       public_key: inserted_timmy_person.public_key,
       last_refreshed_at: inserted_timmy_person.last_refreshed_at,
+      also_known_as: vec![],
+      moved_to: None,
     });
     assert_eq!(read_sara_app_view_after_approve, expected_sara_app_view);
 
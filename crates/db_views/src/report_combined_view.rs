@@ -0,0 +1,493 @@
+use crate::{
+  comment_report_view::CommentReportQuery,
+  post_report_view::PostReportQuery,
+  private_message_report_view::PrivateMessageReportQuery,
+  structs::{
+    CommentReportView,
+    LocalUserView,
+    PostReportView,
+    PrivateMessageReportView,
+    ReportCombinedView,
+  },
+};
+use diesel::{
+  dsl::exists,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, PersonId, ReportCategoryId, ReportCombinedId},
+  schema::{
+    comment_report,
+    community_moderator,
+    person,
+    post_report,
+    private_message_report,
+    report_combined,
+  },
+  source::{person::Person, report_combined::ReportCombined},
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+  ReportStatus,
+};
+use std::collections::HashMap;
+
+impl ReportCombinedView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    report_id: ReportCombinedId,
+    my_person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    match ReportCombined::read(pool, report_id).await? {
+      Some(report_combined) => Ok(Some(Self::load(pool, report_combined, my_person_id).await?)),
+      None => Ok(None),
+    }
+  }
+
+  /// Reads the view of the post, comment or private message report and its assignee.
+  async fn load(
+    pool: &mut DbPool<'_>,
+    report_combined: ReportCombined,
+    my_person_id: PersonId,
+  ) -> Result<Self, Error> {
+    let mut view = ReportCombinedView {
+      report_combined,
+      post_report: None,
+      comment_report: None,
+      private_message_report: None,
+      assignee: None,
+    };
+    let assignee_id = if let Some(report_id) = view.report_combined.post_report_id {
+      view.post_report = PostReportView::read(pool, report_id, my_person_id).await?;
+      view.post_report.as_ref().map(|r| r.post_report.assignee_id)
+    } else if let Some(report_id) = view.report_combined.comment_report_id {
+      view.comment_report = CommentReportView::read(pool, report_id, my_person_id).await?;
+      view
+        .comment_report
+        .as_ref()
+        .map(|r| r.comment_report.assignee_id)
+    } else if let Some(report_id) = view.report_combined.private_message_report_id {
+      view.private_message_report = PrivateMessageReportView::read(pool, report_id).await?;
+      view
+        .private_message_report
+        .as_ref()
+        .map(|r| r.private_message_report.assignee_id)
+    } else {
+      None
+    };
+    if let Some(assignee_id) = assignee_id.flatten() {
+      view.assignee = Person::read(pool, assignee_id).await?;
+    }
+    Ok(view)
+  }
+}
+
+#[derive(Default)]
+pub struct ReportCombinedQuery {
+  pub community_id: Option<CommunityId>,
+  pub category_id: Option<ReportCategoryId>,
+  pub assignee_id: Option<PersonId>,
+  pub unassigned_only: bool,
+  pub status: Option<ReportStatus>,
+  pub unresolved_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl ReportCombinedQuery {
+  /// Lists the post, comment and private message reports which the user can handle. Admins get
+  /// all of them, moderators only the post and comment reports of their communities.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    user: &LocalUserView,
+  ) -> Result<Vec<ReportCombinedView>, Error> {
+    let mut query = report_combined::table
+      .left_join(post_report::table)
+      .left_join(comment_report::table)
+      .left_join(private_message_report::table)
+      .select(report_combined::all_columns)
+      .into_boxed();
+
+    if let Some(community_id) = self.community_id {
+      query = query.filter(report_combined::community_id.eq(community_id));
+    }
+
+    if let Some(category_id) = self.category_id {
+      query = query.filter(
+        post_report::category_id
+          .eq(category_id)
+          .or(comment_report::category_id.eq(category_id))
+          .or(private_message_report::category_id.eq(category_id)),
+      );
+    }
+
+    if let Some(assignee_id) = self.assignee_id {
+      query = query.filter(
+        post_report::assignee_id
+          .eq(assignee_id)
+          .or(comment_report::assignee_id.eq(assignee_id))
+          .or(private_message_report::assignee_id.eq(assignee_id)),
+      );
+    } else if self.unassigned_only {
+      query = query.filter(
+        post_report::assignee_id
+          .is_null()
+          .and(comment_report::assignee_id.is_null())
+          .and(private_message_report::assignee_id.is_null()),
+      );
+    }
+
+    if let Some(status) = self.status {
+      query = query.filter(
+        post_report::status
+          .nullable()
+          .eq(status)
+          .or(comment_report::status.nullable().eq(status))
+          .or(private_message_report::status.nullable().eq(status)),
+      );
+    }
+
+    if !user.local_user.admin {
+      query = query.filter(exists(
+        community_moderator::table
+          .filter(
+            community_moderator::community_id
+              .nullable()
+              .eq(report_combined::community_id),
+          )
+          .filter(community_moderator::person_id.eq(user.person.id)),
+      ));
+    }
+
+    // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
+    // first (FIFO)
+    if self.unresolved_only {
+      query = query
+        .filter(
+          post_report::resolved
+            .nullable()
+            .eq(false)
+            .or(comment_report::resolved.nullable().eq(false))
+            .or(private_message_report::resolved.nullable().eq(false)),
+        )
+        .order_by(report_combined::published.asc());
+    } else {
+      query = query.order_by(report_combined::published.desc());
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+    let reports = {
+      let conn = &mut get_conn(pool).await?;
+      query
+        .limit(limit)
+        .offset(offset)
+        .load::<ReportCombined>(conn)
+        .await?
+    };
+
+    // Load the views of each report type with a single query, instead of one per report. There
+    // are at most `limit` reports of each type.
+    let post_report_ids = reports
+      .iter()
+      .filter_map(|r| r.post_report_id)
+      .collect::<Vec<_>>();
+    let mut post_reports = HashMap::new();
+    if !post_report_ids.is_empty() {
+      let query = PostReportQuery {
+        report_ids: Some(post_report_ids),
+        limit: Some(limit),
+        ..Default::default()
+      };
+      for view in query.list(pool, user).await? {
+        post_reports.insert(view.post_report.id, view);
+      }
+    }
+
+    let comment_report_ids = reports
+      .iter()
+      .filter_map(|r| r.comment_report_id)
+      .collect::<Vec<_>>();
+    let mut comment_reports = HashMap::new();
+    if !comment_report_ids.is_empty() {
+      let query = CommentReportQuery {
+        report_ids: Some(comment_report_ids),
+        limit: Some(limit),
+        ..Default::default()
+      };
+      for view in query.list(pool, user).await? {
+        comment_reports.insert(view.comment_report.id, view);
+      }
+    }
+
+    let private_message_report_ids = reports
+      .iter()
+      .filter_map(|r| r.private_message_report_id)
+      .collect::<Vec<_>>();
+    let mut private_message_reports = HashMap::new();
+    if !private_message_report_ids.is_empty() {
+      let query = PrivateMessageReportQuery {
+        report_ids: Some(private_message_report_ids),
+        limit: Some(limit),
+        ..Default::default()
+      };
+      for view in query.list(pool).await? {
+        private_message_reports.insert(view.private_message_report.id, view);
+      }
+    }
+
+    let assignee_ids = post_reports
+      .values()
+      .filter_map(|r| r.post_report.assignee_id)
+      .chain(
+        comment_reports
+          .values()
+          .filter_map(|r| r.comment_report.assignee_id),
+      )
+      .chain(
+        private_message_reports
+          .values()
+          .filter_map(|r| r.private_message_report.assignee_id),
+      )
+      .collect::<Vec<_>>();
+    let assignees = if assignee_ids.is_empty() {
+      HashMap::new()
+    } else {
+      let conn = &mut get_conn(pool).await?;
+      person::table
+        .filter(person::id.eq_any(assignee_ids))
+        .load::<Person>(conn)
+        .await?
+        .into_iter()
+        .map(|p| (p.id, p))
+        .collect::<HashMap<_, _>>()
+    };
+
+    Ok(
+      reports
+        .into_iter()
+        .map(|report_combined| {
+          let post_report = report_combined
+            .post_report_id
+            .and_then(|id| post_reports.remove(&id));
+          let comment_report = report_combined
+            .comment_report_id
+            .and_then(|id| comment_reports.remove(&id));
+          let private_message_report = report_combined
+            .private_message_report_id
+            .and_then(|id| private_message_reports.remove(&id));
+          let assignee_id = post_report
+            .as_ref()
+            .and_then(|r| r.post_report.assignee_id)
+            .or(
+              comment_report
+                .as_ref()
+                .and_then(|r| r.comment_report.assignee_id),
+            )
+            .or(
+              private_message_report
+                .as_ref()
+                .and_then(|r| r.private_message_report.assignee_id),
+            );
+          ReportCombinedView {
+            report_combined,
+            post_report,
+            comment_report,
+            private_message_report,
+            assignee: assignee_id.and_then(|id| assignees.get(&id).cloned()),
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{report_combined_view::ReportCombinedQuery, structs::LocalUserView};
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+      private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
+      report_combined::ReportTriageForm,
+    },
+    traits::{Crud, Joinable, Reportable},
+    utils::{build_db_pool_for_tests, DbPool},
+    ReportStatus,
+  };
+  use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  async fn create_local_user(
+    pool: &mut DbPool<'_>,
+    person: &Person,
+    admin: bool,
+  ) -> LemmyResult<LocalUserView> {
+    let form = if admin {
+      LocalUserInsertForm::test_form_admin(person.id)
+    } else {
+      LocalUserInsertForm::test_form(person.id)
+    };
+    LocalUser::create(pool, &form, vec![]).await?;
+    Ok(
+      LocalUserView::read_person(pool, person.id)
+        .await?
+        .ok_or(LemmyErrorType::CouldntFindPerson)?,
+    )
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_report_combined_view() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let form = PersonInsertForm::test_form(instance.id, "report_combined_admin");
+    let admin_person = Person::create(pool, &form).await?;
+    let admin = create_local_user(pool, &admin_person, true).await?;
+    let form = PersonInsertForm::test_form(instance.id, "report_combined_mod");
+    let mod_person = Person::create(pool, &form).await?;
+    let moderator = create_local_user(pool, &mod_person, false).await?;
+    let form = PersonInsertForm::test_form(instance.id, "report_combined_reporter");
+    let reporter = Person::create(pool, &form).await?;
+
+    let form = CommunityInsertForm::builder()
+      .name("test_community_rcv".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &form).await?;
+    let form = CommunityModeratorForm {
+      community_id: community.id,
+      person_id: mod_person.id,
+    };
+    CommunityModerator::join(pool, &form).await?;
+
+    let form = PostInsertForm::builder()
+      .name("A test post rcv".into())
+      .creator_id(admin_person.id)
+      .community_id(community.id)
+      .build();
+    let post = Post::create(pool, &form).await?;
+    let form = PostReportForm {
+      creator_id: reporter.id,
+      post_id: post.id,
+      reason: "spam".to_string(),
+      ..Default::default()
+    };
+    let post_report = PostReport::report(pool, &form).await?;
+
+    let form = PrivateMessageInsertForm::builder()
+      .creator_id(admin_person.id)
+      .recipient_id(reporter.id)
+      .content("something offensive".to_string())
+      .build();
+    let pm = PrivateMessage::create(pool, &form).await?;
+    let form = PrivateMessageReportForm {
+      creator_id: reporter.id,
+      private_message_id: pm.id,
+      original_pm_text: pm.content.clone(),
+      reason: "rude".to_string(),
+      category_id: None,
+    };
+    let pm_report = PrivateMessageReport::report(pool, &form).await?;
+
+    // Admins see all reports, newest first
+    let reports = ReportCombinedQuery::default().list(pool, &admin).await?;
+    assert_length!(2, reports);
+    let pm_report_view = reports[0].private_message_report.as_ref();
+    assert_eq!(
+      Some(pm_report.id),
+      pm_report_view.map(|r| r.private_message_report.id)
+    );
+    let post_report_view = reports[1].post_report.as_ref();
+    assert_eq!(
+      Some(post_report.id),
+      post_report_view.map(|r| r.post_report.id)
+    );
+
+    // Moderators only see the reports of their communities
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &moderator)
+      .await?;
+    assert_length!(1, reports);
+    let post_report_combined = reports[0].report_combined.clone();
+    assert_eq!(Some(community.id), post_report_combined.community_id);
+
+    let form = ReportTriageForm {
+      assignee_id: Some(Some(mod_person.id)),
+      status: Some(ReportStatus::Escalated),
+      ..Default::default()
+    };
+    post_report_combined
+      .triage(pool, mod_person.id, &form)
+      .await?;
+
+    let reports = ReportCombinedQuery {
+      assignee_id: Some(mod_person.id),
+      ..Default::default()
+    }
+    .list(pool, &admin)
+    .await?;
+    assert_length!(1, reports);
+    assert_eq!(
+      Some(mod_person.id),
+      reports[0].assignee.as_ref().map(|a| a.id)
+    );
+    assert_eq!(
+      Some(ReportStatus::Escalated),
+      reports[0]
+        .post_report
+        .as_ref()
+        .map(|r| r.post_report.status)
+    );
+
+    let reports = ReportCombinedQuery {
+      unassigned_only: true,
+      ..Default::default()
+    }
+    .list(pool, &admin)
+    .await?;
+    assert_length!(1, reports);
+    assert!(reports[0].private_message_report.is_some());
+
+    let reports = ReportCombinedQuery {
+      status: Some(ReportStatus::Open),
+      ..Default::default()
+    }
+    .list(pool, &admin)
+    .await?;
+    assert_length!(1, reports);
+    assert!(reports[0].private_message_report.is_some());
+
+    PrivateMessageReport::resolve(pool, pm_report.id, admin_person.id).await?;
+    let reports = ReportCombinedQuery {
+      unresolved_only: true,
+      ..Default::default()
+    }
+    .list(pool, &admin)
+    .await?;
+    assert_length!(1, reports);
+    assert!(reports[0].post_report.is_some());
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
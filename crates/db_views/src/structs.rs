@@ -3,9 +3,11 @@ use diesel::Queryable;
 use lemmy_db_schema::{
   aggregates::structs::{CommentAggregates, PersonAggregates, PostAggregates, SiteAggregates},
   source::{
+    ban_appeal::BanAppeal,
     comment::Comment,
     comment_report::CommentReport,
     community::Community,
+    conversation::{Conversation, ConversationParticipant},
     custom_emoji::CustomEmoji,
     custom_emoji_keyword::CustomEmojiKeyword,
     images::{ImageDetails, LocalImage},
@@ -13,13 +15,17 @@ use lemmy_db_schema::{
     local_site_rate_limit::LocalSiteRateLimit,
     local_user::LocalUser,
     local_user_vote_display_mode::LocalUserVoteDisplayMode,
+    mod_note::ModNotes,
     person::Person,
+    poll::Poll,
     post::Post,
     post_report::PostReport,
     private_message::PrivateMessage,
     private_message_report::PrivateMessageReport,
     registration_application::RegistrationApplication,
+    report_combined::ReportCombined,
     site::Site,
+    tag::PostTags,
   },
   SubscribedType,
 };
@@ -50,6 +56,8 @@ pub struct CommentReportView {
   pub saved: bool,
   pub my_vote: Option<i16>,
   pub resolver: Option<Person>,
+  /// Moderator notes about the comment creator which you are allowed to see.
+  pub comment_creator_mod_notes: ModNotes,
 }
 
 #[skip_serializing_none]
@@ -110,6 +118,8 @@ pub struct PostReportView {
   pub unread_comments: i64,
   pub counts: PostAggregates,
   pub resolver: Option<Person>,
+  /// Moderator notes about the post creator which you are allowed to see.
+  pub post_creator_mod_notes: ModNotes,
 }
 
 /// currently this is just a wrapper around post id, but should be seen as opaque from the client's
@@ -144,6 +154,11 @@ pub struct PostView {
   pub creator_blocked: bool,
   pub my_vote: Option<i16>,
   pub unread_comments: i64,
+  /// Only set for poll posts.
+  pub poll: Option<Poll>,
+  /// The options which you voted for, if any.
+  pub my_poll_choices: Option<Vec<i32>>,
+  pub tags: PostTags,
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
@@ -157,6 +172,21 @@ pub struct PrivateMessageView {
   pub recipient: Person,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A group conversation view, for one of its participants.
+pub struct ConversationView {
+  pub conversation: Conversation,
+  /// Read state and settings of the current user.
+  pub participant: ConversationParticipant,
+  /// Everyone who is currently part of the conversation.
+  pub participants: Vec<Person>,
+  pub last_message: Option<PrivateMessage>,
+  pub unread_count: i64,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
@@ -184,6 +214,33 @@ pub struct RegistrationApplicationView {
   pub admin: Option<Person>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A post, comment or private message report in the combined report queue.
+pub struct ReportCombinedView {
+  pub report_combined: ReportCombined,
+  pub post_report: Option<PostReportView>,
+  pub comment_report: Option<CommentReportView>,
+  pub private_message_report: Option<PrivateMessageReportView>,
+  /// The moderator or admin who is handling the report.
+  pub assignee: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A ban appeal view.
+pub struct BanAppealView {
+  pub ban_appeal: BanAppeal,
+  pub appellant: Person,
+  pub community: Option<Community>,
+  pub resolver: Option<Person>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
 #[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
@@ -10,7 +10,7 @@ use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::{CommunityId, DbUrl, InstanceId, PersonId},
   schema::{community, community_follower, person},
-  utils::{functions::coalesce, get_conn, DbPool},
+  utils::{functions::coalesce, get_conn, limit_and_offset, DbPool},
 };
 
 impl CommunityFollowerView {
@@ -36,6 +36,8 @@ impl CommunityFollowerView {
       .filter(community::local) // this should be a no-op since community_followers table only has
       // local-person+remote-community or remote-person+local-community
       .filter(not(person::local))
+      // followers of private communities only receive activities once approved
+      .filter(community_follower::pending.eq(false))
       .filter(community_follower::published.gt(published_since.naive_utc()))
       .select((
         community::id,
@@ -52,6 +54,7 @@ impl CommunityFollowerView {
     let conn = &mut get_conn(pool).await?;
     let res = community_follower::table
       .filter(community_follower::community_id.eq(community_id))
+      .filter(community_follower::pending.eq(false))
       .filter(not(person::local))
       .inner_join(person::table)
       .select(coalesce(person::shared_inbox_url, person::inbox_url))
@@ -88,4 +91,26 @@ impl CommunityFollowerView {
       .load::<CommunityFollowerView>(conn)
       .await
   }
+
+  /// List follow requests of a private community which are waiting for approval by a mod.
+  pub async fn list_pending_follows(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    community_follower::table
+      .inner_join(community::table)
+      .inner_join(person::table)
+      .select((community::all_columns, person::all_columns))
+      .filter(community_follower::community_id.eq(community_id))
+      .filter(community_follower::pending)
+      .order_by(community_follower::published)
+      .limit(limit)
+      .offset(offset)
+      .load::<CommunityFollowerView>(conn)
+      .await
+  }
 }
@@ -13,6 +13,8 @@ pub mod community_view;
 #[cfg(feature = "full")]
 pub mod instance_block_view;
 #[cfg(feature = "full")]
+pub mod notification_view;
+#[cfg(feature = "full")]
 pub mod person_block_view;
 #[cfg(feature = "full")]
 pub mod person_mention_view;
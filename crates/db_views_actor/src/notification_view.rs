@@ -0,0 +1,348 @@
+use crate::structs::NotificationView;
+use diesel::{
+  dsl::{count, exists, not},
+  pg::Pg,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{NotificationId, PersonId},
+  schema::{comment, community, notification, person, person_block, post, private_message},
+  source::local_user::LocalUser,
+  utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  NotificationType,
+};
+
+/// Notification kinds which are hidden if they were created by a bot account, and the recipient
+/// doesn't want to see bots.
+const BOT_FILTERED_KINDS: [NotificationType; 3] = [
+  NotificationType::Reply,
+  NotificationType::Mention,
+  NotificationType::PrivateMessage,
+];
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, NotificationView, NotificationId>,
+  impl ListFn<'a, NotificationView, NotificationQuery>,
+> {
+  let is_creator_blocked = |person_id: PersonId| {
+    exists(
+      person_block::table.filter(
+        notification::creator_id
+          .eq(person_block::target_id.nullable())
+          .and(person_block::person_id.eq(person_id)),
+      ),
+    )
+  };
+
+  let all_joins = |query: notification::BoxedQuery<'a, Pg>| {
+    query
+      .left_join(person::table.on(notification::creator_id.eq(person::id.nullable())))
+      .left_join(comment::table.on(notification::comment_id.eq(comment::id.nullable())))
+      .left_join(post::table.on(notification::post_id.eq(post::id.nullable())))
+      .left_join(community::table.on(notification::community_id.eq(community::id.nullable())))
+      .left_join(
+        private_message::table
+          .on(notification::private_message_id.eq(private_message::id.nullable())),
+      )
+      .select((
+        notification::all_columns,
+        person::all_columns.nullable(),
+        comment::all_columns.nullable(),
+        post::all_columns.nullable(),
+        community::all_columns.nullable(),
+        private_message::all_columns.nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>, notification_id: NotificationId| async move {
+    all_joins(notification::table.find(notification_id).into_boxed())
+      .first(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, options: NotificationQuery| async move {
+    // These filters need to be kept in sync with the filters in
+    // NotificationView::get_unread_counts()
+    let mut query = all_joins(notification::table.into_boxed())
+      .filter(notification::recipient_id.eq(options.recipient_id))
+      .filter(not(is_creator_blocked(options.recipient_id)));
+
+    if options.unread_only {
+      query = query.filter(notification::read.eq(false));
+    }
+
+    if let Some(notification_id) = options.notification_id {
+      query = query.filter(notification::id.eq(notification_id));
+    }
+
+    if let Some(kind) = options.kind {
+      query = query.filter(notification::kind.eq(kind));
+    }
+
+    if !options.show_bot_accounts {
+      query = query.filter(
+        notification::kind
+          .ne_all(BOT_FILTERED_KINDS)
+          .or(person::bot_account.eq(false)),
+      );
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query
+      .order_by(notification::published.desc())
+      .then_order_by(notification::id.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<NotificationView>(&mut conn)
+      .await
+  };
+
+  Queries::new(read, list)
+}
+
+impl NotificationView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    notification_id: NotificationId,
+  ) -> Result<Option<Self>, Error> {
+    queries().read(pool, notification_id).await
+  }
+
+  /// Gets the number of unread notifications of a user, grouped by notification type.
+  pub async fn get_unread_counts(
+    pool: &mut DbPool<'_>,
+    local_user: &LocalUser,
+  ) -> Result<Vec<(NotificationType, i64)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    let mut query = notification::table
+      .left_join(person::table.on(notification::creator_id.eq(person::id.nullable())))
+      .left_join(
+        person_block::table.on(
+          notification::creator_id
+            .eq(person_block::target_id.nullable())
+            .and(person_block::person_id.eq(local_user.person_id)),
+        ),
+      )
+      .group_by(notification::kind)
+      .select((notification::kind, count(notification::id)))
+      .into_boxed();
+
+    // These filters need to be kept in sync with the filters in queries().list()
+    if !local_user.show_bot_accounts {
+      query = query.filter(
+        notification::kind
+          .ne_all(BOT_FILTERED_KINDS)
+          .or(person::bot_account.eq(false)),
+      );
+    }
+
+    query
+      // Don't count notifications from blocked users
+      .filter(person_block::person_id.is_null())
+      .filter(notification::recipient_id.eq(local_user.person_id))
+      .filter(notification::read.eq(false))
+      .load::<(NotificationType, i64)>(conn)
+      .await
+  }
+}
+
+#[derive(Default, Clone)]
+pub struct NotificationQuery {
+  pub recipient_id: PersonId,
+  /// Only return this notification, if it passes the other filters.
+  pub notification_id: Option<NotificationId>,
+  pub kind: Option<NotificationType>,
+  pub unread_only: bool,
+  pub show_bot_accounts: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl NotificationQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<NotificationView>, Error> {
+    queries().list(pool, self).await
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{notification_view::NotificationQuery, structs::NotificationView};
+  use lemmy_db_schema::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      comment_reply::{CommentReply, CommentReplyInsertForm},
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      notification::{Notification, NotificationInsertForm},
+      person::{Person, PersonInsertForm},
+      person_block::{PersonBlock, PersonBlockForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::{Blockable, Crud},
+    utils::build_db_pool_for_tests,
+    NotificationType,
+  };
+  use lemmy_utils::{error::LemmyResult, LemmyErrorType};
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "notification_creator");
+    let inserted_person = Person::create(pool, &new_person).await?;
+
+    let recipient_form =
+      PersonInsertForm::test_form(inserted_instance.id, "notification_recipient");
+    let inserted_recipient = Person::create(pool, &recipient_form).await?;
+    let recipient_id = inserted_recipient.id;
+
+    let recipient_local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(recipient_id), vec![]).await?;
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community notification".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let new_post = PostInsertForm::builder()
+      .name("A test post".into())
+      .creator_id(inserted_recipient.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await?;
+
+    let comment_form = CommentInsertForm::builder()
+      .content("A test comment".into())
+      .creator_id(inserted_person.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &comment_form, None).await?;
+
+    let comment_reply_form = CommentReplyInsertForm {
+      recipient_id,
+      comment_id: inserted_comment.id,
+      read: None,
+    };
+    let inserted_reply = CommentReply::create(pool, &comment_reply_form).await?;
+
+    let reply_form = NotificationInsertForm::builder()
+      .recipient_id(recipient_id)
+      .kind(NotificationType::Reply)
+      .creator_id(Some(inserted_person.id))
+      .comment_id(Some(inserted_comment.id))
+      .post_id(Some(inserted_post.id))
+      .community_id(Some(inserted_community.id))
+      .build();
+    let inserted_notification = Notification::create(pool, &reply_form).await?;
+
+    // Creating the same reply notification again is ignored
+    assert!(Notification::create(pool, &reply_form).await.is_err());
+
+    let removal_form = NotificationInsertForm::builder()
+      .recipient_id(recipient_id)
+      .kind(NotificationType::ModRemovePost)
+      .creator_id(Some(inserted_person.id))
+      .post_id(Some(inserted_post.id))
+      .community_id(Some(inserted_community.id))
+      .reason(Some("off topic".to_string()))
+      .build();
+    Notification::create(pool, &removal_form).await?;
+
+    let read_notification = NotificationView::read(pool, inserted_notification.id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindNotification)?;
+    assert_eq!(
+      Some(inserted_comment.id),
+      read_notification.comment.map(|c| c.id)
+    );
+    assert_eq!(
+      Some(inserted_person.id),
+      read_notification.creator.map(|c| c.id)
+    );
+    assert!(read_notification.private_message.is_none());
+
+    let query = NotificationQuery {
+      recipient_id,
+      unread_only: true,
+      show_bot_accounts: true,
+      ..Default::default()
+    };
+    let notifications = query.clone().list(pool).await?;
+    assert_eq!(2, notifications.len());
+    // Newest first
+    assert_eq!(
+      NotificationType::ModRemovePost,
+      notifications[0].notification.kind
+    );
+
+    let mut counts = NotificationView::get_unread_counts(pool, &recipient_local_user).await?;
+    counts.sort_by_key(|(kind, _)| kind.to_string());
+    assert_eq!(
+      vec![
+        (NotificationType::ModRemovePost, 1),
+        (NotificationType::Reply, 1)
+      ],
+      counts
+    );
+
+    // Marking the reply notification as read also marks the comment reply as read
+    Notification::mark_as_read(
+      pool,
+      recipient_id,
+      Some(vec![inserted_notification.id]),
+      true,
+    )
+    .await?;
+    let read_reply = CommentReply::read(pool, inserted_reply.id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindCommentReply)?;
+    assert!(read_reply.read);
+    assert_eq!(1, query.clone().list(pool).await?.len());
+
+    // Block the creator, and make sure their notifications are hidden
+    let block_form = PersonBlockForm {
+      person_id: recipient_id,
+      target_id: inserted_person.id,
+    };
+    PersonBlock::block(pool, &block_form).await?;
+    let notifications_after_block = query.clone().list(pool).await?;
+    let counts_after_block =
+      NotificationView::get_unread_counts(pool, &recipient_local_user).await?;
+    assert_eq!(0, notifications_after_block.len());
+    assert_eq!(0, counts_after_block.len());
+    PersonBlock::unblock(pool, &block_form).await?;
+
+    // Mark everything as read
+    Notification::mark_as_read(pool, recipient_id, None, true).await?;
+    assert_eq!(0, query.list(pool).await?.len());
+
+    Comment::delete(pool, inserted_comment.id).await?;
+    Post::delete(pool, inserted_post.id).await?;
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Person::delete(pool, inserted_recipient.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -121,8 +121,8 @@ fn queries<'a>(
 
         if let Some(listing_type) = options.listing_type {
           query = match listing_type {
-            // return nothing as its not possible to follow users
-            ListingType::Subscribed => query.limit(0),
+            // return nothing as the logged in user is not known here
+            ListingType::Subscribed | ListingType::FollowedPeople => query.limit(0),
             ListingType::Local => query.filter(person::local.eq(true)),
             _ => query,
           };
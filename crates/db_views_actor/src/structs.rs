@@ -7,9 +7,11 @@ use lemmy_db_schema::{
     comment_reply::CommentReply,
     community::Community,
     instance::Instance,
+    notification::Notification,
     person::Person,
     person_mention::PersonMention,
     post::Post,
+    private_message::PrivateMessage,
     site::Site,
   },
   SubscribedType,
@@ -151,3 +153,18 @@ pub struct PersonView {
   pub counts: PersonAggregates,
   pub is_admin: bool,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A notification view, with the objects it refers to.
+pub struct NotificationView {
+  pub notification: Notification,
+  pub creator: Option<Person>,
+  pub comment: Option<Comment>,
+  pub post: Option<Post>,
+  pub community: Option<Community>,
+  pub private_message: Option<PrivateMessage>,
+}
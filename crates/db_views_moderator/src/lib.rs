@@ -27,5 +27,9 @@ pub mod mod_remove_community_view;
 #[cfg(feature = "full")]
 pub mod mod_remove_post_view;
 #[cfg(feature = "full")]
+pub mod mod_resolve_ban_appeal_view;
+#[cfg(feature = "full")]
 pub mod mod_transfer_community_view;
+#[cfg(feature = "full")]
+pub mod mod_warning_view;
 pub mod structs;
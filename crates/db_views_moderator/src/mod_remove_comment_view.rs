@@ -11,7 +11,7 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::PersonId,
-  schema::{comment, community, mod_remove_comment, person, post},
+  schema::{comment, community, mod_remove_comment, person, post, rule},
   utils::{get_conn, limit_and_offset, DbPool},
 };
 
@@ -32,6 +32,7 @@ impl ModRemoveCommentView {
       .inner_join(person_alias_1.on(comment::creator_id.eq(person_alias_1.field(person::id))))
       .inner_join(post::table.on(comment::post_id.eq(post::id)))
       .inner_join(community::table.on(post::community_id.eq(community::id)))
+      .left_join(rule::table.on(mod_remove_comment::rule_id.eq(rule::id.nullable())))
       .select((
         mod_remove_comment::all_columns,
         person::all_columns.nullable(),
@@ -39,6 +40,7 @@ impl ModRemoveCommentView {
         person_alias_1.fields(person::all_columns),
         post::all_columns,
         community::all_columns,
+        rule::all_columns.nullable(),
       ))
       .into_boxed();
 
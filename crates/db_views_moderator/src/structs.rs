@@ -20,10 +20,13 @@ use lemmy_db_schema::{
       ModRemoveComment,
       ModRemoveCommunity,
       ModRemovePost,
+      ModResolveBanAppeal,
       ModTransferCommunity,
+      ModWarning,
     },
     person::Person,
     post::Post,
+    rule::Rule,
   },
 };
 use serde::{Deserialize, Serialize};
@@ -119,6 +122,7 @@ pub struct ModRemoveCommentView {
   pub commenter: Person,
   pub post: Post,
   pub community: Community,
+  pub rule: Option<Rule>,
 }
 
 #[skip_serializing_none]
@@ -144,6 +148,7 @@ pub struct ModRemovePostView {
   pub moderator: Option<Person>,
   pub post: Post,
   pub community: Community,
+  pub rule: Option<Rule>,
 }
 
 #[skip_serializing_none]
@@ -218,6 +223,32 @@ pub struct AdminPurgePostView {
   pub community: Community,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When someone is warned by a moderator or admin.
+pub struct ModWarningView {
+  pub mod_warning: ModWarning,
+  pub moderator: Option<Person>,
+  pub community: Option<Community>,
+  pub warned_person: Person,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a ban appeal is accepted or denied.
+pub struct ModResolveBanAppealView {
+  pub mod_resolve_ban_appeal: ModResolveBanAppeal,
+  pub moderator: Option<Person>,
+  pub community: Option<Community>,
+  pub appellant: Person,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
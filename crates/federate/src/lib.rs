@@ -1,13 +1,19 @@
-use crate::{util::CancellableTask, worker::InstanceWorker};
+use crate::{util::CancellableTask, webhook::WebhookWorker, worker::InstanceWorker};
 use activitypub_federation::config::FederationConfig;
 use lemmy_api_common::{
   context::LemmyContext,
   lemmy_utils::settings::structs::FederationWorkerConfig,
 };
-use lemmy_db_schema::{newtypes::InstanceId, source::instance::Instance};
+use lemmy_db_schema::{
+  newtypes::{InstanceId, WebhookId},
+  source::{instance::Instance, webhook::Webhook},
+};
 use lemmy_utils::error::LemmyResult;
 use stats::receive_print_stats;
-use std::{collections::HashMap, time::Duration};
+use std::{
+  collections::{HashMap, HashSet},
+  time::Duration,
+};
 use tokio::{
   sync::mpsc::{unbounded_channel, UnboundedSender},
   task::JoinHandle,
@@ -21,6 +27,7 @@ mod inboxes;
 mod send;
 mod stats;
 mod util;
+mod webhook;
 mod worker;
 
 static WORKER_EXIT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -40,6 +47,7 @@ pub struct Opts {
 pub struct SendManager {
   opts: Opts,
   workers: HashMap<InstanceId, CancellableTask>,
+  webhook_workers: HashMap<WebhookId, CancellableTask>,
   context: FederationConfig<LemmyContext>,
   stats_sender: UnboundedSender<FederationQueueStateWithDomain>,
   exit_print: JoinHandle<()>,
@@ -60,6 +68,7 @@ impl SendManager {
     Self {
       opts,
       workers: HashMap::new(),
+      webhook_workers: HashMap::new(),
       stats_sender,
       exit_print: tokio::spawn(receive_print_stats(
         context.inner_pool().clone(),
@@ -106,13 +115,12 @@ impl SendManager {
       self.opts.process_count, process_index
     );
     let local_domain = self.context.settings().get_hostname_without_port()?;
-    let mut pool = self.context.pool();
     loop {
       let mut total_count = 0;
       let mut dead_count = 0;
       let mut disallowed_count = 0;
       for (instance, allowed, is_dead) in
-        Instance::read_federated_with_blocked_and_dead(&mut pool).await?
+        Instance::read_federated_with_blocked_and_dead(&mut self.context.pool()).await?
       {
         if instance.domain == local_domain {
           continue;
@@ -163,6 +171,7 @@ impl SendManager {
       }
       let worker_count = self.workers.len();
       tracing::info!("Federating to {worker_count}/{total_count} instances ({dead_count} dead, {disallowed_count} disallowed)");
+      self.update_webhook_workers().await?;
       tokio::select! {
         () = sleep(INSTANCES_RECHECK_DELAY) => {},
         _ = cancel.cancelled() => { return Ok(()) }
@@ -170,6 +179,47 @@ impl SendManager {
     }
   }
 
+  /// Starts a worker for each webhook which has payloads waiting, and stops the workers of
+  /// webhooks which have nothing left to send. Like instances, webhooks are split between the
+  /// federation processes by id.
+  async fn update_webhook_workers(&mut self) -> LemmyResult<()> {
+    let process_index = self.opts.process_index - 1;
+    let pending = Webhook::list_with_pending_deliveries(&mut self.context.pool())
+      .await?
+      .into_iter()
+      .map(|w| w.id)
+      .filter(|id| id.inner() % self.opts.process_count == process_index)
+      .collect::<HashSet<_>>();
+
+    let idle = self
+      .webhook_workers
+      .keys()
+      .filter(|id| !pending.contains(id))
+      .copied()
+      .collect::<Vec<_>>();
+    for webhook_id in idle {
+      if let Some(worker) = self.webhook_workers.remove(&webhook_id) {
+        if let Err(e) = worker.cancel().await {
+          tracing::error!("error stopping webhook worker: {e}");
+        }
+      }
+    }
+
+    for webhook_id in pending {
+      if self.webhook_workers.contains_key(&webhook_id) {
+        continue;
+      }
+      let context = self.context.clone();
+      self.webhook_workers.insert(
+        webhook_id,
+        CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |stop| {
+          WebhookWorker::init_and_loop(webhook_id, context.clone(), stop)
+        }),
+      );
+    }
+    Ok(())
+  }
+
   pub async fn cancel(self) -> LemmyResult<()> {
     drop(self.stats_sender);
     tracing::warn!(
@@ -183,6 +233,7 @@ impl SendManager {
       self
         .workers
         .into_values()
+        .chain(self.webhook_workers.into_values())
         .map(util::CancellableTask::cancel),
     )
     .await;
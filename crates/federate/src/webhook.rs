@@ -0,0 +1,150 @@
+use activitypub_federation::config::{Data, FederationConfig};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use lemmy_api_common::{
+  context::LemmyContext,
+  federate_retry_sleep_duration,
+  request::check_url_is_public,
+};
+use lemmy_db_schema::{
+  newtypes::WebhookId,
+  source::{
+    webhook::{Webhook, WebhookUpdateForm},
+    webhook_delivery::WebhookDelivery,
+  },
+  traits::Crud,
+};
+use sha2::Sha256;
+use std::time::Duration;
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+
+/// How long to wait before checking for new payloads when the queue of a webhook is empty.
+#[cfg(debug_assertions)]
+const WEBHOOK_POLL_DELAY: Duration = Duration::from_secs(1);
+#[cfg(not(debug_assertions))]
+const WEBHOOK_POLL_DELAY: Duration = Duration::from_secs(10);
+
+/// Webhooks are expected to respond quickly, unlike remote ActivityPub inboxes.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A webhook is disabled after this many failed deliveries in a row. With the exponential backoff
+/// this takes roughly three days, so a short outage of the receiver doesn't disable it.
+const WEBHOOK_MAX_FAIL_COUNT: i32 = 50;
+
+/// Delivers the queued payloads of a single webhook in order. Like activity sending, a failed
+/// delivery is retried with exponential backoff, and the fail count is stored in the database.
+pub(crate) struct WebhookWorker {
+  webhook_id: WebhookId,
+  context: Data<LemmyContext>,
+  stop: CancellationToken,
+}
+
+impl WebhookWorker {
+  pub(crate) async fn init_and_loop(
+    webhook_id: WebhookId,
+    config: FederationConfig<LemmyContext>,
+    stop: CancellationToken,
+  ) -> Result<()> {
+    let worker = WebhookWorker {
+      webhook_id,
+      context: config.to_request_data(),
+      stop,
+    };
+    worker.loop_until_stopped().await
+  }
+
+  async fn loop_until_stopped(&self) -> Result<()> {
+    let pool = &mut self.context.pool();
+    while !self.stop.is_cancelled() {
+      // Read the webhook again every time, in case it was edited or deleted
+      let webhook = Webhook::read(pool, self.webhook_id).await?;
+      let delivery = match webhook {
+        Some(ref webhook) if webhook.enabled => {
+          WebhookDelivery::read_next(pool, webhook.id).await?
+        }
+        _ => None,
+      };
+      let (Some(webhook), Some(delivery)) = (webhook, delivery) else {
+        // The SendManager stops this worker once it notices that there is nothing to send
+        self.sleep_or_stop(WEBHOOK_POLL_DELAY).await;
+        continue;
+      };
+
+      match self.send(&webhook, &delivery).await {
+        Ok(()) => {
+          WebhookDelivery::delete(pool, delivery.id).await?;
+          let form = WebhookUpdateForm {
+            fail_count: Some(0),
+            last_successful_delivery: Some(Some(Utc::now())),
+            ..Default::default()
+          };
+          Webhook::update(pool, webhook.id, &form).await?;
+        }
+        Err(e) => {
+          let fail_count = webhook.fail_count + 1;
+          let disable = fail_count >= WEBHOOK_MAX_FAIL_COUNT;
+          let form = WebhookUpdateForm {
+            fail_count: Some(fail_count),
+            last_failed_delivery: Some(Some(Utc::now())),
+            enabled: disable.then_some(false),
+            ..Default::default()
+          };
+          Webhook::update(pool, webhook.id, &form).await?;
+          if disable {
+            tracing::warn!(
+              "webhook {:?}: disabled after {fail_count} failed deliveries. ({e})",
+              webhook.id
+            );
+            WebhookDelivery::delete_for_webhook(pool, webhook.id).await?;
+            continue;
+          }
+          let retry_delay = federate_retry_sleep_duration(fail_count);
+          tracing::info!(
+            "webhook {:?}: retrying delivery {} attempt {} with delay {retry_delay:.2?}. ({e})",
+            webhook.id,
+            delivery.id,
+            fail_count
+          );
+          self.sleep_or_stop(retry_delay).await;
+        }
+      }
+    }
+    Ok(())
+  }
+
+  async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> Result<()> {
+    // The domain may have been changed to point into the local network after the webhook was
+    // saved
+    check_url_is_public(webhook.url.inner())
+      .await
+      .map_err(|e| anyhow!("webhook url is not allowed: {e}"))?;
+    let body = serde_json::to_vec(&delivery.payload)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(webhook.secret.as_bytes())
+      .map_err(|e| anyhow!("invalid webhook secret: {e}"))?;
+    mac.update(&body);
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    self
+      .context
+      .client()
+      .post(webhook.url.inner().clone())
+      .timeout(WEBHOOK_TIMEOUT)
+      .header("Content-Type", "application/json")
+      .header("X-Lemmy-Signature", format!("sha256={signature}"))
+      .header("X-Lemmy-Delivery", delivery.id.to_string())
+      .body(body)
+      .send()
+      .await?
+      .error_for_status()?;
+    Ok(())
+  }
+
+  async fn sleep_or_stop(&self, duration: Duration) {
+    tokio::select! {
+      () = sleep(duration) => {},
+      () = self.stop.cancelled() => {},
+    }
+  }
+}
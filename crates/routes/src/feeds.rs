@@ -4,16 +4,18 @@ use anyhow::anyhow;
 use chrono::{DateTime, Utc};
 use lemmy_api_common::{context::LemmyContext, utils::check_private_instance};
 use lemmy_db_schema::{
-  source::{community::Community, person::Person},
-  traits::ApubActor,
+  newtypes::MultiCommunityId,
+  source::{community::Community, multi_community::MultiCommunity, person::Person},
+  traits::{ApubActor, Crud},
   CommentSortType,
   CommunityVisibility,
   ListingType,
   SortType,
 };
 use lemmy_db_views::{
+  comment_view::CommentQuery,
   post_view::PostQuery,
-  structs::{PostView, SiteView},
+  structs::{CommentView, PostView, SiteView},
 };
 use lemmy_db_views_actor::{
   comment_reply_view::CommentReplyQuery,
@@ -22,7 +24,7 @@ use lemmy_db_views_actor::{
 };
 use lemmy_utils::{
   cache_header::cache_1hour,
-  error::{LemmyError, LemmyErrorType, LemmyResult},
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult},
   utils::markdown::markdown_to_html,
 };
 use rss::{
@@ -43,6 +45,8 @@ struct Params {
   sort: Option<String>,
   limit: Option<i64>,
   page: Option<i64>,
+  /// Also include comments, only used by the followed people feed.
+  comments: Option<bool>,
 }
 
 impl Params {
@@ -63,9 +67,11 @@ impl Params {
 
 enum RequestType {
   Community,
+  MultiCommunity,
   User,
   Front,
   Inbox,
+  People,
 }
 
 pub fn config(cfg: &mut web::ServiceConfig) {
@@ -185,8 +191,10 @@ async fn get_feed(
   let request_type = match req_type.as_str() {
     "u" => RequestType::User,
     "c" => RequestType::Community,
+    "m" => RequestType::MultiCommunity,
     "front" => RequestType::Front,
     "inbox" => RequestType::Inbox,
+    "people" => RequestType::People,
     _ => return Err(ErrorBadRequest(LemmyError::from(anyhow!("wrong_type")))),
   };
 
@@ -211,6 +219,16 @@ async fn get_feed(
       )
       .await
     }
+    RequestType::MultiCommunity => {
+      get_feed_multi_community(
+        &context,
+        &info.sort_type()?,
+        &info.get_limit(),
+        &info.get_page(),
+        &param,
+      )
+      .await
+    }
     RequestType::Front => {
       get_feed_front(
         &context,
@@ -222,6 +240,17 @@ async fn get_feed(
       .await
     }
     RequestType::Inbox => get_feed_inbox(&context, &param).await,
+    RequestType::People => {
+      get_feed_people(
+        &context,
+        &info.sort_type()?,
+        &info.get_limit(),
+        &info.get_page(),
+        &param,
+        info.comments.unwrap_or(false),
+      )
+      .await
+    }
   }
   .map_err(ErrorBadRequest)?;
 
@@ -321,6 +350,58 @@ async fn get_feed_community(
   Ok(channel)
 }
 
+/// Only public multi-community feeds are available, by id.
+#[tracing::instrument(skip_all)]
+async fn get_feed_multi_community(
+  context: &LemmyContext,
+  sort_type: &SortType,
+  limit: &i64,
+  page: &i64,
+  multi_community_id: &str,
+) -> LemmyResult<Channel> {
+  let site_view = SiteView::read_local(&mut context.pool())
+    .await?
+    .ok_or(LemmyErrorType::LocalSiteNotSetup)?;
+  let multi_community_id = multi_community_id
+    .parse::<i32>()
+    .map(MultiCommunityId)
+    .with_lemmy_type(LemmyErrorType::CouldntFindMultiCommunity)?;
+  let multi_community = MultiCommunity::read(&mut context.pool(), multi_community_id)
+    .await?
+    .filter(|m| m.public)
+    .ok_or(LemmyErrorType::CouldntFindMultiCommunity)?;
+
+  check_private_instance(&None, &site_view.local_site)?;
+
+  let posts = PostQuery {
+    listing_type: (Some(ListingType::All)),
+    sort: (Some(*sort_type)),
+    multi_community_id: (Some(multi_community.id)),
+    limit: (Some(*limit)),
+    page: (Some(*page)),
+    ..Default::default()
+  }
+  .list(&site_view.site, &mut context.pool())
+  .await?;
+
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let items = create_post_items(posts, &protocol_and_hostname)?;
+
+  let mut channel = Channel {
+    namespaces: RSS_NAMESPACE.clone(),
+    title: format!("{} - {}", site_view.site.name, multi_community.name),
+    link: format!("{protocol_and_hostname}/m/{}", multi_community.id.0),
+    items,
+    ..Default::default()
+  };
+
+  if let Some(description) = multi_community.description {
+    channel.set_description(markdown_to_html(&description));
+  }
+
+  Ok(channel)
+}
+
 #[tracing::instrument(skip_all)]
 async fn get_feed_front(
   context: &LemmyContext,
@@ -364,6 +445,66 @@ async fn get_feed_front(
   Ok(channel)
 }
 
+/// Posts, and optionally comments, from people the user follows.
+#[tracing::instrument(skip_all)]
+async fn get_feed_people(
+  context: &LemmyContext,
+  sort_type: &SortType,
+  limit: &i64,
+  page: &i64,
+  jwt: &str,
+  include_comments: bool,
+) -> LemmyResult<Channel> {
+  let site_view = SiteView::read_local(&mut context.pool())
+    .await?
+    .ok_or(LemmyErrorType::LocalSiteNotSetup)?;
+  let local_user = local_user_view_from_jwt(jwt, context).await?;
+
+  check_private_instance(&Some(local_user.clone()), &site_view.local_site)?;
+
+  let posts = PostQuery {
+    listing_type: (Some(ListingType::FollowedPeople)),
+    local_user: (Some(&local_user.local_user)),
+    sort: (Some(*sort_type)),
+    limit: (Some(*limit)),
+    page: (Some(*page)),
+    ..Default::default()
+  }
+  .list(&site_view.site, &mut context.pool())
+  .await?;
+
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let mut items = create_post_items(posts, &protocol_and_hostname)?;
+
+  if include_comments {
+    let comments = CommentQuery {
+      listing_type: (Some(ListingType::FollowedPeople)),
+      local_user: (Some(&local_user.local_user)),
+      sort: (Some(CommentSortType::New)),
+      limit: (Some(*limit)),
+      page: (Some(*page)),
+      ..Default::default()
+    }
+    .list(&mut context.pool())
+    .await?;
+    items.append(&mut create_comment_items(comments, &protocol_and_hostname)?);
+  }
+
+  let mut channel = Channel {
+    namespaces: RSS_NAMESPACE.clone(),
+    title: format!("{} - Followed People", site_view.site.name),
+    link: protocol_and_hostname,
+    items,
+    ..Default::default()
+  };
+
+  if let Some(site_desc) = site_view.site.description {
+    channel.set_description(markdown_to_html(&site_desc));
+  }
+
+  Ok(channel)
+}
+
 #[tracing::instrument(skip_all)]
 async fn get_feed_inbox(context: &LemmyContext, jwt: &str) -> LemmyResult<Channel> {
   let site_view = SiteView::read_local(&mut context.pool())
@@ -455,6 +596,26 @@ fn create_reply_and_mention_items(
   Ok(reply_items)
 }
 
+#[tracing::instrument(skip_all)]
+fn create_comment_items(
+  comments: Vec<CommentView>,
+  protocol_and_hostname: &str,
+) -> LemmyResult<Vec<Item>> {
+  comments
+    .iter()
+    .map(|c| {
+      let comment_url = format!("{}/comment/{}", protocol_and_hostname, c.comment.id);
+      build_item(
+        &c.creator.name,
+        &c.comment.published,
+        &comment_url,
+        &c.comment.content,
+        protocol_and_hostname,
+      )
+    })
+    .collect()
+}
+
 #[tracing::instrument(skip_all)]
 fn build_item(
   creator_name: &str,
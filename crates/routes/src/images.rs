@@ -14,7 +14,10 @@ use lemmy_api_common::{
   context::LemmyContext,
   request::{delete_image_from_pictrs, PictrsResponse},
 };
-use lemmy_db_schema::source::images::{LocalImage, LocalImageForm, RemoteImage};
+use lemmy_db_schema::{
+  source::images::{LocalImage, LocalImageForm, RemoteImage},
+  ApiTokenScope,
+};
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{error::LemmyResult, rate_limit::RateLimitCell, REQWEST_TIMEOUT};
 use reqwest::Body;
@@ -29,6 +32,7 @@ pub fn config(cfg: &mut ServiceConfig, client: ClientWithMiddleware, rate_limit:
     .service(
       resource("/pictrs/image")
         .wrap(rate_limit.image())
+        .app_data(ApiTokenScope::Post)
         .route(post().to(upload)),
     )
     // This has optional query params: /image/{filename}?format=jpg&thumbnail=256
@@ -9,8 +9,8 @@ pub mod webfinger;
 
 #[tracing::instrument(skip_all)]
 async fn local_user_view_from_jwt(jwt: &str, context: &LemmyContext) -> LemmyResult<LocalUserView> {
-  let local_user_id = Claims::validate(jwt, context).await?;
-  let local_user_view = LocalUserView::read(&mut context.pool(), local_user_id)
+  let login_token = Claims::validate(jwt, context).await?;
+  let local_user_view = LocalUserView::read(&mut context.pool(), login_token.user_id)
     .await?
     .ok_or(LemmyErrorType::CouldntFindLocalUser)?;
   check_user_valid(&local_user_view.person)?;
@@ -54,7 +54,7 @@ async fn get_webfinger_response(
       .ok()
       .flatten()
       .and_then(|c| {
-        if c.visibility == CommunityVisibility::Public {
+        if c.visibility != CommunityVisibility::LocalOnly {
           let id: Url = c.actor_id.into();
           Some(id)
         } else {
@@ -22,6 +22,7 @@ pub enum LemmyErrorType {
   EmailRequired,
   CouldntUpdateComment,
   CouldntUpdatePrivateMessage,
+  CouldntUpdateNotification,
   CannotLeaveAdmin,
   NoLinesInHtml,
   SiteMetadataPageIsNotDoctypeHtml,
@@ -55,6 +56,7 @@ pub enum LemmyErrorType {
   CouldntFindCommentReply,
   CouldntFindPrivateMessage,
   CouldntFindActivity,
+  CouldntFindNotification,
   PersonIsBlocked,
   CommunityIsBlocked,
   InstanceIsBlocked,
@@ -140,6 +142,7 @@ pub enum LemmyErrorType {
   PostTitleTooLong,
   CouldntCreatePost,
   CouldntCreatePrivateMessage,
+  CouldntCreateNotification,
   CouldntUpdatePrivate,
   SystemErrLogin,
   CouldntSetAllRegistrationsAccepted,
@@ -180,6 +183,91 @@ pub enum LemmyErrorType {
   Unknown(String),
   CantDeleteSite,
   UrlLengthOverflow,
+  CouldntFindWebhook,
+  CouldntCreateWebhook,
+  CouldntUpdateWebhook,
+  TooManyWebhooks,
+  InvalidPollOptions,
+  CouldntFindPoll,
+  PollEnded,
+  InvalidPollChoice,
+  CouldntVoteInPoll,
+  InvalidScheduledPublishTime,
+  TooManyScheduledPosts,
+  NotApprovedFollower,
+  CouldntApproveFollow,
+  CouldntFindMultiCommunity,
+  MultiCommunityAlreadyExists,
+  CouldntUpdateMultiCommunity,
+  TooManyCommunitiesInFeed,
+  NoMultiCommunityEditAllowed,
+  CouldntFindTag,
+  CouldntCreateTag,
+  CouldntUpdateTag,
+  TagAlreadyExists,
+  InvalidTagName,
+  InvalidTagColor,
+  OnlyModsCanUseTag,
+  InvalidKeywordFilter,
+  CouldntCreateKeywordFilter,
+  CouldntFindKeywordFilter,
+  TooManyKeywordFilters,
+  OauthAuthorizationInvalid,
+  OauthLoginFailed,
+  OauthRegistrationClosed,
+  RegistrationUsernameRequired,
+  CouldntCreateOauthProvider,
+  CouldntUpdateOauthProvider,
+  MissingWebauthnCredential,
+  IncorrectWebauthnCredential,
+  CouldntStartWebauthn,
+  CouldntRegisterWebauthnCredential,
+  CouldntFindWebauthnCredential,
+  TooManyWebauthnCredentials,
+  InvalidWebauthnCredentialName,
+  InvalidApiTokenName,
+  ApiTokenAlreadyExists,
+  ApiTokenNeedsScope,
+  ApiTokenScopeNotAllowed,
+  CouldntFindApiToken,
+  CouldntFindLogin,
+  InviteCodeRequired,
+  InvalidInviteCode,
+  TooManyInvites,
+  AccountTooNewToInvite,
+  CouldntFindInvite,
+  InvalidInviteMaxUses,
+  InvalidProofOfWorkDifficulty,
+  InvalidMoveTarget,
+  TooManyAccountAliases,
+  DataExportTooFrequent,
+  CouldntFindDataExport,
+  CantFollowYourself,
+  PersonFollowerAlreadyExists,
+  CouldntFindConversation,
+  CouldntCreateConversation,
+  TooManyConversationParticipants,
+  CouldntFindModNote,
+  InvalidStrikePolicy,
+  CouldntCreateModWarning,
+  NotBanned,
+  BanAlreadyAppealed,
+  CouldntFindBanAppeal,
+  BanAppealAlreadyResolved,
+  CouldntFindReport,
+  CouldntFindReportCategory,
+  CouldntCreateReportCategory,
+  InvalidReportStatus,
+  InvalidReportAssignee,
+  InvalidReportCategoryName,
+  CouldntFindRule,
+  CouldntCreateRule,
+  CouldntUpdateRule,
+  RuleNotInCommunity,
+  InvalidRuleTitle,
+  PostIsScheduled,
+  OauthLoginRequiresPassword,
+  CouldntUnfollowPerson,
 }
 
 cfg_if! {
@@ -39,6 +39,10 @@ pub const CACHE_DURATION_API: Duration = Duration::from_secs(1);
 
 pub const MAX_COMMENT_DEPTH_LIMIT: usize = 50;
 
+/// The maximum number of keyword filters per user, as every filter is checked for each listed post
+/// and comment.
+pub const MAX_KEYWORD_FILTERS: i64 = 100;
+
 #[macro_export]
 macro_rules! location_info {
   () => {
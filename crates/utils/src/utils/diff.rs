@@ -0,0 +1,31 @@
+use itertools::Itertools;
+
+/// Compares two texts line by line. Lines which were removed are prefixed with `- `, lines which
+/// were added with `+ ` and unchanged lines with two spaces.
+pub fn line_diff(old: &str, new: &str) -> String {
+  diff::lines(old, new)
+    .into_iter()
+    .map(|line| match line {
+      diff::Result::Left(l) => format!("- {l}"),
+      diff::Result::Right(r) => format!("+ {r}"),
+      diff::Result::Both(l, _) => format!("  {l}"),
+    })
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::diff::line_diff;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_line_diff() {
+    let old = "first line\nsecond line\nthird line";
+    let new = "first line\nchanged line\nthird line\nfourth line";
+    assert_eq!(
+      "  first line\n- second line\n+ changed line\n  third line\n+ fourth line",
+      line_diff(old, new)
+    );
+    assert_eq!("  same", line_diff("same", "same"));
+  }
+}
@@ -38,6 +38,23 @@ pub fn sanitize_html(text: &str) -> String {
     .replace('\'', "&#x27;")
 }
 
+/// Escapes text so that it is shown as is when rendered as markdown. Line breaks are replaced with
+/// spaces, as they could start a new block.
+pub fn escape_markdown(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    if c.is_ascii_punctuation() {
+      escaped.push('\\');
+      escaped.push(c);
+    } else if c.is_whitespace() {
+      escaped.push(' ');
+    } else {
+      escaped.push(c);
+    }
+  }
+  escaped
+}
+
 pub fn markdown_to_html(text: &str) -> String {
   MARKDOWN_PARSER.parse(text).xrender()
 }
@@ -380,4 +397,13 @@ mod tests {
     let expected = "Polling the group: what do y&#x27;all know about the Orion browser from Kagi?";
     assert_eq!(expected, sanitized);
   }
+
+  #[test]
+  fn test_escape_markdown() {
+    let escaped = escape_markdown("Mozilla/5.0 `x` [click](evil)\n# <b>hi</b>");
+    assert_eq!(
+      "<p>Mozilla/5.0 `x` [click](evil) # &lt;b&gt;hi&lt;/b&gt;</p>\n",
+      markdown_to_html(&escaped)
+    );
+  }
 }
@@ -1,3 +1,4 @@
+pub mod diff;
 pub mod markdown;
 pub mod mention;
 pub mod slurs;
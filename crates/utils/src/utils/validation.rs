@@ -10,6 +10,8 @@ static VALID_MATRIX_ID_REGEX: LazyLock<Regex> = LazyLock::new(|| {
   Regex::new(r"^@[A-Za-z0-9\x21-\x39\x3B-\x7F]+:[A-Za-z0-9.-]+(:[0-9]{2,5})?$")
     .expect("compile regex")
 });
+static VALID_TAG_COLOR_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^#[0-9a-fA-F]{6}$").expect("compile regex"));
 // taken from https://en.wikipedia.org/wiki/UTM_parameters
 static URL_CLEANER: LazyLock<UrlCleaner> =
   LazyLock::new(|| UrlCleaner::from_embedded_rules().expect("compile clearurls"));
@@ -23,6 +25,14 @@ const ALT_TEXT_MAX_LENGTH: usize = 1500;
 const SITE_NAME_MAX_LENGTH: usize = 20;
 const SITE_NAME_MIN_LENGTH: usize = 1;
 const SITE_DESCRIPTION_MAX_LENGTH: usize = 150;
+const POLL_OPTIONS_MAX_COUNT: usize = 20;
+const POLL_OPTION_MAX_LENGTH: usize = 200;
+const TAG_NAME_MAX_LENGTH: usize = 50;
+const KEYWORD_FILTER_MAX_LENGTH: usize = 255;
+const WEBAUTHN_CREDENTIAL_NAME_MAX_LENGTH: usize = 255;
+const API_TOKEN_NAME_MAX_LENGTH: usize = 255;
+const REPORT_CATEGORY_NAME_MAX_LENGTH: usize = 50;
+const RULE_TITLE_MAX_LENGTH: usize = 200;
 //Invisible unicode characters, taken from https://invisible-characters.com/
 const FORBIDDEN_DISPLAY_CHARS: [char; 53] = [
   '\u{0009}',
@@ -286,6 +296,98 @@ pub fn is_valid_url(url: &Url) -> LemmyResult<()> {
   Ok(())
 }
 
+/// Webhook payloads are signed, but they must still be sent over an encrypted connection.
+pub fn is_valid_webhook_url(url: &Url) -> LemmyResult<()> {
+  if url.scheme() != "https" {
+    Err(LemmyErrorType::InvalidUrlScheme)?
+  }
+
+  max_length_check(
+    url.as_str(),
+    URL_MAX_LENGTH,
+    LemmyErrorType::UrlLengthOverflow,
+  )?;
+
+  Ok(())
+}
+
+/// Votes are federated with the name of the option, so the names must be unique.
+pub fn is_valid_poll_options(options: &[String]) -> LemmyResult<()> {
+  let count_valid = (2..=POLL_OPTIONS_MAX_COUNT).contains(&options.len());
+  let options_valid = options.iter().all(|o| {
+    let length = o.trim().chars().count();
+    (1..=POLL_OPTION_MAX_LENGTH).contains(&length) && !has_newline(o)
+  });
+  if count_valid && options_valid && options.iter().all_unique() {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidPollOptions.into())
+  }
+}
+
+pub fn is_valid_tag_name(name: &str) -> LemmyResult<()> {
+  let length = name.trim().chars().count();
+  if (1..=TAG_NAME_MAX_LENGTH).contains(&length) && !has_newline(name) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidTagName.into())
+  }
+}
+
+pub fn is_valid_keyword_filter(keyword: &str) -> LemmyResult<()> {
+  let length = keyword.trim().chars().count();
+  if (1..=KEYWORD_FILTER_MAX_LENGTH).contains(&length) && !has_newline(keyword) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidKeywordFilter.into())
+  }
+}
+
+pub fn is_valid_webauthn_credential_name(name: &str) -> LemmyResult<()> {
+  let length = name.trim().chars().count();
+  if (1..=WEBAUTHN_CREDENTIAL_NAME_MAX_LENGTH).contains(&length) && !has_newline(name) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidWebauthnCredentialName.into())
+  }
+}
+
+pub fn is_valid_api_token_name(name: &str) -> LemmyResult<()> {
+  let length = name.trim().chars().count();
+  if (1..=API_TOKEN_NAME_MAX_LENGTH).contains(&length) && !has_newline(name) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidApiTokenName.into())
+  }
+}
+
+pub fn is_valid_report_category_name(name: &str) -> LemmyResult<()> {
+  let length = name.trim().chars().count();
+  if (1..=REPORT_CATEGORY_NAME_MAX_LENGTH).contains(&length) && !has_newline(name) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidReportCategoryName.into())
+  }
+}
+
+pub fn is_valid_rule_title(title: &str) -> LemmyResult<()> {
+  let length = title.trim().chars().count();
+  if (1..=RULE_TITLE_MAX_LENGTH).contains(&length) && !has_newline(title) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidRuleTitle.into())
+  }
+}
+
+/// Tag colors are given as hex, like `#ff0000`.
+pub fn is_valid_tag_color(color: &str) -> LemmyResult<()> {
+  if VALID_TAG_COLOR_REGEX.is_match(color) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidTagColor.into())
+  }
+}
+
 pub fn is_url_blocked(url: &Url, blocklist: &RegexSet) -> LemmyResult<()> {
   if blocklist.is_match(url.as_str()) {
     Err(LemmyErrorType::BlockedUrl)?
@@ -347,11 +449,20 @@ mod tests {
       clean_urls_in_text,
       is_url_blocked,
       is_valid_actor_name,
+      is_valid_api_token_name,
       is_valid_bio_field,
       is_valid_display_name,
+      is_valid_keyword_filter,
       is_valid_matrix_id,
+      is_valid_poll_options,
       is_valid_post_title,
+      is_valid_report_category_name,
+      is_valid_rule_title,
+      is_valid_tag_color,
+      is_valid_tag_name,
       is_valid_url,
+      is_valid_webauthn_credential_name,
+      is_valid_webhook_url,
       site_description_length_check,
       site_name_length_check,
       BIO_MAX_LENGTH,
@@ -454,6 +565,71 @@ mod tests {
     assert!(is_valid_post_title("\n \n \n \n    		").is_err()); // tabs/spaces/newlines
   }
 
+  #[test]
+  fn test_valid_poll_options() {
+    let options = |o: &[&str]| o.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert!(is_valid_poll_options(&options(&["Yes", "No"])).is_ok());
+    assert!(is_valid_poll_options(&options(&["Yes"])).is_err());
+    assert!(is_valid_poll_options(&options(&["Yes", "Yes"])).is_err());
+    assert!(is_valid_poll_options(&options(&["Yes", "  "])).is_err());
+    assert!(is_valid_poll_options(&options(&["Yes", "No\nMaybe"])).is_err());
+    assert!(is_valid_poll_options(&options(&["a"; 21])).is_err());
+  }
+
+  #[test]
+  fn test_valid_tag() {
+    assert!(is_valid_tag_name("News").is_ok());
+    assert!(is_valid_tag_name("  ").is_err());
+    assert!(is_valid_tag_name("Breaking\nNews").is_err());
+    assert!(is_valid_tag_name(&"a".repeat(51)).is_err());
+
+    assert!(is_valid_tag_color("#ff00AA").is_ok());
+    assert!(is_valid_tag_color("ff00aa").is_err());
+    assert!(is_valid_tag_color("#f0a").is_err());
+    assert!(is_valid_tag_color("#gg0000").is_err());
+  }
+
+  #[test]
+  fn test_valid_keyword_filter() {
+    assert!(is_valid_keyword_filter("spoiler").is_ok());
+    assert!(is_valid_keyword_filter("^(breaking )?news$").is_ok());
+    assert!(is_valid_keyword_filter(" ").is_err());
+    assert!(is_valid_keyword_filter("two\nlines").is_err());
+    assert!(is_valid_keyword_filter(&"a".repeat(256)).is_err());
+  }
+
+  #[test]
+  fn test_valid_webauthn_credential_name() {
+    assert!(is_valid_webauthn_credential_name("YubiKey").is_ok());
+    assert!(is_valid_webauthn_credential_name("").is_err());
+    assert!(is_valid_webauthn_credential_name("two\nlines").is_err());
+    assert!(is_valid_webauthn_credential_name(&"a".repeat(256)).is_err());
+  }
+
+  #[test]
+  fn test_valid_api_token_name() {
+    assert!(is_valid_api_token_name("Moderation bot").is_ok());
+    assert!(is_valid_api_token_name(" ").is_err());
+    assert!(is_valid_api_token_name("two\nlines").is_err());
+    assert!(is_valid_api_token_name(&"a".repeat(256)).is_err());
+  }
+
+  #[test]
+  fn test_valid_report_category_name() {
+    assert!(is_valid_report_category_name("Spam").is_ok());
+    assert!(is_valid_report_category_name(" ").is_err());
+    assert!(is_valid_report_category_name("two\nlines").is_err());
+    assert!(is_valid_report_category_name(&"a".repeat(51)).is_err());
+  }
+
+  #[test]
+  fn test_valid_rule_title() {
+    assert!(is_valid_rule_title("No spam").is_ok());
+    assert!(is_valid_rule_title(" ").is_err());
+    assert!(is_valid_rule_title("two\nlines").is_err());
+    assert!(is_valid_rule_title(&"a".repeat(201)).is_err());
+  }
+
   #[test]
   fn test_valid_matrix_id() {
     assert!(is_valid_matrix_id("@dess:matrix.org").is_ok());
@@ -602,6 +778,16 @@ mod tests {
     Ok(())
   }
 
+  #[test]
+  fn test_check_webhook_url_valid() -> LemmyResult<()> {
+    assert!(is_valid_webhook_url(&Url::parse("https://example.com/hook")?).is_ok());
+    assert!(
+      is_valid_webhook_url(&Url::parse("http://example.com/hook")?)
+        .is_err_and(|e| e.error_type.eq(&LemmyErrorType::InvalidUrlScheme))
+    );
+    Ok(())
+  }
+
   #[test]
   fn test_url_block() -> LemmyResult<()> {
     let set = regex::RegexSet::new(vec![
@@ -0,0 +1,4 @@
+DROP TABLE notification;
+
+DROP TYPE notification_type_enum;
+
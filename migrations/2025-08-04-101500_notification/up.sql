@@ -0,0 +1,86 @@
+CREATE TYPE notification_type_enum AS enum (
+    'Reply',
+    'Mention',
+    'PrivateMessage',
+    'ModRemovePost',
+    'ModRemoveComment',
+    'ModLockPost',
+    'ModBanFromCommunity',
+    'ReportResolved'
+);
+
+CREATE TABLE notification (
+    id serial PRIMARY KEY,
+    recipient_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    kind notification_type_enum NOT NULL,
+    -- The person who caused the notification, eg the commenter, the sender or the moderator
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    private_message_id int REFERENCES private_message ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text,
+    read boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_notification_recipient_published ON notification (recipient_id, published DESC);
+
+CREATE INDEX idx_notification_recipient_unread ON notification (recipient_id)
+WHERE
+    NOT read;
+
+-- Replies and mentions are re-sent on comment edits, so they need to be deduplicated
+CREATE UNIQUE INDEX idx_notification_recipient_comment_kind ON notification (recipient_id, comment_id, kind)
+WHERE
+    kind IN ('Reply', 'Mention');
+
+CREATE UNIQUE INDEX idx_notification_recipient_private_message ON notification (recipient_id, private_message_id)
+WHERE
+    kind = 'PrivateMessage';
+
+-- Copy over the existing notifications
+INSERT INTO notification (recipient_id, kind, creator_id, comment_id, post_id, community_id, read, published)
+SELECT
+    cr.recipient_id,
+    'Reply',
+    c.creator_id,
+    c.id,
+    p.id,
+    p.community_id,
+    cr.read,
+    cr.published
+FROM
+    comment_reply cr
+    INNER JOIN comment c ON cr.comment_id = c.id
+    INNER JOIN post p ON c.post_id = p.id;
+
+INSERT INTO notification (recipient_id, kind, creator_id, comment_id, post_id, community_id, read, published)
+SELECT
+    pm.recipient_id,
+    'Mention',
+    c.creator_id,
+    c.id,
+    p.id,
+    p.community_id,
+    pm.read,
+    pm.published
+FROM
+    person_mention pm
+    INNER JOIN comment c ON pm.comment_id = c.id
+    INNER JOIN post p ON c.post_id = p.id;
+
+INSERT INTO notification (recipient_id, kind, creator_id, private_message_id, read, published)
+SELECT
+    pm.recipient_id,
+    'PrivateMessage',
+    pm.creator_id,
+    pm.id,
+    pm.read,
+    pm.published
+FROM
+    private_message pm
+    INNER JOIN person p ON pm.recipient_id = p.id
+WHERE
+    p.local;
+
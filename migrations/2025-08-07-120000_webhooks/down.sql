@@ -0,0 +1,4 @@
+DROP TABLE webhook_delivery;
+
+DROP TABLE webhook;
+
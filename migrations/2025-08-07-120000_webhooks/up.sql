@@ -0,0 +1,28 @@
+-- Webhooks which are registered by admins (site-wide, when community_id is null) or community
+-- moderators
+CREATE TABLE webhook (
+    id serial PRIMARY KEY,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    url text NOT NULL,
+    secret text NOT NULL DEFAULT gen_random_uuid (),
+    enabled boolean NOT NULL DEFAULT TRUE,
+    fail_count int NOT NULL DEFAULT 0,
+    last_successful_delivery timestamptz,
+    last_failed_delivery timestamptz,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_webhook_community ON webhook (community_id);
+
+-- Payloads which are waiting to be sent to a webhook. Rows are deleted once they are delivered.
+CREATE TABLE webhook_delivery (
+    id bigserial PRIMARY KEY,
+    webhook_id int NOT NULL REFERENCES webhook ON UPDATE CASCADE ON DELETE CASCADE,
+    payload jsonb NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_webhook_delivery_webhook ON webhook_delivery (webhook_id, id);
+
@@ -0,0 +1,2 @@
+DROP TABLE poll_vote, poll;
+
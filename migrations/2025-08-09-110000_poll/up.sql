@@ -0,0 +1,25 @@
+-- Polls are attached to a post. The options are stored in order, and vote_counts[i] is the number
+-- of votes for options[i]. For local polls the counts are updated by triggers, for remote polls
+-- they are copied from the federated Question object.
+CREATE TABLE poll (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE INITIALLY DEFERRED,
+    multiple_choice boolean NOT NULL DEFAULT FALSE,
+    options text[] NOT NULL,
+    vote_counts int[] NOT NULL,
+    voters_count int NOT NULL DEFAULT 0,
+    end_time timestamptz,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+-- The choice is the index of the option in poll.options, starting at 0.
+CREATE TABLE poll_vote (
+    post_id int NOT NULL REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    choice int NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (post_id, person_id, choice)
+);
+
+CREATE INDEX idx_poll_vote_person ON poll_vote (person_id);
+
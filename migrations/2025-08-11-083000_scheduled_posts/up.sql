@@ -0,0 +1,7 @@
+ALTER TABLE post
+    ADD COLUMN scheduled_publish_time timestamptz;
+
+CREATE INDEX idx_post_scheduled_publish_time ON post (scheduled_publish_time)
+WHERE
+    scheduled_publish_time IS NOT NULL;
+
@@ -0,0 +1,22 @@
+-- Previous versions of edited posts and comments. Rows are inserted by triggers whenever the
+-- content changes, and published is the time when that version was written.
+CREATE TABLE post_revision (
+    id serial PRIMARY KEY,
+    post_id int NOT NULL REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(200) NOT NULL,
+    url varchar(2000),
+    body text,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_post_revision_post ON post_revision (post_id);
+
+CREATE TABLE comment_revision (
+    id serial PRIMARY KEY,
+    comment_id int NOT NULL REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_comment_revision_comment ON comment_revision (comment_id);
+
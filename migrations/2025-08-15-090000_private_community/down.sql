@@ -0,0 +1,26 @@
+-- Postgres can't remove values from an enum, so the type needs to be recreated
+UPDATE
+    community
+SET
+    visibility = 'LocalOnly'
+WHERE
+    visibility = 'Private';
+
+ALTER TABLE community
+    ALTER COLUMN visibility DROP DEFAULT;
+
+ALTER TYPE community_visibility RENAME TO community_visibility__;
+
+CREATE TYPE community_visibility AS enum (
+    'Public',
+    'LocalOnly'
+);
+
+ALTER TABLE community
+    ALTER COLUMN visibility TYPE community_visibility
+    USING visibility::text::community_visibility;
+
+ALTER TABLE community
+    ALTER COLUMN visibility SET DEFAULT 'Public';
+
+DROP TYPE community_visibility__;
@@ -0,0 +1,2 @@
+ALTER TYPE community_visibility
+    ADD VALUE 'Private';
@@ -0,0 +1 @@
+DROP TABLE multi_community_entry, multi_community;
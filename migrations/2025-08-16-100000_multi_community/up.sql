@@ -0,0 +1,19 @@
+-- Named feeds which combine the posts of several local or remote communities
+CREATE TABLE multi_community (
+    id serial PRIMARY KEY,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(255) NOT NULL,
+    description text,
+    public boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz,
+    UNIQUE (creator_id, name)
+);
+
+CREATE TABLE multi_community_entry (
+    multi_community_id int NOT NULL REFERENCES multi_community ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int NOT NULL REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    PRIMARY KEY (multi_community_id, community_id)
+);
+
+CREATE INDEX idx_multi_community_entry_community ON multi_community_entry (community_id);
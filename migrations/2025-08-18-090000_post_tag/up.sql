@@ -0,0 +1,24 @@
+-- Tags are defined by the moderators of a community, and can be attached to posts in that
+-- community. Mod-only tags can only be attached by moderators. Tags of remote communities are
+-- created when a post with that tag is received.
+CREATE TABLE tag (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE,
+    community_id int NOT NULL REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(255) NOT NULL,
+    color varchar(7),
+    mod_only boolean NOT NULL DEFAULT FALSE,
+    local boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_tag_community ON tag (community_id);
+
+CREATE TABLE post_tag (
+    post_id int NOT NULL REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    tag_id int NOT NULL REFERENCES tag ON UPDATE CASCADE ON DELETE CASCADE,
+    PRIMARY KEY (post_id, tag_id)
+);
+
+CREATE INDEX idx_post_tag_tag ON post_tag (tag_id);
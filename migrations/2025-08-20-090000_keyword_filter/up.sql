@@ -0,0 +1,14 @@
+-- Keywords or regular expressions which hide matching posts and comments for a user, either
+-- everywhere or only in a single community
+CREATE TABLE keyword_filter (
+    id serial PRIMARY KEY,
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    keyword varchar(255) NOT NULL,
+    is_regex boolean NOT NULL DEFAULT FALSE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    published timestamptz NOT NULL DEFAULT now(),
+    -- Reject invalid regular expressions on insert
+    CHECK (NOT is_regex OR ('' ~* keyword) IS NOT NULL)
+);
+
+CREATE INDEX idx_keyword_filter_local_user ON keyword_filter (local_user_id);
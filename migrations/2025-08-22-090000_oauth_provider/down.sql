@@ -0,0 +1,4 @@
+ALTER TABLE local_site
+    DROP COLUMN oauth_registration;
+
+DROP TABLE oauth_account, oauth_provider;
@@ -0,0 +1,35 @@
+-- OpenID Connect providers which users can log in or register with
+CREATE TABLE oauth_provider (
+    id serial PRIMARY KEY,
+    display_name text NOT NULL,
+    issuer text NOT NULL,
+    authorization_endpoint text NOT NULL,
+    token_endpoint text NOT NULL,
+    userinfo_endpoint text NOT NULL,
+    -- The claim in the userinfo response which uniquely identifies the user, usually `sub`
+    id_claim text NOT NULL,
+    client_id text NOT NULL UNIQUE,
+    client_secret text NOT NULL,
+    scopes text NOT NULL,
+    auto_verify_email boolean NOT NULL DEFAULT FALSE,
+    account_linking_enabled boolean NOT NULL DEFAULT FALSE,
+    use_pkce boolean NOT NULL DEFAULT TRUE,
+    enabled boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+-- Links a local user to their account at an OAuth provider
+CREATE TABLE oauth_account (
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    oauth_provider_id int NOT NULL REFERENCES oauth_provider ON UPDATE CASCADE ON DELETE CASCADE,
+    oauth_user_id text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (oauth_provider_id, local_user_id),
+    UNIQUE (oauth_provider_id, oauth_user_id)
+);
+
+CREATE INDEX idx_oauth_account_local_user ON oauth_account (local_user_id);
+
+ALTER TABLE local_site
+    ADD COLUMN oauth_registration boolean NOT NULL DEFAULT FALSE;
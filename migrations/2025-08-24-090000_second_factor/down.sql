@@ -0,0 +1 @@
+DROP TABLE totp_recovery_code, webauthn_credential, webauthn_challenge;
@@ -0,0 +1,30 @@
+-- One-time codes which can be used instead of a TOTP token, in case the authenticator is lost.
+-- Only a bcrypt hash of each code is stored.
+CREATE TABLE totp_recovery_code (
+    id serial PRIMARY KEY,
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    code_hash text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_totp_recovery_code_local_user ON totp_recovery_code (local_user_id);
+
+-- WebAuthn credentials (security keys or passkeys) which can be used as second factor
+CREATE TABLE webauthn_credential (
+    id serial PRIMARY KEY,
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    name varchar(255) NOT NULL,
+    -- The serialized public key and signature counter
+    passkey jsonb NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    last_used timestamptz
+);
+
+CREATE INDEX idx_webauthn_credential_local_user ON webauthn_credential (local_user_id);
+
+-- The state of a WebAuthn registration or authentication which was started, but not finished yet
+CREATE TABLE webauthn_challenge (
+    local_user_id int PRIMARY KEY REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    state jsonb NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
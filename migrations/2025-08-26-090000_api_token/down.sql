@@ -0,0 +1,8 @@
+DELETE FROM login_token
+WHERE scopes IS NOT NULL;
+
+ALTER TABLE login_token
+    DROP COLUMN name,
+    DROP COLUMN scopes,
+    DROP COLUMN expires;
+
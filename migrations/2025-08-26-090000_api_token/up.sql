@@ -0,0 +1,12 @@
+-- Personal access tokens are stored as login tokens with a name and a list of scopes. Normal
+-- login sessions leave the scopes empty, which grants full access to the account. The scopes are
+-- stored as text, as diesel-async can't look up the type of an array of a custom enum.
+ALTER TABLE login_token
+    ADD COLUMN name varchar(255),
+    ADD COLUMN scopes text[] CHECK (scopes <@ ARRAY['Read', 'Post', 'Comment', 'Vote', 'Moderate', 'Admin']),
+    ADD COLUMN expires timestamptz;
+
+CREATE UNIQUE INDEX idx_login_token_user_name ON login_token (user_id, name)
+WHERE
+    name IS NOT NULL;
+
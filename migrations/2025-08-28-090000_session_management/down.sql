@@ -0,0 +1,6 @@
+ALTER TABLE login_token
+    DROP COLUMN id,
+    DROP COLUMN last_used;
+
+ALTER TABLE local_user
+    DROP COLUMN send_login_alerts_to_email;
@@ -0,0 +1,7 @@
+-- Login tokens are secret, so sessions need a separate id to be revoked individually
+ALTER TABLE login_token
+    ADD COLUMN id serial UNIQUE NOT NULL,
+    ADD COLUMN last_used timestamptz;
+
+ALTER TABLE local_user
+    ADD COLUMN send_login_alerts_to_email boolean NOT NULL DEFAULT FALSE;
@@ -0,0 +1,36 @@
+ALTER TABLE local_user
+    DROP COLUMN invited_by;
+
+DROP TABLE invite;
+
+ALTER TABLE local_site
+    DROP COLUMN invites_per_user,
+    DROP COLUMN invite_min_account_age_days;
+
+-- Postgres can't remove values from an enum, so the type needs to be recreated
+UPDATE
+    local_site
+SET
+    registration_mode = 'Closed'
+WHERE
+    registration_mode = 'InviteOnly';
+
+ALTER TABLE local_site
+    ALTER COLUMN registration_mode DROP DEFAULT;
+
+ALTER TYPE registration_mode_enum RENAME TO registration_mode_enum__;
+
+CREATE TYPE registration_mode_enum AS enum (
+    'Closed',
+    'RequireApplication',
+    'Open'
+);
+
+ALTER TABLE local_site
+    ALTER COLUMN registration_mode TYPE registration_mode_enum
+    USING registration_mode::text::registration_mode_enum;
+
+ALTER TABLE local_site
+    ALTER COLUMN registration_mode SET DEFAULT 'RequireApplication';
+
+DROP TYPE registration_mode_enum__;
@@ -0,0 +1,25 @@
+ALTER TYPE registration_mode_enum
+    ADD VALUE 'InviteOnly';
+
+ALTER TABLE local_site
+    ADD COLUMN invites_per_user int NOT NULL DEFAULT 5,
+    ADD COLUMN invite_min_account_age_days int NOT NULL DEFAULT 30;
+
+-- Invite codes which users can share to let others sign up in invite-only mode
+CREATE TABLE invite (
+    id serial PRIMARY KEY,
+    code varchar(32) UNIQUE NOT NULL DEFAULT replace(gen_random_uuid ()::text, '-', ''),
+    creator_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    max_uses int NOT NULL DEFAULT 1,
+    uses int NOT NULL DEFAULT 0,
+    expires timestamptz,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_invite_creator ON invite (creator_id);
+
+-- Who invited this user, so that admins can follow the invite tree
+ALTER TABLE local_user
+    ADD COLUMN invited_by int REFERENCES local_user ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX idx_local_user_invited_by ON local_user (invited_by);
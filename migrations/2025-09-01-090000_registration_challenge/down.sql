@@ -0,0 +1,6 @@
+ALTER TABLE local_site
+    DROP COLUMN challenge_type,
+    DROP COLUMN proof_of_work_difficulty,
+    DROP COLUMN new_account_post_challenge_hours;
+
+DROP TYPE challenge_type_enum;
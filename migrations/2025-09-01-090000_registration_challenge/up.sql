@@ -0,0 +1,9 @@
+CREATE TYPE challenge_type_enum AS enum (
+    'Captcha',
+    'ProofOfWork'
+);
+
+ALTER TABLE local_site
+    ADD COLUMN challenge_type challenge_type_enum NOT NULL DEFAULT 'Captcha',
+    ADD COLUMN proof_of_work_difficulty int NOT NULL DEFAULT 20,
+    ADD COLUMN new_account_post_challenge_hours int NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+-- Other accounts of the same person, a move to one of them is only valid if it lists the old
+-- account here.
+ALTER TABLE person
+    ADD COLUMN also_known_as text[] NOT NULL DEFAULT '{}',
+    ADD COLUMN moved_to varchar(255);
@@ -0,0 +1,11 @@
+-- Archives of all data which a user created, generated in the background on request
+CREATE TABLE data_export (
+    id serial PRIMARY KEY,
+    local_user_id int NOT NULL REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE,
+    published timestamptz NOT NULL DEFAULT now(),
+    finished timestamptz,
+    archive jsonb,
+    outbox jsonb
+);
+
+CREATE INDEX idx_data_export_local_user ON data_export (local_user_id);
@@ -0,0 +1,48 @@
+ALTER TABLE person_aggregates
+    DROP COLUMN followers;
+
+-- Postgres can't remove values from an enum, so the type needs to be recreated
+ALTER TABLE local_user
+    ALTER default_listing_type DROP DEFAULT;
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type DROP DEFAULT;
+
+UPDATE
+    local_user
+SET
+    default_listing_type = 'Local'
+WHERE
+    default_listing_type = 'FollowedPeople';
+
+UPDATE
+    local_site
+SET
+    default_post_listing_type = 'Local'
+WHERE
+    default_post_listing_type = 'FollowedPeople';
+
+ALTER TYPE listing_type_enum RENAME TO listing_type_enum__;
+
+CREATE TYPE listing_type_enum AS ENUM (
+    'All',
+    'Local',
+    'Subscribed',
+    'ModeratorView'
+);
+
+ALTER TABLE local_user
+    ALTER COLUMN default_listing_type TYPE listing_type_enum
+    USING default_listing_type::text::listing_type_enum;
+
+ALTER TABLE local_site
+    ALTER COLUMN default_post_listing_type TYPE listing_type_enum
+    USING default_post_listing_type::text::listing_type_enum;
+
+ALTER TABLE local_user
+    ALTER default_listing_type SET DEFAULT 'Local';
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type SET DEFAULT 'Local';
+
+DROP TYPE listing_type_enum__;
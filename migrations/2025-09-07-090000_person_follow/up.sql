@@ -0,0 +1,18 @@
+ALTER TYPE listing_type_enum
+    ADD VALUE 'FollowedPeople';
+
+-- Kept up to date by a trigger on person_follower
+ALTER TABLE person_aggregates
+    ADD COLUMN followers bigint NOT NULL DEFAULT 0;
+
+UPDATE
+    person_aggregates AS a
+SET
+    followers = (
+        SELECT
+            count(*)
+        FROM
+            person_follower
+        WHERE
+            person_follower.person_id = a.person_id
+            AND NOT person_follower.pending);
@@ -0,0 +1,7 @@
+DELETE FROM private_message
+WHERE conversation_id IS NOT NULL;
+
+ALTER TABLE private_message
+    DROP COLUMN conversation_id;
+
+DROP TABLE conversation_participant, conversation;
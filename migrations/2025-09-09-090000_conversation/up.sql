@@ -0,0 +1,27 @@
+-- Group conversations between multiple persons. Their messages are stored in private_message with
+-- conversation_id set, and the creator of each message as recipient.
+CREATE TABLE conversation (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    local boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+-- Read state, mute and leave controls are per participant.
+CREATE TABLE conversation_participant (
+    conversation_id int NOT NULL REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    published timestamptz NOT NULL DEFAULT now(),
+    last_read timestamptz NOT NULL DEFAULT now(),
+    muted boolean NOT NULL DEFAULT FALSE,
+    left_at timestamptz,
+    PRIMARY KEY (conversation_id, person_id)
+);
+
+CREATE INDEX idx_conversation_participant_person ON conversation_participant (person_id);
+
+ALTER TABLE private_message
+    ADD COLUMN conversation_id int REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE INDEX idx_private_message_conversation ON private_message (conversation_id, published);
@@ -0,0 +1,12 @@
+-- Private notes about a person, either visible to the moderators of a community or site-wide
+-- and only visible to admins
+CREATE TABLE mod_note (
+    id serial PRIMARY KEY,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mod_note_person ON mod_note (person_id, community_id);
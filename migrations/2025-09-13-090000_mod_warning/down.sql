@@ -0,0 +1,29 @@
+ALTER TABLE local_site
+    DROP COLUMN strike_limit,
+    DROP COLUMN strike_expiry_days,
+    DROP COLUMN strike_ban_days;
+
+DROP TABLE mod_warning;
+
+-- Postgres can't remove values from an enum, so the type needs to be recreated
+DELETE FROM notification
+WHERE kind = 'ModWarning';
+
+ALTER TYPE notification_type_enum RENAME TO notification_type_enum__;
+
+CREATE TYPE notification_type_enum AS enum (
+    'Reply',
+    'Mention',
+    'PrivateMessage',
+    'ModRemovePost',
+    'ModRemoveComment',
+    'ModLockPost',
+    'ModBanFromCommunity',
+    'ReportResolved'
+);
+
+ALTER TABLE notification
+    ALTER COLUMN kind TYPE notification_type_enum
+    USING kind::text::notification_type_enum;
+
+DROP TYPE notification_type_enum__;
@@ -0,0 +1,28 @@
+ALTER TYPE notification_type_enum
+    ADD VALUE 'ModWarning';
+
+-- A formal warning from a moderator, for a community or site-wide. Active warnings count as
+-- strikes until they expire.
+CREATE TABLE mod_warning (
+    id serial PRIMARY KEY,
+    mod_person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    other_person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text NOT NULL,
+    expires timestamptz NOT NULL,
+    -- Set once the warning led to an automatic ban, so it isn't counted again
+    escalated boolean NOT NULL DEFAULT FALSE,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mod_warning_other_person ON mod_warning (other_person_id, community_id);
+
+CREATE INDEX idx_mod_warning_active ON mod_warning (expires)
+WHERE
+    NOT escalated;
+
+-- Zero strikes disables the automatic bans
+ALTER TABLE local_site
+    ADD COLUMN strike_limit int NOT NULL DEFAULT 0,
+    ADD COLUMN strike_expiry_days int NOT NULL DEFAULT 30,
+    ADD COLUMN strike_ban_days int NOT NULL DEFAULT 7;
@@ -0,0 +1,3 @@
+DROP TABLE mod_resolve_ban_appeal;
+
+DROP TABLE ban_appeal;
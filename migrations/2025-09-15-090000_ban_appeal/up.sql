@@ -0,0 +1,29 @@
+-- Appeals of banned users against a community ban, or a site ban if there is no community
+CREATE TABLE ban_appeal (
+    id serial PRIMARY KEY,
+    person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text NOT NULL,
+    -- The moderator or admin who accepted or denied the appeal
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    accepted boolean NOT NULL DEFAULT FALSE,
+    deny_reason text,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_ban_appeal_person ON ban_appeal (person_id, community_id);
+
+CREATE INDEX idx_ban_appeal_community ON ban_appeal (community_id)
+WHERE
+    resolver_id IS NULL;
+
+CREATE TABLE mod_resolve_ban_appeal (
+    id serial PRIMARY KEY,
+    mod_person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    other_person_id int NOT NULL REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    accepted boolean NOT NULL,
+    reason text,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
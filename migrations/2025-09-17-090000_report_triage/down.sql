@@ -0,0 +1,20 @@
+DROP TABLE report_combined;
+
+ALTER TABLE post_report
+    DROP COLUMN category_id,
+    DROP COLUMN assignee_id,
+    DROP COLUMN status;
+
+ALTER TABLE comment_report
+    DROP COLUMN category_id,
+    DROP COLUMN assignee_id,
+    DROP COLUMN status;
+
+ALTER TABLE private_message_report
+    DROP COLUMN category_id,
+    DROP COLUMN assignee_id,
+    DROP COLUMN status;
+
+DROP TABLE report_category;
+
+DROP TYPE report_status_enum;
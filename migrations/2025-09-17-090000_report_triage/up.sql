@@ -0,0 +1,93 @@
+CREATE TYPE report_status_enum AS enum (
+    'Open',
+    'InProgress',
+    'Escalated',
+    'Resolved'
+);
+
+-- Categories which admins define for reports, like spam or harassment
+CREATE TABLE report_category (
+    id serial PRIMARY KEY,
+    name text NOT NULL UNIQUE,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+-- `status` is kept in sync with `resolved`, which stays for compatibility
+ALTER TABLE post_report
+    ADD COLUMN category_id int REFERENCES report_category ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN status report_status_enum NOT NULL DEFAULT 'Open';
+
+ALTER TABLE comment_report
+    ADD COLUMN category_id int REFERENCES report_category ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN status report_status_enum NOT NULL DEFAULT 'Open';
+
+ALTER TABLE private_message_report
+    ADD COLUMN category_id int REFERENCES report_category ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN status report_status_enum NOT NULL DEFAULT 'Open';
+
+UPDATE
+    post_report
+SET
+    status = 'Resolved'
+WHERE
+    resolved;
+
+UPDATE
+    comment_report
+SET
+    status = 'Resolved'
+WHERE
+    resolved;
+
+UPDATE
+    private_message_report
+SET
+    status = 'Resolved'
+WHERE
+    resolved;
+
+-- One row for each post, comment and private message report, so that all of them can be listed
+-- in a single queue. Rows are created by triggers.
+CREATE TABLE report_combined (
+    id serial PRIMARY KEY,
+    published timestamptz NOT NULL,
+    -- Private message reports don't have a community
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    post_report_id int UNIQUE REFERENCES post_report ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_report_id int UNIQUE REFERENCES comment_report ON UPDATE CASCADE ON DELETE CASCADE,
+    private_message_report_id int UNIQUE REFERENCES private_message_report ON UPDATE CASCADE ON DELETE CASCADE,
+    CHECK (num_nonnulls(post_report_id, comment_report_id, private_message_report_id) = 1)
+);
+
+CREATE INDEX idx_report_combined_published ON report_combined (published DESC);
+
+CREATE INDEX idx_report_combined_community ON report_combined (community_id);
+
+INSERT INTO report_combined (published, community_id, post_report_id)
+SELECT
+    pr.published,
+    p.community_id,
+    pr.id
+FROM
+    post_report AS pr
+    INNER JOIN post AS p ON pr.post_id = p.id;
+
+INSERT INTO report_combined (published, community_id, comment_report_id)
+SELECT
+    cr.published,
+    p.community_id,
+    cr.id
+FROM
+    comment_report AS cr
+    INNER JOIN comment AS c ON cr.comment_id = c.id
+    INNER JOIN post AS p ON c.post_id = p.id;
+
+INSERT INTO report_combined (published, private_message_report_id)
+SELECT
+    published,
+    id
+FROM
+    private_message_report;
@@ -0,0 +1,13 @@
+ALTER TABLE post_report
+    DROP COLUMN rule_id;
+
+ALTER TABLE comment_report
+    DROP COLUMN rule_id;
+
+ALTER TABLE mod_remove_post
+    DROP COLUMN rule_id;
+
+ALTER TABLE mod_remove_comment
+    DROP COLUMN rule_id;
+
+DROP TABLE rule;
@@ -0,0 +1,27 @@
+-- Numbered rules of a community, or of the site if community_id is null. Rules of remote
+-- communities are received with the community, and matched by their ActivityPub id when they are
+-- received again so that citations keep pointing to the same rule. Local rules don't store one.
+CREATE TABLE rule (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    position int NOT NULL,
+    title text NOT NULL,
+    description text,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz,
+    ap_id varchar(255) UNIQUE
+);
+
+CREATE INDEX idx_rule_community ON rule (community_id, position);
+
+ALTER TABLE post_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE comment_report
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE mod_remove_post
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE mod_remove_comment
+    ADD COLUMN rule_id int REFERENCES rule ON UPDATE CASCADE ON DELETE SET NULL;
@@ -4,6 +4,7 @@ use lemmy_api::{
     distinguish::distinguish_comment,
     like::like_comment,
     list_comment_likes::list_comment_likes,
+    list_revisions::list_comment_revisions,
     save::save_comment,
   },
   comment_report::{
@@ -17,36 +18,74 @@ use lemmy_api::{
     block::block_community,
     follow::follow_community,
     hide::hide_community,
+    pending_follows::{
+      approve::approve_community_pending_follow,
+      list::list_community_pending_follows,
+    },
     transfer::transfer_community,
   },
+  conversation::{
+    leave::leave_conversation,
+    mark_read::mark_conversation_as_read,
+    mute::mute_conversation,
+  },
   local_user::{
     add_admin::add_admin,
+    api_token::{create::create_api_token, revoke::revoke_api_token},
+    ban_appeal::{
+      create::create_ban_appeal,
+      list::list_ban_appeals,
+      resolve::resolve_ban_appeal,
+    },
     ban_person::ban_from_site,
     block::block_person,
     change_password::change_password,
     change_password_after_reset::change_password_after_reset,
     donation_dialog_shown::donation_dialog_shown,
+    follow::follow_person,
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
+    invite::{
+      create::create_invite,
+      delete::delete_invite,
+      list::list_invites,
+      revoke_tree::revoke_invite_tree,
+      tree::get_invite_tree,
+    },
+    keyword_filter::{create::create_keyword_filter, delete::delete_keyword_filter},
     list_banned::list_banned_users,
     list_logins::list_logins,
     list_media::list_media,
     login::login,
     logout::logout,
+    mod_note::{create::create_mod_note, delete::delete_mod_note, list::list_mod_notes},
     notifications::{
       list_mentions::list_mentions,
+      list_notifications::list_notifications,
       list_replies::list_replies,
       mark_all_read::mark_all_notifications_read,
       mark_mention_read::mark_person_mention_as_read,
+      mark_notifications_read::mark_notifications_as_read,
       mark_reply_read::mark_reply_as_read,
+      push_stream::push_stream,
       unread_count::unread_count,
     },
     report_count::report_count,
     reset_password::reset_password,
+    revoke_login::revoke_login,
+    revoke_other_logins::revoke_other_logins,
     save_settings::save_user_settings,
     update_totp::update_totp,
     validate_auth::validate_auth,
     verify_email::verify_email,
+    warn_person::warn_person,
+    webauthn::{
+      delete::delete_webauthn_credential,
+      finish_registration::finish_webauthn_registration,
+      list::list_webauthn_credentials,
+      start_login::start_webauthn_login,
+      start_registration::start_webauthn_registration,
+    },
   },
   post::{
     feature::feature_post,
@@ -54,9 +93,12 @@ use lemmy_api::{
     hide::hide_post,
     like::like_post,
     list_post_likes::list_post_likes,
+    list_revisions::list_post_revisions,
     lock::lock_post,
     mark_read::mark_post_as_read,
     save::save_post,
+    update_tags::update_post_tags,
+    vote_poll::vote_in_poll,
   },
   post_report::{
     create::create_post_report,
@@ -69,6 +111,7 @@ use lemmy_api::{
     list::list_pm_reports,
     resolve::resolve_pm_report,
   },
+  report::{assign::assign_report, list::list_reports, triage::triage_report},
   site::{
     block::block_instance,
     federated_instances::get_federated_instances,
@@ -105,11 +148,29 @@ use lemmy_api_crud::{
     remove::remove_community,
     update::update_community,
   },
+  conversation::{
+    create::create_conversation,
+    create_message::create_conversation_message,
+    list::list_conversations,
+    read::get_conversation,
+  },
   custom_emoji::{
     create::create_custom_emoji,
     delete::delete_custom_emoji,
     update::update_custom_emoji,
   },
+  multi_community::{
+    create::create_multi_community,
+    delete::delete_multi_community,
+    list::list_multi_communities,
+    read::get_multi_community,
+    update::update_multi_community,
+  },
+  oauth_provider::{
+    create::create_oauth_provider,
+    delete::delete_oauth_provider,
+    update::update_oauth_provider,
+  },
   post::{
     create::create_post,
     delete::delete_post,
@@ -123,18 +184,42 @@ use lemmy_api_crud::{
     read::get_private_message,
     update::update_private_message,
   },
+  report_category::{
+    create::create_report_category,
+    delete::delete_report_category,
+    list::list_report_categories,
+  },
+  rule::{create::create_rule, delete::delete_rule, list::list_rules, update::update_rule},
   site::{create::create_site, read::get_site, update::update_site},
-  user::{create::register, delete::delete_account},
+  tag::{create::create_tag, delete::delete_tag, list::list_community_tags, update::update_tag},
+  user::{
+    create::{authenticate_with_oauth, register},
+    delete::delete_account,
+  },
+  webhook::{
+    create::create_webhook,
+    delete::delete_webhook,
+    list::list_webhooks,
+    update::update_webhook,
+  },
 };
 use lemmy_apub::api::{
+  data_export::{
+    create_data_export,
+    download_data_export,
+    download_data_export_outbox,
+    get_data_export,
+  },
   list_comments::list_comments,
   list_posts::list_posts,
+  move_account::move_account,
   read_community::get_community,
   read_person::read_person,
   resolve_object::resolve_object,
   search::search,
   user_settings_backup::{export_settings, import_settings},
 };
+use lemmy_db_schema::{ApiTokenScope, SessionOnly};
 use lemmy_routes::images::image_proxy;
 use lemmy_utils::rate_limit::RateLimitCell;
 
@@ -147,10 +232,14 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
         web::scope("/site")
           .wrap(rate_limit.message())
           .route("", web::get().to(get_site))
+          .route("/block", web::post().to(block_instance))
           // Admin Actions
-          .route("", web::post().to(create_site))
-          .route("", web::put().to(update_site))
-          .route("/block", web::post().to(block_instance)),
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Admin)
+              .route("", web::post().to(create_site))
+              .route("", web::put().to(update_site)),
+          ),
       )
       .service(
         web::resource("/modlog")
@@ -172,23 +261,44 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
         web::resource("/community")
           .guard(guard::Post())
           .wrap(rate_limit.register())
+          .app_data(ApiTokenScope::Moderate)
           .route(web::post().to(create_community)),
       )
       .service(
         web::scope("/community")
           .wrap(rate_limit.message())
           .route("", web::get().to(get_community))
-          .route("", web::put().to(update_community))
-          .route("/hide", web::put().to(hide_community))
+          .service(
+            web::resource("/hide")
+              .app_data(ApiTokenScope::Admin)
+              .route(web::put().to(hide_community)),
+          )
           .route("/list", web::get().to(list_communities))
           .route("/follow", web::post().to(follow_community))
           .route("/block", web::post().to(block_community))
-          .route("/delete", web::post().to(delete_community))
+          .route("/tag/list", web::get().to(list_community_tags))
           // Mod Actions
-          .route("/remove", web::post().to(remove_community))
-          .route("/transfer", web::post().to(transfer_community))
-          .route("/ban_user", web::post().to(ban_from_community))
-          .route("/mod", web::post().to(add_mod_to_community)),
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Moderate)
+              .route("", web::put().to(update_community))
+              .route("/delete", web::post().to(delete_community))
+              .route("/remove", web::post().to(remove_community))
+              .route("/transfer", web::post().to(transfer_community))
+              .route("/ban_user", web::post().to(ban_from_community))
+              .route("/mod", web::post().to(add_mod_to_community))
+              .route(
+                "/pending_follows/list",
+                web::get().to(list_community_pending_follows),
+              )
+              .route(
+                "/pending_follows/approve",
+                web::post().to(approve_community_pending_follow),
+              )
+              .route("/tag", web::post().to(create_tag))
+              .route("/tag", web::put().to(update_tag))
+              .route("/tag/delete", web::post().to(delete_tag)),
+          ),
       )
       .service(
         web::scope("/federated_instances")
@@ -201,27 +311,48 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
         web::resource("/post")
           .guard(guard::Post())
           .wrap(rate_limit.post())
+          .app_data(ApiTokenScope::Post)
           .route(web::post().to(create_post)),
       )
       .service(
         web::scope("/post")
           .wrap(rate_limit.message())
           .route("", web::get().to(get_post))
-          .route("", web::put().to(update_post))
-          .route("/delete", web::post().to(delete_post))
-          .route("/remove", web::post().to(remove_post))
-          .route("/mark_as_read", web::post().to(mark_post_as_read))
-          .route("/hide", web::post().to(hide_post))
-          .route("/lock", web::post().to(lock_post))
-          .route("/feature", web::post().to(feature_post))
           .route("/list", web::get().to(list_posts))
-          .route("/like", web::post().to(like_post))
           .route("/like/list", web::get().to(list_post_likes))
-          .route("/save", web::put().to(save_post))
-          .route("/report", web::post().to(create_post_report))
-          .route("/report/resolve", web::put().to(resolve_post_report))
-          .route("/report/list", web::get().to(list_post_reports))
-          .route("/site_metadata", web::get().to(get_link_metadata)),
+          .route("/revisions", web::get().to(list_post_revisions))
+          .route("/site_metadata", web::get().to(get_link_metadata))
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Post)
+              .route("", web::put().to(update_post))
+              .route("/delete", web::post().to(delete_post))
+              .route("/mark_as_read", web::post().to(mark_post_as_read))
+              .route("/hide", web::post().to(hide_post))
+              .service(
+                web::resource("/like")
+                  .app_data(ApiTokenScope::Vote)
+                  .route(web::post().to(like_post)),
+              )
+              .service(
+                web::resource("/poll/vote")
+                  .app_data(ApiTokenScope::Vote)
+                  .route(web::post().to(vote_in_poll)),
+              )
+              .route("/tags", web::put().to(update_post_tags))
+              .route("/save", web::put().to(save_post))
+              .route("/report", web::post().to(create_post_report))
+              // Mod Actions
+              .service(
+                web::scope("")
+                  .app_data(ApiTokenScope::Moderate)
+                  .route("/remove", web::post().to(remove_post))
+                  .route("/lock", web::post().to(lock_post))
+                  .route("/feature", web::post().to(feature_post))
+                  .route("/report/list", web::get().to(list_post_reports))
+                  .route("/report/resolve", web::put().to(resolve_post_report)),
+              ),
+          ),
       )
       // Comment
       .service(
@@ -229,37 +360,106 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
         web::resource("/comment")
           .guard(guard::Post())
           .wrap(rate_limit.comment())
+          .app_data(ApiTokenScope::Comment)
           .route(web::post().to(create_comment)),
       )
       .service(
         web::scope("/comment")
           .wrap(rate_limit.message())
           .route("", web::get().to(get_comment))
-          .route("", web::put().to(update_comment))
-          .route("/delete", web::post().to(delete_comment))
-          .route("/remove", web::post().to(remove_comment))
-          .route("/mark_as_read", web::post().to(mark_reply_as_read))
-          .route("/distinguish", web::post().to(distinguish_comment))
-          .route("/like", web::post().to(like_comment))
           .route("/like/list", web::get().to(list_comment_likes))
-          .route("/save", web::put().to(save_comment))
+          .route("/revisions", web::get().to(list_comment_revisions))
           .route("/list", web::get().to(list_comments))
-          .route("/report", web::post().to(create_comment_report))
-          .route("/report/resolve", web::put().to(resolve_comment_report))
-          .route("/report/list", web::get().to(list_comment_reports)),
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Comment)
+              .route("", web::put().to(update_comment))
+              .route("/delete", web::post().to(delete_comment))
+              .route("/mark_as_read", web::post().to(mark_reply_as_read))
+              .service(
+                web::resource("/like")
+                  .app_data(ApiTokenScope::Vote)
+                  .route(web::post().to(like_comment)),
+              )
+              .route("/save", web::put().to(save_comment))
+              .route("/report", web::post().to(create_comment_report))
+              // Mod Actions
+              .service(
+                web::scope("")
+                  .app_data(ApiTokenScope::Moderate)
+                  .route("/remove", web::post().to(remove_comment))
+                  .route("/distinguish", web::post().to(distinguish_comment))
+                  .route("/report/list", web::get().to(list_comment_reports))
+                  .route("/report/resolve", web::put().to(resolve_comment_report)),
+              ),
+          ),
       )
       // Private Message
       .service(
         web::scope("/private_message")
           .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Comment)
           .route("/list", web::get().to(get_private_message))
           .route("", web::post().to(create_private_message))
           .route("", web::put().to(update_private_message))
           .route("/delete", web::post().to(delete_private_message))
           .route("/mark_as_read", web::post().to(mark_pm_as_read))
           .route("/report", web::post().to(create_pm_report))
-          .route("/report/resolve", web::put().to(resolve_pm_report))
-          .route("/report/list", web::get().to(list_pm_reports)),
+          .service(
+            web::resource("/report/resolve")
+              .app_data(ApiTokenScope::Admin)
+              .route(web::put().to(resolve_pm_report)),
+          )
+          .service(
+            web::resource("/report/list")
+              .app_data(ApiTokenScope::Admin)
+              .route(web::get().to(list_pm_reports)),
+          ),
+      )
+      // Combined queue of post, comment and private message reports
+      .service(
+        web::scope("/report")
+          .wrap(rate_limit.message())
+          .route("/category/list", web::get().to(list_report_categories))
+          .service(
+            web::scope("/category")
+              .app_data(ApiTokenScope::Admin)
+              .route("", web::post().to(create_report_category))
+              .route("/delete", web::post().to(delete_report_category)),
+          )
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Moderate)
+              .route("/list", web::get().to(list_reports))
+              .route("/assign", web::put().to(assign_report))
+              .route("/triage", web::put().to(triage_report)),
+          ),
+      )
+      // Community and site rules
+      .service(
+        web::scope("/rule")
+          .wrap(rate_limit.message())
+          .route("/list", web::get().to(list_rules))
+          .service(
+            web::scope("")
+              .app_data(ApiTokenScope::Moderate)
+              .route("", web::post().to(create_rule))
+              .route("", web::put().to(update_rule))
+              .route("/delete", web::post().to(delete_rule)),
+          ),
+      )
+      // Group conversation
+      .service(
+        web::scope("/conversation")
+          .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Comment)
+          .route("", web::get().to(get_conversation))
+          .route("", web::post().to(create_conversation))
+          .route("/list", web::get().to(list_conversations))
+          .route("/message", web::post().to(create_conversation_message))
+          .route("/mark_as_read", web::post().to(mark_conversation_as_read))
+          .route("/mute", web::post().to(mute_conversation))
+          .route("/leave", web::post().to(leave_conversation)),
       )
       // User
       .service(
@@ -281,6 +481,24 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.register())
           .route(web::post().to(login)),
       )
+      .service(
+        // Checks the password, so it needs the same rate limit as login
+        web::resource("/user/webauthn/login")
+          .wrap(rate_limit.register())
+          .route(web::post().to(start_webauthn_login)),
+      )
+      .service(
+        // Site banned users send their password here, so it needs the same rate limit as login
+        web::resource("/user/ban_appeal")
+          .guard(guard::Post())
+          .wrap(rate_limit.register())
+          .route(web::post().to(create_ban_appeal)),
+      )
+      .service(
+        web::resource("/oauth/authenticate")
+          .wrap(rate_limit.register())
+          .route(web::post().to(authenticate_with_oauth)),
+      )
       .service(
         web::resource("/user/password_reset")
           .wrap(rate_limit.register())
@@ -302,6 +520,11 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.import_user_settings())
           .route(web::post().to(import_settings)),
       )
+      .service(
+        web::resource("/user/export_data")
+          .wrap(rate_limit.import_user_settings())
+          .route(web::post().to(create_data_export)),
+      )
       // TODO, all the current account related actions under /user need to get moved here eventually
       .service(
         web::scope("/account")
@@ -319,13 +542,62 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
             web::post().to(mark_person_mention_as_read),
           )
           .route("/replies", web::get().to(list_replies))
+          .route("/notifications", web::get().to(list_notifications))
+          .route(
+            "/notifications/mark_as_read",
+            web::post().to(mark_notifications_as_read),
+          )
+          .route("/notifications/stream", web::get().to(push_stream))
           // Admin action. I don't like that it's in /user
-          .route("/ban", web::post().to(ban_from_site))
+          .service(
+            web::resource("/ban")
+              .app_data(ApiTokenScope::Admin)
+              .route(web::post().to(ban_from_site)),
+          )
           .route("/banned", web::get().to(list_banned_users))
           .route("/block", web::post().to(block_person))
+          .route("/follow", web::post().to(follow_person))
+          .route("/keyword_filter", web::post().to(create_keyword_filter))
+          .route(
+            "/keyword_filter/delete",
+            web::post().to(delete_keyword_filter),
+          )
+          .route("/invite", web::post().to(create_invite))
+          .route("/invite/list", web::get().to(list_invites))
+          .route("/invite/delete", web::post().to(delete_invite))
+          .service(
+            web::scope("/mod_note")
+              .app_data(ApiTokenScope::Moderate)
+              .route("", web::post().to(create_mod_note))
+              .route("/list", web::get().to(list_mod_notes))
+              .route("/delete", web::post().to(delete_mod_note)),
+          )
+          .service(
+            web::resource("/warn")
+              .app_data(ApiTokenScope::Moderate)
+              .route(web::post().to(warn_person)),
+          )
+          .service(
+            web::scope("/ban_appeal")
+              .app_data(ApiTokenScope::Moderate)
+              .route("/list", web::get().to(list_ban_appeals))
+              .route("/resolve", web::put().to(resolve_ban_appeal)),
+          )
           // TODO Account actions. I don't like that they're in /user maybe /accounts
           .route("/logout", web::post().to(logout))
           .route("/delete_account", web::post().to(delete_account))
+          .route("/move", web::post().to(move_account))
+          .route("/data_export", web::get().to(get_data_export))
+          .service(
+            web::resource("/data_export/download")
+              .app_data(SessionOnly)
+              .route(web::get().to(download_data_export)),
+          )
+          .service(
+            web::resource("/data_export/outbox")
+              .app_data(SessionOnly)
+              .route(web::get().to(download_data_export_outbox)),
+          )
           .route(
             "/password_change",
             web::post().to(change_password_after_reset),
@@ -343,7 +615,25 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/leave_admin", web::post().to(leave_admin))
           .route("/totp/generate", web::post().to(generate_totp_secret))
           .route("/totp/update", web::post().to(update_totp))
+          .route("/webauthn", web::get().to(list_webauthn_credentials))
+          .route(
+            "/webauthn/register",
+            web::post().to(start_webauthn_registration),
+          )
+          .route(
+            "/webauthn/register/finish",
+            web::post().to(finish_webauthn_registration),
+          )
+          .route(
+            "/webauthn/delete",
+            web::post().to(delete_webauthn_credential),
+          )
+          // API tokens can't create other tokens, as no scope is set here
+          .route("/api_token", web::post().to(create_api_token))
+          .route("/api_token/revoke", web::post().to(revoke_api_token))
           .route("/list_logins", web::get().to(list_logins))
+          .route("/revoke_login", web::post().to(revoke_login))
+          .route("/revoke_other_logins", web::post().to(revoke_other_logins))
           .route("/validate_auth", web::get().to(validate_auth))
           .route(
             "/donation_dialog_shown",
@@ -354,6 +644,7 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
       .service(
         web::scope("/admin")
           .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Admin)
           .route("/add", web::post().to(add_admin))
           .route(
             "/registration_application/count",
@@ -372,6 +663,8 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
             web::get().to(get_registration_application),
           )
           .route("/list_all_media", web::get().to(list_all_media))
+          .route("/invite/tree", web::get().to(get_invite_tree))
+          .route("/invite/revoke_tree", web::post().to(revoke_invite_tree))
           .service(
             web::scope("/purge")
               .route("/person", web::post().to(purge_person))
@@ -383,9 +676,36 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
       .service(
         web::scope("/custom_emoji")
           .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Admin)
           .route("", web::post().to(create_custom_emoji))
           .route("", web::put().to(update_custom_emoji))
           .route("/delete", web::post().to(delete_custom_emoji)),
+      )
+      .service(
+        web::scope("/oauth_provider")
+          .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Admin)
+          .route("", web::post().to(create_oauth_provider))
+          .route("", web::put().to(update_oauth_provider))
+          .route("/delete", web::post().to(delete_oauth_provider)),
+      )
+      .service(
+        web::scope("/multi_community")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_multi_community))
+          .route("", web::put().to(update_multi_community))
+          .route("", web::get().to(get_multi_community))
+          .route("/delete", web::post().to(delete_multi_community))
+          .route("/list", web::get().to(list_multi_communities)),
+      )
+      .service(
+        web::scope("/webhook")
+          .wrap(rate_limit.message())
+          .app_data(ApiTokenScope::Moderate)
+          .route("", web::post().to(create_webhook))
+          .route("", web::put().to(update_webhook))
+          .route("/delete", web::post().to(delete_webhook))
+          .route("/list", web::get().to(list_webhooks)),
       ),
   );
   cfg.service(
@@ -27,6 +27,7 @@ use clap::Parser;
 use lemmy_api_common::{
   context::LemmyContext,
   lemmy_db_views::structs::SiteView,
+  push::PushChannel,
   request::client_builder,
   send_activity::{ActivityChannel, MATCH_OUTGOING_ACTIVITIES},
   utils::local_site_rate_limit_to_rate_limit_config,
@@ -169,11 +170,6 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     rate_limit_cell.clone(),
   );
 
-  let scheduled_tasks = (!args.disable_scheduled_tasks).then(|| {
-    // Schedules various cleanup tasks for the DB
-    tokio::task::spawn(scheduled_tasks::setup(context.clone()))
-  });
-
   if let Some(prometheus) = SETTINGS.prometheus.clone() {
     serve_prometheus(prometheus, context.clone())?;
   }
@@ -199,6 +195,10 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     }))
     .expect("set function pointer");
   let request_data = federation_config.to_request_data();
+  let scheduled_tasks = (!args.disable_scheduled_tasks).then(|| {
+    // Schedules various cleanup tasks for the DB, and publishes scheduled posts
+    tokio::task::spawn(scheduled_tasks::setup(request_data.reset_request_count()))
+  });
   let outgoing_activities_task = tokio::task::spawn(handle_outgoing_activities(request_data));
 
   let server = if !args.disable_http_server {
@@ -206,6 +206,9 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
       startup_server_handle.stop(true).await;
     }
 
+    // Receives new content from all server processes for the push stream
+    tokio::task::spawn(PushChannel::listen());
+
     Some(create_http_server(
       federation_config.clone(),
       SETTINGS.clone(),
@@ -1,7 +1,9 @@
+use activitypub_federation::config::Data;
 use chrono::{DateTime, TimeZone, Utc};
 use clokwerk::{AsyncScheduler, TimeUnits as CTimeUnits};
 use diesel::{
-  dsl::IntervalDsl,
+  dsl::{exists, not, IntervalDsl},
+  BoolExpressionMethods,
   sql_query,
   sql_types::{Integer, Timestamptz},
   ExpressionMethods,
@@ -10,22 +12,34 @@ use diesel::{
   QueryableByName,
 };
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api::local_user::warn_person::escalate_strikes;
+use lemmy_api_common::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::send_webmention,
+};
 use lemmy_db_schema::{
   schema::{
     captcha_answer,
     comment,
+    comment_revision,
+    community,
     community_person_ban,
     instance,
     person,
     post,
+    post_revision,
     received_activity,
     sent_activity,
   },
   source::{
+    community::Community,
     instance::{Instance, InstanceForm},
     local_user::LocalUser,
+    login_token::LoginToken,
+    post::{Post, PostUpdateForm},
   },
+  traits::Crud,
   utils::{get_conn, naive_now, now, DbPool, DELETED_REPLACEMENT_TEXT},
 };
 use lemmy_routes::nodeinfo::{NodeInfo, NodeInfoWellKnown};
@@ -35,18 +49,22 @@ use std::time::Duration;
 use tracing::{error, info, warn};
 
 /// Schedules various cleanup tasks for lemmy in a background thread
-pub async fn setup(context: LemmyContext) -> LemmyResult<()> {
+pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // https://github.com/mdsherry/clokwerk/issues/38
   let mut scheduler = AsyncScheduler::with_tz(Utc);
 
-  let context_1 = context.clone();
-  // Update active counts every hour
+  let context_1 = context.reset_request_count();
+  // Update active counts, expired bans and bans for strikes every hour
   scheduler.every(CTimeUnits::hour(1)).run(move || {
-    let context = context_1.clone();
+    let context = context_1.reset_request_count();
 
     async move {
       active_counts(&mut context.pool(), ONE_DAY).await;
       update_banned_when_expired(&mut context.pool()).await;
+      escalate_strikes(&context)
+        .await
+        .map_err(|e| error!("Failed to escalate strikes: {e}"))
+        .ok();
     }
   });
 
@@ -60,6 +78,19 @@ pub async fn setup(context: LemmyContext) -> LemmyResult<()> {
     }
   });
 
+  let context_1 = context.reset_request_count();
+  // Publish scheduled posts every minute
+  scheduler.every(CTimeUnits::minutes(1)).run(move || {
+    let context = context_1.reset_request_count();
+
+    async move {
+      publish_scheduled_posts(&context)
+        .await
+        .map_err(|e| error!("Failed to publish scheduled posts: {e}"))
+        .ok();
+    }
+  });
+
   let context_1 = context.clone();
   // Delete any captcha answers older than ten minutes, every ten minutes
   scheduler.every(CTimeUnits::minutes(10)).run(move || {
@@ -74,6 +105,7 @@ pub async fn setup(context: LemmyContext) -> LemmyResult<()> {
   // Daily tasks:
   // - Overwrite deleted & removed posts and comments every day
   // - Delete old denied users
+  // - Delete idle logins and expired API tokens
   // - Update instance software
   scheduler.every(CTimeUnits::days(1)).run(move || {
     let context = context_1.clone();
@@ -82,6 +114,7 @@ pub async fn setup(context: LemmyContext) -> LemmyResult<()> {
       all_active_counts(&mut context.pool(), ALL_ACTIVE_INTERVALS).await;
       overwrite_deleted_posts_and_comments(&mut context.pool()).await;
       delete_old_denied_users(&mut context.pool()).await;
+      delete_idle_logins(&mut context.pool()).await;
       update_instance_software(&mut context.pool(), context.client())
         .await
         .map_err(|e| warn!("Failed to update instance software: {e}"))
@@ -310,7 +343,15 @@ async fn delete_old_denied_users(pool: &mut DbPool<'_>) {
     .ok();
 }
 
-/// overwrite posts and comments 30d after deletion
+async fn delete_idle_logins(pool: &mut DbPool<'_>) {
+  LoginToken::delete_idle(pool)
+    .await
+    .map(|count| info!("Deleted {count} idle logins."))
+    .map_err(|e| error!("Failed to delete idle logins: {e}"))
+    .ok();
+}
+
+/// overwrite posts and comments 30d after deletion, and delete their previous versions
 async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) {
   info!("Overwriting deleted posts...");
   let conn = get_conn(pool).await;
@@ -350,6 +391,40 @@ async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) {
       })
       .map_err(|e| error!("Failed to overwrite deleted comments: {e}"))
       .ok();
+
+      info!("Deleting revisions of deleted posts and comments...");
+      diesel::delete(
+        post_revision::table.filter(
+          post_revision::post_id.eq_any(
+            post::table
+              .filter(post::deleted.eq(true))
+              .filter(post::updated.lt(now().nullable() - 1.months()))
+              .select(post::id),
+          ),
+        ),
+      )
+      .execute(&mut conn)
+      .await
+      .map_err(|e| error!("Failed to delete revisions of deleted posts: {e}"))
+      .ok();
+
+      diesel::delete(
+        comment_revision::table.filter(
+          comment_revision::comment_id.eq_any(
+            comment::table
+              .filter(comment::deleted.eq(true))
+              .filter(comment::updated.lt(now().nullable() - 1.months()))
+              .select(comment::id),
+          ),
+        ),
+      )
+      .execute(&mut conn)
+      .await
+      .map(|_| {
+        info!("Done.");
+      })
+      .map_err(|e| error!("Failed to delete revisions of deleted comments: {e}"))
+      .ok();
     }
     Err(e) => {
       error!("Failed to get connection from pool: {e}");
@@ -539,6 +614,43 @@ async fn update_banned_when_expired(pool: &mut DbPool<'_>) {
   }
 }
 
+/// Publishes posts whose scheduled time has arrived, and federates them. Posts which can't be
+/// published currently, eg because the creator is banned, stay scheduled.
+async fn publish_scheduled_posts(context: &Data<LemmyContext>) -> LemmyResult<()> {
+  let pool = &mut context.pool();
+  let scheduled_posts = {
+    let mut conn = get_conn(pool).await?;
+    post::table
+      .inner_join(community::table)
+      .inner_join(person::table)
+      .filter(post::scheduled_publish_time.lt(now().nullable()))
+      .filter(not(post::deleted.or(post::removed)))
+      .filter(not(person::banned.or(person::deleted)))
+      .filter(not(community::deleted.or(community::removed)))
+      .filter(not(exists(
+        community_person_ban::table
+          .filter(community_person_ban::community_id.eq(post::community_id))
+          .filter(community_person_ban::person_id.eq(post::creator_id)),
+      )))
+      .select((post::all_columns, community::all_columns))
+      .load::<(Post, Community)>(&mut conn)
+      .await?
+  };
+
+  for (post, community) in scheduled_posts {
+    let form = PostUpdateForm {
+      scheduled_publish_time: Some(None),
+      published: Some(Utc::now()),
+      ..Default::default()
+    };
+    let post = Post::update(pool, post.id, &form).await?;
+
+    ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), context).await?;
+    send_webmention(post, &community);
+  }
+  Ok(())
+}
+
 /// Updates the instance software and version.
 ///
 /// Does so using the /.well-known/nodeinfo protocol described here:
@@ -639,8 +751,18 @@ async fn build_update_instance_form(
 #[allow(clippy::indexing_slicing)]
 mod tests {
 
-  use crate::scheduled_tasks::build_update_instance_form;
-  use lemmy_api_common::request::client_builder;
+  use crate::scheduled_tasks::{build_update_instance_form, publish_scheduled_posts};
+  use chrono::{TimeDelta, Utc};
+  use lemmy_api_common::{context::LemmyContext, request::client_builder};
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+  };
   use lemmy_utils::{error::LemmyResult, settings::structs::Settings, LemmyErrorType};
   use pretty_assertions::assert_eq;
   use reqwest_middleware::ClientBuilder;
@@ -673,4 +795,52 @@ mod tests {
     );
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_publish_scheduled_posts() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(instance.id, "scheduler");
+    let person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::builder()
+      .name("test community scheduled".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(instance.id)
+      .build();
+    let community = Community::create(pool, &community_form).await?;
+
+    let past = Utc::now() - TimeDelta::minutes(1);
+    let mut posts = vec![];
+    for scheduled_publish_time in [past, Utc::now() + TimeDelta::hours(1)] {
+      let post_form = PostInsertForm::builder()
+        .name("scheduled post".into())
+        .creator_id(person.id)
+        .community_id(community.id)
+        .scheduled_publish_time(Some(scheduled_publish_time))
+        .build();
+      posts.push(Post::create(pool, &post_form).await?);
+    }
+
+    publish_scheduled_posts(&context).await?;
+
+    let due_post = Post::read(pool, posts[0].id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPost)?;
+    assert_eq!(None, due_post.scheduled_publish_time);
+    assert!(due_post.published > posts[0].published);
+
+    let later_post = Post::read(pool, posts[1].id)
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindPost)?;
+    assert!(later_post.scheduled_publish_time.is_some());
+
+    Person::delete(pool, person.id).await?;
+    Community::delete(pool, community.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
 }
@@ -72,8 +72,10 @@ where
         //       to add a separate endpoint for that.
         //       https://github.com/LemmyNet/lemmy/issues/3702
         let local_user_view = local_user_view_from_jwt(jwt, &context).await.ok();
-        if let Some(local_user_view) = local_user_view {
+        if let Some((local_user_view, login_token)) = local_user_view {
           req.extensions_mut().insert(local_user_view);
+          // Needed to check the scopes of API tokens
+          req.extensions_mut().insert(login_token);
         }
       }
 